@@ -0,0 +1,41 @@
+//! Throughput benchmarks for parsing, exporting, and property lookup.
+//!
+//! Uses a small std timing harness rather than an external benchmark framework, run with `cargo bench`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use vcard_parser::parse_vcards;
+
+const SINGLE: &str = include_str!("../tests/assets/single.vcf");
+const CONCAT: &str = include_str!("../tests/assets/concat.vcf");
+
+fn main() {
+    let many = SINGLE.repeat(10_000);
+    let vcards = parse_vcards(SINGLE).expect("Unable to parse vCard.");
+    let vcard = vcards.first().expect("Missing vCard.");
+
+    bench("parse single card", SINGLE.len(), || parse_vcards(black_box(SINGLE)).unwrap());
+    bench("parse folded card", CONCAT.len(), || parse_vcards(black_box(CONCAT)).unwrap());
+    bench("parse 10k cards", many.len(), || parse_vcards(black_box(many.as_str())).unwrap());
+    bench("export single card", SINGLE.len(), || black_box(vcard).export());
+    bench("property lookup", 0, || black_box(vcard).get_properties_by_name(black_box("TEL")));
+}
+
+/// Helper function running a closure repeatedly for about a second and printing the mean time per iteration.
+fn bench<T, F: FnMut() -> T>(name: &str, bytes: usize, mut f: F) {
+    let start = Instant::now();
+    let mut iterations = 0u32;
+
+    while start.elapsed() < Duration::from_secs(1) || iterations < 10 {
+        black_box(f());
+        iterations += 1;
+    }
+
+    let mean = start.elapsed() / iterations;
+    if bytes > 0 {
+        println!("{:<20} {:>12.3?}/iter {:>10.1} MiB/s", name, mean, bytes as f64 / mean.as_secs_f64() / (1024.0 * 1024.0));
+    } else {
+        println!("{:<20} {:>12.3?}/iter", name, mean);
+    }
+}
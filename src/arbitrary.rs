@@ -0,0 +1,270 @@
+//! Random generation of valid vCards for property-based and round-trip testing.
+//!
+//! Enabled with the `arbitrary` feature. [`Arbitrary`] is implemented for [`Property`] and [`Vcard`] using the
+//! [property specs](PropertySpec), so generated properties cover every registered property, value type and
+//! parameter. Generation is driven by a seeded [`Generator`], so a failing case can be reproduced from its seed.
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::arbitrary::{Arbitrary, Generator};
+//! use vcard_parser::vcard::Vcard;
+//!
+//! for seed in 0..32 {
+//!     let vcard = Vcard::arbitrary(&mut Generator::new(seed));
+//!     let export = vcard.export();
+//!     assert_eq!(Vcard::try_from(export.as_str()).expect("Unable to parse vCard.").export(), export);
+//! }
+//! ```
+
+use crate::constants::{ParameterName, PropertyName, ValueName};
+use crate::parse::encoding::escape;
+use crate::vcard::property::PropertySpec;
+use crate::vcard::Vcard;
+use crate::Property;
+
+/// Maximum number of attempts at generating a valid property before falling back to the property default.
+const PROPERTY_ATTEMPTS: usize = 8;
+
+/// Characters used for generated words, including characters that must be escaped in TEXT values.
+const TEXT_CHARS: [char; 16] = [
+    'a', 'e', 'k', 'o', 's', 'z', 'A', 'Q', '0', '7', ' ', 'é', '漢', ',', ';', '\n',
+];
+
+/// A small deterministic pseudo-random number generator, see [SplitMix64](https://prng.di.unimi.it/splitmix64.c).
+#[derive(Clone, Debug)]
+pub struct Generator {
+    state: u64,
+}
+
+impl Generator {
+    /// Create a generator from a seed, equal seeds generate equal values.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Get the next random number.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Get a random number in the range `0..n`, `n` must not be zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Get a random boolean which is true with the probability `numerator / denominator`.
+    pub fn ratio(&mut self, numerator: usize, denominator: usize) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    /// Choose a random item from a non-empty slice.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
+
+/// Generate a random valid instance of a type.
+pub trait Arbitrary: Sized {
+    fn arbitrary(generator: &mut Generator) -> Self;
+}
+
+impl Arbitrary for Property {
+    /// Generate a random property of any registered type except CLIENTPIDMAP, without PID parameters.
+    ///
+    /// Properties are generated as content lines from their [spec](PropertySpec) and parsed, lines failing
+    /// property specific validation are regenerated.
+    fn arbitrary(generator: &mut Generator) -> Self {
+        let specs: Vec<&PropertySpec> = PropertySpec::ALL.iter().copied().filter(|spec| spec.name != PropertyName::CLIENTPIDMAP).collect();
+        let spec = *generator.choose(specs.as_slice());
+
+        for _ in 0..PROPERTY_ATTEMPTS {
+            if let Ok(property) = Property::try_from(arbitrary_content_line(generator, spec).as_str()) {
+                return property;
+            }
+        }
+
+        Property::default(spec.name)
+    }
+}
+
+impl Arbitrary for Vcard {
+    /// Generate a random vCard with a random FN and up to 12 other properties.
+    fn arbitrary(generator: &mut Generator) -> Self {
+        let mut vcard = Vcard::new(arbitrary_word(generator).trim());
+
+        for _ in 0..generator.below(13) {
+            vcard.set_property(&Property::arbitrary(generator)).ok();
+        }
+
+        vcard
+    }
+}
+
+/// Helper function generating a content line for a property spec.
+fn arbitrary_content_line(generator: &mut Generator, spec: &PropertySpec) -> String {
+    let mut string = String::from(spec.name);
+
+    // Values other than the first need a VALUE parameter to be parsed back to the same value type.
+    let index = generator.below(spec.values.len());
+    let value = spec.values[index];
+    if index > 0 {
+        if let Some(value_types) = value.value_types {
+            string.push_str(format!(";VALUE={}", generator.choose(value_types)).as_str());
+        }
+    }
+
+    for parameter in spec.parameters.iter() {
+        if generator.ratio(1, 4) {
+            if let Some(parameter_value) = arbitrary_parameter_value(generator, spec, parameter) {
+                string.push_str(format!(";{}={}", parameter, parameter_value).as_str());
+            }
+        }
+    }
+
+    string.push(':');
+    if spec.name == PropertyName::GEO {
+        string.push_str(format!("geo:{}.{},{}.{}", generator.below(90), generator.below(1000), generator.below(180), generator.below(1000)).as_str());
+    } else {
+        string.push_str(arbitrary_value(generator, value.name).as_str());
+    }
+    string.push('\n');
+
+    string
+}
+
+/// Helper function generating a parameter value, returning None for parameters which aren't generated.
+fn arbitrary_parameter_value(generator: &mut Generator, spec: &PropertySpec, name: &str) -> Option<String> {
+    let value = match name {
+        ParameterName::ALTID => generator.below(10).to_string(),
+        ParameterName::CALSCALE => String::from("gregorian"),
+        ParameterName::CC => generator.choose(&["US", "CA", "DE", "JP"]).to_string(),
+        ParameterName::GEO => format!("\"geo:{}.{},{}.{}\"", generator.below(90), generator.below(1000), generator.below(180), generator.below(1000)),
+        ParameterName::INDEX => (generator.below(10) + 1).to_string(),
+        ParameterName::LABEL => format!("\"{}\"", arbitrary_plain_word(generator)),
+        ParameterName::LANGUAGE => generator
+            .choose(&[
+                "en",
+                "en-US",
+                "fr-CA",
+                "de",
+                "zh-Hant-TW",
+            ])
+            .to_string(),
+        ParameterName::LEVEL => generator.choose(spec.levels?).to_lowercase(),
+        ParameterName::MEDIATYPE => generator
+            .choose(&[
+                "image/png",
+                "audio/mpeg",
+                "text/plain",
+            ])
+            .to_string(),
+        ParameterName::PREF => (generator.below(100) + 1).to_string(),
+        ParameterName::SORTAS => format!("\"{}\"", arbitrary_plain_word(generator)),
+        ParameterName::TYPE => generator
+            .choose(&[
+                "home", "work", "cell", "voice", "text",
+            ])
+            .to_string(),
+        ParameterName::TZ => generator
+            .choose(&[
+                "America/New_York",
+                "Europe/Berlin",
+                "Asia/Tokyo",
+            ])
+            .to_string(),
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+/// Helper function generating a raw value string for a value name.
+fn arbitrary_value(generator: &mut Generator, name: &str) -> String {
+    match name {
+        ValueName::BOOLEAN => generator.choose(&["TRUE", "FALSE"]).to_string(),
+        ValueName::DATE => format!("{:04}{:02}{:02}", 1900 + generator.below(200), generator.below(12) + 1, generator.below(28) + 1),
+        ValueName::FLOAT => format!("{}.{}", generator.below(1000), generator.below(1000)),
+        ValueName::INTEGER => (generator.next_u64() as i64).to_string(),
+        ValueName::LANGUAGE_TAG => generator
+            .choose(&[
+                "en",
+                "en-US",
+                "fr-CA",
+                "de",
+                "zh-Hant-TW",
+            ])
+            .to_string(),
+        ValueName::LISTCOMPONENT => (0..generator.below(7) + 1).map(|_| (0..generator.below(3)).map(|_| arbitrary_plain_word(generator)).collect::<Vec<String>>().join(",")).collect::<Vec<String>>().join(";"),
+        ValueName::PID => format!("{}.{}", generator.below(10) + 1, generator.below(10) + 1),
+        ValueName::TEXTLIST => (0..generator.below(4) + 1).map(|_| escape(arbitrary_word(generator).as_str())).collect::<Vec<String>>().join(","),
+        ValueName::TIMESTAMP => format!("{:04}{:02}{:02}T{:02}{:02}{:02}Z", 1970 + generator.below(100), generator.below(12) + 1, generator.below(28) + 1, generator.below(24), generator.below(60), generator.below(60)),
+        ValueName::URI => match generator.below(5) {
+            0 => format!("https://example.com/{}", arbitrary_plain_word(generator).replace(' ', "-")),
+            1 => format!("mailto:{}@example.com", generator.below(1000)),
+            2 => format!("tel:+1-555-555-{:04}", generator.below(10000)),
+            3 => format!("geo:{}.{},{}.{}", generator.below(90), generator.below(1000), generator.below(180), generator.below(1000)),
+            _ => format!("urn:uuid:{:016x}", generator.next_u64()),
+        },
+        ValueName::UTCOFFSET => format!("{}{:02}{:02}", generator.choose(&["+", "-"]), generator.below(13), generator.choose(&[0, 30, 45])),
+        _ => escape(arbitrary_word(generator).as_str()),
+    }
+}
+
+/// Helper function generating a word which may contain characters that must be escaped.
+fn arbitrary_word(generator: &mut Generator) -> String {
+    (0..generator.below(12) + 1).map(|_| *generator.choose(&TEXT_CHARS)).collect()
+}
+
+/// Helper function generating a word without any characters that must be escaped or quoted.
+fn arbitrary_plain_word(generator: &mut Generator) -> String {
+    (0..generator.below(12) + 1).map(|_| *generator.choose(&TEXT_CHARS[..13])).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::arbitrary::{Arbitrary, Generator};
+    use crate::vcard::property::{Property, PropertySpec};
+    use crate::vcard::Vcard;
+    use crate::HasName;
+
+    #[test]
+    fn arbitrary_generator() {
+        let a: Vec<u64> = (0..8).map(|_| Generator::new(1).next_u64()).collect();
+        assert!(a.iter().all(|n| *n == a[0]));
+
+        let mut generator = Generator::new(2);
+        assert!((0..1000).all(|_| generator.below(7) < 7));
+
+        let mut generator = Generator::new(3);
+        let mut names: Vec<String> = (0..2000).map(|_| Property::arbitrary(&mut generator).name().to_string()).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), PropertySpec::ALL.len() - 1);
+    }
+
+    #[test]
+    fn arbitrary_round_trip_property() {
+        let mut generator = Generator::new(4);
+
+        for _ in 0..2000 {
+            let property = Property::arbitrary(&mut generator);
+            let string = property.to_string();
+            let parsed = Property::try_from(string.as_str()).unwrap_or_else(|err| panic!("Unable to parse {:?}: {}", string, err));
+            assert_eq!(parsed.to_string(), string);
+        }
+    }
+
+    #[test]
+    fn arbitrary_round_trip_vcard() {
+        for seed in 0..500 {
+            let vcard = Vcard::arbitrary(&mut Generator::new(seed));
+            let export = vcard.export();
+            let parsed = Vcard::try_from(export.as_str()).unwrap_or_else(|err| panic!("Unable to parse seed {} {:?}: {}", seed, export, err));
+            assert_eq!(parsed.export(), export, "Round trip failed for seed {}.", seed);
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! Arena-backed vCards for bulk processing.
+//!
+//! [`VcardArena`] keeps the content lines of all vCards of a file in one string buffer, with each group, name,
+//! parameter and value stored as a range into it. Parsing a file allocates a handful of growing buffers instead of
+//! strings for every property, which suits read-heavy jobs that parse, filter and re-export many vCards. Values are
+//! unfolded but kept as written, use [`ArenaVcard::to_vcard`] to get a typed [Vcard](Vcard) when needed.
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::arena::VcardArena;
+//!
+//! let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL:john@example.com\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nEND:VCARD\n";
+//! let mut arena = VcardArena::try_from(input).expect("Unable to parse text.");
+//! assert_eq!(arena.len(), 2);
+//!
+//! arena.retain(|vcard| vcard.value("EMAIL").is_some());
+//! assert_eq!(arena.get(0).and_then(|vcard| vcard.value("FN")), Some("John Doe"));
+//! assert_eq!(arena.to_string(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL:john@example.com\nEND:VCARD\n");
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::ops::Range;
+
+use crate::parse::value::utf8_to_str;
+use crate::parse::{Data, PropertyData};
+use crate::vcard::Vcard;
+use crate::{parse, VcardError};
+
+/// The vCards of a file with all content line strings stored in one buffer, see the [module](self) documentation.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VcardArena {
+    buffer: String,
+    parameters: Vec<(Range<usize>, Range<usize>)>,
+    lines: Vec<LineRanges>,
+    vcards: Vec<Range<usize>>,
+}
+
+/// Ranges of a content line, into the buffer for strings and into the parameters for parameters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct LineRanges {
+    group: Option<Range<usize>>,
+    name: Range<usize>,
+    parameters: Range<usize>,
+    value: Range<usize>,
+}
+
+impl VcardArena {
+    /// Get the number of vCards.
+    pub fn len(&self) -> usize {
+        self.vcards.len()
+    }
+
+    /// Check if there are no vCards.
+    pub fn is_empty(&self) -> bool {
+        self.vcards.is_empty()
+    }
+
+    /// Get a vCard by index.
+    pub fn get(&self, index: usize) -> Option<ArenaVcard<'_>> {
+        self.vcards.get(index).map(|lines| ArenaVcard {
+            arena: self,
+            lines: lines.clone(),
+        })
+    }
+
+    /// Iterate over the vCards.
+    pub fn iter(&self) -> impl Iterator<Item = ArenaVcard<'_>> {
+        self.vcards.iter().map(|lines| ArenaVcard {
+            arena: self,
+            lines: lines.clone(),
+        })
+    }
+
+    /// Keep only the vCards matching a predicate. The strings of removed vCards stay in the buffer until the arena is
+    /// dropped.
+    pub fn retain<F: FnMut(&ArenaVcard) -> bool>(&mut self, mut predicate: F) {
+        let vcards = std::mem::take(&mut self.vcards);
+        self.vcards = vcards
+            .into_iter()
+            .filter(|lines| {
+                predicate(&ArenaVcard {
+                    arena: self,
+                    lines: lines.clone(),
+                })
+            })
+            .collect();
+    }
+
+    /// Write all vCards to a writer, flushing it when done.
+    pub fn write<W: Write>(&self, w: &mut W) -> std::io::Result<()> {
+        for vcard in self.iter() {
+            write!(w, "{}", vcard)?;
+        }
+        w.flush()
+    }
+
+    /// Helper function copying a string into the buffer and returning its range.
+    fn push_str(&mut self, str: &str) -> Range<usize> {
+        let start = self.buffer.len();
+        self.buffer.push_str(str);
+        start..self.buffer.len()
+    }
+
+    /// Helper function copying a parsed content line into the arena.
+    fn push_line(&mut self, ((group, name), parameters, (value, folds)): PropertyData) -> Result<(), VcardError> {
+        let group = group.map(|group| Ok::<_, VcardError>(self.push_str(utf8_to_str(group)?))).transpose()?;
+        let name = self.push_str(utf8_to_str(name)?);
+
+        let start = self.parameters.len();
+        for (parameter_name, parameter_value) in parameters {
+            let parameter_name = self.push_str(utf8_to_str(parameter_name)?);
+            let parameter_value = self.push_str(utf8_to_str(parameter_value)?);
+            self.parameters.push((parameter_name, parameter_value));
+        }
+
+        let value_start = self.buffer.len();
+        self.buffer.push_str(utf8_to_str(value)?);
+        for fold in folds.unwrap_or_default() {
+            self.buffer.push_str(utf8_to_str(fold)?);
+        }
+
+        self.lines.push(LineRanges {
+            group,
+            name,
+            parameters: start..self.parameters.len(),
+            value: value_start..self.buffer.len(),
+        });
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&str> for VcardArena {
+    type Error = VcardError;
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        Self::try_from(str.as_bytes())
+    }
+}
+
+impl TryFrom<Data<'_>> for VcardArena {
+    type Error = VcardError;
+    fn try_from(bytes: Data<'_>) -> Result<Self, Self::Error> {
+        let mut arena = Self {
+            buffer: String::with_capacity(bytes.len()),
+            ..Self::default()
+        };
+
+        for data in parse::vcard::vcards(bytes)?.1 {
+            let start = arena.lines.len();
+            for datum in data {
+                arena.push_line(datum)?;
+            }
+            arena.vcards.push(start..arena.lines.len());
+        }
+
+        Ok(arena)
+    }
+}
+
+impl Display for VcardArena {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.iter().try_for_each(|vcard| write!(f, "{}", vcard))
+    }
+}
+
+/// A vCard borrowed from a [VcardArena](VcardArena).
+#[derive(Clone, Debug)]
+pub struct ArenaVcard<'a> {
+    arena: &'a VcardArena,
+    lines: Range<usize>,
+}
+
+impl<'a> ArenaVcard<'a> {
+    /// Iterate over the content lines, excluding BEGIN, VERSION and END.
+    pub fn lines(&self) -> impl Iterator<Item = ArenaLine<'a>> + 'a {
+        let arena = self.arena;
+        arena.lines[self.lines.clone()].iter().map(move |line| ArenaLine { arena, line })
+    }
+
+    /// Iterate over the content lines with a name, the name is compared case-insensitively.
+    pub fn lines_by_name<'b>(&self, name: &'b str) -> impl Iterator<Item = ArenaLine<'a>> + 'b
+    where
+        'a: 'b,
+    {
+        self.lines().filter(move |line| line.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Get the written value of the first content line with a name, the name is compared case-insensitively.
+    pub fn value(&self, name: &str) -> Option<&'a str> {
+        self.lines_by_name(name).next().map(|line| line.value())
+    }
+
+    /// Parse the vCard into a typed [Vcard](Vcard).
+    pub fn to_vcard(&self) -> Result<Vcard, VcardError> {
+        Vcard::try_from(self.to_string().as_str())
+    }
+}
+
+impl Display for ArenaVcard<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "BEGIN:VCARD\nVERSION:4.0")?;
+        for line in self.lines() {
+            writeln!(f, "{}", line)?;
+        }
+        writeln!(f, "END:VCARD")
+    }
+}
+
+/// A content line borrowed from a [VcardArena](VcardArena), kept as written like a [ContentLine](crate::parse::ContentLine).
+#[derive(Clone, Copy, Debug)]
+pub struct ArenaLine<'a> {
+    arena: &'a VcardArena,
+    line: &'a LineRanges,
+}
+
+impl<'a> ArenaLine<'a> {
+    /// Get the group.
+    pub fn group(&self) -> Option<&'a str> {
+        self.line.group.clone().map(|range| &self.arena.buffer[range])
+    }
+
+    /// Get the name as written.
+    pub fn name(&self) -> &'a str {
+        &self.arena.buffer[self.line.name.clone()]
+    }
+
+    /// Iterate over the parameter names and written values.
+    pub fn parameters(&self) -> impl Iterator<Item = (&'a str, &'a str)> + 'a {
+        let buffer = self.arena.buffer.as_str();
+        self.arena.parameters[self.line.parameters.clone()].iter().map(move |(name, value)| (&buffer[name.clone()], &buffer[value.clone()]))
+    }
+
+    /// Get the written value of the first parameter with a name, the name is compared case-insensitively.
+    pub fn parameter(&self, name: &str) -> Option<&'a str> {
+        self.parameters().find(|(other, _)| other.eq_ignore_ascii_case(name)).map(|(_, value)| value)
+    }
+
+    /// Get the value, unfolded but not unescaped.
+    pub fn value(&self) -> &'a str {
+        &self.arena.buffer[self.line.value.clone()]
+    }
+}
+
+impl Display for ArenaLine<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(group) = self.group() {
+            write!(f, "{}.", group)?;
+        }
+        write!(f, "{}", self.name())?;
+        for (name, value) in self.parameters() {
+            write!(f, ";{}={}", name, value)?;
+        }
+        write!(f, ":{}", self.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::arena::VcardArena;
+    use crate::HasValue;
+
+    #[test]
+    pub fn arena() {
+        let input = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:John Doe\r\nitem1.TEL;TYPE=\"work,voice\";VALUE=uri:tel:+1-555-\r\n 555-5555\r\nNOTE:a\\, b\r\nEND:VCARD\r\n";
+        let arena = VcardArena::try_from(input).unwrap();
+        let vcard = arena.get(0).unwrap();
+
+        let tel = vcard.lines_by_name("tel").next().unwrap();
+        assert_eq!(tel.group(), Some("item1"));
+        assert_eq!(
+            tel.parameters().collect::<Vec<_>>(),
+            [
+                ("TYPE", "\"work,voice\""),
+                ("VALUE", "uri")
+            ]
+        );
+        assert_eq!(tel.parameter("value"), Some("uri"));
+        assert_eq!(tel.value(), "tel:+1-555-555-5555");
+        assert_eq!(vcard.value("NOTE"), Some("a\\, b"));
+        assert_eq!(vcard.lines().count(), 3);
+        assert!(arena.get(1).is_none());
+
+        let typed = vcard.to_vcard().unwrap();
+        assert_eq!(typed.display_name(), "John Doe");
+        assert_eq!(typed.get_properties_by_name("NOTE")[0].get_value().as_str(), Some("a, b"));
+
+        let mut written = Vec::new();
+        arena.write(&mut written).unwrap();
+        assert_eq!(String::from_utf8(written).unwrap(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nitem1.TEL;TYPE=\"work,voice\";VALUE=uri:tel:+1-555-555-5555\nNOTE:a\\, b\nEND:VCARD\n");
+
+        assert!(VcardArena::try_from("BEGIN:VCARD\nEND:VCARD\n").is_err());
+        assert!(VcardArena::try_from(b"BEGIN:VCARD\nVERSION:4.0\nFN:Jos\xe9\nEND:VCARD\n".as_slice()).is_err());
+    }
+}
@@ -0,0 +1,74 @@
+//! Lints vcf files, printing issues as `file:line: severity: message (reference)`.
+//!
+//! Usage: `vcard-lint [--fix] [--strict] [--deny-warnings] <FILE>...`
+//!
+//! With `--fix` fixable issues are repaired in place before linting. Exits with status 1 if any error is found,
+//! or any warning with `--deny-warnings`, so it can be used in CI.
+
+use std::fs::{read_to_string, write};
+use std::process::ExitCode;
+
+use vcard_parser::lint::{fix, lint_with_options, Severity};
+use vcard_parser::options::ParserOptions;
+
+fn main() -> ExitCode {
+    let mut options = ParserOptions::default();
+    let mut apply_fixes = false;
+    let mut deny_warnings = false;
+    let mut files = Vec::new();
+
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--fix" => apply_fixes = true,
+            "--strict" => options.strict = true,
+            "--deny-warnings" => deny_warnings = true,
+            "-h" | "--help" => {
+                println!("Usage: vcard-lint [--fix] [--strict] [--deny-warnings] <FILE>...");
+                return ExitCode::SUCCESS;
+            }
+            _ => files.push(arg),
+        }
+    }
+
+    if files.is_empty() {
+        eprintln!("Usage: vcard-lint [--fix] [--strict] [--deny-warnings] <FILE>...");
+        return ExitCode::from(2);
+    }
+
+    let threshold = if deny_warnings { Severity::Warning } else { Severity::Error };
+    let mut failed = false;
+
+    for file in files {
+        let mut input = match read_to_string(file.as_str()) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{}: {}", file, err);
+                failed = true;
+                continue;
+            }
+        };
+
+        if apply_fixes {
+            let fixed = fix(input.as_str());
+            if fixed != input {
+                if let Err(err) = write(file.as_str(), fixed.as_str()) {
+                    eprintln!("{}: {}", file, err);
+                    failed = true;
+                    continue;
+                }
+                input = fixed;
+            }
+        }
+
+        for issue in lint_with_options(input.as_str(), &options) {
+            println!("{}:{}", file, issue);
+            failed |= issue.severity >= threshold;
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,151 @@
+//! Extraction of data across a collection of vCards.
+//!
+//! [`ContactPoint`] unifies the EMAIL, IMPP, TEL and URL properties into one shape, with the TYPE and PREF metadata of
+//! each property, see [`Vcard::contact_points`].
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::collection::{all_contact_points, ContactPoint};
+//! use vcard_parser::parse_vcards;
+//!
+//! let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL;TYPE=work;PREF=1:john@example.com\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nTEL;VALUE=URI:tel:+1-555-555-5555\nEND:VCARD\n";
+//! let vcards = parse_vcards(input).expect("Unable to parse text.");
+//!
+//! let points = all_contact_points(&vcards);
+//! assert_eq!(points.len(), 2);
+//! assert!(matches!(&points[0], (0, ContactPoint::Email(data)) if data.value == "john@example.com" && data.pref == Some(1)));
+//! assert_eq!(points[1].0, 1);
+//! assert_eq!(points[1].1.value(), "+1-555-555-5555");
+//! ```
+
+use crate::traits::HasGroup;
+use crate::vcard::parameter::Parameter;
+use crate::vcard::value::Value;
+use crate::vcard::Vcard;
+use crate::{HasParameters, HasValue, Property};
+
+/// A way of contacting the subject of a vCard, see [`Vcard::contact_points`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ContactPoint {
+    /// An EMAIL property, the value is the email address.
+    Email(ContactPointData),
+    /// An IMPP property, the value is the instant messaging uri.
+    Impp(ContactPointData),
+    /// A TEL property, the value is the number without any "tel:" scheme.
+    Tel(ContactPointData),
+    /// A URL property, the value is the uri.
+    Url(ContactPointData),
+}
+
+impl ContactPoint {
+    /// Get the contact point data.
+    pub fn data(&self) -> &ContactPointData {
+        match self {
+            ContactPoint::Email(data) | ContactPoint::Impp(data) | ContactPoint::Tel(data) | ContactPoint::Url(data) => data,
+        }
+    }
+
+    /// Get the contact point value, e.g. the email address or phone number.
+    pub fn value(&self) -> &str {
+        self.data().value.as_str()
+    }
+
+    /// Create a contact point from an EMAIL, IMPP, TEL or URL property, returning None for other properties.
+    pub fn from_property(property: &Property) -> Option<Self> {
+        let value = match (property, property.get_value()) {
+            (Property::PropertyEmail(data), _) => data.address(),
+            (Property::PropertyTel(_), Value::ValueUri(uri)) => uri.value.trim_start_matches("tel:").to_string(),
+            (Property::PropertyTel(_), Value::ValueText(text)) => text.value.clone(),
+            (Property::PropertyImpp(_) | Property::PropertyUrl(_), value) => value.to_string(),
+            _ => return None,
+        };
+
+        let mut data = ContactPointData {
+            group: property.group().clone(),
+            pref: None,
+            types: Vec::new(),
+            value,
+        };
+
+        for parameter in property.get_parameters() {
+            match (&parameter, parameter.get_value()) {
+                (Parameter::ParameterPref(pref), _) => data.pref = Some(pref.pref()),
+                (Parameter::ParameterType(_), Value::ValueTextList(list)) => data.types.extend(list.value.iter().map(|s| s.trim_matches('"').to_lowercase()).filter(|s| !s.is_empty())),
+                _ => {}
+            }
+        }
+
+        Some(match property {
+            Property::PropertyEmail(_) => ContactPoint::Email(data),
+            Property::PropertyImpp(_) => ContactPoint::Impp(data),
+            Property::PropertyTel(_) => ContactPoint::Tel(data),
+            _ => ContactPoint::Url(data),
+        })
+    }
+}
+
+/// The value and metadata of a [`ContactPoint`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContactPointData {
+    /// The property group, if any.
+    pub group: Option<String>,
+    /// The PREF parameter value, between 1 and 100 with 1 being the most preferred.
+    pub pref: Option<u8>,
+    /// The lowercased values of all TYPE parameters, e.g. "work" or "cell".
+    pub types: Vec<String>,
+    /// The contact point value.
+    pub value: String,
+}
+
+impl ContactPointData {
+    /// Check if the TYPE parameters contain a type, ignoring case.
+    pub fn has_type(&self, str: &str) -> bool {
+        self.types.iter().any(|t| t.eq_ignore_ascii_case(str))
+    }
+}
+
+/// Get the contact points of all vCards, each paired with the position of its vCard in the array.
+pub fn all_contact_points(vcards: &[Vcard]) -> Vec<(usize, ContactPoint)> {
+    vcards.iter().enumerate().flat_map(|(i, vcard)| vcard.contact_points().into_iter().map(move |point| (i, point))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collection::{all_contact_points, ContactPoint};
+    use crate::vcard::Vcard;
+
+    #[test]
+    fn collection_contact_points() {
+        let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nitem1.EMAIL;TYPE=INTERNET;type=HOME;PREF=2:john@example.com\nEMAIL;VALUE=URI:mailto:jdoe@example.com\nTEL;TYPE=\"cell,voice\":+1 (555) 555-5555\nIMPP;PREF=1:xmpp:john@example.com\nURL:https://example.com\nNOTE:Not a contact point\nEND:VCARD\n").unwrap();
+
+        let points = vcard.contact_points();
+        assert_eq!(points.len(), 5);
+
+        let ContactPoint::Email(email) = &points[0] else { panic!("Expected email.") };
+        assert_eq!(email.value, "john@example.com");
+        assert_eq!(email.group.as_deref(), Some("item1"));
+        assert_eq!(
+            email.types,
+            Vec::from([
+                String::from("internet"),
+                String::from("home")
+            ])
+        );
+        assert_eq!(email.pref, Some(2));
+        assert!(email.has_type("HOME"));
+
+        assert!(matches!(&points[1], ContactPoint::Email(data) if data.value == "jdoe@example.com" && data.pref.is_none()));
+        assert!(matches!(&points[2], ContactPoint::Tel(data) if data.value == "+1 (555) 555-5555" && data.has_type("cell") && data.has_type("voice")));
+        assert!(matches!(&points[3], ContactPoint::Impp(data) if data.value == "xmpp:john@example.com" && data.pref == Some(1)));
+        assert!(matches!(&points[4], ContactPoint::Url(_)));
+
+        let all = all_contact_points(&[
+            Vcard::new("Empty"),
+            vcard.clone(),
+            vcard,
+        ]);
+        assert_eq!(all.len(), 10);
+        assert_eq!(all[0].0, 1);
+        assert_eq!(all[9].0, 2);
+    }
+}
@@ -0,0 +1,235 @@
+//! Verification of written vCards against the formatting rules of RFC 6350, e.g. for gating exports to strict consumers.
+//!
+//! [`lint()`](crate::lint::lint) checks content leniently and reports what the parser would reject, while
+//! [`verify_rfc_compliance`] checks the text as written: every line must end with CRLF and be at most 75 octets long,
+//! see [RFC 6350 3.2](https://datatracker.ietf.org/doc/html/rfc6350#section-3.2), every BEGIN:VCARD must be paired
+//! with an END:VCARD, and VERSION:4.0 must directly follow BEGIN:VCARD, see [RFC 6350 6.7.9](https://datatracker.ietf.org/doc/html/rfc6350#section-6.7.9).
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::compliance::{line_stats, verify_rfc_compliance, ComplianceRule};
+//! use vcard_parser::vcard::Vcard;
+//!
+//! let vcard = Vcard::new("John Doe");
+//! let issues = verify_rfc_compliance(vcard.export().as_str());
+//! assert!(issues.iter().all(|issue| issue.rule == ComplianceRule::LineEnding));
+//!
+//! let output = vcard.export().replace('\n', "\r\n");
+//! assert!(verify_rfc_compliance(output.as_str()).is_empty());
+//! assert_eq!(line_stats(output.as_str()).longest, 11);
+//! ```
+
+use std::fmt::{Display, Formatter};
+
+use crate::parse::encoding::FOLD_LENGTH;
+
+/// The checks run by [`verify_rfc_compliance`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ComplianceRule {
+    /// A line ends with LF or a lone CR instead of CRLF, or the last line isn't terminated.
+    LineEnding,
+    /// A line is longer than 75 octets, excluding the line break.
+    LineTooLong,
+    /// BEGIN:VCARD and END:VCARD lines aren't paired, or content is outside of a vCard.
+    VcardMalformed,
+    /// VERSION:4.0 doesn't directly follow BEGIN:VCARD.
+    VersionMissing,
+}
+
+/// An issue found by [`verify_rfc_compliance`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ComplianceIssue {
+    /// The rule reporting the issue.
+    pub rule: ComplianceRule,
+    /// The 1-based physical line number where the issue was found.
+    pub line: usize,
+    /// A description of the issue.
+    pub message: String,
+}
+
+impl ComplianceIssue {
+    fn new(rule: ComplianceRule, line: usize, message: String) -> Self {
+        Self { rule, line, message }
+    }
+}
+
+impl Display for ComplianceIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.line, self.message)
+    }
+}
+
+/// Line length statistics of written vCards, see [`line_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LineStats {
+    /// Number of physical lines.
+    pub lines: usize,
+    /// Number of content lines, after unfolding.
+    pub content_lines: usize,
+    /// Number of content lines folded over several physical lines.
+    pub folded: usize,
+    /// Length in octets of the longest physical line, excluding the line break.
+    pub longest: usize,
+    /// Number of physical lines longer than 75 octets.
+    pub too_long: usize,
+}
+
+/// Get the line length statistics of written vCards.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::compliance::line_stats;
+///
+/// let stats = line_stats("BEGIN:VCARD\r\nVERSION:4.0\r\nFN:John\r\n  Doe\r\nEND:VCARD\r\n");
+/// assert_eq!((stats.lines, stats.content_lines, stats.folded, stats.longest, stats.too_long), (5, 4, 1, 11, 0));
+/// ```
+pub fn line_stats(output: &str) -> LineStats {
+    let mut stats = LineStats::default();
+    let mut continued = false;
+
+    for (_, text, _) in physical_lines(output) {
+        stats.lines += 1;
+        stats.longest = stats.longest.max(text.len());
+        if text.len() > FOLD_LENGTH {
+            stats.too_long += 1;
+        }
+
+        if text.starts_with([' ', '\t']) && stats.content_lines > 0 {
+            if !continued {
+                stats.folded += 1;
+            }
+            continued = true;
+        } else {
+            stats.content_lines += 1;
+            continued = false;
+        }
+    }
+
+    stats
+}
+
+/// Verify written vCards against the formatting rules of RFC 6350, returning all issues in line order.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::compliance::{verify_rfc_compliance, ComplianceRule};
+///
+/// let issues = verify_rfc_compliance("BEGIN:VCARD\r\nFN:John Doe\nEND:VCARD\r\nEND:VCARD");
+/// let rules: Vec<(usize, ComplianceRule)> = issues.iter().map(|issue| (issue.line, issue.rule)).collect();
+/// assert_eq!(rules, [(2, ComplianceRule::LineEnding), (2, ComplianceRule::VersionMissing), (4, ComplianceRule::LineEnding), (4, ComplianceRule::VcardMalformed)]);
+/// ```
+pub fn verify_rfc_compliance(output: &str) -> Vec<ComplianceIssue> {
+    let mut issues = Vec::new();
+    // The line of the current BEGIN:VCARD and whether the VERSION was checked.
+    let mut card: Option<(usize, bool)> = None;
+
+    for (line, text, ending) in physical_lines(output) {
+        if text.contains('\r') {
+            issues.push(ComplianceIssue::new(ComplianceRule::LineEnding, line, String::from("Line contains a CR which isn't followed by LF.")));
+        }
+        match ending {
+            "\r\n" => {}
+            "\n" => issues.push(ComplianceIssue::new(ComplianceRule::LineEnding, line, String::from("Line ends with LF instead of CRLF."))),
+            _ => issues.push(ComplianceIssue::new(ComplianceRule::LineEnding, line, String::from("Last line isn't terminated by CRLF."))),
+        }
+        if text.len() > FOLD_LENGTH {
+            issues.push(ComplianceIssue::new(ComplianceRule::LineTooLong, line, format!("Line is {} octets long, lines must be folded at {} octets.", text.len(), FOLD_LENGTH)));
+        }
+
+        if text.starts_with([' ', '\t']) {
+            continue;
+        }
+
+        if let Some((begin, false)) = card {
+            if !text.eq_ignore_ascii_case("VERSION:4.0") {
+                issues.push(ComplianceIssue::new(ComplianceRule::VersionMissing, line, format!("VERSION:4.0 must directly follow BEGIN:VCARD on line {}.", begin)));
+            }
+            card = Some((begin, true));
+            if text.to_uppercase().starts_with("VERSION:") {
+                continue;
+            }
+        }
+
+        if text.eq_ignore_ascii_case("BEGIN:VCARD") {
+            if let Some((begin, _)) = card {
+                issues.push(ComplianceIssue::new(ComplianceRule::VcardMalformed, line, format!("BEGIN:VCARD on line {} has no matching END:VCARD.", begin)));
+            }
+            card = Some((line, false));
+        } else if text.eq_ignore_ascii_case("END:VCARD") {
+            if card.take().is_none() {
+                issues.push(ComplianceIssue::new(ComplianceRule::VcardMalformed, line, String::from("END:VCARD has no matching BEGIN:VCARD.")));
+            }
+        } else if card.is_none() && !text.is_empty() {
+            issues.push(ComplianceIssue::new(ComplianceRule::VcardMalformed, line, String::from("Content line outside of BEGIN:VCARD and END:VCARD.")));
+        }
+    }
+
+    if let Some((begin, _)) = card {
+        issues.push(ComplianceIssue::new(ComplianceRule::VcardMalformed, begin, format!("BEGIN:VCARD on line {} has no matching END:VCARD.", begin)));
+    }
+
+    issues.sort_by_key(|issue| issue.line);
+    issues
+}
+
+/// Helper function splitting text into physical lines, with their 1-based number, text and line break.
+fn physical_lines(output: &str) -> impl Iterator<Item = (usize, &str, &str)> {
+    output.split_inclusive('\n').enumerate().map(|(i, line)| {
+        let (text, ending) = match line.strip_suffix("\r\n") {
+            Some(text) => (text, "\r\n"),
+            None => match line.strip_suffix('\n') {
+                Some(text) => (text, "\n"),
+                None => (line, ""),
+            },
+        };
+        (i + 1, text, ending)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compliance::{line_stats, verify_rfc_compliance, ComplianceRule, LineStats};
+    use crate::options::ExportOptions;
+    use crate::vcard::Vcard;
+
+    #[test]
+    pub fn compliance_verify() {
+        let note = "A".repeat(100);
+        let vcard = Vcard::try_from(format!("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nNOTE:{}\nEND:VCARD\n", note).as_str()).unwrap();
+
+        let rules = |output: &str| verify_rfc_compliance(output).iter().map(|issue| (issue.line, issue.rule)).collect::<Vec<(usize, ComplianceRule)>>();
+        let plain = vcard.export().replace('\n', "\r\n");
+        assert_eq!(rules(plain.as_str()), [(4, ComplianceRule::LineTooLong)]);
+
+        let folded = vcard.export_with(&ExportOptions::canonical()).replace('\n', "\r\n");
+        assert!(verify_rfc_compliance(folded.as_str()).is_empty());
+        assert_eq!(
+            line_stats(folded.as_str()),
+            LineStats {
+                lines: 6,
+                content_lines: 5,
+                folded: 1,
+                longest: 75,
+                too_long: 0
+            }
+        );
+
+        assert_eq!(
+            rules("BEGIN:VCARD\r\nVERSION:3.0\r\nFN:A\rB\r\nEND:VCARD\r\n"),
+            [
+                (2, ComplianceRule::VersionMissing),
+                (3, ComplianceRule::LineEnding)
+            ]
+        );
+        assert_eq!(
+            rules("FN:A\r\nBEGIN:VCARD\r\nVERSION:4.0\r\nBEGIN:VCARD\r\nVERSION:4.0\r\nEND:VCARD\r\n"),
+            [
+                (1, ComplianceRule::VcardMalformed),
+                (4, ComplianceRule::VcardMalformed)
+            ]
+        );
+        assert_eq!(rules("BEGIN:VCARD\r\nVERSION:4.0\r\n"), [(1, ComplianceRule::VcardMalformed)]);
+        assert!(verify_rfc_compliance("").is_empty());
+        assert_eq!(verify_rfc_compliance("END:VCARD\r\n")[0].to_string(), "1: END:VCARD has no matching BEGIN:VCARD.");
+    }
+}
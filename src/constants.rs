@@ -83,6 +83,68 @@ impl PropertyName {
     pub const UID: &'static str = "UID";
     pub const URL: &'static str = "URL";
     pub const XML: &'static str = "XML";
+
+    pub const TYPES: [&'static str; 46] = [
+        PropertyName::BEGIN,
+        PropertyName::VERSION,
+        PropertyName::END,
+        PropertyName::ADR,
+        PropertyName::ANNIVERSARY,
+        PropertyName::BDAY,
+        PropertyName::BIRTHPLACE,
+        PropertyName::CALADRURI,
+        PropertyName::CALURI,
+        PropertyName::CATEGORIES,
+        PropertyName::CLIENTPIDMAP,
+        PropertyName::CONTACTURI,
+        PropertyName::DEATHDATE,
+        PropertyName::DEATHPLACE,
+        PropertyName::EMAIL,
+        PropertyName::EXPERTISE,
+        PropertyName::FBURL,
+        PropertyName::FN,
+        PropertyName::GENDER,
+        PropertyName::GEO,
+        PropertyName::HOBBY,
+        PropertyName::IMPP,
+        PropertyName::INTEREST,
+        PropertyName::KEY,
+        PropertyName::KIND,
+        PropertyName::LANG,
+        PropertyName::LOGO,
+        PropertyName::MEMBER,
+        PropertyName::NICKNAME,
+        PropertyName::NOTE,
+        PropertyName::N,
+        PropertyName::ORGDIRECTORY,
+        PropertyName::ORG,
+        PropertyName::PHOTO,
+        PropertyName::PRODID,
+        PropertyName::RELATED,
+        PropertyName::REV,
+        PropertyName::ROLE,
+        PropertyName::SOUND,
+        PropertyName::SOURCE,
+        PropertyName::TEL,
+        PropertyName::TITLE,
+        PropertyName::TZ,
+        PropertyName::UID,
+        PropertyName::URL,
+        PropertyName::XML,
+    ];
+
+    /// Get the static property name matching a name case-insensitively, avoiding an uppercased copy when dispatching.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::constants::PropertyName;
+    ///
+    /// assert_eq!(PropertyName::intern("contact-uri"), Some(PropertyName::CONTACTURI));
+    /// assert_eq!(PropertyName::intern("X-CUSTOM"), None);
+    /// ```
+    pub fn intern(name: &str) -> Option<&'static str> {
+        PropertyName::TYPES.into_iter().find(|known| known.eq_ignore_ascii_case(name))
+    }
 }
 
 #[non_exhaustive]
@@ -105,6 +167,29 @@ impl ParameterName {
     pub const TYPE: &'static str = "TYPE";
     pub const TZ: &'static str = "TZ";
     pub const VALUE: &'static str = "VALUE";
+
+    pub const TYPES: [&'static str; 15] = [
+        ParameterName::ALTID,
+        ParameterName::CALSCALE,
+        ParameterName::CC,
+        ParameterName::GEO,
+        ParameterName::INDEX,
+        ParameterName::LABEL,
+        ParameterName::LANGUAGE,
+        ParameterName::LEVEL,
+        ParameterName::MEDIATYPE,
+        ParameterName::PID,
+        ParameterName::PREF,
+        ParameterName::SORTAS,
+        ParameterName::TYPE,
+        ParameterName::TZ,
+        ParameterName::VALUE,
+    ];
+
+    /// Get the static parameter name matching a name case-insensitively, avoiding an uppercased copy when dispatching.
+    pub fn intern(name: &str) -> Option<&'static str> {
+        ParameterName::TYPES.into_iter().find(|known| known.eq_ignore_ascii_case(name))
+    }
 }
 
 #[non_exhaustive]
@@ -124,6 +209,27 @@ impl ValueName {
     pub const TIMESTAMP: &'static str = "TIMESTAMP";
     pub const URI: &'static str = "URI";
     pub const UTCOFFSET: &'static str = "UTCOFFSET";
+
+    pub const TYPES: [&'static str; 13] = [
+        ValueName::BOOLEAN,
+        ValueName::CLIENTPIDMAP,
+        ValueName::DATE,
+        ValueName::FLOAT,
+        ValueName::INTEGER,
+        ValueName::LANGUAGE_TAG,
+        ValueName::LISTCOMPONENT,
+        ValueName::PID,
+        ValueName::TEXT,
+        ValueName::TEXTLIST,
+        ValueName::TIMESTAMP,
+        ValueName::URI,
+        ValueName::UTCOFFSET,
+    ];
+
+    /// Get the static value name matching a name case-insensitively, avoiding an uppercased copy when dispatching.
+    pub fn intern(name: &str) -> Option<&'static str> {
+        ValueName::TYPES.into_iter().find(|known| known.eq_ignore_ascii_case(name))
+    }
 }
 
 #[non_exhaustive]
@@ -167,10 +273,22 @@ impl PropertyExpertiseValues {
     pub const BEGINNER: &'static str = "BEGINNER";
     pub const AVERAGE: &'static str = "AVERAGE";
     pub const EXPERT: &'static str = "EXPERT";
+    #[deprecated(note = "LOW is a HOBBY and INTEREST level, use PropertyHobbyValues::LOW")]
     pub const LOW: &'static str = "LOW";
+    #[deprecated(note = "MEDIUM is a HOBBY and INTEREST level, use PropertyHobbyValues::MEDIUM")]
     pub const MEDIUM: &'static str = "MEDIUM";
+    #[deprecated(note = "HIGH is a HOBBY and INTEREST level, use PropertyHobbyValues::HIGH")]
     pub const HIGH: &'static str = "HIGH";
 
+    /// The EXPERTISE levels, see [RFC 6715 3.2](https://datatracker.ietf.org/doc/html/rfc6715#section-3.2).
+    pub const LEVELS: [&'static str; 3] = [
+        PropertyExpertiseValues::BEGINNER,
+        PropertyExpertiseValues::AVERAGE,
+        PropertyExpertiseValues::EXPERT,
+    ];
+
+    #[deprecated(note = "includes the HOBBY and INTEREST levels, use PropertyExpertiseValues::LEVELS")]
+    #[allow(deprecated)]
     pub const TYPES: [&'static str; 6] = [
         PropertyExpertiseValues::BEGINNER,
         PropertyExpertiseValues::AVERAGE,
@@ -247,6 +365,31 @@ impl PropertyKindValues {
     ];
 }
 
+#[non_exhaustive]
+pub struct PropertyUriSchemes;
+
+/// Uri schemes allowed for properties whose uri values are restricted to specific schemes.
+impl PropertyUriSchemes {
+    pub const EMAIL: [&'static str; 1] = ["mailto"];
+    pub const GEO: [&'static str; 1] = ["geo"];
+    /// Known instant messaging schemes, IMPP values are only restricted to these in strict mode.
+    pub const IMPP: [&'static str; 14] = [
+        "aim", "gtalk", "icq", "im", "irc", "ircs", "matrix", "msnim", "sip", "sips", "skype", "tel", "xmpp", "ymsgr",
+    ];
+    pub const TEL: [&'static str; 3] = ["tel", "sip", "sips"];
+}
+
+#[non_exhaustive]
+pub struct VcardVersion;
+
+/// Supported VERSION values, see [RFC 6350 6.7.9](https://datatracker.ietf.org/doc/html/rfc6350#section-6.7.9)
+impl VcardVersion {
+    pub const V3: &'static str = "3.0";
+    pub const V4: &'static str = "4.0";
+
+    pub const TYPES: [&'static str; 1] = [VcardVersion::V4];
+}
+
 #[non_exhaustive]
 pub struct TestData;
 
@@ -259,7 +402,7 @@ impl TestData {
     pub const VCARD_MATCH_MINIMAL: (&'static str, &'static str) = ("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEND:VCARD\n", "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEND:VCARD\n");
     pub const VCARD_MATCH_CONCAT: (&'static str, &'static str) = ("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nN:Doe;\n John\n\t;Jr.;;\nEND:VCARD\n", "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nN:Doe;John;Jr.;;\nEND:VCARD\n");
     pub const VCARD_MATCH_XNAME: (&'static str, &'static str) = ("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nitem1.X-ABADR;X-SERVICE=TEST:us\nEND:VCARD\n", "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nitem1.X-ABADR;X-SERVICE=TEST:us\nEND:VCARD\n");
-    pub const VCARD_MATCH_COMPOUND: (&'static str, &'static str) = ("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL;TYPE=\"INTERNET,HOME\":user@example.com\nEND:VCARD\n", "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL;TYPE=\"INTERNET,HOME\":user@example.com\nEND:VCARD\n");
+    pub const VCARD_MATCH_COMPOUND: (&'static str, &'static str) = ("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL;TYPE=\"INTERNET,HOME\":user@example.com\nEND:VCARD\n", "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL;TYPE=INTERNET,HOME:user@example.com\nEND:VCARD\n");
 }
 
 #[non_exhaustive]
@@ -319,6 +462,7 @@ impl VcardParseError {
     pub const DELIMITER_COMMA: &'static str = "DELIMITER_COMMA";
     pub const DELIMITER_CONCAT: &'static str = "DELIMITER_CONCAT";
     pub const DELIMITER_EQUALS: &'static str = "DELIMITER_EQUALS";
+    pub const DELIMITER_LINE_ENDING: &'static str = "DELIMITER_LINE_ENDING";
     pub const DELIMITER_SEMI_COLON: &'static str = "DELIMITER_SEMI_COLON";
     pub const PARAMETER: &'static str = "PARAMETER";
     pub const PARAMETER_TYPE: &'static str = "PARAMETER_TYPE";
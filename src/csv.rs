@@ -0,0 +1,548 @@
+//! Conversion between vCards and [CSV](https://datatracker.ietf.org/doc/html/rfc4180) contact lists.
+//!
+//! A [`CsvProfile`] maps CSV columns to vCard properties using [slots](CsvSlot), each slot is one property and its
+//! columns, e.g. the street and city columns of an address. Ready-made profiles are provided for the
+//! [Google Contacts](CsvProfile::google) and [Outlook](CsvProfile::outlook) CSV formats.
+//!
+//! When exporting, slots with TYPE parameters are filled first, so a `TEL;TYPE=work,fax` property is written to a
+//! fax column rather than a phone column, and each property is written to at most one slot.
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::csv::{parse_csv, to_csv, CsvProfile};
+//! use vcard_parser::vcard::Vcard;
+//!
+//! let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nN:Doe;John;;;\nTEL;TYPE=cell:555-5555\nEND:VCARD\n").expect("Unable to parse vCard.");
+//!
+//! let csv = to_csv(&[vcard.clone()], &CsvProfile::outlook());
+//! assert!(csv.starts_with("Title,First Name,Middle Name,Last Name,Suffix,"));
+//!
+//! let vcards = parse_csv(csv.as_str(), &CsvProfile::outlook()).expect("Unable to parse CSV.");
+//! assert_eq!(vcards[0].export(), vcard.export());
+//! ```
+
+use crate::collection::ContactPoint;
+use crate::constants::PropertyName;
+use crate::parse::encoding::escape;
+use crate::vcard::parameter::Parameter;
+use crate::vcard::value::Value;
+use crate::vcard::Vcard;
+use crate::{HasName, HasParameters, HasValue, Property, VcardError};
+
+/// The content of a CSV column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CsvValue {
+    /// The unescaped property value, list values are joined with commas.
+    Value,
+    /// A component of an N, ADR or ORG value, e.g. 0 for the family name of N.
+    Component(usize),
+    /// The TYPE parameters as a Google Contacts label, e.g. "Work Fax" or "* Mobile" for a preferred cell phone.
+    Label,
+}
+
+/// A CSV column with its header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsvColumn {
+    pub header: String,
+    pub value: CsvValue,
+}
+
+/// A property mapped to one or more CSV columns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsvSlot {
+    /// The property name, e.g. "TEL".
+    pub property: String,
+    /// The lowercase TYPE parameter values a property must have to be written to the slot, and which are set on import.
+    pub types: Vec<String>,
+    /// The columns of the slot.
+    pub columns: Vec<CsvColumn>,
+}
+
+impl CsvSlot {
+    /// Create a slot from a property name, types and column headers.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::csv::{CsvSlot, CsvValue};
+    ///
+    /// let slot = CsvSlot::new("ADR", &["home"], &[("Home Street", CsvValue::Component(2)), ("Home City", CsvValue::Component(3))]);
+    /// assert_eq!(slot.columns[1].header, "Home City");
+    /// ```
+    pub fn new(property: &str, types: &[&str], columns: &[(&str, CsvValue)]) -> Self {
+        Self {
+            property: property.to_uppercase(),
+            types: types.iter().map(|t| t.to_lowercase()).collect(),
+            columns: columns
+                .iter()
+                .map(|(header, value)| CsvColumn {
+                    header: header.to_string(),
+                    value: *value,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A mapping between CSV columns and vCard properties.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CsvProfile {
+    pub slots: Vec<CsvSlot>,
+}
+
+impl CsvProfile {
+    /// Create a profile from slots, columns are written in slot order.
+    pub fn new(slots: Vec<CsvSlot>) -> Self {
+        Self { slots }
+    }
+
+    /// Get the column headers in order.
+    pub fn headers(&self) -> Vec<&str> {
+        self.slots.iter().flat_map(|slot| slot.columns.iter().map(|column| column.header.as_str())).collect()
+    }
+
+    /// The Google Contacts CSV format, with two of each email, phone, address and website columns.
+    pub fn google() -> Self {
+        let mut slots = Vec::from([
+            CsvSlot::new(
+                PropertyName::N,
+                &[],
+                &[
+                    ("First Name", CsvValue::Component(1)),
+                    ("Middle Name", CsvValue::Component(2)),
+                    ("Last Name", CsvValue::Component(0)),
+                    ("Name Prefix", CsvValue::Component(3)),
+                    ("Name Suffix", CsvValue::Component(4)),
+                ],
+            ),
+            CsvSlot::new(PropertyName::NICKNAME, &[], &[("Nickname", CsvValue::Value)]),
+            CsvSlot::new(
+                PropertyName::ORG,
+                &[],
+                &[
+                    ("Organization Name", CsvValue::Component(0)),
+                    ("Organization Department", CsvValue::Component(1)),
+                ],
+            ),
+            CsvSlot::new(PropertyName::TITLE, &[], &[("Organization Title", CsvValue::Value)]),
+            CsvSlot::new(PropertyName::BDAY, &[], &[("Birthday", CsvValue::Value)]),
+            CsvSlot::new(PropertyName::NOTE, &[], &[("Notes", CsvValue::Value)]),
+            CsvSlot::new(PropertyName::PHOTO, &[], &[("Photo", CsvValue::Value)]),
+        ]);
+
+        for (property, prefix) in [
+            (PropertyName::EMAIL, "E-mail"),
+            (PropertyName::TEL, "Phone"),
+        ] {
+            for i in 1..=2 {
+                slots.push(CsvSlot::new(
+                    property,
+                    &[],
+                    &[
+                        (format!("{} {} - Label", prefix, i).as_str(), CsvValue::Label),
+                        (format!("{} {} - Value", prefix, i).as_str(), CsvValue::Value),
+                    ],
+                ));
+            }
+        }
+
+        for i in 1..=2 {
+            let header = |name: &str| format!("Address {} - {}", i, name);
+            slots.push(CsvSlot::new(
+                PropertyName::ADR,
+                &[],
+                &[
+                    (header("Label").as_str(), CsvValue::Label),
+                    (header("Street").as_str(), CsvValue::Component(2)),
+                    (header("City").as_str(), CsvValue::Component(3)),
+                    (header("PO Box").as_str(), CsvValue::Component(0)),
+                    (header("Region").as_str(), CsvValue::Component(4)),
+                    (header("Postal Code").as_str(), CsvValue::Component(5)),
+                    (header("Country").as_str(), CsvValue::Component(6)),
+                    (header("Extended Address").as_str(), CsvValue::Component(1)),
+                ],
+            ));
+        }
+
+        for i in 1..=2 {
+            slots.push(CsvSlot::new(
+                PropertyName::URL,
+                &[],
+                &[
+                    (format!("Website {} - Label", i).as_str(), CsvValue::Label),
+                    (format!("Website {} - Value", i).as_str(), CsvValue::Value),
+                ],
+            ));
+        }
+
+        Self::new(slots)
+    }
+
+    /// The Outlook CSV format, phone and address columns are mapped by TYPE parameter.
+    ///
+    /// Outlook writes birthdays in a locale specific format, so the birthday column isn't mapped.
+    pub fn outlook() -> Self {
+        let mut slots = Vec::from([
+            CsvSlot::new(
+                PropertyName::N,
+                &[],
+                &[
+                    ("Title", CsvValue::Component(3)),
+                    ("First Name", CsvValue::Component(1)),
+                    ("Middle Name", CsvValue::Component(2)),
+                    ("Last Name", CsvValue::Component(0)),
+                    ("Suffix", CsvValue::Component(4)),
+                ],
+            ),
+            CsvSlot::new(
+                PropertyName::ORG,
+                &[],
+                &[
+                    ("Company", CsvValue::Component(0)),
+                    ("Department", CsvValue::Component(1)),
+                ],
+            ),
+            CsvSlot::new(PropertyName::TITLE, &[], &[("Job Title", CsvValue::Value)]),
+        ]);
+
+        for (prefix, types) in [
+            ("Business", ["work"]),
+            ("Home", ["home"]),
+        ] {
+            let header = |name: &str| format!("{} {}", prefix, name);
+            slots.push(CsvSlot::new(
+                PropertyName::ADR,
+                &types,
+                &[
+                    (header("Street").as_str(), CsvValue::Component(2)),
+                    (header("City").as_str(), CsvValue::Component(3)),
+                    (header("State").as_str(), CsvValue::Component(4)),
+                    (header("Postal Code").as_str(), CsvValue::Component(5)),
+                    (header("Country/Region").as_str(), CsvValue::Component(6)),
+                ],
+            ));
+        }
+
+        for (header, types) in [
+            ("Business Fax", &["work", "fax"][..]),
+            ("Business Phone", &["work"]),
+            ("Home Phone", &["home"]),
+            ("Mobile Phone", &["cell"]),
+            ("Other Phone", &[]),
+        ] {
+            slots.push(CsvSlot::new(PropertyName::TEL, types, &[(header, CsvValue::Value)]));
+        }
+
+        for header in [
+            "E-mail Address",
+            "E-mail 2 Address",
+            "E-mail 3 Address",
+        ] {
+            slots.push(CsvSlot::new(PropertyName::EMAIL, &[], &[(header, CsvValue::Value)]));
+        }
+
+        slots.push(CsvSlot::new(PropertyName::URL, &[], &[("Web Page", CsvValue::Value)]));
+        slots.push(CsvSlot::new(PropertyName::NOTE, &[], &[("Notes", CsvValue::Value)]));
+
+        Self::new(slots)
+    }
+}
+
+/// Render vCards as CSV with a header row, using CRLF line endings.
+pub fn to_csv(vcards: &[Vcard], profile: &CsvProfile) -> String {
+    let mut string = csv_row(&profile.headers());
+
+    // Slots requiring more types are filled first, keeping the slot order otherwise.
+    let mut order: Vec<usize> = (0..profile.slots.len()).collect();
+    order.sort_by_key(|i| std::cmp::Reverse(profile.slots[*i].types.len()));
+
+    for vcard in vcards.iter() {
+        let properties = vcard.get_properties();
+        let mut used = vec![false; properties.len()];
+        let mut assigned: Vec<Option<&Property>> = vec![None; profile.slots.len()];
+
+        for i in order.iter() {
+            let slot = &profile.slots[*i];
+            let position = properties.iter().enumerate().position(|(n, property)| {
+                let (types, _) = property_types(property);
+                !used[n] && property.name().eq_ignore_ascii_case(&slot.property) && slot.types.iter().all(|t| types.contains(t))
+            });
+            if let Some(n) = position {
+                used[n] = true;
+                assigned[*i] = Some(&properties[n]);
+            }
+        }
+
+        let mut fields = Vec::new();
+        for (slot, property) in profile.slots.iter().zip(assigned) {
+            for column in slot.columns.iter() {
+                fields.push(property.map(|property| column_value(property, column.value)).unwrap_or_default());
+            }
+        }
+        string.push_str(&csv_row(&fields.iter().map(String::as_str).collect::<Vec<&str>>()));
+    }
+
+    string
+}
+
+/// Parse CSV with a header row into vCards, columns are matched to the profile by header, ignoring case.
+///
+/// Unknown columns are ignored and rows without any mapped values are skipped. When there is no FN column, the FN
+/// property is set to the [display name](Vcard::display_name), e.g. the name built from the N columns.
+pub fn parse_csv(str: &str, profile: &CsvProfile) -> Result<Vec<Vcard>, VcardError> {
+    let mut rows = csv_rows(str.trim_start_matches('\u{feff}')).into_iter();
+    let Some(headers) = rows.next() else { return Ok(Vec::new()) };
+
+    let mut vcards = Vec::new();
+    for row in rows {
+        let field = |header: &str| headers.iter().position(|h| h.trim().eq_ignore_ascii_case(header)).and_then(|i| row.get(i)).map(|s| s.trim()).unwrap_or_default();
+
+        let mut vcard = Vcard::new("");
+        let mut empty = true;
+        for slot in profile.slots.iter() {
+            let values: Vec<(CsvValue, &str)> = slot.columns.iter().map(|column| (column.value, field(&column.header))).collect();
+            if values.iter().all(|(value, str)| str.is_empty() || *value == CsvValue::Label) {
+                continue;
+            }
+            empty = false;
+
+            let mut types = slot.types.clone();
+            let mut pref = false;
+            if let Some((_, label)) = values.iter().find(|(value, _)| *value == CsvValue::Label) {
+                let (label_types, label_pref) = label_to_types(label);
+                types.extend(label_types.into_iter().filter(|t| !slot.types.contains(t)));
+                pref = label_pref;
+            }
+
+            let name = slot.property.as_str();
+            let value = match name {
+                PropertyName::ADR | PropertyName::N | PropertyName::ORG => {
+                    let length = match name {
+                        PropertyName::ADR => 7,
+                        PropertyName::N => 5,
+                        _ => values.iter().filter_map(|(value, _)| if let CsvValue::Component(i) = value { Some(i + 1) } else { None }).max().unwrap_or(1),
+                    };
+                    let mut components = vec![String::new(); length];
+                    for (value, str) in values.iter() {
+                        if let CsvValue::Component(i) = value {
+                            if let Some(component) = components.get_mut(*i) {
+                                *component = escape(str);
+                            }
+                        }
+                    }
+                    if name == PropertyName::ORG {
+                        while components.len() > 1 && components.last().is_some_and(|c| c.is_empty()) {
+                            components.pop();
+                        }
+                    }
+                    components.join(";")
+                }
+                PropertyName::BDAY | PropertyName::PHOTO | PropertyName::URL => values.iter().find(|(value, _)| *value == CsvValue::Value).map(|(_, str)| str.to_string()).unwrap_or_default(),
+                _ => values.iter().find(|(value, _)| *value == CsvValue::Value).map(|(_, str)| escape(str)).unwrap_or_default(),
+            };
+
+            let mut line = String::from(name);
+            if !types.is_empty() {
+                line.push_str(format!(";TYPE={}", types.join(",")).as_str());
+            }
+            if pref {
+                line.push_str(";PREF=1");
+            }
+            line.push_str(format!(":{}\n", value).as_str());
+
+            vcard.set_property(&Property::try_from(line.as_str())?)?;
+        }
+
+        if empty {
+            continue;
+        }
+        if !profile.slots.iter().any(|slot| slot.property == PropertyName::FN) {
+            vcard.set_property(&Property::try_from(format!("FN:{}\n", escape(&vcard.display_name())).as_str())?)?;
+        }
+        vcards.push(vcard);
+    }
+
+    Ok(vcards)
+}
+
+/// Helper function getting the value of a column for a property.
+fn column_value(property: &Property, value: CsvValue) -> String {
+    match (value, property.get_value()) {
+        (CsvValue::Value, value) => match ContactPoint::from_property(property) {
+            Some(point) => point.value().to_string(),
+            None => match value {
+                Value::ValueText(text) => text.value.clone(),
+                Value::ValueTextList(list) => list.value.join(","),
+                value => value.to_string(),
+            },
+        },
+        (CsvValue::Component(i), Value::ValueListComponent(list)) => list.value.get(i).map(|component| component.join(",")).unwrap_or_default(),
+        (CsvValue::Component(i), Value::ValueTextList(list)) => list.value.get(i).cloned().unwrap_or_default(),
+        (CsvValue::Component(_), _) => String::new(),
+        (CsvValue::Label, _) => {
+            let (types, pref) = property_types(property);
+            types_to_label(&types, pref)
+        }
+    }
+}
+
+/// Helper function getting the lowercase TYPE parameter values of a property, and whether PREF is 1.
+fn property_types(property: &Property) -> (Vec<String>, bool) {
+    let mut types = Vec::new();
+    let mut pref = false;
+
+    for parameter in property.get_parameters() {
+        match (&parameter, parameter.get_value()) {
+            (Parameter::ParameterType(_), Value::ValueTextList(list)) => types.extend(list.value.iter().map(|s| s.trim_matches('"').to_lowercase()).filter(|s| !s.is_empty())),
+            (Parameter::ParameterPref(_), value) => pref = value.as_integer() == Some(1),
+            _ => {}
+        }
+    }
+
+    (types, pref)
+}
+
+/// Helper function creating a Google Contacts label from types, e.g. "* Mobile" for a preferred cell phone.
+fn types_to_label(types: &[String], pref: bool) -> String {
+    let words: Vec<String> = types
+        .iter()
+        .map(|t| match t.as_str() {
+            "cell" => String::from("Mobile"),
+            t => t.chars().take(1).flat_map(char::to_uppercase).chain(t.chars().skip(1)).collect(),
+        })
+        .collect();
+
+    match (pref, words.is_empty()) {
+        (true, true) => String::from("*"),
+        (true, false) => format!("* {}", words.join(" ")),
+        _ => words.join(" "),
+    }
+}
+
+/// Helper function creating types from a Google Contacts label, returning the types and whether the label is preferred.
+fn label_to_types(label: &str) -> (Vec<String>, bool) {
+    let (label, pref) = match label.trim().strip_prefix('*') {
+        Some(label) => (label, true),
+        None => (label, false),
+    };
+
+    let types = label
+        .split_whitespace()
+        .map(|word| match word.to_lowercase().as_str() {
+            "mobile" => String::from("cell"),
+            word => word.chars().filter(|c| c.is_alphanumeric() || *c == '-').collect(),
+        })
+        .filter(|t: &String| !t.is_empty())
+        .collect();
+
+    (types, pref)
+}
+
+/// Helper function writing a CSV row, quoting fields as needed.
+fn csv_row(fields: &[&str]) -> String {
+    let fields: Vec<String> = fields.iter().map(|field| if field.contains([',', '"', '\r', '\n']) || field.starts_with(' ') || field.ends_with(' ') { format!("\"{}\"", field.replace('"', "\"\"")) } else { field.to_string() }).collect();
+
+    format!("{}\r\n", fields.join(","))
+}
+
+/// Helper function parsing CSV rows, accepting CRLF and LF line endings and quoted fields spanning lines.
+fn csv_rows(str: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+
+    let mut chars = str.chars().peekable();
+    while let Some(char) = chars.next() {
+        match char {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' if quoted => quoted = false,
+            '"' if field.is_empty() => quoted = true,
+            ',' if !quoted => row.push(std::mem::take(&mut field)),
+            '\r' if !quoted && chars.peek() == Some(&'\n') => {}
+            '\n' if !quoted => {
+                row.push(std::mem::take(&mut field));
+                if row.iter().any(|f| !f.is_empty()) {
+                    rows.push(std::mem::take(&mut row));
+                }
+            }
+            _ => field.push(char),
+        }
+    }
+
+    row.push(field);
+    if row.iter().any(|f| !f.is_empty()) {
+        rows.push(row);
+    }
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csv::{csv_rows, parse_csv, to_csv, CsvProfile, CsvSlot, CsvValue};
+    use crate::vcard::Vcard;
+
+    #[test]
+    fn csv_google() {
+        let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:Dr. John Doe\nN:Doe;John;;Dr.;\nORG:ABC;Marketing\nEMAIL;TYPE=work;PREF=1:john@example.com\nEMAIL:jdoe@example.com\nEMAIL:third@example.com\nTEL;TYPE=cell:+1 555-5555\nTEL;TYPE=work,fax:555-1234\nADR;TYPE=home:;;123 Main St.;Any Town;CA;91921;USA\nNOTE:Line two\\, with \"quotes\"\nURL:https://example.com/john\nEND:VCARD\n").unwrap();
+
+        let profile = CsvProfile::google();
+        let csv = to_csv(&[vcard], &profile);
+        let rows = csv_rows(&csv);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].len(), profile.headers().len());
+
+        let field = |header: &str| rows[1][profile.headers().iter().position(|h| *h == header).unwrap()].as_str();
+        assert_eq!(field("First Name"), "John");
+        assert_eq!(field("Name Prefix"), "Dr.");
+        assert_eq!(field("Organization Department"), "Marketing");
+        assert_eq!(field("Notes"), "Line two, with \"quotes\"");
+        assert_eq!(field("E-mail 1 - Label"), "* Work");
+        assert_eq!(field("E-mail 2 - Value"), "jdoe@example.com");
+        assert_eq!(field("Phone 1 - Label"), "Mobile");
+        assert_eq!(field("Phone 2 - Label"), "Work Fax");
+        assert_eq!(field("Address 1 - City"), "Any Town");
+        assert_eq!(field("Address 2 - City"), "");
+
+        let vcards = parse_csv(&csv, &profile).unwrap();
+        assert_eq!(vcards.len(), 1);
+        assert_eq!(
+            vcards[0].export(),
+            "BEGIN:VCARD\nVERSION:4.0\nFN:Dr. John Doe\nN:Doe;John;;Dr.;\nORG:ABC;Marketing\nNOTE:Line two\\, with \"quotes\"\nEMAIL;TYPE=work;PREF=1:john@example.com\nEMAIL:jdoe@example.com\nTEL;TYPE=cell:+1 555-5555\nTEL;TYPE=work,fax:555-1234\nADR;TYPE=home:;;123 Main St.;Any Town;CA;91921;USA\nURL:https://example.com/john\nEND:VCARD\n"
+        );
+    }
+
+    #[test]
+    fn csv_outlook() {
+        let csv = "\u{feff}First Name,Last Name,Company,Business Phone,Mobile Phone,Business Fax,E-mail Address,Home Street,Home City,Unknown\r\nJane,Roe,Example Inc.,555-1111,555-2222,555-3333,jane@example.com,\"1 Main St.\nApt 2\",Town,x\r\n,,,,,,,,,ignored\r\n";
+
+        let profile = CsvProfile::outlook();
+        let vcards = parse_csv(csv, &profile).unwrap();
+        assert_eq!(vcards.len(), 1);
+        assert_eq!(vcards[0].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nN:Roe;Jane;;;\nORG:Example Inc.\nADR;TYPE=home:;;1 Main St.\\\\nApt 2;Town;;;\nTEL;TYPE=work,fax:555-3333\nTEL;TYPE=work:555-1111\nTEL;TYPE=cell:555-2222\nEMAIL:jane@example.com\nEND:VCARD\n");
+
+        let csv = to_csv(&vcards, &profile);
+        assert!(csv.contains(",\"1 Main St.\nApt 2\",Town,"));
+        assert_eq!(parse_csv(&csv, &profile).unwrap()[0].export(), vcards[0].export());
+
+        assert!(parse_csv("", &profile).unwrap().is_empty());
+        assert!(parse_csv("Web Page\r\nnot a uri\r\n", &profile).is_err());
+    }
+
+    #[test]
+    fn csv_custom_profile() {
+        let profile = CsvProfile::new(Vec::from([
+            CsvSlot::new("FN", &[], &[("Name", CsvValue::Value)]),
+            CsvSlot::new("TEL", &["home"], &[("Home", CsvValue::Value)]),
+            CsvSlot::new("TEL", &[], &[("Phone", CsvValue::Value)]),
+        ]));
+        assert_eq!(profile.headers(), Vec::from(["Name", "Home", "Phone",]));
+
+        let vcards = parse_csv("name,phone,home\n\"Doe, John\",555-0000,555-1111\n", &profile).unwrap();
+        assert_eq!(vcards[0].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:Doe\\, John\nTEL;TYPE=home:555-1111\nTEL:555-0000\nEND:VCARD\n");
+        assert_eq!(to_csv(&vcards, &profile), "Name,Home,Phone\r\n\"Doe, John\",555-1111,555-0000\r\n");
+    }
+}
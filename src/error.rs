@@ -4,20 +4,35 @@ use std::fmt::{Display, Formatter};
 use std::error::Error;
 use nom::error::{ContextError, ErrorKind, ParseError};
 
-#[derive(Debug, Eq, PartialEq)]
+/// Maximum number of bytes of input kept in parse errors.
+const ERROR_CONTEXT_LENGTH: usize = 64;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum VcardError {
     #[doc = "Signifies that a u8 array was not converted to UTF-8."]
     ConversionFailure,
+    #[doc = "Signifies that media referenced by a uri couldn't be resolved or stored."]
+    MediaUnavailable(String, String),
+    #[doc = "Signifies that media exceeds the maximum size in bytes."]
+    MediaTooLarge(usize, usize),
     #[doc = "Signifies a parsing error."]
     ParseError(Vec<String>),
-    #[doc = "Signifies that the parameter type isn't allowed for the property type."]
-    ParameterTypeNotAllowed(String, String),
+    #[doc = "Signifies that the parameter type isn't allowed for the property type, with the written parameter, the property name and the property group."]
+    ParameterTypeNotAllowed(String, String, Option<String>),
+    #[doc = "Signifies that a CLIENTPIDMAP source identifier is already used by the vCard."]
+    PropertyClientPidMapConflict(i64),
+    #[doc = "Signifies attempted removal of a CLIENTPIDMAP source that is still referenced by PID parameters."]
+    PropertyClientPidMapReferenced(i64),
     #[doc = "Signifies that the vCard was parsed without FN property."]
     PropertyFnMissing,
     #[doc = "Signifies attempted removal of a required property."]
     PropertyFnRequired,
+    #[doc = "Signifies that adding a property exceeds the maximum number of properties configured for its name."]
+    PropertyLimitExceeded(String, usize),
     #[doc = "Signifies an error retrieving a property after setting it."]
     PropertySetError(String),
+    #[doc = "Signifies that no value was given for a template placeholder."]
+    TemplateFieldMissing(String),
     #[doc = "Signifies a validation error for a value."]
     ValueInvalid(String, String),
     #[doc = "Signifies value name is not known."]
@@ -26,8 +41,12 @@ pub enum VcardError {
     ValueNotAllowed(String, String),
     #[doc = "Signifies attempted creation of a property with the parameter VALUE set to a another value type."]
     ValueMismatch(String, String, String),
+    #[doc = "Signifies that a property was created with an empty value of a type treated as unset."]
+    ValueEmpty(String),
     #[doc = "Signifies that a value string was malformed."]
     ValueMalformed(String),
+    #[doc = "Signifies that a value exceeds the maximum length in bytes configured for the property."]
+    ValueTooLong(String, usize),
 }
 
 impl VcardError {
@@ -69,8 +88,14 @@ impl From<String> for VcardError {
 
 impl ParseError<&[u8]> for VcardError {
     fn from_error_kind(input: &[u8], _: ErrorKind) -> Self {
-        if let Ok(string) = String::from_utf8(input.to_vec()) {
-            Self::ParseError(Vec::from([string]))
+        // Only keep the start of the current line, nom creates errors for every failed alternative while backtracking.
+        let end = input.iter().take(ERROR_CONTEXT_LENGTH).position(|c| *c == b'\n' || *c == b'\r').unwrap_or(input.len().min(ERROR_CONTEXT_LENGTH));
+        let line = match std::str::from_utf8(&input[..end]) {
+            Ok(string) => Ok(string),
+            Err(err) => std::str::from_utf8(&input[..err.valid_up_to()]),
+        };
+        if let Ok(string) = line {
+            Self::ParseError(Vec::from([string.to_string()]))
         } else {
             Self::ParseError(Vec::new())
         }
@@ -100,16 +125,25 @@ impl Display for VcardError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             VcardError::ConversionFailure => write!(f, "Unable to convert string to UTF8."),
+            VcardError::MediaTooLarge(size, max_size) => write!(f, "Media size of {} bytes exceeds the maximum of {} bytes.", size, max_size),
+            VcardError::MediaUnavailable(uri, reason) => write!(f, "Media {} is unavailable: {}.", uri, reason),
             VcardError::ParseError(v) => write!(f, "{}", v.join(",")),
-            VcardError::ParameterTypeNotAllowed(parameter_name, property_name) => write!(f, "Parameter {} is not allowed for {}.", parameter_name, property_name),
+            VcardError::ParameterTypeNotAllowed(parameter, property_name, Some(group)) => write!(f, "Parameter {} is not allowed for {}.{}.", parameter, group, property_name),
+            VcardError::ParameterTypeNotAllowed(parameter, property_name, None) => write!(f, "Parameter {} is not allowed for {}.", parameter, property_name),
+            VcardError::PropertyClientPidMapConflict(id) => write!(f, "CLIENTPIDMAP source {} is already in use.", id),
+            VcardError::PropertyClientPidMapReferenced(id) => write!(f, "CLIENTPIDMAP source {} is referenced by PID parameters.", id),
             VcardError::PropertyFnMissing => write!(f, "vCard is missing FN property."),
             VcardError::PropertyFnRequired => write!(f, "Property FN is required."),
+            VcardError::PropertyLimitExceeded(property_name, max_count) => write!(f, "Property {} exceeds the maximum of {} properties.", property_name, max_count),
             VcardError::PropertySetError(property) => write!(f, "There was an issue setting {} property.", property),
+            VcardError::TemplateFieldMissing(name) => write!(f, "Missing value for template placeholder {}.", name),
             VcardError::ValueInvalid(property_value, property_name) => write!(f, "Invalid value {} for {}.", property_value, property_name),
             VcardError::ValueNotAllowed(string, property_name) => write!(f, "Value type {} not allowed for {}.", string, property_name),
             VcardError::ValueMismatch(property_value, a, b) => write!(f, "Value {} does not match required type {} for {}.", property_value, a, b),
+            VcardError::ValueEmpty(property_name) => write!(f, "Value for {} is empty.", property_name),
             VcardError::ValueMalformed(property_value) => write!(f, "Unable to parse value from {}.", property_value),
             VcardError::ValueNameUnknown(name) => write!(f, "Unknown value name: {}.", name),
+            VcardError::ValueTooLong(property_name, max_length) => write!(f, "Value for {} exceeds the maximum length of {} bytes.", property_name, max_length),
         }
     }
 }
@@ -0,0 +1,47 @@
+//! Change events emitted when properties are set on or removed from a vCard.
+//!
+//! Observers are attached with [`Vcard::add_observer`](crate::vcard::Vcard::add_observer) and are notified after each
+//! successful change, e.g. for audit logging or keeping an external index in sync. Clones of a vCard share its observers.
+//!
+//! # Examples
+//! ```
+//! use std::sync::{Arc, Mutex};
+//! use vcard_parser::events::{VcardEvent, VcardObserver};
+//! use vcard_parser::vcard::property::Property;
+//! use vcard_parser::vcard::Vcard;
+//!
+//! #[derive(Debug, Default)]
+//! struct Log(Mutex<Vec<String>>);
+//!
+//! impl VcardObserver for Log {
+//!     fn notify(&self, event: &VcardEvent) {
+//!         if let VcardEvent::PropertySet { after, .. } = event {
+//!             self.0.lock().unwrap().push(after.export());
+//!         }
+//!     }
+//! }
+//!
+//! let log = Arc::new(Log::default());
+//! let mut vcard = Vcard::new("John Doe");
+//! vcard.add_observer(log.clone());
+//!
+//! vcard.set_property(&Property::try_from("NICKNAME:Johnny\n").unwrap()).unwrap();
+//! assert_eq!(log.0.lock().unwrap().as_slice(), ["NICKNAME:Johnny\n"]);
+//! ```
+
+use std::fmt::Debug;
+
+use crate::Property;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum VcardEvent {
+    /// A property was added or replaced, before is the replaced property if there was a match.
+    PropertySet { before: Option<Property>, after: Property },
+    /// A property was removed.
+    PropertyRemoved { before: Property },
+}
+
+/// Receives [change events](VcardEvent) from the vCards it is attached to.
+pub trait VcardObserver: Debug + Send + Sync {
+    fn notify(&self, event: &VcardEvent);
+}
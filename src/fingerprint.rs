@@ -0,0 +1,235 @@
+//! Stable fingerprints of vCards, e.g. for weak ETags in CardDAV servers, see [`Vcard::fingerprint`].
+//!
+//! A fingerprint is the lowercase hex SHA-256 digest of the [canonical form](canonical) of a vCard. The canonical form
+//! leaves out PID parameters and the CLIENTPIDMAP, PRODID and REV properties, which change without changing the
+//! contact, merges repeated TYPE parameters, uppercases group, property and parameter names, lowercases and sorts the
+//! values of enumerated parameters such as TYPE, sorts parameters by name and then sorts the content lines. The canonical form and digest don't change between versions of this crate, so the same
+//! logical content always has the same fingerprint.
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::vcard::Vcard;
+//!
+//! let a = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;TYPE=home;TYPE=voice:555-5555\nREV:20240101T000000Z\nEND:VCARD\n").expect("Unable to parse vCard.");
+//! let b = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\ntel;TYPE=VOICE,home:555-5555\nFN:John Doe\nEND:VCARD\n").expect("Unable to parse vCard.");
+//!
+//! assert_eq!(a.fingerprint(), b.fingerprint());
+//! assert_eq!(a.fingerprint().len(), 64);
+//! ```
+
+use std::fmt::Write;
+
+use crate::constants::ParameterName;
+use crate::options::{ExportOptions, ParameterStyle, ProdIdPolicy};
+use crate::parse::property::property;
+use crate::vcard::Vcard;
+
+/// Parameters with case-insensitive enumerated values, which are lowercased and sorted in the canonical form.
+const ENUMERATED_PARAMETERS: [&str; 4] = [
+    ParameterName::CALSCALE,
+    ParameterName::LEVEL,
+    ParameterName::TYPE,
+    ParameterName::VALUE,
+];
+
+/// Round constants of SHA-256, see [RFC 6234 5.1](https://datatracker.ietf.org/doc/html/rfc6234#section-5.1).
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b,
+    0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// Get the canonical form of a vCard, which is hashed for its [fingerprint](fingerprint).
+///
+/// # Examples
+/// ```
+/// use vcard_parser::fingerprint::canonical;
+/// use vcard_parser::vcard::Vcard;
+///
+/// let vcard = Vcard::try_from(("urn:uuid:client", "BEGIN:VCARD\nVERSION:4.0\nitem1.email:john@example.com\nFN:John Doe\nPRODID:-//Example//EN\nEND:VCARD\n")).expect("Unable to parse vCard.");
+/// assert_eq!(canonical(&vcard), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nITEM1.EMAIL:john@example.com\nEND:VCARD\n");
+/// ```
+pub fn canonical(vcard: &Vcard) -> String {
+    // All options are set explicitly, so changes to the defaults don't change the canonical form.
+    let options = ExportOptions {
+        include_clientpidmap: false,
+        include_pids: false,
+        include_rev: false,
+        include_properties: None,
+        exclude_properties: Vec::new(),
+        sort_properties: true,
+        fold_lines: false,
+        prodid: ProdIdPolicy::Suppress,
+        parameter_style: ParameterStyle::Merged,
+        include_empty: true,
+    };
+
+    // Sorting the canonical lines keeps the order of properties independent of the order of their parameters.
+    let mut lines: Vec<String> = vcard.export_with(&options).lines().map(canonical_line).collect();
+    if lines.len() > 3 {
+        let end = lines.len() - 1;
+        lines[2..end].sort();
+    }
+
+    let mut string = String::new();
+    for line in lines {
+        string.push_str(line.as_str());
+        string.push('\n');
+    }
+
+    string
+}
+
+/// Helper function getting the canonical form of an exported content line.
+fn canonical_line(line: &str) -> String {
+    let data = format!("{}\n", line);
+    let Ok((_, ((group, name), parameters, (value, _)))) = property(data.as_bytes()) else {
+        let index = line.find([';', ':']).unwrap_or(line.len());
+        return format!("{}{}", line[..index].to_uppercase(), &line[index..]);
+    };
+
+    let mut parameters: Vec<(String, String)> = parameters
+        .into_iter()
+        .map(|(name, value)| {
+            let name = String::from_utf8_lossy(name).to_uppercase();
+            let value = String::from_utf8_lossy(value).to_string();
+            if !ENUMERATED_PARAMETERS.contains(&name.as_str()) {
+                return (name, value);
+            }
+            let mut values: Vec<String> = value.trim_matches('"').split(',').map(|value| value.to_lowercase()).collect();
+            values.sort();
+            values.dedup();
+            (name, values.join(","))
+        })
+        .collect();
+    parameters.sort();
+
+    let mut string = String::new();
+    if let Some(group) = group {
+        string.push_str(String::from_utf8_lossy(group).to_uppercase().as_str());
+        string.push('.');
+    }
+    string.push_str(String::from_utf8_lossy(name).to_uppercase().as_str());
+    for (name, value) in parameters {
+        let _ = write!(string, ";{}={}", name, value);
+    }
+    string.push(':');
+    string.push_str(String::from_utf8_lossy(value).as_ref());
+    string
+}
+
+/// Get the fingerprint of a vCard, the lowercase hex SHA-256 digest of its [canonical form](canonical).
+pub fn fingerprint(vcard: &Vcard) -> String {
+    let mut string = String::with_capacity(64);
+    for byte in sha256(canonical(vcard).as_bytes()) {
+        // Writing to a string can't fail.
+        let _ = write!(string, "{:02x}", byte);
+    }
+    string
+}
+
+/// Get the SHA-256 digest of bytes, see [RFC 6234](https://datatracker.ietf.org/doc/html/rfc6234).
+///
+/// # Examples
+/// ```
+/// use vcard_parser::fingerprint::sha256;
+///
+/// let digest = sha256(b"abc");
+/// assert_eq!(digest[..4], [0xba, 0x78, 0x16, 0xbf]);
+/// ```
+pub fn sha256(bytes: &[u8]) -> [u8; 32] {
+    let mut state: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+    ];
+
+    let mut message = bytes.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((bytes.len() as u64).wrapping_mul(8)).to_be_bytes());
+
+    for block in message.chunks_exact(64) {
+        let mut w = [0u32; 64];
+        for (i, word) in block.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes([
+                word[0], word[1], word[2], word[3],
+            ]);
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(K[i]).wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+
+        for (value, add) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *value = value.wrapping_add(add);
+        }
+    }
+
+    let mut digest = [0u8; 32];
+    for (chunk, value) in digest.chunks_exact_mut(4).zip(state) {
+        chunk.copy_from_slice(&value.to_be_bytes());
+    }
+    digest
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fingerprint::{canonical, sha256};
+    use crate::vcard::Vcard;
+
+    fn hex(bytes: [u8; 32]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    pub fn fingerprint_sha256() {
+        assert_eq!(hex(sha256(b"")), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+        assert_eq!(hex(sha256(b"abc")), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        assert_eq!(hex(sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")), "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1");
+        assert_eq!(hex(sha256("a".repeat(1000).as_bytes())), "41edece42d63e8d9bf515a9ba6932e1c20cbc9f5a5d134645adb5db1b9737ea3");
+    }
+
+    #[test]
+    pub fn fingerprint_vcard() {
+        let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL;TYPE=work:john@example.com\nNOTE:Hello\nEND:VCARD\n";
+        let vcard = Vcard::try_from(text).unwrap();
+        assert_eq!(vcard.fingerprint(), "942ba3d7f35f2821388e1f6086f856c6956dbe5ab028d0253d7cb62fdca0c451");
+
+        let synced = Vcard::try_from(("urn:uuid:client", text.replace("FN:John Doe\n", "FN:John Doe\nREV:20240101T000000Z\n").as_str())).unwrap();
+        assert_eq!(canonical(&synced), canonical(&vcard));
+        assert_eq!(synced.fingerprint(), vcard.fingerprint());
+
+        let changed = Vcard::try_from(text.replace("Hello", "Goodbye").as_str()).unwrap();
+        assert_ne!(changed.fingerprint(), vcard.fingerprint());
+
+        // Parameter order and the case of names and enumerated values don't change the fingerprint.
+        let a = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;TYPE=home;PREF=1:555\nTEL;TYPE=work;PREF=1:556\nEND:VCARD\n").unwrap();
+        let b = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;pref=1;TYPE=HOME:555\nTEL;TYPE=Work;PREF=1:556\nEND:VCARD\n").unwrap();
+        assert_eq!(canonical(&a), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;PREF=1;TYPE=home:555\nTEL;PREF=1;TYPE=work:556\nEND:VCARD\n");
+        assert_eq!(canonical(&a), canonical(&b));
+        assert_eq!(a.fingerprint(), b.fingerprint());
+
+        let other = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;TYPE=home;PREF=2:555\nTEL;TYPE=work;PREF=1:556\nEND:VCARD\n").unwrap();
+        assert_ne!(a.fingerprint(), other.fingerprint());
+    }
+}
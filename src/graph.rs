@@ -0,0 +1,278 @@
+//! Relationship graphs of a collection of vCards, e.g. for org charts or household grouping.
+//!
+//! Every vCard with a UID becomes a node. RELATED properties with a uri value become edges typed by their TYPE
+//! parameters, or "related" without any, and MEMBER properties of groups become "member" edges from the group to the
+//! member, see [RFC 6350 6.6.5](https://datatracker.ietf.org/doc/html/rfc6350#section-6.6.5) and [RFC 6350 6.6.6](https://datatracker.ietf.org/doc/html/rfc6350#section-6.6.6).
+//! Edges may point to UIDs of vCards outside the collection, [validate_references] reports urn:uuid references which
+//! don't resolve within it.
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::graph::VcardGraph;
+//! use vcard_parser::parse_vcards;
+//!
+//! let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nUID:urn:uuid:1\nRELATED;TYPE=spouse:urn:uuid:2\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Doe\nUID:urn:uuid:2\nEND:VCARD\n";
+//! let vcards = parse_vcards(input).expect("Unable to parse text.");
+//!
+//! let graph = VcardGraph::build(&vcards);
+//! assert_eq!(graph.nodes.len(), 2);
+//! assert_eq!(graph.edges[0].relation, "spouse");
+//! assert_eq!(graph.to_dot(), "digraph vcards {\n  \"urn:uuid:1\" [label=\"John Doe\"];\n  \"urn:uuid:2\" [label=\"Jane Doe\"];\n  \"urn:uuid:1\" -> \"urn:uuid:2\" [label=\"spouse\"];\n}\n");
+//! ```
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::constants::PropertyName;
+use crate::parse::encoding::json_string;
+use crate::vcard::parameter::Parameter;
+use crate::vcard::value::Value;
+use crate::vcard::Vcard;
+use crate::{HasName, HasParameters, HasValue};
+
+/// A vCard in a [VcardGraph](VcardGraph).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphNode {
+    /// The UID of the vCard.
+    pub uid: String,
+    /// The [display name](Vcard::display_name) of the vCard.
+    pub name: String,
+    /// The lowercase KIND of the vCard, "individual" if the vCard has no KIND property.
+    pub kind: String,
+}
+
+/// A typed relation between two vCards in a [VcardGraph](VcardGraph).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphEdge {
+    /// The UID of the vCard with the RELATED or MEMBER property.
+    pub from: String,
+    /// The uri of the related vCard or member.
+    pub to: String,
+    /// The lowercase TYPE of the RELATED property, "related" if untyped, or "member".
+    pub relation: String,
+}
+
+/// Graph of the relations between vCards, see the [graph](crate::graph) module.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VcardGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+impl VcardGraph {
+    /// Build a graph of an array of vCards, vCards without a UID are left out.
+    pub fn build(vcards: &[Vcard]) -> Self {
+        let mut graph = Self::default();
+
+        for vcard in vcards {
+            let Some(uid) = vcard.get_property_by_name(PropertyName::UID).map(|uid| uid.get_value().to_string()) else {
+                continue;
+            };
+
+            for property in vcard.get_properties_by_name(PropertyName::RELATED) {
+                let Value::ValueUri(uri) = property.get_value() else {
+                    continue;
+                };
+
+                let mut relations: Vec<String> = Vec::new();
+                for parameter in property.get_parameters() {
+                    if let (Parameter::ParameterType(_), Value::ValueTextList(list)) = (&parameter, parameter.get_value()) {
+                        for relation in list.value.iter().map(|relation| relation.to_lowercase()) {
+                            if !relation.is_empty() && !relations.contains(&relation) {
+                                relations.push(relation);
+                            }
+                        }
+                    }
+                }
+                if relations.is_empty() {
+                    relations.push(String::from("related"));
+                }
+
+                for relation in relations {
+                    graph.edges.push(GraphEdge {
+                        from: uid.clone(),
+                        to: uri.value.clone(),
+                        relation,
+                    });
+                }
+            }
+
+            for property in vcard.get_properties_by_name(PropertyName::MEMBER) {
+                graph.edges.push(GraphEdge {
+                    from: uid.clone(),
+                    to: property.get_value().to_string(),
+                    relation: String::from("member"),
+                });
+            }
+
+            let kind = vcard.get_property_by_name(PropertyName::KIND).map(|kind| kind.get_value().to_string().to_lowercase()).unwrap_or_else(|| String::from("individual"));
+            graph.nodes.push(GraphNode {
+                uid,
+                name: vcard.display_name(),
+                kind,
+            });
+        }
+
+        graph
+    }
+
+    /// Get the node of a UID, if the vCard is in the graph.
+    pub fn node(&self, uid: &str) -> Option<&GraphNode> {
+        self.nodes.iter().find(|node| node.uid == uid)
+    }
+
+    /// Write the graph in the [DOT language](https://graphviz.org/doc/info/lang.html), nodes are labelled with their name and edges with their relation.
+    pub fn to_dot(&self) -> String {
+        let mut string = String::from("digraph vcards {\n");
+
+        // Writing to a string can't fail.
+        for node in self.nodes.iter() {
+            let _ = writeln!(string, "  \"{}\" [label=\"{}\"];", dot_escape(node.uid.as_str()), dot_escape(node.name.as_str()));
+        }
+        for edge in self.edges.iter() {
+            let _ = writeln!(string, "  \"{}\" -> \"{}\" [label=\"{}\"];", dot_escape(edge.from.as_str()), dot_escape(edge.to.as_str()), dot_escape(edge.relation.as_str()));
+        }
+
+        string.push_str("}\n");
+        string
+    }
+
+    /// Write the graph as a JSON object with "nodes" and "edges" arrays.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::graph::VcardGraph;
+    /// use vcard_parser::parse_vcards;
+    ///
+    /// let input = "BEGIN:VCARD\nVERSION:4.0\nKIND:group\nFN:The Doe Family\nUID:urn:uuid:1\nMEMBER:urn:uuid:2\nEND:VCARD\n";
+    /// let graph = VcardGraph::build(&parse_vcards(input).expect("Unable to parse text."));
+    /// assert_eq!(graph.to_json(), r#"{"nodes":[{"uid":"urn:uuid:1","name":"The Doe Family","kind":"group"}],"edges":[{"from":"urn:uuid:1","to":"urn:uuid:2","relation":"member"}]}"#);
+    /// ```
+    pub fn to_json(&self) -> String {
+        let nodes: Vec<String> = self.nodes.iter().map(|node| format!("{{\"uid\":{},\"name\":{},\"kind\":{}}}", json_string(node.uid.as_str()), json_string(node.name.as_str()), json_string(node.kind.as_str()))).collect();
+        let edges: Vec<String> = self.edges.iter().map(|edge| format!("{{\"from\":{},\"to\":{},\"relation\":{}}}", json_string(edge.from.as_str()), json_string(edge.to.as_str()), json_string(edge.relation.as_str()))).collect();
+
+        format!("{{\"nodes\":[{}],\"edges\":[{}]}}", nodes.join(","), edges.join(","))
+    }
+}
+
+/// A MEMBER or RELATED urn:uuid reference to a vCard missing from a collection, see [validate_references].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BrokenReference {
+    /// The position of the vCard with the reference in the array.
+    pub index: usize,
+    /// The name of the property, MEMBER or RELATED.
+    pub property: String,
+    /// The urn:uuid reference.
+    pub reference: String,
+}
+
+/// Check that the MEMBER and RELATED properties referencing a urn:uuid resolve to the UID of a vCard in the array,
+/// e.g. before publishing a group address book. UUIDs are compared ignoring case, other uris aren't checked.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::graph::validate_references;
+/// use vcard_parser::parse_vcards;
+///
+/// let input = "BEGIN:VCARD\nVERSION:4.0\nKIND:group\nFN:The Doe Family\nUID:urn:uuid:1\nMEMBER:urn:uuid:2\nMEMBER:urn:uuid:3\nMEMBER:mailto:john@example.com\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Doe\nUID:urn:uuid:2\nEND:VCARD\n";
+/// let broken = validate_references(&parse_vcards(input).expect("Unable to parse text."));
+/// assert_eq!(broken.len(), 1);
+/// assert_eq!((broken[0].index, broken[0].property.as_str(), broken[0].reference.as_str()), (0, "MEMBER", "urn:uuid:3"));
+/// ```
+pub fn validate_references(vcards: &[Vcard]) -> Vec<BrokenReference> {
+    let uids: HashSet<String> = vcards.iter().filter_map(|vcard| vcard.get_property_by_name(PropertyName::UID)).map(|uid| uid.get_value().to_string().to_lowercase()).collect();
+
+    let mut broken = Vec::new();
+    for (index, vcard) in vcards.iter().enumerate() {
+        for property in vcard.get_properties().iter().filter(|p| p.name() == PropertyName::MEMBER || p.name() == PropertyName::RELATED) {
+            let Value::ValueUri(uri) = property.get_value() else {
+                continue;
+            };
+            if uri.value.get(..9).is_some_and(|scheme| scheme.eq_ignore_ascii_case("urn:uuid:")) && !uids.contains(&uri.value.to_lowercase()) {
+                broken.push(BrokenReference {
+                    index,
+                    property: property.name().to_string(),
+                    reference: uri.value.clone(),
+                });
+            }
+        }
+    }
+
+    broken
+}
+
+/// Helper function escaping a DOT quoted string.
+fn dot_escape(str: &str) -> String {
+    str.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{validate_references, BrokenReference, GraphEdge, VcardGraph};
+    use crate::parse_vcards;
+
+    #[test]
+    pub fn graph_build() {
+        let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John \"JD\" Doe\nUID:urn:uuid:1\nRELATED;TYPE=spouse,CO-RESIDENT:urn:uuid:2\nRELATED:urn:uuid:3\nRELATED;VALUE=text:My assistant\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Doe\nUID:urn:uuid:2\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nKIND:group\nFN:Doe Household\nUID:urn:uuid:4\nMEMBER:urn:uuid:1\nMEMBER:urn:uuid:2\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:No UID\nRELATED:urn:uuid:1\nEND:VCARD\n";
+        let graph = VcardGraph::build(&parse_vcards(input).unwrap());
+
+        assert_eq!(
+            graph.nodes.iter().map(|node| node.uid.as_str()).collect::<Vec<&str>>(),
+            [
+                "urn:uuid:1",
+                "urn:uuid:2",
+                "urn:uuid:4"
+            ]
+        );
+        assert_eq!(graph.node("urn:uuid:4").unwrap().kind, "group");
+        assert_eq!(graph.node("urn:uuid:1").unwrap().kind, "individual");
+        assert!(graph.node("urn:uuid:3").is_none());
+
+        let edge = |from: &str, to: &str, relation: &str| GraphEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            relation: relation.to_string(),
+        };
+        assert_eq!(
+            graph.edges,
+            [
+                edge("urn:uuid:1", "urn:uuid:2", "spouse"),
+                edge("urn:uuid:1", "urn:uuid:2", "co-resident"),
+                edge("urn:uuid:1", "urn:uuid:3", "related"),
+                edge("urn:uuid:4", "urn:uuid:1", "member"),
+                edge("urn:uuid:4", "urn:uuid:2", "member"),
+            ]
+        );
+
+        let dot = graph.to_dot();
+        assert!(dot.contains("  \"urn:uuid:1\" [label=\"John \\\"JD\\\" Doe\"];\n"));
+        assert!(dot.contains("  \"urn:uuid:4\" -> \"urn:uuid:1\" [label=\"member\"];\n"));
+
+        let json = graph.to_json();
+        assert!(json.starts_with("{\"nodes\":[{\"uid\":\"urn:uuid:1\",\"name\":\"John \\\"JD\\\" Doe\",\"kind\":\"individual\"},"));
+        assert!(json.ends_with("{\"from\":\"urn:uuid:4\",\"to\":\"urn:uuid:2\",\"relation\":\"member\"}]}"));
+
+        assert_eq!(VcardGraph::build(&[]).to_json(), "{\"nodes\":[],\"edges\":[]}");
+    }
+
+    #[test]
+    pub fn graph_validate_references() {
+        let input = "BEGIN:VCARD\nVERSION:4.0\nKIND:group\nFN:Doe Household\nUID:urn:uuid:A1\nMEMBER:urn:uuid:a2\nMEMBER:URN:UUID:a3\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Doe\nUID:urn:uuid:a2\nRELATED;TYPE=spouse:urn:uuid:a1\nRELATED:urn:uuid:a4\nRELATED:https://example.com/john\nRELATED;VALUE=text:urn:uuid:a5\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:No UID\nRELATED:urn:uuid:a2\nEND:VCARD\n";
+        let broken = validate_references(&parse_vcards(input).unwrap());
+
+        let reference = |index: usize, property: &str, reference: &str| BrokenReference {
+            index,
+            property: property.to_string(),
+            reference: reference.to_string(),
+        };
+        assert_eq!(
+            broken,
+            [
+                reference(0, "MEMBER", "urn:UUID:a3"),
+                reference(1, "RELATED", "urn:uuid:a4")
+            ]
+        );
+        assert!(validate_references(&[]).is_empty());
+    }
+}
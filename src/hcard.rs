@@ -0,0 +1,569 @@
+//! Conversion between vCards and [h-card](https://microformats.org/wiki/h-card) microformats2 HTML.
+//!
+//! [`to_hcard`] renders the FN, N, NICKNAME, PHOTO, BDAY, ANNIVERSARY, ADR, TEL, EMAIL, IMPP, TZ, GEO, TITLE, ROLE,
+//! LOGO, ORG, CATEGORIES, NOTE, UID, KEY and URL properties of a vCard, other properties have no h-card equivalent
+//! and are left out. [`parse_hcards`] reads those properties back from simple h-card markup, nested microformats other
+//! than an h-adr address and properties with invalid values are skipped.
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::hcard::{parse_hcards, to_hcard};
+//! use vcard_parser::vcard::Vcard;
+//!
+//! let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL:john@example.com\nURL:https://example.com/\nEND:VCARD\n").expect("Unable to parse vCard.");
+//!
+//! let html = to_hcard(&vcard);
+//! assert_eq!(html, "<div class=\"h-card\">\n  <span class=\"p-name\">John Doe</span>\n  <a class=\"u-email\" href=\"mailto:john@example.com\">john@example.com</a>\n  <a class=\"u-url\" href=\"https://example.com/\">https://example.com/</a>\n</div>\n");
+//!
+//! let vcards = parse_hcards(html.as_str()).expect("Unable to parse h-card.");
+//! assert_eq!(vcards[0].export(), vcard.export());
+//! ```
+
+use crate::constants::PropertyName;
+use crate::parse::encoding::escape;
+use crate::vcard::value::Value;
+use crate::vcard::Vcard;
+use crate::{HasName, HasValue, Property, VcardError};
+
+/// The h-card class names of the N components, in value order.
+const N_CLASSES: [&str; 5] = [
+    "p-family-name",
+    "p-given-name",
+    "p-additional-name",
+    "p-honorific-prefix",
+    "p-honorific-suffix",
+];
+
+/// The h-adr class names of the ADR components, in value order.
+const ADR_CLASSES: [&str; 7] = [
+    "p-post-office-box",
+    "p-extended-address",
+    "p-street-address",
+    "p-locality",
+    "p-region",
+    "p-postal-code",
+    "p-country-name",
+];
+
+/// The h-card class names of properties with a single value.
+const PROPERTY_CLASSES: [(&str, &str); 15] = [
+    (PropertyName::ANNIVERSARY, "dt-anniversary"),
+    (PropertyName::BDAY, "dt-bday"),
+    (PropertyName::EMAIL, "u-email"),
+    (PropertyName::GEO, "u-geo"),
+    (PropertyName::IMPP, "u-impp"),
+    (PropertyName::KEY, "u-key"),
+    (PropertyName::LOGO, "u-logo"),
+    (PropertyName::NOTE, "p-note"),
+    (PropertyName::PHOTO, "u-photo"),
+    (PropertyName::ROLE, "p-role"),
+    (PropertyName::TEL, "p-tel"),
+    (PropertyName::TITLE, "p-job-title"),
+    (PropertyName::TZ, "p-tz"),
+    (PropertyName::UID, "u-uid"),
+    (PropertyName::URL, "u-url"),
+];
+
+/// Elements without content or end tag.
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source", "track", "wbr",
+];
+
+/// Render a vCard as an h-card.
+pub fn to_hcard(vcard: &Vcard) -> String {
+    let mut string = String::from("<div class=\"h-card\">\n");
+
+    for property in vcard.get_properties().iter() {
+        let value = property.get_value();
+        match (property.name(), value) {
+            (PropertyName::FN, _) => string.push_str(&html_element("span", "p-name", None, &value_text(value))),
+            (PropertyName::N, Value::ValueListComponent(list)) => {
+                for (class, component) in N_CLASSES.iter().zip(list.value.iter()) {
+                    for str in component.iter().filter(|s| !s.is_empty()) {
+                        string.push_str(&html_element("span", class, None, str));
+                    }
+                }
+            }
+            (PropertyName::ADR, Value::ValueListComponent(list)) => {
+                string.push_str("  <div class=\"p-adr h-adr\">\n");
+                for (class, component) in ADR_CLASSES.iter().zip(list.value.iter()) {
+                    for str in component.iter().filter(|s| !s.is_empty()) {
+                        string.push_str("  ");
+                        string.push_str(&html_element("span", class, None, str));
+                    }
+                }
+                string.push_str("  </div>\n");
+            }
+            (PropertyName::CATEGORIES | PropertyName::NICKNAME, Value::ValueTextList(list)) => {
+                let class = if property.name() == PropertyName::CATEGORIES { "p-category" } else { "p-nickname" };
+                for str in list.value.iter().filter(|s| !s.is_empty()) {
+                    string.push_str(&html_element("span", class, None, str));
+                }
+            }
+            (PropertyName::ORG, Value::ValueTextList(list)) => string.push_str(&html_element("span", "p-org", None, &list.value.join(", "))),
+            (name, value) => {
+                let Some((_, class)) = PROPERTY_CLASSES.iter().find(|(n, _)| *n == name) else { continue };
+                let text = value_text(value);
+                string.push_str(&match name {
+                    PropertyName::ANNIVERSARY | PropertyName::BDAY => html_element("time", class, Some(("datetime", &text)), &text),
+                    PropertyName::EMAIL => html_element("a", class, Some(("href", &format!("mailto:{}", text.trim_start_matches("mailto:")))), text.trim_start_matches("mailto:")),
+                    PropertyName::TEL => html_element("a", class, Some(("href", &format!("tel:{}", text.trim_start_matches("tel:")))), text.trim_start_matches("tel:")),
+                    PropertyName::LOGO | PropertyName::PHOTO => html_element("img", class, Some(("src", &text)), ""),
+                    PropertyName::GEO | PropertyName::UID | PropertyName::KEY => html_element("data", class, Some(("value", &text)), &text),
+                    PropertyName::IMPP | PropertyName::URL => html_element("a", class, Some(("href", &text)), &text),
+                    _ => html_element("span", class, None, &text),
+                });
+            }
+        }
+    }
+
+    string.push_str("</div>\n");
+
+    string
+}
+
+/// Parse all h-cards in an HTML document, including h-cards nested in other elements.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::hcard::parse_hcards;
+///
+/// let html = "<p class=\"h-card\"><img class=\"u-photo\" src=\"https://example.com/photo.png\" alt=\"\"><a class=\"p-name u-url\" href=\"https://example.com/\">Jane &amp; John</a></p>";
+/// let vcards = parse_hcards(html).expect("Unable to parse h-card.");
+/// assert_eq!(vcards[0].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:Jane & John\nPHOTO:https://example.com/photo.png\nURL:https://example.com/\nEND:VCARD\n");
+/// ```
+pub fn parse_hcards(html: &str) -> Result<Vec<Vcard>, VcardError> {
+    let nodes = html_nodes(html);
+    let mut elements = Vec::new();
+    find_hcards(&nodes, &mut elements);
+
+    let mut vcards = Vec::new();
+    for element in elements {
+        vcards.push(hcard_vcard(element));
+    }
+
+    Ok(vcards)
+}
+
+/// A parsed HTML node.
+#[derive(Clone, Debug)]
+enum HtmlNode {
+    Element(HtmlElement),
+    Text(String),
+}
+
+/// A parsed HTML element, attribute names are lowercased and values are decoded.
+#[derive(Clone, Debug, Default)]
+struct HtmlElement {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<HtmlNode>,
+}
+
+impl HtmlElement {
+    /// Get the value of an attribute.
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+    }
+
+    /// Get the class names of the element.
+    fn classes(&self) -> Vec<&str> {
+        self.attribute("class").unwrap_or_default().split_ascii_whitespace().collect()
+    }
+
+    /// Check if the element is the root of a microformat, e.g. an h-card or h-adr.
+    fn is_microformat(&self) -> bool {
+        self.classes().iter().any(|class| class.starts_with("h-"))
+    }
+
+    /// Get the text content of the element and its descendants.
+    fn text(&self) -> String {
+        let mut string = String::new();
+        for child in self.children.iter() {
+            match child {
+                HtmlNode::Element(element) if element.name == "img" => string.push_str(element.attribute("alt").unwrap_or_default()),
+                HtmlNode::Element(element) => string.push_str(&element.text()),
+                HtmlNode::Text(text) => string.push_str(text),
+            }
+        }
+        string
+    }
+
+    /// Get the value of a property element, see [microformats2 parsing](https://microformats.org/wiki/microformats2-parsing).
+    fn property_value(&self, class: &str) -> String {
+        let attributes: &[(&str, &str)] = if class.starts_with("u-") {
+            &[
+                ("a", "href"),
+                ("area", "href"),
+                ("link", "href"),
+                ("img", "src"),
+                ("audio", "src"),
+                ("video", "src"),
+                ("source", "src"),
+                ("object", "data"),
+                ("data", "value"),
+                ("abbr", "title"),
+            ]
+        } else if class.starts_with("dt-") {
+            &[
+                ("time", "datetime"),
+                ("ins", "datetime"),
+                ("del", "datetime"),
+                ("data", "value"),
+                ("abbr", "title"),
+            ]
+        } else {
+            &[
+                ("data", "value"),
+                ("abbr", "title"),
+                ("img", "alt"),
+            ]
+        };
+
+        match attributes.iter().find_map(|(element, attribute)| if self.name == *element { self.attribute(attribute) } else { None }) {
+            Some(value) => value.trim().to_string(),
+            None => self.text().trim().to_string(),
+        }
+    }
+}
+
+/// Helper function rendering an indented element with an optional attribute.
+fn html_element(name: &str, class: &str, attribute: Option<(&str, &str)>, text: &str) -> String {
+    let attribute = attribute.map(|(name, value)| format!(" {}=\"{}\"", name, html_escape(value))).unwrap_or_default();
+    if VOID_ELEMENTS.contains(&name) {
+        format!("  <{} class=\"{}\"{} alt=\"{}\">\n", name, class, attribute, html_escape(text))
+    } else {
+        format!("  <{} class=\"{}\"{}>{}</{}>\n", name, class, attribute, html_escape(text), name)
+    }
+}
+
+/// Helper function escaping HTML text and attribute values.
+fn html_escape(str: &str) -> String {
+    let mut string = String::with_capacity(str.len());
+    for char in str.chars() {
+        match char {
+            '&' => string.push_str("&amp;"),
+            '<' => string.push_str("&lt;"),
+            '>' => string.push_str("&gt;"),
+            '"' => string.push_str("&quot;"),
+            '\'' => string.push_str("&#39;"),
+            _ => string.push(char),
+        }
+    }
+    string
+}
+
+/// Helper function decoding HTML character references, unknown references are kept as is.
+fn html_unescape(str: &str) -> String {
+    let mut string = String::with_capacity(str.len());
+    let mut rest = str;
+
+    while let Some(start) = rest.find('&') {
+        string.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').filter(|end| *end <= 10).and_then(|end| {
+            let reference = &rest[1..end];
+            let char = match reference {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some('\u{a0}'),
+                _ => match reference.strip_prefix('#') {
+                    Some(hex) if hex.starts_with(['x', 'X']) => u32::from_str_radix(&hex[1..], 16).ok().and_then(char::from_u32),
+                    Some(decimal) => decimal.parse::<u32>().ok().and_then(char::from_u32),
+                    None => None,
+                },
+            };
+            char.map(|char| (char, end))
+        });
+
+        match decoded {
+            Some((char, end)) => {
+                string.push(char);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                string.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    string.push_str(rest);
+
+    string
+}
+
+/// Helper function parsing HTML into a tree of nodes, unclosed elements are closed at the end of their parent.
+fn html_nodes(html: &str) -> Vec<HtmlNode> {
+    let mut stack: Vec<HtmlElement> = Vec::from([HtmlElement::default()]);
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push_node(&mut stack, HtmlNode::Text(html_unescape(rest)));
+            break;
+        };
+        if start > 0 {
+            push_node(&mut stack, HtmlNode::Text(html_unescape(&rest[..start])));
+        }
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+        } else if let Some(end_tag) = rest.strip_prefix("</") {
+            let end = end_tag.find('>').unwrap_or(end_tag.len());
+            let name = end_tag[..end].trim().to_lowercase();
+            rest = end_tag.get(end + 1..).unwrap_or_default();
+            if stack.iter().skip(1).any(|element| element.name == name) {
+                while let Some(element) = stack.pop() {
+                    let closed = element.name == name;
+                    push_node(&mut stack, HtmlNode::Element(element));
+                    if closed {
+                        break;
+                    }
+                }
+            }
+        } else if rest[1..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+            let (element, self_closing, remainder) = html_start_tag(&rest[1..]);
+            rest = remainder;
+            if element.name == "script" || element.name == "style" {
+                let end = format!("</{}", element.name);
+                rest = rest.to_ascii_lowercase().find(&end).map_or("", |i| &rest[i..]);
+                push_node(&mut stack, HtmlNode::Element(element));
+            } else if self_closing || VOID_ELEMENTS.contains(&element.name.as_str()) {
+                push_node(&mut stack, HtmlNode::Element(element));
+            } else {
+                stack.push(element);
+            }
+        } else {
+            push_node(&mut stack, HtmlNode::Text(String::from("<")));
+            rest = &rest[1..];
+        }
+    }
+
+    while stack.len() > 1 {
+        if let Some(element) = stack.pop() {
+            push_node(&mut stack, HtmlNode::Element(element));
+        }
+    }
+
+    stack.pop().map(|root| root.children).unwrap_or_default()
+}
+
+/// Helper function appending a node to the innermost open element.
+fn push_node(stack: &mut [HtmlElement], node: HtmlNode) {
+    if let Some(parent) = stack.last_mut() {
+        parent.children.push(node);
+    }
+}
+
+/// Helper function parsing a start tag after the "<", returning the element, whether it is self-closing, and the rest of the input.
+fn html_start_tag(str: &str) -> (HtmlElement, bool, &str) {
+    let end = str.find(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/').unwrap_or(str.len());
+    let mut element = HtmlElement {
+        name: str[..end].to_lowercase(),
+        ..HtmlElement::default()
+    };
+    let mut rest = &str[end..];
+
+    loop {
+        rest = rest.trim_start();
+        if let Some(remainder) = rest.strip_prefix("/>") {
+            return (element, true, remainder);
+        }
+        if let Some(remainder) = rest.strip_prefix('>') {
+            return (element, false, remainder);
+        }
+        if rest.is_empty() {
+            return (element, false, rest);
+        }
+
+        let end = rest.find(|c: char| c.is_ascii_whitespace() || c == '=' || c == '>' || c == '/').unwrap_or(rest.len()).max(1);
+        let name = rest[..end].to_lowercase();
+        rest = rest[end..].trim_start();
+
+        let mut value = String::new();
+        if let Some(remainder) = rest.strip_prefix('=') {
+            rest = remainder.trim_start();
+            if let Some(quote) = rest.chars().next().filter(|c| *c == '"' || *c == '\'') {
+                let end = rest[1..].find(quote).map_or(rest.len(), |i| i + 1);
+                value = html_unescape(&rest[1..end]);
+                rest = rest.get(end + 1..).unwrap_or_default();
+            } else {
+                let end = rest.find(|c: char| c.is_ascii_whitespace() || c == '>').unwrap_or(rest.len());
+                value = html_unescape(&rest[..end]);
+                rest = &rest[end..];
+            }
+        }
+
+        element.attributes.push((name, value));
+    }
+}
+
+/// Helper function collecting all h-card elements.
+fn find_hcards<'a>(nodes: &'a [HtmlNode], elements: &mut Vec<&'a HtmlElement>) {
+    for node in nodes.iter() {
+        if let HtmlNode::Element(element) = node {
+            if element.classes().contains(&"h-card") {
+                elements.push(element);
+            }
+            find_hcards(&element.children, elements);
+        }
+    }
+}
+
+/// Helper function collecting the property class names and values of a microformat, without descending into nested microformats.
+fn find_properties<'a>(nodes: &'a [HtmlNode], properties: &mut Vec<(String, String, &'a HtmlElement)>) {
+    for node in nodes.iter() {
+        if let HtmlNode::Element(element) = node {
+            for class in element.classes().iter().filter(|class| class.starts_with("p-") || class.starts_with("u-") || class.starts_with("dt-")) {
+                properties.push((class.to_string(), element.property_value(class), element));
+            }
+            if !element.is_microformat() {
+                find_properties(&element.children, properties);
+            }
+        }
+    }
+}
+
+/// Helper function creating a vCard from an h-card element.
+fn hcard_vcard(element: &HtmlElement) -> Vcard {
+    let mut properties = Vec::new();
+    find_properties(&element.children, &mut properties);
+
+    let name = properties.iter().find(|(class, _, _)| class == "p-name").map(|(_, value, _)| value.clone()).unwrap_or_default();
+    let mut vcard = Vcard::new(name.as_str());
+
+    let mut n: Vec<Vec<String>> = Vec::from([
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    ]);
+    let mut categories = Vec::new();
+    let mut created = Vec::new();
+
+    for (class, value, element) in properties.iter() {
+        if let Some(i) = N_CLASSES.iter().position(|c| c == class) {
+            n[i].push(escape(value));
+        } else if class == "p-adr" && element.classes().contains(&"h-adr") {
+            let mut adr: Vec<Vec<String>> = Vec::from([
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+            ]);
+            let mut components = Vec::new();
+            find_properties(&element.children, &mut components);
+            for (class, value, _) in components.iter() {
+                if let Some(i) = ADR_CLASSES.iter().position(|c| c == class) {
+                    adr[i].push(escape(value));
+                }
+            }
+            created.push(create_property(PropertyName::ADR, list_component(&adr).as_str()));
+        } else if class == "p-adr" {
+            created.push(create_property(PropertyName::ADR, format!(";;{};;;;", escape(value)).as_str()));
+        } else if class == "p-category" {
+            categories.push(escape(value));
+        } else if value.is_empty() {
+            continue;
+        } else if class == "p-nickname" {
+            created.push(create_property(PropertyName::NICKNAME, escape(value).as_str()));
+        } else if class == "p-org" {
+            created.push(create_property(PropertyName::ORG, escape(value).as_str()));
+        } else if let Some((name, _)) = PROPERTY_CLASSES.iter().find(|(_, c)| c == class) {
+            let property = match *name {
+                PropertyName::EMAIL => Property::email(value.trim_start_matches("mailto:")),
+                PropertyName::TEL => Property::tel(value.trim_start_matches("tel:")),
+                PropertyName::URL => Property::url(value),
+                PropertyName::NOTE | PropertyName::ROLE | PropertyName::TITLE | PropertyName::TZ => create_property(name, escape(value).as_str()),
+                _ => create_property(name, value),
+            };
+            created.push(property);
+        }
+    }
+
+    if n.iter().any(|component| !component.is_empty()) {
+        created.insert(0, create_property(PropertyName::N, list_component(&n).as_str()));
+    }
+    if !categories.is_empty() {
+        created.push(create_property(PropertyName::CATEGORIES, categories.join(",").as_str()));
+    }
+
+    // Invalid properties are skipped, keeping the rest of the h-card.
+    for property in created.into_iter().flatten() {
+        let _ = vcard.set_property(&property);
+    }
+
+    vcard
+}
+
+/// Helper function creating a property from a value written as in a content line, uri values aren't escaped.
+fn create_property(name: &str, value: &str) -> Result<Property, VcardError> {
+    Property::create((None, name, Vec::new(), value))
+}
+
+/// Helper function joining escaped list components.
+fn list_component(components: &[Vec<String>]) -> String {
+    components.iter().map(|component| component.join(",")).collect::<Vec<String>>().join(";")
+}
+
+/// Helper function returning unescaped text for a value.
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::ValueText(text) => text.value.clone(),
+        Value::ValueTextList(list) => list.value.join(", "),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hcard::{parse_hcards, to_hcard};
+    use crate::vcard::Vcard;
+
+    #[test]
+    fn hcard_round_trip() {
+        let text = "BEGIN:VCARD\nVERSION:4.0\nFN:Dr. John Doe\nN:Doe;John;Quinlan,Q.;Dr.;\nNICKNAME:Johnny\nPHOTO:data:image/png;base64,iVBORw0KGgo=\nBDAY:2000-01-01\nADR:;;123 Main St.;Any Town;CA;91921;USA\nTEL:+1 555-555-5555\nEMAIL:john@example.com\nIMPP:xmpp:john@example.com\nGEO:geo:37.386013,-122.082932\nTITLE:Research <Scientist>\nORG:ABC & Co\nNOTE:Likes \"quotes\" and 'apostrophes'\nUID:urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6\nURL:https://example.com/john?a=1&b=2\nCATEGORIES:friend,colleague\nEND:VCARD\n";
+        let vcard = Vcard::try_from(text).unwrap();
+
+        let html = to_hcard(&vcard);
+        assert!(html.contains("<span class=\"p-honorific-prefix\">Dr.</span>\n"));
+        assert!(html.contains("<span class=\"p-additional-name\">Quinlan</span>\n  <span class=\"p-additional-name\">Q.</span>\n"));
+        assert!(html.contains("  <div class=\"p-adr h-adr\">\n    <span class=\"p-street-address\">123 Main St.</span>\n"));
+        assert!(html.contains("<time class=\"dt-bday\" datetime=\"2000-01-01\">2000-01-01</time>\n"));
+        assert!(html.contains("<span class=\"p-job-title\">Research &lt;Scientist&gt;</span>\n"));
+        assert!(html.contains("<a class=\"u-url\" href=\"https://example.com/john?a=1&amp;b=2\">"));
+
+        let vcards = parse_hcards(format!("<!DOCTYPE html><html><body><!-- <div class=\"h-card\"> -->{}</body></html>", html).as_str()).unwrap();
+        assert_eq!(vcards.len(), 1);
+        assert_eq!(vcards[0].export(), vcard.export());
+    }
+
+    #[test]
+    fn hcard_parse() {
+        let html = "<ul>\n<li class=\"h-card vcard\"><a class=\"p-name u-url\" href=\"https://example.com/jane\">  Jane   Roe </a><br>\n<abbr class=\"p-org\" title=\"Example Inc.\">EI</abbr><img class=\"u-photo\" src=https://example.com/photo.png>\n<span class=\"p-tel\">555&#x2d;5555</span><span class=\"p-adr\">Somewhere</span><div class=\"h-card p-author\"><span class=\"p-name\">Nested</span></div>\n<li class=\"h-card\"><span class=\"p-given-name\">Max</span><span class=\"p-category\">a</span><span class=\"p-category\">b</span></ul><p class=\"h-card\">";
+        let vcards = parse_hcards(html).unwrap();
+        assert_eq!(vcards.len(), 4);
+
+        assert_eq!(vcards[0].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:Jane   Roe\nURL:https://example.com/jane\nORG:Example Inc.\nPHOTO:https://example.com/photo.png\nTEL:555-5555\nADR:;;Somewhere;;;;\nEND:VCARD\n");
+        assert_eq!(vcards[1].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:Nested\nEND:VCARD\n");
+        assert_eq!(vcards[2].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:\nN:;Max;;;\nCATEGORIES:a,b\nEND:VCARD\n");
+        assert_eq!(vcards[3].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:\nEND:VCARD\n");
+
+        assert!(parse_hcards("<p>No h-card</p>").unwrap().is_empty());
+
+        let html = "<div class=\"h-card\"><span class=\"p-name\">A;B</span><a class=\"u-url\" href=\"not a uri\">Home</a><a class=\"u-email\" href=\"mailto:a,b\">a</a><a class=\"u-email\" href=\"mailto:a@example.com\">a</a><span class=\"p-tel\">555;5555&#10;NOTE:x</span></div>";
+        let vcards = parse_hcards(html).unwrap();
+        assert_eq!(vcards[0].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:A\\;B\nEMAIL:a@example.com\nTEL:555\\;5555\\\\nNOTE:x\nEND:VCARD\n");
+    }
+}
@@ -0,0 +1,150 @@
+//! A lightweight inverted index for prefix searching a collection of vCards.
+//!
+//! Words from the FN, N, NICKNAME, EMAIL, TEL, and ORG properties are lowercased and indexed. Phone numbers are
+//! indexed by their digits and digit groups, so both "555" and "1555" match "+1 (555) 555-5555".
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::index::VcardIndex;
+//! use vcard_parser::parse_vcards;
+//!
+//! let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nUID:urn:uuid:1\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nEMAIL:jane@example.com\nEND:VCARD\n";
+//! let vcards = parse_vcards(input).expect("Unable to parse text.");
+//!
+//! let index = VcardIndex::build(&vcards);
+//! assert_eq!(index.search("jo"), Vec::from([(0, Some(String::from("urn:uuid:1")))]));
+//! assert_eq!(index.search("example"), Vec::from([(1, None)]));
+//! assert_eq!(index.search("j"), Vec::from([(0, Some(String::from("urn:uuid:1"))), (1, None)]));
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::constants::PropertyName;
+use crate::vcard::value::value_component::Component;
+use crate::vcard::value::Value;
+use crate::vcard::Vcard;
+use crate::{HasName, HasValue, Property};
+
+#[derive(Clone, Debug, Default)]
+pub struct VcardIndex {
+    tokens: BTreeMap<String, BTreeSet<usize>>,
+    uids: Vec<Option<String>>,
+}
+
+impl VcardIndex {
+    /// Build an index over an array of vCards. Results refer to the position of a vCard in the array.
+    pub fn build(vcards: &[Vcard]) -> Self {
+        let mut index = Self::default();
+
+        for (i, vcard) in vcards.iter().enumerate() {
+            index.uids.push(vcard.get_property_by_name(PropertyName::UID).map(|uid| value_to_string(uid.get_value())));
+
+            for property in vcard.get_properties() {
+                for token in property_tokens(&property) {
+                    index.tokens.entry(token).or_default().insert(i);
+                }
+            }
+        }
+
+        index
+    }
+
+    /// Search the index, returning the position and UID of each vCard where every word in the query prefixes an indexed word.
+    pub fn search(&self, query: &str) -> Vec<(usize, Option<String>)> {
+        let mut matches: Option<BTreeSet<usize>> = None;
+
+        for word in tokenize(query) {
+            let found: BTreeSet<usize> = self.tokens.range(word.clone()..).take_while(|(token, _)| token.starts_with(word.as_str())).flat_map(|(_, set)| set.iter().copied()).collect();
+            matches = Some(match matches {
+                Some(matches) => matches.intersection(&found).copied().collect(),
+                None => found,
+            });
+        }
+
+        matches.unwrap_or_default().into_iter().map(|i| (i, self.uids.get(i).cloned().flatten())).collect()
+    }
+
+    /// Get the number of indexed vCards.
+    pub fn len(&self) -> usize {
+        self.uids.len()
+    }
+
+    /// Check if the index contains any vCards.
+    pub fn is_empty(&self) -> bool {
+        self.uids.is_empty()
+    }
+}
+
+/// Helper function returning the searchable words of a property.
+fn property_tokens(property: &Property) -> Vec<String> {
+    match property.name() {
+        PropertyName::FN | PropertyName::N | PropertyName::NICKNAME | PropertyName::ORG => tokenize(value_to_string(property.get_value()).as_str()),
+        PropertyName::EMAIL => {
+            let address = value_to_string(property.get_value()).trim_start_matches("mailto:").to_lowercase();
+            let mut tokens = tokenize(address.as_str());
+            tokens.push(address);
+            tokens
+        }
+        PropertyName::TEL => {
+            let value = value_to_string(property.get_value());
+            let mut tokens: Vec<String> = value.split(|c: char| !c.is_ascii_digit()).filter(|s| !s.is_empty()).map(String::from).collect();
+            if tokens.len() > 1 {
+                tokens.push(tokens.concat());
+            }
+            tokens
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Helper function returning unescaped text for text based values.
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::ValueText(text) => text.value.clone(),
+        Value::ValueTextList(list) => list.value.join(" "),
+        Value::ValueListComponent(list) => list.value.iter().flatten().map(Component::as_str).collect::<Vec<&str>>().join(" "),
+        value => value.to_string(),
+    }
+}
+
+/// Helper function splitting text into lowercase words, phone numbers are kept whole.
+fn tokenize(str: &str) -> Vec<String> {
+    let lowercase = str.to_lowercase();
+    if lowercase.chars().any(|c| c.is_ascii_digit()) && lowercase.chars().all(|c| c.is_ascii_digit() || "+-. ()".contains(c)) {
+        return Vec::from([lowercase.chars().filter(|c| c.is_ascii_digit()).collect()]);
+    }
+    lowercase.split(|c: char| !c.is_alphanumeric()).filter(|s| !s.is_empty()).map(String::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::index::VcardIndex;
+    use crate::parse_vcards;
+
+    #[test]
+    fn index_search() {
+        let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nN:Doe;John;;;\nNICKNAME:Johnny,JD\nTEL:+1 (555) 555-5555\nUID:urn:uuid:a\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Doe\nORG:ACME\\, Inc.;Sales\nEMAIL:jane.doe@example.com\nUID:urn:uuid:b\nEND:VCARD\n";
+        let index = VcardIndex::build(&parse_vcards(input).unwrap());
+
+        assert_eq!(index.len(), 2);
+        assert_eq!(index.search("doe").len(), 2);
+        assert_eq!(index.search("doe jo"), Vec::from([(0, Some(String::from("urn:uuid:a")))]));
+        assert_eq!(index.search("johnny"), Vec::from([(0, Some(String::from("urn:uuid:a")))]));
+        assert_eq!(index.search("1555"), Vec::from([(0, Some(String::from("urn:uuid:a")))]));
+        assert_eq!(index.search("555"), Vec::from([(0, Some(String::from("urn:uuid:a")))]));
+        assert_eq!(index.search("ACME sal"), Vec::from([(1, Some(String::from("urn:uuid:b")))]));
+        assert_eq!(index.search("jane.doe@"), Vec::from([(1, Some(String::from("urn:uuid:b")))]));
+
+        // Words are compared case-insensitively.
+        assert_eq!(index.search("JANE.DOE@EXAMPLE.COM"), Vec::from([(1, Some(String::from("urn:uuid:b")))]));
+        assert_eq!(index.search("jOhN"), Vec::from([(0, Some(String::from("urn:uuid:a")))]));
+
+        // Every word must match a field of the same vCard.
+        assert_eq!(index.search("jane sales example"), Vec::from([(1, Some(String::from("urn:uuid:b")))]));
+        assert_eq!(index.search("jd 555"), Vec::from([(0, Some(String::from("urn:uuid:a")))]));
+        assert!(index.search("john sales").is_empty());
+        assert!(index.search("jane.doe@example.org").is_empty());
+        assert!(index.search("smith").is_empty());
+        assert!(index.search("").is_empty());
+    }
+}
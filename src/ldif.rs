@@ -0,0 +1,251 @@
+//! Conversion between vCards and [LDIF](https://datatracker.ietf.org/doc/html/rfc2849) entries with
+//! [inetOrgPerson](https://datatracker.ietf.org/doc/html/rfc2798) attributes.
+//!
+//! | vCard          | LDIF                                     |
+//! |----------------|------------------------------------------|
+//! | FN             | cn                                       |
+//! | N              | sn, givenName                            |
+//! | EMAIL          | mail                                     |
+//! | TEL            | telephoneNumber                          |
+//! | ORG            | o, ou for each organizational unit       |
+//! | URL            | labeledURI                               |
+//!
+//! Other properties and attributes are left out. Values which aren't a safe string are base64 encoded.
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::ldif::{parse_ldif, to_ldif};
+//! use vcard_parser::vcard::Vcard;
+//!
+//! let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nN:Doe;John;;;\nEMAIL:john@example.com\nORG:ABC\\, Inc.;Marketing\nEND:VCARD\n").expect("Unable to parse vCard.");
+//!
+//! let ldif = to_ldif(&[vcard.clone()], "ou=people,dc=example,dc=com");
+//! assert_eq!(ldif, "version: 1\n\ndn: cn=John Doe,ou=people,dc=example,dc=com\nobjectClass: top\nobjectClass: person\nobjectClass: organizationalPerson\nobjectClass: inetOrgPerson\ncn: John Doe\nsn: Doe\ngivenName: John\nmail: john@example.com\no: ABC, Inc.\nou: Marketing\n\n");
+//!
+//! let vcards = parse_ldif(ldif.as_str()).expect("Unable to parse LDIF.");
+//! assert_eq!(vcards[0].export(), vcard.export());
+//! ```
+
+use crate::collection::ContactPoint;
+use crate::constants::PropertyName;
+use crate::parse::encoding::{base64_decode, base64_encode, escape};
+use crate::vcard::value::value_component::Component;
+use crate::vcard::value::Value;
+use crate::vcard::Vcard;
+use crate::{HasName, HasValue, Property, VcardError};
+
+/// The object classes of exported entries.
+const OBJECT_CLASSES: [&str; 4] = [
+    "top",
+    "person",
+    "organizationalPerson",
+    "inetOrgPerson",
+];
+
+/// Render vCards as LDIF entries below a base distinguished name, each entry is named by its cn attribute.
+pub fn to_ldif(vcards: &[Vcard], base: &str) -> String {
+    let mut string = String::from("version: 1\n\n");
+
+    for vcard in vcards.iter() {
+        let cn = vcard.display_name();
+        let mut attributes = Vec::from([(String::from("cn"), cn.clone())]);
+
+        let properties = vcard.get_properties();
+        for property in properties.iter() {
+            match (property.name(), property.get_value()) {
+                (PropertyName::N, Value::ValueListComponent(list)) => {
+                    let component = |i: usize| list.value.get(i).map(|c| c.iter().filter(|s| !s.is_empty()).map(Component::as_str).collect::<Vec<&str>>().join(" ")).unwrap_or_default();
+                    let sn = component(0);
+                    attributes.push((String::from("sn"), if sn.is_empty() { cn.clone() } else { sn }));
+                    if !component(1).is_empty() {
+                        attributes.push((String::from("givenName"), component(1)));
+                    }
+                }
+                (PropertyName::EMAIL | PropertyName::TEL | PropertyName::URL, _) => {
+                    let Some(point) = ContactPoint::from_property(property) else { continue };
+                    let name = match point {
+                        ContactPoint::Email(_) => "mail",
+                        ContactPoint::Tel(_) => "telephoneNumber",
+                        _ => "labeledURI",
+                    };
+                    attributes.push((String::from(name), point.value().to_string()));
+                }
+                (PropertyName::ORG, Value::ValueTextList(list)) => {
+                    for (i, unit) in list.value.iter().enumerate().filter(|(_, unit)| !unit.is_empty()) {
+                        attributes.push((String::from(if i == 0 { "o" } else { "ou" }), unit.clone()));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // The person object class requires a surname.
+        if !attributes.iter().any(|(name, _)| name == "sn") {
+            attributes.insert(1, (String::from("sn"), cn.clone()));
+        }
+
+        string.push_str(&ldif_line("dn", &format!("cn={},{}", dn_escape(&cn), base)));
+        for object_class in OBJECT_CLASSES {
+            string.push_str(&ldif_line("objectClass", object_class));
+        }
+        for (name, value) in attributes.iter() {
+            string.push_str(&ldif_line(name, value));
+        }
+        string.push('\n');
+    }
+
+    string
+}
+
+/// Parse the person entries of an LDIF file into vCards.
+///
+/// Entries with object classes but without the person, organizationalPerson or inetOrgPerson object class are
+/// skipped, as are change records. Errors are returned for malformed base64 values and invalid property values.
+pub fn parse_ldif(str: &str) -> Result<Vec<Vcard>, VcardError> {
+    let mut vcards = Vec::new();
+
+    for record in ldif_records(str)? {
+        let is_change = record.iter().any(|(name, _)| name.eq_ignore_ascii_case("changetype"));
+        let classes: Vec<&String> = record.iter().filter(|(name, _)| name.eq_ignore_ascii_case("objectClass")).map(|(_, value)| value).collect();
+        let is_person = classes.is_empty() || classes.iter().any(|class| OBJECT_CLASSES[1..].iter().any(|c| class.eq_ignore_ascii_case(c)));
+        if is_change || !is_person || record.iter().all(|(name, _)| name.eq_ignore_ascii_case("dn") || name.eq_ignore_ascii_case("version")) {
+            continue;
+        }
+
+        let values = |attribute: &str| record.iter().filter(|(name, _)| name.eq_ignore_ascii_case(attribute)).map(|(_, value)| value.as_str()).collect::<Vec<&str>>();
+
+        let mut vcard = Vcard::new(values("cn").first().copied().unwrap_or_default());
+        let mut lines = Vec::new();
+
+        let (sn, given) = (values("sn"), values("givenName"));
+        if !sn.is_empty() || !given.is_empty() {
+            lines.push(format!("{}:{};{};;;", PropertyName::N, escape(sn.first().copied().unwrap_or_default()), escape(given.first().copied().unwrap_or_default())));
+        }
+        for mail in values("mail") {
+            lines.push(format!("{}:{}", PropertyName::EMAIL, escape(mail)));
+        }
+        for tel in values("telephoneNumber") {
+            lines.push(format!("{}:{}", PropertyName::TEL, escape(tel)));
+        }
+        let mut units: Vec<String> = values("o").iter().take(1).map(|o| escape(o)).collect();
+        if !units.is_empty() || !values("ou").is_empty() {
+            units.resize(1, String::new());
+            units.extend(values("ou").iter().map(|ou| escape(ou)));
+            lines.push(format!("{}:{}", PropertyName::ORG, units.join(";")));
+        }
+        for uri in values("labeledURI") {
+            lines.push(format!("{}:{}", PropertyName::URL, uri.split_once(' ').map_or(uri, |(uri, _)| uri)));
+        }
+
+        for line in lines {
+            vcard.set_property(&Property::try_from(format!("{}\n", line).as_str())?)?;
+        }
+        vcards.push(vcard);
+    }
+
+    Ok(vcards)
+}
+
+/// Helper function splitting LDIF into records of attribute names and decoded values, unfolding continuation lines.
+///
+/// Attribute options, e.g. ";lang-en", are removed from the attribute names and values referenced by url are skipped.
+fn ldif_records(str: &str) -> Result<Vec<Vec<(String, String)>>, VcardError> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut folded = false;
+    for line in str.lines() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        match line.strip_prefix(' ') {
+            Some(continuation) if folded => {
+                if let Some(last) = lines.last_mut() {
+                    last.push_str(continuation);
+                }
+            }
+            _ => {
+                folded = !line.is_empty();
+                lines.push(line.to_string());
+            }
+        }
+    }
+
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    for line in lines.iter().chain([String::new()].iter()) {
+        if line.is_empty() {
+            if !record.is_empty() {
+                records.push(std::mem::take(&mut record));
+            }
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+
+        let Some((name, value)) = line.split_once(':') else { return Err(VcardError::ValueMalformed(line.to_string())) };
+        let name = name.split(';').next().unwrap_or_default().trim().to_string();
+        let value = if let Some(encoded) = value.strip_prefix(':') {
+            let bytes = base64_decode(encoded.trim()).ok_or(VcardError::ValueMalformed(line.to_string()))?;
+            String::from_utf8(bytes).map_err(|_| VcardError::ConversionFailure)?
+        } else if value.starts_with('<') {
+            continue;
+        } else {
+            value.trim_start().to_string()
+        };
+        record.push((name, value));
+    }
+
+    Ok(records)
+}
+
+/// Helper function writing an attribute line, base64 encoding values which aren't a safe string, see [RFC 2849](https://datatracker.ietf.org/doc/html/rfc2849#page-4).
+fn ldif_line(name: &str, value: &str) -> String {
+    let is_safe = !value.starts_with([' ', ':', '<']) && !value.ends_with(' ') && value.bytes().all(|c| c.is_ascii() && c != b'\0' && c != b'\n' && c != b'\r');
+    if is_safe {
+        format!("{}: {}\n", name, value)
+    } else {
+        format!("{}:: {}\n", name, base64_encode(value.as_bytes()))
+    }
+}
+
+/// Helper function escaping an attribute value in a distinguished name, see [RFC 4514 2.4](https://datatracker.ietf.org/doc/html/rfc4514#section-2.4).
+fn dn_escape(str: &str) -> String {
+    let mut string = String::with_capacity(str.len());
+    let last = str.chars().count().saturating_sub(1);
+    for (i, char) in str.chars().enumerate() {
+        if matches!(char, ',' | '+' | '"' | '\\' | '<' | '>' | ';') || (i == 0 && matches!(char, '#' | ' ')) || (i == last && char == ' ') {
+            string.push('\\');
+        }
+        string.push(char);
+    }
+    string
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ldif::{parse_ldif, to_ldif};
+    use crate::vcard::Vcard;
+
+    #[test]
+    fn ldif_round_trip() {
+        let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:José Doe\\, Jr.\nN:Doe;José;;;Jr.\nEMAIL:jose@example.com\nTEL;VALUE=URI:tel:+1-555-555-5555\nORG:ABC;North;Marketing\nURL:https://example.com/jose\nNOTE:Not mapped\nEND:VCARD\n").unwrap();
+
+        let ldif = to_ldif(&[vcard, Vcard::new("Jane+Roe")], "dc=example,dc=com");
+        assert_eq!(ldif, "version: 1\n\ndn:: Y249Sm9zw6kgRG9lXCwgSnIuLGRjPWV4YW1wbGUsZGM9Y29t\nobjectClass: top\nobjectClass: person\nobjectClass: organizationalPerson\nobjectClass: inetOrgPerson\ncn:: Sm9zw6kgRG9lLCBKci4=\nsn: Doe\ngivenName:: Sm9zw6k=\nmail: jose@example.com\ntelephoneNumber: +1-555-555-5555\no: ABC\nou: North\nou: Marketing\nlabeledURI: https://example.com/jose\n\ndn: cn=Jane\\+Roe,dc=example,dc=com\nobjectClass: top\nobjectClass: person\nobjectClass: organizationalPerson\nobjectClass: inetOrgPerson\ncn: Jane+Roe\nsn: Jane+Roe\n\n");
+
+        let vcards = parse_ldif(ldif.as_str()).unwrap();
+        assert_eq!(vcards.len(), 2);
+        assert_eq!(vcards[0].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:José Doe\\, Jr.\nN:Doe;José;;;\nEMAIL:jose@example.com\nTEL:+1-555-555-5555\nORG:ABC;North;Marketing\nURL:https://example.com/jose\nEND:VCARD\n");
+        assert_eq!(vcards[1].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:Jane+Roe\nN:Jane+Roe;;;;\nEND:VCARD\n");
+    }
+
+    #[test]
+    fn ldif_parse() {
+        let ldif = "version: 1\r\n# Comment\r\ndn: ou=people,dc=example,dc=com\r\nobjectClass: organizationalUnit\r\nou: people\r\n\r\ndn: cn=Jane Roe,ou=people,dc=example,dc=com\r\nobjectClass: inetOrgPerson\r\ncn;lang-en: Jane\r\n  Roe\r\nsn: Roe\r\nmail: jane@example.com\r\nmail: roe@example.com\r\nou: Sales\r\njpegPhoto:< file:///tmp/jane.jpg\r\nlabeledURI: https://example.com/jane Jane's Site\r\n\r\ndn: cn=John Doe,dc=example,dc=com\r\nchangetype: delete\r\n";
+        let vcards = parse_ldif(ldif).unwrap();
+        assert_eq!(vcards.len(), 1);
+        assert_eq!(vcards[0].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nN:Roe;;;;\nEMAIL:jane@example.com\nEMAIL:roe@example.com\nORG:;Sales\nURL:https://example.com/jane\nEND:VCARD\n");
+
+        assert!(parse_ldif("dn: cn=Jane Roe\nobjectClass: person\ncn:: %%%\n").is_err());
+        assert!(parse_ldif("dn: cn=Jane Roe\nnot an attribute\n").is_err());
+        assert!(parse_ldif("").unwrap().is_empty());
+    }
+}
@@ -65,16 +65,48 @@
 //! // write("contacts.vcf", data).expect("Unable to write file.");
 //! ```
 
+use std::io::Write;
+
 use crate::error::VcardError;
+use crate::options::{ExportOptions, ParserOptions};
 use crate::traits::{HasCardinality, HasName, HasParameters, HasValue};
 use crate::vcard::property::Property;
 use crate::vcard::Vcard;
 
+#[cfg(any(test, feature = "arbitrary"))]
+pub mod arbitrary;
+pub mod arena;
+pub mod collection;
+pub mod compliance;
 pub mod constants;
+pub mod csv;
 pub mod error;
+pub mod events;
+pub mod fingerprint;
+pub mod graph;
+pub mod hcard;
+pub mod index;
+pub mod ldif;
+pub mod lint;
+pub mod mecard;
+pub mod media;
+pub mod options;
 pub mod parse;
+pub mod reader;
+pub mod redact;
+pub mod report;
+pub mod sanitize;
+pub mod social;
+pub mod stats;
+pub mod store;
+#[cfg(feature = "stream")]
+pub mod stream;
+pub mod template;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
 pub mod traits;
 pub mod vcard;
+pub mod vcf;
 
 /// Parses a string and returns either a [VcardError](VcardError) or an array of [Vcard](Vcard)s as the result.
 ///
@@ -88,10 +120,29 @@ pub mod vcard;
 /// assert_eq!(vcards.len(), 1);
 /// ```
 pub fn parse_vcards(input: &str) -> Result<Vec<Vcard>, VcardError> {
+    parse_vcards_bytes(input.as_bytes())
+}
+
+/// Parses bytes like [parse_vcards](parse_vcards), e.g. the contents of a file, without converting them to a string first.
+///
+/// UTF-8 is only validated when values are converted, invalid UTF-8 in a content line returns a
+/// [ConversionFailure](VcardError::ConversionFailure).
+///
+/// # Examples
+/// ```
+/// use vcard_parser::parse_vcards_bytes;
+///
+/// let vcards = parse_vcards_bytes(b"BEGIN:VCARD\nVERSION:4.0\nFN:Jos\xc3\xa9\nEND:VCARD\n").expect("Unable to parse bytes.");
+/// assert_eq!(vcards[0].display_name(), "José");
+/// assert!(parse_vcards_bytes(b"BEGIN:VCARD\nVERSION:4.0\nFN:Jos\xe9\nEND:VCARD\n").is_err());
+/// ```
+pub fn parse_vcards_bytes(input: &[u8]) -> Result<Vec<Vcard>, VcardError> {
     let mut vcards = Vec::new();
 
-    for data in parse::vcard::vcards(input.as_bytes())?.1 {
-        vcards.push(Vcard::try_from((None, data))?);
+    for (version, data) in parse::vcard::vcards_versioned(input)?.1 {
+        let mut vcard = Vcard::try_from((None, data))?;
+        vcard.set_version(parse::value::utf8_to_str(version)?)?;
+        vcards.push(vcard);
     }
 
     Ok(vcards)
@@ -111,17 +162,131 @@ pub fn parse_vcards(input: &str) -> Result<Vec<Vcard>, VcardError> {
 pub fn parse_vcards_with_client(client: &str, input: &str) -> Result<Vec<Vcard>, VcardError> {
     let mut vcards = Vec::new();
 
-    for data in parse::vcard::vcards(input.as_bytes())?.1 {
-        vcards.push(Vcard::try_from((Some(client.to_string()), data))?);
+    for (version, data) in parse::vcard::vcards_versioned(input.as_bytes())?.1 {
+        let mut vcard = Vcard::try_from((Some(client.to_string()), data))?;
+        vcard.set_version(parse::value::utf8_to_str(version)?)?;
+        vcards.push(vcard);
     }
 
     Ok(vcards)
 }
 
+/// Takes vcard string(s) and [ParserOptions](ParserOptions) and returns either a [VcardError](VcardError) or an array of [Vcard](Vcard)s as the result.
+///
+/// The options are kept on each vCard and applied to properties set later on. With [lenient](ParserOptions::lenient)
+/// options, content lines that can't be parsed are kept as [unparsed lines](Vcard::unparsed_lines).
+///
+/// # Examples
+/// ```
+/// use vcard_parser::options::ParserOptions;
+/// use vcard_parser::parse_vcards_with_options;
+///
+/// let options = ParserOptions { strict: true, ..ParserOptions::default() };
+/// let vcards = parse_vcards_with_options("BEGIN:VCARD\nVERSION:4.0\nFN:\nEND:VCARD\n", &options).expect("Unable to parse text.");
+/// assert_eq!(vcards.len(), 1);
+/// ```
+pub fn parse_vcards_with_options(input: &str, options: &ParserOptions) -> Result<Vec<Vcard>, VcardError> {
+    parse_vcards_bytes_with_options(input.as_bytes(), options)
+}
+
+/// Parses bytes like [parse_vcards_with_options](parse_vcards_with_options), without converting them to a string first.
+///
+/// With [lenient](ParserOptions::lenient) options, invalid UTF-8 in a content line is replaced with U+FFFD and the
+/// property is kept with a [warning](Vcard::warnings), e.g. for files mixing UTF-8 and Latin-1.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::options::ParserOptions;
+/// use vcard_parser::parse_vcards_bytes_with_options;
+///
+/// let options = ParserOptions { lenient: true, ..ParserOptions::default() };
+/// let vcards = parse_vcards_bytes_with_options(b"BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nNOTE:Caf\xe9\nEND:VCARD\n", &options).expect("Unable to parse bytes.");
+/// assert_eq!(vcards[0].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nNOTE:Caf\u{FFFD}\nEND:VCARD\n");
+/// assert_eq!(vcards[0].warnings(), ["Invalid UTF-8 in NOTE was replaced."]);
+/// ```
+pub fn parse_vcards_bytes_with_options(input: &[u8], options: &ParserOptions) -> Result<Vec<Vcard>, VcardError> {
+    let mut vcards = Vec::new();
+
+    if options.lenient {
+        for (version, data, skipped) in parse::vcard::vcards_lenient(input)?.1 {
+            let mut vcard = Vcard::from_data(None, options.clone(), data, skipped)?;
+            vcard.set_version(parse::value::utf8_to_str(version)?)?;
+            vcards.push(vcard);
+        }
+
+        return Ok(vcards);
+    }
+
+    for (version, data) in parse::vcard::vcards_versioned(input)?.1 {
+        let mut vcard = Vcard::try_from((None, options.clone(), data))?;
+        vcard.set_version(parse::value::utf8_to_str(version)?)?;
+        vcards.push(vcard);
+    }
+
+    Ok(vcards)
+}
+
+/// Normalizes a whole multi-card file, parsing it leniently and writing every vCard with [ExportOptions](ExportOptions).
+///
+/// Content lines that can't be parsed or fail validation are left out, vCards which can't be parsed at all return an
+/// error. Values are written with their canonical escaping, and [ExportOptions::canonical] adds canonical ordering and
+/// folding.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::normalize_vcf;
+/// use vcard_parser::options::ExportOptions;
+///
+/// let input = "BEGIN:VCARD\r\nVERSION:4.0\r\nTEL;TYPE=home;TYPE=voice:555-5555\r\nGEO:not a uri\r\nFN:John Doe\r\nEND:VCARD\r\nBEGIN:VCARD\r\nVERSION:4.0\r\nFN:Jane Roe\r\nEND:VCARD\r\n";
+/// let output = normalize_vcf(input, &ExportOptions::canonical()).expect("Unable to normalize text.");
+/// assert_eq!(output, "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;TYPE=home,voice:555-5555\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nEND:VCARD\n");
+/// ```
+pub fn normalize_vcf(input: &str, options: &ExportOptions) -> Result<String, VcardError> {
+    let parser_options = ParserOptions {
+        lenient: true,
+        ..ParserOptions::default()
+    };
+
+    let mut string = String::with_capacity(input.len());
+    for vcard in parse_vcards_with_options(input, &parser_options)? {
+        string.push_str(vcard.export_with(options).as_str());
+    }
+
+    Ok(string)
+}
+
+/// Writes vCards with [ExportOptions](ExportOptions) to a writer one at a time, so memory use is bounded by the largest
+/// vCard rather than the whole output. Wrap unbuffered writers, e.g. files, in a [BufWriter](std::io::BufWriter).
+///
+/// # Examples
+/// ```
+/// use vcard_parser::options::ExportOptions;
+/// use vcard_parser::vcard::Vcard;
+/// use vcard_parser::write_vcards;
+///
+/// let vcards = [Vcard::new("John Doe"), Vcard::new("Jane Roe")];
+///
+/// let mut output = Vec::new();
+/// write_vcards(&vcards, &mut output, &ExportOptions::default()).expect("Unable to write vCards.");
+/// assert_eq!(output, b"BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nEND:VCARD\n");
+/// ```
+pub fn write_vcards<W: Write>(vcards: &[Vcard], w: &mut W, options: &ExportOptions) -> std::io::Result<()> {
+    for vcard in vcards {
+        w.write_all(vcard.export_with(options).as_bytes())?;
+    }
+    w.flush()
+}
+
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use crate::constants::{TestData, VcardParseError};
-    use crate::{parse_vcards, VcardError};
+    use crate::lint::{lint_with_options, LintRule};
+    use crate::options::{CountLimit, E164TelNormalizer, ExportOptions, LengthAction, ParserOptions, ValueLimit};
+    use crate::vcard::value::value_text::ValueTextData;
+    use crate::vcard::value::Value;
+    use crate::{normalize_vcf, parse_vcards, parse_vcards_bytes, parse_vcards_bytes_with_options, parse_vcards_with_options, write_vcards, HasValue, Vcard, VcardError};
 
     fn _match((a, b): (&str, &str)) {
         assert_eq!(parse_vcards(a).unwrap().first().unwrap().export(), b.to_string())
@@ -171,4 +336,168 @@ mod tests {
     fn sample_compound() {
         _match(TestData::VCARD_MATCH_COMPOUND);
     }
+
+    #[test]
+    fn parse_with_tel_normalizer() {
+        let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:(555) 555-5555\nTEL;VALUE=URI:tel:+44-20-7946-0958;ext=12\nTEL:ask reception\nEND:VCARD\n";
+
+        let mut options = ParserOptions {
+            tel_normalizer: Some(Arc::new(E164TelNormalizer { default_country_code: Some(1) })),
+            ..ParserOptions::default()
+        };
+        let vcards = parse_vcards_with_options(input, &options).unwrap();
+        assert_eq!(vcards.first().unwrap().export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:+15555555555\nTEL;VALUE=URI:tel:+442079460958;ext=12\nTEL:ask reception\nEND:VCARD\n");
+
+        options.strict = true;
+        assert!(parse_vcards_with_options(input, &options).is_err());
+    }
+
+    #[test]
+    fn parse_with_value_limits() {
+        let note = format!("{}\\,End", "é".repeat(6));
+        let input = format!("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nNOTE:{}\nPHOTO:data:image/png;base64,AAAA\nEND:VCARD\n", note);
+
+        let mut options = ParserOptions {
+            value_limits: Vec::from([
+                ValueLimit::new("NOTE", 13, LengthAction::Truncate),
+                ValueLimit::new("PHOTO", 16, LengthAction::Warn),
+            ]),
+            ..ParserOptions::default()
+        };
+        let mut vcard = parse_vcards_with_options(input.as_str(), &options).unwrap().remove(0);
+        assert_eq!(vcard.get_properties_by_name("NOTE")[0].get_value().to_string(), "éééééé");
+        assert_eq!(vcard.get_properties_by_name("PHOTO").len(), 1);
+
+        let mut note = vcard.get_properties_by_name("NOTE").remove(0);
+        note.set_value(Value::from(ValueTextData::from("Short"))).unwrap();
+        assert_eq!(vcard.set_property(&note).unwrap().get_value().to_string(), "Short");
+
+        options.value_limits[0].max_length = 14;
+        assert!(parse_vcards_with_options(input.as_str(), &options).unwrap()[0].get_properties_by_name("NOTE")[0].get_value().to_string().ends_with("\\,"));
+
+        options.value_limits[1].action = LengthAction::Truncate;
+        assert_eq!(parse_vcards_with_options(input.as_str(), &options).unwrap_err(), VcardError::ValueTooLong(String::from("PHOTO"), 16));
+
+        options.value_limits[0].action = LengthAction::Reject;
+        options.value_limits[1].action = LengthAction::Warn;
+        assert_eq!(parse_vcards_with_options(input.as_str(), &options).unwrap_err(), VcardError::ValueTooLong(String::from("NOTE"), 14));
+        vcard.set_options(options);
+        note.set_value(Value::from(ValueTextData::from("Far too long for the limit"))).unwrap();
+        assert!(vcard.set_property(&note).is_err());
+
+        let issues = lint_with_options(input.as_str(), vcard.get_options());
+        assert_eq!(
+            issues.iter().map(|issue| (issue.line, issue.rule)).collect::<Vec<(usize, LintRule)>>(),
+            [
+                (4, LintRule::PropertyInvalid),
+                (5, LintRule::ValueTooLong)
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_with_count_limits() {
+        let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL:john@example.com\nEMAIL:jdoe@example.com\nX-A:1\nX-B:1\nX-B:2\nEND:VCARD\n";
+
+        let mut options = ParserOptions {
+            count_limits: Vec::from([
+                CountLimit::new("email", 2),
+                CountLimit::new("X-*", 1),
+            ]),
+            ..ParserOptions::default()
+        };
+        assert_eq!(parse_vcards_with_options(input, &options).unwrap_err(), VcardError::PropertyLimitExceeded(String::from("X-B"), 1));
+
+        options.lenient = true;
+        let mut vcard = parse_vcards_with_options(input, &options).unwrap().remove(0);
+        assert_eq!(vcard.unparsed_lines(), ["X-B:2"]);
+        assert_eq!(vcard.get_properties_by_name("EMAIL").len(), 2);
+
+        let mut email = vcard.get_properties_by_name("EMAIL").remove(0);
+        email.set_value(Value::from(ValueTextData::from("johnny@example.com"))).unwrap();
+        assert!(vcard.set_property(&email).is_ok());
+        assert_eq!(vcard.set_property(&crate::Property::try_from("EMAIL:other@example.com\n").unwrap()).unwrap_err(), VcardError::PropertyLimitExceeded(String::from("EMAIL"), 2));
+        assert_eq!(VcardError::PropertyLimitExceeded(String::from("EMAIL"), 2).to_string(), "Property EMAIL exceeds the maximum of 2 properties.");
+    }
+
+    #[test]
+    fn parse_bytes() {
+        let input: &[u8] = b"BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Ren\xc3\xa9e\r\nitem1.ORG;SORT-AS=\"M\xfcller\":M\xfcller GmbH\r\nNOTE:ok\r\nEND:VCARD\r\n";
+        assert_eq!(parse_vcards_bytes(input).unwrap_err(), VcardError::ConversionFailure);
+        assert_eq!(Vcard::try_from(input).unwrap_err(), VcardError::ConversionFailure);
+
+        let options = ParserOptions {
+            lenient: true,
+            ..ParserOptions::default()
+        };
+        let vcards = parse_vcards_bytes_with_options(input, &options).unwrap();
+        assert_eq!(vcards[0].display_name(), "Renée");
+        assert_eq!(vcards[0].get_properties_by_name("ORG")[0].export(), "item1.ORG;SORT-AS=M\u{FFFD}ller:M\u{FFFD}ller GmbH\n");
+        assert_eq!(vcards[0].warnings(), ["Invalid UTF-8 in ORG was replaced."]);
+        assert!(vcards[0].unparsed_lines().is_empty());
+
+        let input = "BEGIN:VCARD\nVERSION:4.0\nFN:Renée\nEND:VCARD\n";
+        assert_eq!(Vcard::try_from(input.as_bytes()).unwrap().export(), Vcard::try_from(input).unwrap().export());
+        assert_eq!(parse_vcards_bytes(input.as_bytes()).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn parse_line_endings() {
+        let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John \n Doe\nNOTE:Folded \n value\\, escaped\nX-BAD;=:skipped\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nEND:VCARD\n";
+        let lenient = ParserOptions {
+            lenient: true,
+            ..ParserOptions::default()
+        };
+
+        for ending in ["\r\n", "\n", "\r"] {
+            let input = input.replace('\n', ending);
+            let vcards = parse_vcards_with_options(input.as_str(), &lenient).unwrap();
+            assert_eq!(vcards.len(), 2, "{:?}", ending);
+            assert_eq!(vcards[0].display_name(), "John Doe");
+            assert_eq!(vcards[0].get_properties_by_name("NOTE")[0].get_value().as_str(), Some("Folded value, escaped"));
+            assert_eq!(vcards[0].unparsed_lines(), ["X-BAD;=:skipped"]);
+            assert_eq!(vcards[1].display_name(), "Jane Roe");
+
+            let strict = input.replace(format!("X-BAD;=:skipped{}", ending).as_str(), "");
+            let vcards = parse_vcards(strict.as_str()).unwrap();
+            assert_eq!(vcards[0].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nNOTE:Folded value\\, escaped\nEND:VCARD\n");
+            assert_eq!(Vcard::try_from(strict.as_str()).unwrap().export(), vcards[0].export());
+        }
+
+        let mixed = "BEGIN:VCARD\r\nVERSION:4.0\rFN:John \n Doe\rEND:VCARD\n";
+        assert_eq!(parse_vcards(mixed).unwrap()[0].display_name(), "John Doe");
+    }
+
+    #[test]
+    fn normalize_whole_file() {
+        let note = "A".repeat(100);
+        let input = format!("BEGIN:VCARD\r\nVERSION:4.0\r\nNOTE:{}\r\nitem1.EMAIL;TYPE=work:john@example.com\r\nFN:Doe\\, John\r\nEND:VCARD\r\n", note);
+
+        let canonical = normalize_vcf(input.as_str(), &ExportOptions::canonical()).unwrap();
+        assert!(canonical.starts_with("BEGIN:VCARD\nVERSION:4.0\nitem1.EMAIL;TYPE=work:john@example.com\nFN:Doe\\, John\nNOTE:"));
+        assert!(canonical.lines().all(|line| line.len() <= 75));
+        assert_eq!(normalize_vcf(canonical.as_str(), &ExportOptions::canonical()).unwrap(), canonical);
+
+        let plain = normalize_vcf(input.as_str(), &ExportOptions::default()).unwrap();
+        assert_eq!(plain, input.replace("\r\n", "\n"));
+
+        assert!(normalize_vcf("BEGIN:VCARD\nFN:John Doe\n", &ExportOptions::default()).is_err());
+    }
+
+    #[test]
+    fn write_batch() {
+        let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nNOTE:Hello\\, world\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nEND:VCARD\n";
+        let vcards = parse_vcards(input).unwrap();
+
+        let mut output = Vec::new();
+        write_vcards(&vcards, &mut output, &ExportOptions::canonical()).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), normalize_vcf(input, &ExportOptions::canonical()).unwrap());
+
+        let mut output = std::io::BufWriter::new(Vec::new());
+        write_vcards(&[], &mut output, &ExportOptions::default()).unwrap();
+        assert!(output.get_ref().is_empty());
+
+        let mut full = [0u8; 16];
+        assert_eq!(write_vcards(&vcards, &mut full.as_mut_slice(), &ExportOptions::default()).unwrap_err().kind(), std::io::ErrorKind::WriteZero);
+    }
 }
@@ -0,0 +1,529 @@
+//! Linting of vCard text, reporting issues with a severity, line number and RFC reference.
+//!
+//! Unlike [`parse_vcards()`](crate::parse_vcards), which stops at the first error, [`lint()`] checks every content
+//! line and keeps going, so a whole file of contacts can be checked at once. Trivial issues can be repaired with
+//! [`fix()`]. The optional `vcard-lint` binary, enabled with the `cli` feature, runs both over vcf files.
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::lint::{fix, lint, LintRule, Severity};
+//!
+//! let input = "BEGIN:VCARD\nVERSION:4.0\nFN:Doe, John\nTEL;TYPE=pref,cell:555-5555\nEND:VCARD\n";
+//!
+//! let issues = lint(input);
+//! assert_eq!(issues.len(), 2);
+//! assert_eq!(issues[0].rule, LintRule::CommaUnescaped);
+//! assert_eq!(issues[0].line, 3);
+//! assert_eq!(issues[0].severity, Severity::Warning);
+//!
+//! let fixed = fix(input);
+//! assert_eq!(fixed, "BEGIN:VCARD\nVERSION:4.0\nFN:Doe\\, John\nTEL;TYPE=cell;PREF=1:555-5555\nEND:VCARD\n");
+//! assert!(lint(fixed.as_str()).is_empty());
+//! ```
+
+use std::fmt::{Display, Formatter};
+
+use crate::constants::{ParameterName, PropertyName};
+use crate::options::ParserOptions;
+use crate::parse::encoding::{fold_line, FOLD_LENGTH};
+use crate::vcard::value::Value;
+use crate::{HasCardinality, HasName, HasParameters, HasValue, Property};
+
+/// RFC references for the properties known to the parser.
+const PROPERTY_REFERENCES: [(&str, &str); 43] = [
+    (PropertyName::ADR, "RFC 6350 6.3.1"),
+    (PropertyName::ANNIVERSARY, "RFC 6350 6.2.6"),
+    (PropertyName::BDAY, "RFC 6350 6.2.5"),
+    (PropertyName::BIRTHPLACE, "RFC 6474 2.1"),
+    (PropertyName::CALADRURI, "RFC 6350 6.9.2"),
+    (PropertyName::CALURI, "RFC 6350 6.9.3"),
+    (PropertyName::CATEGORIES, "RFC 6350 6.7.1"),
+    (PropertyName::CLIENTPIDMAP, "RFC 6350 6.7.7"),
+    (PropertyName::CONTACTURI, "RFC 8605 2.1"),
+    (PropertyName::DEATHDATE, "RFC 6474 2.3"),
+    (PropertyName::DEATHPLACE, "RFC 6474 2.2"),
+    (PropertyName::EMAIL, "RFC 6350 6.4.2"),
+    (PropertyName::EXPERTISE, "RFC 6715 2.1"),
+    (PropertyName::FBURL, "RFC 6350 6.9.1"),
+    (PropertyName::FN, "RFC 6350 6.2.1"),
+    (PropertyName::GENDER, "RFC 6350 6.2.7"),
+    (PropertyName::GEO, "RFC 6350 6.5.2"),
+    (PropertyName::HOBBY, "RFC 6715 2.2"),
+    (PropertyName::IMPP, "RFC 6350 6.4.3"),
+    (PropertyName::INTEREST, "RFC 6715 2.3"),
+    (PropertyName::KEY, "RFC 6350 6.8.1"),
+    (PropertyName::KIND, "RFC 6350 6.1.4"),
+    (PropertyName::LANG, "RFC 6350 6.4.4"),
+    (PropertyName::LOGO, "RFC 6350 6.6.3"),
+    (PropertyName::MEMBER, "RFC 6350 6.6.5"),
+    (PropertyName::N, "RFC 6350 6.2.2"),
+    (PropertyName::NICKNAME, "RFC 6350 6.2.3"),
+    (PropertyName::NOTE, "RFC 6350 6.7.2"),
+    (PropertyName::ORG, "RFC 6350 6.6.4"),
+    (PropertyName::ORGDIRECTORY, "RFC 6715 2.4"),
+    (PropertyName::PHOTO, "RFC 6350 6.2.4"),
+    (PropertyName::PRODID, "RFC 6350 6.7.3"),
+    (PropertyName::RELATED, "RFC 6350 6.6.6"),
+    (PropertyName::REV, "RFC 6350 6.7.4"),
+    (PropertyName::ROLE, "RFC 6350 6.6.2"),
+    (PropertyName::SOUND, "RFC 6350 6.7.5"),
+    (PropertyName::SOURCE, "RFC 6350 6.1.3"),
+    (PropertyName::TEL, "RFC 6350 6.4.1"),
+    (PropertyName::TITLE, "RFC 6350 6.6.1"),
+    (PropertyName::TZ, "RFC 6350 6.5.1"),
+    (PropertyName::UID, "RFC 6350 6.7.6"),
+    (PropertyName::URL, "RFC 6350 6.7.8"),
+    (PropertyName::XML, "RFC 6350 6.1.5"),
+];
+
+/// The severity of a lint issue.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    /// The content is valid but could be improved.
+    Info,
+    /// The content is accepted by the parser but doesn't follow the RFC.
+    Warning,
+    /// The content is rejected by the parser.
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// The checks run by [`lint()`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LintRule {
+    /// A property which may only appear once appears more than once.
+    CardinalityExceeded,
+    /// A TEXT value contains a comma which isn't escaped.
+    CommaUnescaped,
+    /// A content line is longer than 75 octets and should be folded.
+    LineTooLong,
+    /// A vCard is missing the required FN property.
+    PropertyFnMissing,
+    /// A property can't be parsed or fails validation.
+    PropertyInvalid,
+    /// A TYPE parameter uses the vCard 3.0 "pref" value instead of the PREF parameter.
+    TypePref,
+    /// A value exceeds a [limit](crate::options::ValueLimit) which truncates or warns, values exceeding rejecting limits are reported as invalid.
+    ValueTooLong,
+    /// BEGIN, VERSION or END lines are missing or misplaced.
+    VcardMalformed,
+}
+
+impl LintRule {
+    /// Get the severity of issues reported by the rule.
+    pub fn severity(&self) -> Severity {
+        match self {
+            LintRule::CardinalityExceeded | LintRule::PropertyFnMissing | LintRule::PropertyInvalid | LintRule::VcardMalformed => Severity::Error,
+            LintRule::CommaUnescaped | LintRule::LineTooLong | LintRule::ValueTooLong => Severity::Warning,
+            LintRule::TypePref => Severity::Info,
+        }
+    }
+
+    /// Check if issues reported by the rule are repaired by [`fix()`].
+    pub fn is_fixable(&self) -> bool {
+        matches!(self, LintRule::CommaUnescaped | LintRule::LineTooLong | LintRule::TypePref)
+    }
+}
+
+/// An issue found by [`lint()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LintIssue {
+    /// The rule reporting the issue.
+    pub rule: LintRule,
+    /// The severity of the issue, see [`LintRule::severity`].
+    pub severity: Severity,
+    /// The 1-based line number where the issue was found, for folded content lines the first line.
+    pub line: usize,
+    /// The name of the property the issue was found in, if any.
+    pub property: Option<String>,
+    /// A description of the issue.
+    pub message: String,
+    /// The RFC section describing the rule, e.g. "RFC 6350 3.2".
+    pub reference: &'static str,
+}
+
+impl LintIssue {
+    fn new(rule: LintRule, line: usize, property: Option<&str>, message: String, reference: &'static str) -> Self {
+        Self {
+            rule,
+            severity: rule.severity(),
+            line,
+            property: property.map(|name| name.to_string()),
+            message,
+            reference,
+        }
+    }
+}
+
+impl Display for LintIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}: {} ({})", self.line, self.severity, self.message, self.reference)
+    }
+}
+
+/// Lint vCard text with the default [ParserOptions](ParserOptions), returning all issues in line order.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::lint::{lint, LintRule};
+///
+/// let issues = lint("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nBDAY;VALUE=DATE:not a date\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nEND:VCARD\n");
+/// assert_eq!(issues.len(), 2);
+/// assert_eq!(issues[0].rule, LintRule::PropertyInvalid);
+/// assert_eq!(issues[0].line, 4);
+/// assert_eq!(issues[1].rule, LintRule::PropertyFnMissing);
+/// assert_eq!(issues[1].line, 6);
+/// ```
+pub fn lint(input: &str) -> Vec<LintIssue> {
+    lint_with_options(input, &ParserOptions::default())
+}
+
+/// Lint vCard text, validating properties against the [ParserOptions](ParserOptions), returning all issues in line order.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::lint::lint_with_options;
+/// use vcard_parser::options::ParserOptions;
+///
+/// let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL:user@@example.com\nEND:VCARD\n";
+/// assert!(lint_with_options(input, &ParserOptions::default()).is_empty());
+/// assert_eq!(lint_with_options(input, &ParserOptions { strict: true, ..ParserOptions::default() }).len(), 1);
+/// ```
+pub fn lint_with_options(input: &str, options: &ParserOptions) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    let mut card: Option<LintCard> = None;
+
+    for content_line in content_lines(input) {
+        for (line, length) in content_line.lengths.iter() {
+            if *length > FOLD_LENGTH {
+                issues.push(LintIssue::new(LintRule::LineTooLong, *line, None, format!("Line is {} octets long, lines should be folded at {} octets.", length, FOLD_LENGTH), "RFC 6350 3.2"));
+            }
+        }
+
+        let text = content_line.text.as_str();
+        let line = content_line.line;
+
+        if text.eq_ignore_ascii_case("BEGIN:VCARD") {
+            if let Some(card) = card.replace(LintCard::new(line)) {
+                issues.push(LintIssue::new(LintRule::VcardMalformed, card.line, None, String::from("BEGIN:VCARD without matching END:VCARD."), "RFC 6350 6.1.1"));
+            }
+            continue;
+        }
+
+        let Some(current) = card.as_mut() else {
+            issues.push(LintIssue::new(LintRule::VcardMalformed, line, None, String::from("Content line outside of BEGIN:VCARD and END:VCARD."), "RFC 6350 3.3"));
+            continue;
+        };
+
+        if text.eq_ignore_ascii_case("END:VCARD") {
+            if !current.version {
+                issues.push(LintIssue::new(LintRule::VcardMalformed, current.line, None, String::from("VERSION:4.0 must follow BEGIN:VCARD."), "RFC 6350 6.7.9"));
+            }
+            if !current.names.iter().any(|name| name == PropertyName::FN) {
+                issues.push(LintIssue::new(LintRule::PropertyFnMissing, current.line, None, String::from("vCard is missing FN property."), "RFC 6350 6.2.1"));
+            }
+            card = None;
+            continue;
+        }
+
+        if text.eq_ignore_ascii_case("VERSION:4.0") {
+            if current.names.is_empty() && !current.version {
+                current.version = true;
+            } else {
+                issues.push(LintIssue::new(LintRule::VcardMalformed, line, None, String::from("VERSION:4.0 must directly follow BEGIN:VCARD."), "RFC 6350 6.7.9"));
+            }
+            continue;
+        }
+
+        let RawContentLine { name, parameters, value, .. } = RawContentLine::from(text);
+        let property = match Property::try_from(format!("{}\n", text).as_str()) {
+            Ok(property) => property,
+            Err(err) => {
+                issues.push(LintIssue::new(LintRule::PropertyInvalid, line, Some(name.as_str()), format!("Invalid {} property: {}", name, err), property_reference(name.as_str())));
+                current.names.push(name);
+                continue;
+            }
+        };
+
+        if let Err(err) = options.validate(&property) {
+            issues.push(LintIssue::new(LintRule::PropertyInvalid, line, Some(name.as_str()), format!("Invalid {} property: {}", name, err), property_reference(name.as_str())));
+        } else if let Some(limit) = options.value_limit(&property) {
+            issues.push(LintIssue::new(LintRule::ValueTooLong, line, Some(name.as_str()), format!("Value of {} exceeds the maximum length of {} bytes.", name, limit.max_length), property_reference(name.as_str())));
+        }
+
+        if !property.is_multiple() && current.names.contains(&name) && !property.get_parameters().iter().any(|parameter| parameter.name() == ParameterName::ALTID) {
+            issues.push(LintIssue::new(LintRule::CardinalityExceeded, line, Some(name.as_str()), format!("Property {} may only appear once.", name), property_reference(name.as_str())));
+        }
+
+        if matches!(property.get_value(), Value::ValueText(_)) && has_unescaped_comma(value) {
+            issues.push(LintIssue::new(LintRule::CommaUnescaped, line, Some(name.as_str()), format!("Commas in {} values must be escaped.", name), "RFC 6350 3.4"));
+        }
+
+        if parameters.iter().any(|parameter| is_type_pref(parameter)) {
+            issues.push(LintIssue::new(LintRule::TypePref, line, Some(name.as_str()), String::from("TYPE=pref is replaced by the PREF parameter in vCard 4.0."), "RFC 6350 5.3"));
+        }
+
+        current.names.push(name);
+    }
+
+    if let Some(card) = card {
+        issues.push(LintIssue::new(LintRule::VcardMalformed, card.line, None, String::from("BEGIN:VCARD without matching END:VCARD."), "RFC 6350 6.1.1"));
+    }
+
+    issues.sort_by_key(|issue| issue.line);
+    issues
+}
+
+/// Repair fixable issues, see [`LintRule::is_fixable`], returning the updated text.
+///
+/// Unescaped commas in TEXT values are escaped, TYPE=pref is replaced by PREF=1, and long lines are folded. Line
+/// endings of the input are kept, other content is left as is.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::lint::fix;
+///
+/// let note = "A".repeat(80);
+/// let fixed = fix(format!("BEGIN:VCARD\r\nVERSION:4.0\r\nFN:John Doe\r\nNOTE:{}\r\nEND:VCARD\r\n", note).as_str());
+/// assert!(fixed.lines().all(|line| line.len() <= 75));
+/// assert!(fixed.contains("\r\n AAAAAAAAAA\r\n"));
+/// ```
+pub fn fix(input: &str) -> String {
+    let line_ending = if input.contains("\r\n") { "\r\n" } else { "\n" };
+    let mut string = String::with_capacity(input.len());
+
+    for content_line in content_lines(input) {
+        let text = content_line.text.as_str();
+        let raw = RawContentLine::from(text);
+
+        let line = match Property::try_from(format!("{}\n", text).as_str()) {
+            Ok(property) if !["BEGIN", "END", "VERSION"].contains(&raw.name.as_str()) => {
+                let head = if raw.parameters.iter().any(|parameter| is_type_pref(parameter)) { upgrade_type_pref(raw.head, raw.parameters.as_slice()) } else { raw.head.to_string() };
+                if matches!(property.get_value(), Value::ValueText(_)) {
+                    format!("{}:{}", head, escape_commas(raw.value))
+                } else {
+                    format!("{}:{}", head, raw.value)
+                }
+            }
+            _ => content_line.text.clone(),
+        };
+
+        string.push_str(fold_line(line.as_str()).replace("\r\n", line_ending).as_str());
+        string.push_str(line_ending);
+    }
+
+    string
+}
+
+/// Helper struct tracking the vCard being linted.
+struct LintCard {
+    line: usize,
+    names: Vec<String>,
+    version: bool,
+}
+
+impl LintCard {
+    fn new(line: usize) -> Self {
+        Self {
+            line,
+            names: Vec::new(),
+            version: false,
+        }
+    }
+}
+
+/// Helper struct for an unfolded content line, keeping the number and octet length of each physical line.
+struct ContentLine {
+    line: usize,
+    lengths: Vec<(usize, usize)>,
+    text: String,
+}
+
+/// Helper function unfolding text into content lines, skipping empty lines.
+fn content_lines(input: &str) -> Vec<ContentLine> {
+    let mut lines: Vec<ContentLine> = Vec::new();
+
+    for (i, physical) in input.split('\n').enumerate() {
+        let physical = physical.strip_suffix('\r').unwrap_or(physical);
+        if let (Some(last), Some(folded)) = (lines.last_mut(), physical.strip_prefix([' ', '\t'])) {
+            last.lengths.push((i + 1, physical.len()));
+            last.text.push_str(folded);
+        } else if !physical.is_empty() {
+            lines.push(ContentLine {
+                line: i + 1,
+                lengths: Vec::from([(i + 1, physical.len())]),
+                text: physical.to_string(),
+            });
+        }
+    }
+
+    lines
+}
+
+/// Helper struct for the raw parts of a content line.
+struct RawContentLine<'a> {
+    head: &'a str,
+    name: String,
+    parameters: Vec<&'a str>,
+    value: &'a str,
+}
+
+impl<'a> From<&'a str> for RawContentLine<'a> {
+    fn from(text: &'a str) -> Self {
+        let mut quoted = false;
+        let mut parameters = Vec::new();
+        let mut start = 0;
+        let mut end = text.len();
+
+        for (i, char) in text.char_indices() {
+            match char {
+                '"' => quoted = !quoted,
+                ';' if !quoted => {
+                    parameters.push(&text[start..i]);
+                    start = i + 1;
+                }
+                ':' if !quoted => {
+                    end = i;
+                    break;
+                }
+                _ => {}
+            }
+        }
+        parameters.push(&text[start..end]);
+
+        let name = parameters.remove(0);
+
+        Self {
+            head: &text[..end],
+            name: name.rsplit_once('.').map(|(_, name)| name).unwrap_or(name).to_uppercase(),
+            parameters,
+            value: text.get(end + 1..).unwrap_or_default(),
+        }
+    }
+}
+
+/// Helper function checking if a raw parameter is a TYPE parameter containing "pref".
+fn is_type_pref(parameter: &str) -> bool {
+    match parameter.split_once('=') {
+        Some((name, values)) if name.eq_ignore_ascii_case(ParameterName::TYPE) => values.trim_matches('"').split(',').any(|value| value.eq_ignore_ascii_case("pref")),
+        _ => false,
+    }
+}
+
+/// Helper function rebuilding the head of a content line with "pref" TYPE values replaced by PREF=1.
+fn upgrade_type_pref(head: &str, parameters: &[&str]) -> String {
+    let mut head = String::from(head.split(';').next().unwrap_or_default());
+    let has_pref = parameters.iter().any(|parameter| parameter.split_once('=').is_some_and(|(name, _)| name.eq_ignore_ascii_case(ParameterName::PREF)));
+
+    for parameter in parameters {
+        if is_type_pref(parameter) {
+            let (name, values) = parameter.split_once('=').unwrap_or_default();
+            let values: Vec<&str> = values.trim_matches('"').split(',').filter(|value| !value.eq_ignore_ascii_case("pref")).collect();
+            if !values.is_empty() {
+                head.push_str(format!(";{}={}", name, values.join(",")).as_str());
+            }
+        } else {
+            head.push(';');
+            head.push_str(parameter);
+        }
+    }
+
+    if !has_pref {
+        head.push_str(";PREF=1");
+    }
+
+    head
+}
+
+/// Helper function checking for commas not preceded by a backslash.
+fn has_unescaped_comma(value: &str) -> bool {
+    escape_commas(value).len() != value.len()
+}
+
+/// Helper function escaping commas not preceded by a backslash.
+fn escape_commas(value: &str) -> String {
+    let mut string = String::with_capacity(value.len());
+    let mut escaped = false;
+
+    for char in value.chars() {
+        if char == ',' && !escaped {
+            string.push('\\');
+        }
+        escaped = char == '\\' && !escaped;
+        string.push(char);
+    }
+
+    string
+}
+
+/// Helper function returning the RFC reference for a property name.
+fn property_reference(name: &str) -> &'static str {
+    PROPERTY_REFERENCES.iter().find(|(property, _)| *property == name).map(|(_, reference)| *reference).unwrap_or("RFC 6350 6")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lint::{fix, lint, LintRule, Severity};
+
+    #[test]
+    fn lint_issues() {
+        let input = "FN:Outside\nBEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nFN:Johnny Doe\nUID:urn:uuid:1\nUID:urn:uuid:2\nEMAIL;TYPE=PREF:john@example.com\nEND:VCARD\nBEGIN:VCARD\nFN:Jane Doe\nVERSION:4.0\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Truncated\n";
+        let issues = lint(input);
+
+        let rules: Vec<(usize, LintRule)> = issues.iter().map(|issue| (issue.line, issue.rule)).collect();
+        assert_eq!(
+            rules,
+            Vec::from([
+                (1, LintRule::VcardMalformed),
+                (5, LintRule::CardinalityExceeded),
+                (7, LintRule::CardinalityExceeded),
+                (8, LintRule::TypePref),
+                (10, LintRule::VcardMalformed),
+                (12, LintRule::VcardMalformed),
+                (14, LintRule::VcardMalformed),
+            ])
+        );
+        assert_eq!(issues[2].property.as_deref(), Some("UID"));
+        assert_eq!(issues[2].reference, "RFC 6350 6.7.6");
+        assert_eq!(issues[3].severity, Severity::Info);
+        assert_eq!(issues[2].to_string(), "7: error: Property UID may only appear once. (RFC 6350 6.7.6)");
+    }
+
+    #[test]
+    fn lint_folded_lines() {
+        let note = "A".repeat(100);
+        let input = format!("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nNOTE:{}\nitem1.NOTE:{}\n  {}\nEND:VCARD\n", note, &note[..60], &note[..60]);
+
+        let issues = lint(input.as_str());
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule, LintRule::LineTooLong);
+        assert_eq!(issues[0].line, 4);
+
+        let fixed = fix(input.as_str());
+        assert!(lint(fixed.as_str()).is_empty());
+        assert_eq!(fixed.lines().count(), 8);
+
+        let fixed = fix(format!("BEGIN:VCARD\nVERSION:4.0\nFN:{}\nEND:VCARD\n", "é".repeat(50)).as_str());
+        assert!(fixed.lines().all(|line| line.len() <= 75));
+        assert!(lint(fixed.as_str()).is_empty());
+    }
+
+    #[test]
+    fn lint_fix() {
+        let input = "BEGIN:VCARD\nVERSION:4.0\nFN:Doe\\, John\nNOTE:One, two\\, three\nTEL;TYPE=\"pref\";VALUE=URI:tel:555-5555\nEMAIL;PREF=2;TYPE=work,PREF:john@example.com\nCATEGORIES:one,two\nEND:VCARD\n";
+        let fixed = fix(input);
+
+        assert_eq!(fixed, "BEGIN:VCARD\nVERSION:4.0\nFN:Doe\\, John\nNOTE:One\\, two\\, three\nTEL;VALUE=URI;PREF=1:tel:555-5555\nEMAIL;PREF=2;TYPE=work:john@example.com\nCATEGORIES:one,two\nEND:VCARD\n");
+        assert_eq!(lint(input).len(), 3);
+        assert!(lint(fixed.as_str()).is_empty());
+    }
+}
@@ -0,0 +1,167 @@
+//! Conversion between vCards and the compact MECARD format commonly used in QR codes.
+//!
+//! The N, TEL, EMAIL, ADR, URL and NOTE properties are mapped, other MECARD fields and vCard properties are left out.
+//! MECARD values are escaped with a backslash before "\\", ";", ",", ":" and "\"", see [`Vcard::to_mecard`] and
+//! [`Vcard::from_mecard`].
+
+use crate::collection::ContactPoint;
+use crate::constants::PropertyName;
+use crate::parse::encoding::escape;
+use crate::vcard::value::Value;
+use crate::vcard::Vcard;
+use crate::{HasName, HasValue, Property, VcardError};
+
+/// The MECARD prefix.
+const MECARD: &str = "MECARD:";
+
+/// Render the N, TEL, EMAIL, ADR, URL and NOTE properties of a vCard as a MECARD.
+///
+/// The N field falls back to the [display name](Vcard::display_name) when the vCard has no N property.
+pub fn to_mecard(vcard: &Vcard) -> String {
+    let mut fields = Vec::new();
+
+    let properties = vcard.get_properties();
+    match properties.iter().find(|p| p.name() == PropertyName::N).map(|p| p.get_value()) {
+        Some(Value::ValueListComponent(list)) => {
+            let names: Vec<String> = list.value.iter().take(2).map(|component| mecard_escape(&component.join(" "))).collect();
+            fields.push(format!("N:{}", names.join(",").trim_end_matches(',')));
+        }
+        _ => fields.push(format!("N:{}", mecard_escape(&vcard.display_name()))),
+    }
+
+    for property in properties.iter() {
+        let field = match (property.name(), property.get_value()) {
+            (PropertyName::TEL | PropertyName::EMAIL | PropertyName::URL, _) => match ContactPoint::from_property(property) {
+                Some(point) => format!("{}:{}", property.name(), mecard_escape(point.value())),
+                None => continue,
+            },
+            (PropertyName::ADR, Value::ValueListComponent(list)) => {
+                format!("ADR:{}", list.value.iter().map(|component| mecard_escape(&component.join(" "))).collect::<Vec<String>>().join(","))
+            }
+            (PropertyName::NOTE, Value::ValueText(text)) => format!("NOTE:{}", mecard_escape(&text.value)),
+            _ => continue,
+        };
+        fields.push(field);
+    }
+
+    format!("{}{};;", MECARD, fields.join(";"))
+}
+
+/// Parse a MECARD into a vCard, ignoring unknown fields and fields with invalid values.
+///
+/// The FN property is created from the N field, as "given family".
+pub fn parse_mecard(str: &str) -> Result<Vcard, VcardError> {
+    let str = str.trim();
+    let fields = match str.get(..MECARD.len()) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(MECARD) => &str[MECARD.len()..],
+        _ => return Err(VcardError::ValueMalformed(str.to_string())),
+    };
+
+    let mut vcard = Vcard::new("");
+    for field in mecard_split(fields, ';') {
+        let Some((name, value)) = field.split_once(':') else { continue };
+        let components: Vec<String> = mecard_split(value, ',').iter().map(|s| mecard_unescape(s.trim())).collect();
+        let value = mecard_unescape(value);
+
+        let line = match name.trim().to_uppercase().as_str() {
+            PropertyName::N => {
+                let family = components.first().cloned().unwrap_or_default();
+                let given = components.get(1).cloned().unwrap_or_default();
+                let name = [given.as_str(), family.as_str()].iter().filter(|s| !s.is_empty()).copied().collect::<Vec<&str>>().join(" ");
+                vcard.set_property(&Property::try_from(format!("FN:{}\n", escape(&name)).as_str())?)?;
+                format!("N:{};{};;;", escape(&family), escape(&given))
+            }
+            PropertyName::ADR if components.len() == 1 => format!("ADR:;;{};;;;", escape(&value)),
+            PropertyName::ADR => {
+                let mut components: Vec<String> = components.iter().map(|s| escape(s)).collect();
+                components.resize(7, String::new());
+                format!("ADR:{}", components[..7].join(";"))
+            }
+            name @ (PropertyName::EMAIL | PropertyName::NOTE | PropertyName::TEL) => format!("{}:{}", name, escape(&value)),
+            PropertyName::URL => format!("URL:{}", value),
+            _ => continue,
+        };
+
+        if let Ok(property) = Property::try_from(format!("{}\n", line).as_str()) {
+            vcard.set_property(&property).ok();
+        }
+    }
+
+    Ok(vcard)
+}
+
+/// Helper function escaping a MECARD value.
+fn mecard_escape(str: &str) -> String {
+    let mut string = String::with_capacity(str.len());
+    for char in str.chars() {
+        if matches!(char, '\\' | ';' | ',' | ':' | '"') {
+            string.push('\\');
+        }
+        string.push(char);
+    }
+    string
+}
+
+/// Helper function unescaping a MECARD value.
+fn mecard_unescape(str: &str) -> String {
+    let mut string = String::with_capacity(str.len());
+    let mut chars = str.chars();
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => string.extend(chars.next()),
+            _ => string.push(char),
+        }
+    }
+    string
+}
+
+/// Helper function splitting a MECARD string on unescaped delimiters, empty fields are dropped when splitting on ";".
+fn mecard_split(str: &str, delimiter: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+
+    for (i, char) in str.char_indices() {
+        match char {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            _ if char == delimiter => {
+                parts.push(&str[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&str[start..]);
+
+    if delimiter == ';' {
+        parts.retain(|part| !part.trim().is_empty());
+    }
+
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vcard::Vcard;
+
+    #[test]
+    fn mecard_round_trip() {
+        let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nN:Doe;John;;;\nTEL;VALUE=URI:tel:+1-555-555-5555\nEMAIL:john@example.com\nADR:;;123 Main St.;Any Town;CA;91921;USA\nURL:https://example.com/john\nNOTE:Call after 5:00\\, not before\\; thanks\nBDAY:2000-01-01\nEND:VCARD\n").unwrap();
+
+        let mecard = vcard.to_mecard();
+        assert_eq!(mecard, "MECARD:N:Doe,John;TEL:+1-555-555-5555;EMAIL:john@example.com;ADR:,,123 Main St.,Any Town,CA,91921,USA;URL:https\\://example.com/john;NOTE:Call after 5\\:00\\, not before\\; thanks;;");
+
+        let parsed = Vcard::from_mecard(mecard.as_str()).unwrap();
+        assert_eq!(parsed.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nN:Doe;John;;;\nTEL:+1-555-555-5555\nEMAIL:john@example.com\nADR:;;123 Main St.;Any Town;CA;91921;USA\nURL:https://example.com/john\nNOTE:Call after 5:00\\, not before\\; thanks\nEND:VCARD\n");
+    }
+
+    #[test]
+    fn mecard_parse() {
+        let vcard = Vcard::from_mecard("mecard:N:Roe;SOUND:roe;TEL:555-5555;TEL:555-1234;ADR:1 Main St, Town;URL:not a uri;X-UNKNOWN:1;;").unwrap();
+        assert_eq!(vcard.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:Roe\nN:Roe;;;;\nTEL:555-5555\nTEL:555-1234\nADR:1 Main St;Town;;;;;\nEND:VCARD\n");
+
+        assert_eq!(Vcard::new("Jane Roe").to_mecard(), "MECARD:N:Jane Roe;;");
+        assert!(Vcard::from_mecard("BEGIN:VCARD").is_err());
+    }
+}
@@ -0,0 +1,254 @@
+//! Conversion of PHOTO, LOGO and SOUND properties between external references and inline data uris.
+//!
+//! Fetching and storing media is left to the caller, by implementing [`MediaResolver`] for resolving external uris to
+//! bytes, e.g. with an HTTP client, and [`MediaStore`] for storing inline data and referencing it with a uri. See
+//! [`Vcard::inline_media`](crate::vcard::Vcard::inline_media) and [`Vcard::externalize_media`](crate::vcard::Vcard::externalize_media).
+//!
+//! Inline data uris carry their own mediatype, so the MEDIATYPE parameter is removed when inlining media and set from
+//! the data uri when externalizing media, see [RFC 6350 5.7](https://datatracker.ietf.org/doc/html/rfc6350#section-5.7).
+//! [`PropertyPhotoData::set_bytes`](crate::vcard::property::property_photo::PropertyPhotoData::set_bytes) follows the
+//! same rule.
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::error::VcardError;
+//! use vcard_parser::media::MediaResolver;
+//! use vcard_parser::vcard::Vcard;
+//!
+//! struct Resolver;
+//!
+//! impl MediaResolver for Resolver {
+//!     fn resolve(&self, uri: &str) -> Result<(Option<String>, Vec<u8>), VcardError> {
+//!         match uri {
+//!             "https://example.com/photo.png" => Ok((Some(String::from("image/png")), b"PNG".to_vec())),
+//!             _ => Err(VcardError::MediaUnavailable(uri.to_string(), String::from("not found"))),
+//!         }
+//!     }
+//! }
+//!
+//! let mut vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nPHOTO;MEDIATYPE=image/png:https://example.com/photo.png\nEND:VCARD\n").expect("Unable to parse vCard.");
+//! assert_eq!(vcard.inline_media(&Resolver).expect("Unable to inline media."), 1);
+//! assert_eq!(vcard.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nPHOTO:data:image/png;base64,UE5H\nEND:VCARD\n");
+//! ```
+
+use crate::constants::{ParameterName, PropertyName};
+use crate::parse::encoding::base64_encode;
+use crate::vcard::parameter::Parameter;
+use crate::vcard::value::value_uri::ValueUriData;
+use crate::vcard::value::Value;
+use crate::{HasName, HasParameters, HasValue, Property, VcardError};
+
+/// The names of properties holding media.
+pub const MEDIA_PROPERTIES: [&str; 3] = [
+    PropertyName::LOGO,
+    PropertyName::PHOTO,
+    PropertyName::SOUND,
+];
+
+/// Image mediatypes detected by [`detect_image_mediatype`], with the magic bytes at the start of the image data.
+const IMAGE_SIGNATURES: [(&[u8], &str); 7] = [
+    (b"\x89PNG\r\n\x1a\n", "image/png"),
+    (b"\xff\xd8\xff", "image/jpeg"),
+    (b"GIF87a", "image/gif"),
+    (b"GIF89a", "image/gif"),
+    (b"BM", "image/bmp"),
+    (b"II*\0", "image/tiff"),
+    (b"MM\0*", "image/tiff"),
+];
+
+/// Resolves external media uris to their content, see [`Vcard::inline_media`](crate::vcard::Vcard::inline_media).
+pub trait MediaResolver {
+    /// Fetch the media referenced by a uri, returning its mediatype, if known, and its bytes.
+    ///
+    /// Failures should be returned as [`VcardError::MediaUnavailable`].
+    fn resolve(&self, uri: &str) -> Result<(Option<String>, Vec<u8>), VcardError>;
+}
+
+/// Stores inline media and returns a uri referencing it, see [`Vcard::externalize_media`](crate::vcard::Vcard::externalize_media).
+pub trait MediaStore {
+    /// Store the media bytes with their mediatype, if known, returning the uri of the stored media.
+    ///
+    /// Failures should be returned as [`VcardError::MediaUnavailable`].
+    fn store(&mut self, mediatype: Option<&str>, bytes: &[u8]) -> Result<String, VcardError>;
+}
+
+/// Get a copy of a media property with its external uri replaced by a data uri, or None if there is nothing to inline.
+pub fn inline_property<R: MediaResolver + ?Sized>(property: &Property, resolver: &R) -> Result<Option<Property>, VcardError> {
+    let Some(uri) = media_uri(property) else {
+        return Ok(None);
+    };
+    if uri.has_scheme(&["data"]) {
+        return Ok(None);
+    }
+
+    let (mediatype, bytes) = resolver.resolve(uri.value.as_str())?;
+    let mediatype = mediatype.or_else(|| property.has_mediatype());
+
+    let mut property = property.clone();
+    let data = format!("data:{};base64,{}", mediatype.unwrap_or_default(), base64_encode(&bytes));
+    property.set_value(Value::from(ValueUriData::try_from(data.as_str())?))?;
+    property.set_parameters(property.get_parameters().into_iter().filter(|parameter| parameter.name() != ParameterName::MEDIATYPE).collect());
+
+    Ok(Some(property))
+}
+
+/// Get a copy of a media property with its data uri replaced by a uri from the store, or None if there is nothing to externalize.
+pub fn externalize_property<S: MediaStore + ?Sized>(property: &Property, store: &mut S) -> Result<Option<Property>, VcardError> {
+    let Some(uri) = media_uri(property) else {
+        return Ok(None);
+    };
+    if !uri.has_scheme(&["data"]) {
+        return Ok(None);
+    }
+
+    let Some((mediatype, bytes)) = uri.decode_data() else {
+        return Err(VcardError::ValueMalformed(uri.value.clone()));
+    };
+    let mediatype = mediatype.or_else(|| property.has_mediatype());
+
+    let mut property = property.clone();
+    let stored = store.store(mediatype.as_deref(), &bytes)?;
+    property.set_value(Value::from(ValueUriData::try_from(stored.as_str())?))?;
+
+    if let Some(mediatype) = mediatype {
+        let mut parameters: Vec<Parameter> = property.get_parameters().into_iter().filter(|parameter| parameter.name() != ParameterName::MEDIATYPE).collect();
+        parameters.push(Parameter::try_from((ParameterName::MEDIATYPE, mediatype.as_str()))?);
+        property.set_parameters(parameters);
+    }
+
+    Ok(Some(property))
+}
+
+/// Detect the mediatype of image data from its magic bytes, returning None for unknown formats.
+///
+/// Detects PNG, JPEG, GIF, BMP, TIFF, WebP and the HEIF based HEIC and AVIF formats.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::media::detect_image_mediatype;
+///
+/// assert_eq!(detect_image_mediatype(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"), Some("image/png"));
+/// assert_eq!(detect_image_mediatype(b"RIFF\0\0\0\0WEBPVP8 "), Some("image/webp"));
+/// assert_eq!(detect_image_mediatype(b"plain text"), None);
+/// ```
+pub fn detect_image_mediatype(bytes: &[u8]) -> Option<&'static str> {
+    if let Some((_, mediatype)) = IMAGE_SIGNATURES.iter().find(|(signature, _)| bytes.starts_with(signature)) {
+        return Some(mediatype);
+    }
+
+    if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+        return Some("image/webp");
+    }
+
+    // HEIF files start with a box of type "ftyp" followed by the major brand.
+    if bytes.len() >= 12 && &bytes[4..8] == b"ftyp" {
+        return match &bytes[8..12] {
+            b"avif" | b"avis" => Some("image/avif"),
+            b"heic" | b"heix" | b"heim" | b"heis" => Some("image/heic"),
+            _ => None,
+        };
+    }
+
+    None
+}
+
+/// Helper function returning the uri value of a media property.
+fn media_uri(property: &Property) -> Option<&ValueUriData> {
+    if !MEDIA_PROPERTIES.contains(&property.name()) {
+        return None;
+    }
+    match property.get_value() {
+        Value::ValueUri(uri) => Some(uri),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::media::{detect_image_mediatype, MediaResolver, MediaStore};
+    use crate::options::{LengthAction, ParserOptions, ValueLimit};
+    use crate::vcard::property::property_photo::PropertyPhotoData;
+    use crate::vcard::Vcard;
+    use crate::{HasParameters, HasValue, Property, VcardError};
+
+    #[derive(Default)]
+    struct Media {
+        files: HashMap<String, (Option<String>, Vec<u8>)>,
+    }
+
+    impl MediaResolver for Media {
+        fn resolve(&self, uri: &str) -> Result<(Option<String>, Vec<u8>), VcardError> {
+            self.files.get(uri).cloned().ok_or_else(|| VcardError::MediaUnavailable(uri.to_string(), String::from("not found")))
+        }
+    }
+
+    impl MediaStore for Media {
+        fn store(&mut self, mediatype: Option<&str>, bytes: &[u8]) -> Result<String, VcardError> {
+            let uri = format!("https://example.com/media/{}", self.files.len() + 1);
+            self.files.insert(uri.clone(), (mediatype.map(String::from), bytes.to_vec()));
+            Ok(uri)
+        }
+    }
+
+    #[test]
+    pub fn media_roundtrip() {
+        let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nPHOTO:data:image/jpeg;base64,/9j/\nLOGO;MEDIATYPE=image/png:data:;base64,iVBORw==\nSOUND:data:,OggS\nURL:data:,not%20media\nEND:VCARD\n";
+        let mut vcard = Vcard::try_from(text).unwrap();
+        let mut media = Media::default();
+
+        assert_eq!(vcard.externalize_media(&mut media).unwrap(), 3);
+        assert_eq!(vcard.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nPHOTO;MEDIATYPE=image/jpeg:https://example.com/media/1\nLOGO;MEDIATYPE=image/png:https://example.com/media/2\nSOUND:https://example.com/media/3\nURL:data:,not%20media\nEND:VCARD\n");
+        assert_eq!(media.files.get("https://example.com/media/2"), Some(&(Some(String::from("image/png")), b"\x89PNG".to_vec())));
+        assert_eq!(vcard.externalize_media(&mut media).unwrap(), 0);
+
+        assert_eq!(vcard.inline_media(&media).unwrap(), 3);
+        assert_eq!(vcard.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nPHOTO:data:image/jpeg;base64,/9j/\nLOGO:data:image/png;base64,iVBORw==\nSOUND:data:;base64,T2dnUw==\nURL:data:,not%20media\nEND:VCARD\n");
+    }
+
+    #[test]
+    pub fn media_unavailable() {
+        let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nPHOTO:https://example.com/a.png\nLOGO:https://example.com/missing.png\nEND:VCARD\n";
+        let mut vcard = Vcard::try_from(text).unwrap();
+        let mut media = Media::default();
+        media.files.insert(String::from("https://example.com/a.png"), (None, b"PNG".to_vec()));
+
+        assert_eq!(vcard.inline_media(&media), Err(VcardError::MediaUnavailable(String::from("https://example.com/missing.png"), String::from("not found"))));
+        assert_eq!(vcard.export(), text);
+
+        // Inlined media is validated with the options of the vCard.
+        let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nPHOTO:https://example.com/a.png\nEND:VCARD\n";
+        let mut vcard = Vcard::try_from(text).unwrap();
+        vcard.set_options(ParserOptions {
+            value_limits: Vec::from([ValueLimit::new(
+                "PHOTO",
+                64,
+                LengthAction::Reject,
+            )]),
+            ..ParserOptions::default()
+        });
+        media.files.insert(String::from("https://example.com/a.png"), (None, Vec::from([0; 1000])));
+        assert!(matches!(vcard.inline_media(&media), Err(VcardError::ValueTooLong(..))));
+        assert_eq!(vcard.export(), text);
+    }
+
+    #[test]
+    pub fn media_photo_bytes() {
+        assert_eq!(detect_image_mediatype(b"GIF89a\x01\0"), Some("image/gif"));
+        assert_eq!(detect_image_mediatype(b"\0\0\0\x1cftypheic\0\0\0\0"), Some("image/heic"));
+        assert_eq!(detect_image_mediatype(b"\0\0\0\x1cftypisom\0\0\0\0"), None);
+        assert_eq!(detect_image_mediatype(b"RIFF"), None);
+
+        let Property::PropertyPhoto(mut photo) = Property::try_from("PHOTO;MEDIATYPE=image/jpeg;TYPE=work:https://example.com/a.jpg\n").unwrap() else { unreachable!() };
+        photo.set_bytes(b"\x89PNG\r\n\x1a\n", 8).unwrap();
+        assert_eq!(Property::PropertyPhoto(photo.clone()).export(), "PHOTO;TYPE=work:data:image/png;base64,iVBORw0KGgo=\n");
+
+        photo.set_bytes(b"unknown", 8).unwrap();
+        assert_eq!(Property::PropertyPhoto(photo.clone()).export(), "PHOTO;TYPE=work:data:;base64,dW5rbm93bg==\n");
+
+        assert_eq!(photo.set_bytes(b"too large", 8), Err(VcardError::MediaTooLarge(9, 8)));
+        assert_eq!(photo.get_value().to_string(), "data:;base64,dW5rbm93bg==");
+        assert!(PropertyPhotoData::from_bytes(&[], 0).unwrap().has_mediatype().is_none());
+    }
+}
@@ -0,0 +1,607 @@
+//! Options controlling how vCards are parsed and validated.
+//!
+//! Options are attached to a [`Vcard`](crate::vcard::Vcard) and applied every time a property is set,
+//! so both parsed and manually added properties are checked the same way.
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::options::ParserOptions;
+//! use vcard_parser::parse_vcards_with_options;
+//!
+//! let options = ParserOptions { strict: true, ..ParserOptions::default() };
+//!
+//! let vcards = parse_vcards_with_options("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nLANG:en-US\nEND:VCARD\n", &options).expect("Unable to parse text.");
+//! assert_eq!(vcards.len(), 1);
+//!
+//! let result = parse_vcards_with_options("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nLANG:zz-UK\nEND:VCARD\n", &options);
+//! assert!(result.is_err());
+//! ```
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use crate::constants::{ParameterName, PropertyName};
+use crate::parse::encoding::escape;
+use crate::vcard::parameter::parameter_calscale::CalScale;
+use crate::vcard::parameter::Parameter;
+use crate::vcard::value::value_text::ValueTextData;
+use crate::vcard::value::value_uri::ValueUriData;
+use crate::vcard::value::Value;
+use crate::{HasName, HasParameters, HasValue, Property, VcardError};
+
+#[derive(Clone, Debug, Default)]
+pub struct ParserOptions {
+    /// Reject values that are well-formed but fail stricter checks, e.g. unregistered language subtags, invalid email addresses or unknown IMPP schemes.
+    pub strict: bool,
+    /// Normalizer applied to TEL values, in strict mode values that fail to normalize are rejected.
+    pub tel_normalizer: Option<Arc<dyn TelNormalizer>>,
+    /// Keep parsed properties that fail to be created or validated as [unparsed lines](crate::vcard::Vcard::unparsed_lines) instead of rejecting the vCard.
+    /// Parameters a property doesn't allow are dropped with a [warning](crate::vcard::Vcard::warnings) instead.
+    pub lenient: bool,
+    /// Maximum lengths of property values, the first limit matching a property name applies.
+    pub value_limits: Vec<ValueLimit>,
+    /// Merge repeated TYPE and PID parameters of a property, see [`Property::merge_parameters`](crate::vcard::property::Property::merge_parameters).
+    pub merge_parameters: bool,
+    /// Maximum numbers of properties of a name in a vCard, the first limit matching a property name applies.
+    pub count_limits: Vec<CountLimit>,
+}
+
+impl ParserOptions {
+    /// Normalize a property according to the options, returning the updated property.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use vcard_parser::options::{E164TelNormalizer, ParserOptions};
+    /// use vcard_parser::vcard::property::Property;
+    ///
+    /// let options = ParserOptions { tel_normalizer: Some(Arc::new(E164TelNormalizer { default_country_code: Some(1) })), ..ParserOptions::default() };
+    /// let property = options.normalize(&Property::try_from("TEL:(555) 555-5555\n").unwrap()).unwrap();
+    /// assert_eq!(property.to_string(), "TEL:+15555555555\n");
+    /// ```
+    pub fn normalize(&self, property: &Property) -> Result<Property, VcardError> {
+        let mut property = property.clone();
+
+        if self.merge_parameters {
+            property.merge_parameters();
+        }
+
+        if let Some(limit) = self.value_limit(&property) {
+            if limit.action == LengthAction::Truncate {
+                match property.get_value() {
+                    Value::ValueText(text) => property.set_value(Value::from(ValueTextData {
+                        value: truncate_text(text.value.as_str(), limit.max_length),
+                    }))?,
+                    _ => return Err(VcardError::ValueTooLong(property.name().to_string(), limit.max_length)),
+                }
+            }
+        }
+
+        if let Some(normalizer) = &self.tel_normalizer {
+            if property.name() == PropertyName::TEL {
+                let result = match property.get_value() {
+                    Value::ValueText(text) => normalizer.normalize(text.value.as_str()).map(|value| Value::from(ValueTextData { value })),
+                    Value::ValueUri(uri) if uri.has_scheme(&["tel"]) => {
+                        let (number, rest) = uri.value[4..].split_once(';').map(|(number, rest)| (number, format!(";{}", rest))).unwrap_or((&uri.value[4..], String::new()));
+                        normalizer.normalize(number).and_then(|number| Ok(Value::from(ValueUriData::try_from(format!("tel:{}{}", number, rest).as_str())?)))
+                    }
+                    value => Ok(value.clone()),
+                };
+
+                match result {
+                    Ok(value) => property.set_value(value)?,
+                    Err(err) if self.strict => return Err(err),
+                    Err(_) => {}
+                }
+            }
+        }
+
+        Ok(property)
+    }
+
+    /// Validate a property against the options, returning an error for the first failed check.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::options::ParserOptions;
+    /// use vcard_parser::vcard::property::Property;
+    ///
+    /// let options = ParserOptions { strict: true, ..ParserOptions::default() };
+    /// assert!(options.validate(&Property::try_from("LANG:fr-CA\n").unwrap()).is_ok());
+    /// assert!(options.validate(&Property::try_from("NOTE;LANGUAGE=zz:Bonjour\n").unwrap()).is_err());
+    /// assert!(options.validate(&Property::try_from("EMAIL:user@@example.com\n").unwrap()).is_err());
+    /// assert!(options.validate(&Property::try_from("IMPP:https://chat.example.com\n").unwrap()).is_err());
+    /// assert!(options.validate(&Property::try_from("BDAY;CALSCALE=gregorain:19700101\n").unwrap()).is_err());
+    /// ```
+    pub fn validate(&self, property: &Property) -> Result<(), VcardError> {
+        if let Some(limit) = self.value_limit(property) {
+            if limit.action == LengthAction::Reject {
+                return Err(VcardError::ValueTooLong(property.name().to_string(), limit.max_length));
+            }
+        }
+
+        if self.strict {
+            if let Property::PropertyEmail(data) = property {
+                if !data.is_valid_address() {
+                    return Err(VcardError::ValueInvalid(data.address(), property.name().to_string()));
+                }
+            }
+            if let Property::PropertyImpp(data) = property {
+                data.validate_scheme()?;
+            }
+            #[cfg(feature = "xml")]
+            if let Property::PropertyXml(data) = property {
+                data.validate_xml()?;
+            }
+            if let Value::ValueLanguageTag(tag) = property.get_value() {
+                tag.validate()?;
+            }
+            for parameter in property.get_parameters() {
+                match parameter {
+                    Parameter::ParameterLanguage(data) => {
+                        if let Value::ValueLanguageTag(tag) = data.value {
+                            tag.validate()?;
+                        }
+                    }
+                    Parameter::ParameterCalScale(data) => {
+                        let calscale = data.calscale();
+                        if calscale != CalScale::Gregorian && !calscale.is_extension() {
+                            return Err(VcardError::ValueInvalid(calscale.to_string(), ParameterName::CALSCALE.to_string()));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the limit a property exceeds, if any, see [`ParserOptions::value_limits`].
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::options::{LengthAction, ParserOptions, ValueLimit};
+    /// use vcard_parser::vcard::property::Property;
+    ///
+    /// let options = ParserOptions { value_limits: Vec::from([ValueLimit::new("NOTE", 8, LengthAction::Warn)]), ..ParserOptions::default() };
+    /// assert!(options.value_limit(&Property::try_from("NOTE:Call me\n").unwrap()).is_none());
+    /// assert!(options.value_limit(&Property::try_from("NOTE:Call after 5pm\n").unwrap()).is_some());
+    /// assert!(options.validate(&Property::try_from("NOTE:Call after 5pm\n").unwrap()).is_ok());
+    /// ```
+    pub fn value_limit(&self, property: &Property) -> Option<&ValueLimit> {
+        let limit = self.value_limits.iter().find(|limit| matches_name(limit.name.as_str(), property.name()))?;
+        (property.get_value().to_string().len() > limit.max_length).then_some(limit)
+    }
+
+    /// Get the maximum number of properties with a name in a vCard, if any, see [`ParserOptions::count_limits`].
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::options::{CountLimit, ParserOptions};
+    ///
+    /// let options = ParserOptions { count_limits: Vec::from([CountLimit::new("EMAIL", 10), CountLimit::new("X-*", 2)]), ..ParserOptions::default() };
+    /// assert_eq!(options.max_count("email"), Some(10));
+    /// assert_eq!(options.max_count("X-ABLABEL"), Some(2));
+    /// assert_eq!(options.max_count("TEL"), None);
+    /// ```
+    pub fn max_count(&self, name: &str) -> Option<usize> {
+        self.count_limits.iter().find(|limit| matches_name(limit.name.as_str(), name)).map(|limit| limit.max_count)
+    }
+}
+
+/// How a value longer than its [limit](ValueLimit) is handled when a property is parsed or set.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LengthAction {
+    /// Reject the property with [`VcardError::ValueTooLong`].
+    #[default]
+    Reject,
+    /// Shorten TEXT values to the limit on a character boundary, other value types are rejected.
+    Truncate,
+    /// Keep the value, the property is reported by [`lint_with_options`](crate::lint::lint_with_options).
+    Warn,
+}
+
+/// Maximum length of the values of properties matching a name, see [`ParserOptions::value_limits`].
+///
+/// The length is measured in bytes of the value as written, i.e. after escaping. The name is compared
+/// case-insensitively, a name ending with "*" matches every property name starting with the rest of the name.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::options::{LengthAction, ParserOptions, ValueLimit};
+/// use vcard_parser::parse_vcards_with_options;
+///
+/// let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nNOTE:Call after 5pm\nEND:VCARD\n";
+///
+/// let options = ParserOptions { value_limits: Vec::from([ValueLimit::new("NOTE", 10, LengthAction::Truncate)]), ..ParserOptions::default() };
+/// let vcards = parse_vcards_with_options(input, &options).expect("Unable to parse text.");
+/// assert_eq!(vcards[0].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nNOTE:Call after\nEND:VCARD\n");
+///
+/// let options = ParserOptions { value_limits: Vec::from([ValueLimit::new("*", 10, LengthAction::Reject)]), ..ParserOptions::default() };
+/// assert!(parse_vcards_with_options(input, &options).is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValueLimit {
+    /// The name of the properties the limit applies to.
+    pub name: String,
+    /// The maximum length in bytes.
+    pub max_length: usize,
+    /// How longer values are handled.
+    pub action: LengthAction,
+}
+
+impl ValueLimit {
+    pub fn new(name: &str, max_length: usize, action: LengthAction) -> Self {
+        Self {
+            name: name.to_string(),
+            max_length,
+            action,
+        }
+    }
+}
+
+/// Maximum number of properties with a name in a vCard, see [`ParserOptions::count_limits`].
+///
+/// The limit is checked when a property is added, properties replacing an existing property aren't counted again.
+/// Properties exceeding the limit are rejected with [`VcardError::PropertyLimitExceeded`], or kept as
+/// [unparsed lines](crate::vcard::Vcard::unparsed_lines) when parsing leniently. The name is compared
+/// case-insensitively, a name ending with "*" applies the limit to every property name starting with the rest of the
+/// name, each counted separately.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::options::{CountLimit, ParserOptions};
+/// use vcard_parser::vcard::property::Property;
+/// use vcard_parser::vcard::Vcard;
+/// use vcard_parser::error::VcardError;
+///
+/// let mut vcard = Vcard::new("John Doe");
+/// vcard.set_options(ParserOptions { count_limits: Vec::from([CountLimit::new("EMAIL", 1)]), ..ParserOptions::default() });
+///
+/// vcard.set_property(&Property::try_from("EMAIL:john@example.com\n").unwrap()).expect("Unable to set property.");
+/// let result = vcard.set_property(&Property::try_from("EMAIL:jdoe@example.com\n").unwrap());
+/// assert_eq!(result.unwrap_err(), VcardError::PropertyLimitExceeded(String::from("EMAIL"), 1));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CountLimit {
+    /// The name of the properties the limit applies to.
+    pub name: String,
+    /// The maximum number of properties.
+    pub max_count: usize,
+}
+
+impl CountLimit {
+    pub fn new(name: &str, max_count: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            max_count,
+        }
+    }
+}
+
+/// Shorten text so that its escaped form is at most max_length bytes long.
+fn truncate_text(text: &str, max_length: usize) -> String {
+    let mut length = 0;
+    let mut buffer = [0; 4];
+
+    for (index, char) in text.char_indices() {
+        length += escape(char.encode_utf8(&mut buffer)).len();
+        if length > max_length {
+            return text[..index].to_string();
+        }
+    }
+
+    text.to_string()
+}
+
+/// Check if a property name matches a name pattern, a pattern ending with "*" matches every name starting with the rest of the pattern.
+pub(crate) fn matches_name(pattern: &str, name: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => name.len() >= prefix.len() && name.is_char_boundary(prefix.len()) && name[..prefix.len()].eq_ignore_ascii_case(prefix),
+        None => name.eq_ignore_ascii_case(pattern),
+    }
+}
+
+/// Options controlling which metadata and properties are written by [`Vcard::export_with`](crate::vcard::Vcard::export_with).
+///
+/// The default options match [`Vcard::export`](crate::vcard::Vcard::export), PID parameters and CLIENTPIDMAP
+/// properties are left out and REV properties are kept.
+///
+/// Property names are compared case-insensitively, a name ending with "*" matches every property name starting with
+/// the rest of the name, e.g. "X-*" matches all extended properties.
+#[derive(Clone, Debug)]
+pub struct ExportOptions {
+    /// Write CLIENTPIDMAP properties.
+    pub include_clientpidmap: bool,
+    /// Write PID parameters.
+    pub include_pids: bool,
+    /// Write REV properties.
+    pub include_rev: bool,
+    /// Only write properties matching one of these names, all properties are written if None.
+    pub include_properties: Option<Vec<String>>,
+    /// Never write properties matching one of these names.
+    pub exclude_properties: Vec<String>,
+    /// Write properties in their canonical order, see the `Ord` implementation of [Property](crate::vcard::property::Property).
+    pub sort_properties: bool,
+    /// Fold lines longer than 75 octets, see [RFC 6350 3.2](https://datatracker.ietf.org/doc/html/rfc6350#section-3.2).
+    pub fold_lines: bool,
+    /// How PRODID properties are written.
+    pub prodid: ProdIdPolicy,
+    /// How TYPE and PID parameters with several values are written.
+    pub parameter_style: ParameterStyle,
+    /// Write properties with an [empty value](crate::vcard::value::Value::is_empty), FN is always written.
+    pub include_empty: bool,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            include_clientpidmap: false,
+            include_pids: false,
+            include_rev: true,
+            include_properties: None,
+            exclude_properties: Vec::new(),
+            sort_properties: false,
+            fold_lines: false,
+            prodid: ProdIdPolicy::Keep,
+            parameter_style: ParameterStyle::Preserve,
+            include_empty: true,
+        }
+    }
+}
+
+impl ExportOptions {
+    /// Options writing all metadata, matching the [Display](std::fmt::Display) implementation of a vCard.
+    pub fn all() -> Self {
+        Self {
+            include_clientpidmap: true,
+            include_pids: true,
+            ..Self::default()
+        }
+    }
+
+    /// Options writing properties in their canonical order with merged parameters and folded lines, see [normalize_vcf](crate::normalize_vcf).
+    pub fn canonical() -> Self {
+        Self {
+            sort_properties: true,
+            fold_lines: true,
+            parameter_style: ParameterStyle::Merged,
+            ..Self::default()
+        }
+    }
+
+    /// Check if a property with the given name is written with these options.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::options::ExportOptions;
+    ///
+    /// let options = ExportOptions { exclude_properties: Vec::from([String::from("NOTE"), String::from("X-*")]), ..ExportOptions::default() };
+    /// assert!(options.allows_property("EMAIL"));
+    /// assert!(!options.allows_property("note"));
+    /// assert!(!options.allows_property("X-PRIVATE"));
+    /// assert!(!options.allows_property("CLIENTPIDMAP"));
+    /// ```
+    pub fn allows_property(&self, name: &str) -> bool {
+        let matches = |pattern: &String| matches_name(pattern.as_str(), name);
+
+        let included = match name.to_uppercase().as_str() {
+            PropertyName::CLIENTPIDMAP => self.include_clientpidmap,
+            PropertyName::PRODID => self.prodid == ProdIdPolicy::Keep,
+            PropertyName::REV => self.include_rev,
+            _ => true,
+        };
+
+        included && self.include_properties.as_ref().map_or(true, |names| names.iter().any(matches)) && !self.exclude_properties.iter().any(matches)
+    }
+}
+
+/// How PRODID properties are written by [`Vcard::export_with`](crate::vcard::Vcard::export_with), see [RFC 6350 6.7.3](https://datatracker.ietf.org/doc/html/rfc6350#section-6.7.3).
+///
+/// # Examples
+/// ```
+/// use vcard_parser::options::{ExportOptions, ProdIdPolicy};
+/// use vcard_parser::vcard::Vcard;
+///
+/// let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nPRODID:-//Example//Contacts 1.0//EN\nEND:VCARD\n").expect("Unable to parse vCard.");
+///
+/// let options = ExportOptions { prodid: ProdIdPolicy::Stamp(String::from("-//Example//Sync 2.0//EN")), ..ExportOptions::default() };
+/// assert_eq!(vcard.export_with(&options), "BEGIN:VCARD\nVERSION:4.0\nPRODID:-//Example//Sync 2.0//EN\nFN:John Doe\nEND:VCARD\n");
+///
+/// let options = ExportOptions { prodid: ProdIdPolicy::Suppress, ..ExportOptions::default() };
+/// assert_eq!(vcard.export_with(&options), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEND:VCARD\n");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ProdIdPolicy {
+    /// Write PRODID properties of the vCard as is.
+    #[default]
+    Keep,
+    /// Leave out PRODID properties.
+    Suppress,
+    /// Replace PRODID properties with a PRODID of the given text, written after the VERSION.
+    Stamp(String),
+}
+
+impl ProdIdPolicy {
+    /// The product identifier of this crate and its version.
+    pub const PRODID: &'static str = concat!("-//vcard_parser//vcard_parser ", env!("CARGO_PKG_VERSION"), "//EN");
+
+    /// Replace PRODID properties with the [product identifier](ProdIdPolicy::PRODID) of this crate.
+    pub fn this_crate() -> Self {
+        Self::Stamp(String::from(Self::PRODID))
+    }
+}
+
+/// How TYPE and PID parameters with several values are written by [`Vcard::export_with`](crate::vcard::Vcard::export_with).
+///
+/// # Examples
+/// ```
+/// use vcard_parser::options::{ExportOptions, ParameterStyle};
+/// use vcard_parser::vcard::Vcard;
+///
+/// let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;TYPE=home;TYPE=voice,cell:555-5555\nEND:VCARD\n").expect("Unable to parse vCard.");
+///
+/// let options = ExportOptions { parameter_style: ParameterStyle::Merged, ..ExportOptions::default() };
+/// assert_eq!(vcard.export_with(&options), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;TYPE=home,voice,cell:555-5555\nEND:VCARD\n");
+///
+/// let options = ExportOptions { parameter_style: ParameterStyle::Repeated, ..ExportOptions::default() };
+/// assert_eq!(vcard.export_with(&options), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;TYPE=home;TYPE=voice;TYPE=cell:555-5555\nEND:VCARD\n");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParameterStyle {
+    /// Write the parameters as they are set on the property.
+    #[default]
+    Preserve,
+    /// Write a single parameter with a list of values, e.g. "TYPE=home,voice".
+    Merged,
+    /// Write a parameter for every value, e.g. "TYPE=home;TYPE=voice".
+    Repeated,
+}
+
+/// Adjustments of [`Vcard::export_profile`](crate::vcard::Vcard::export_profile) for importing vCards into an application
+/// with its own quirks.
+///
+/// Each profile is described by an [ExportProfileSpec], adding a profile only takes a variant and its spec.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::options::ExportProfile;
+/// use vcard_parser::vcard::Vcard;
+///
+/// let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nKIND:individual\nTEL;TYPE=work,voice:555-5555\nEND:VCARD\n").expect("Unable to parse vCard.");
+/// assert_eq!(vcard.export_profile(ExportProfile::GoogleContacts), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;TYPE=WORK,VOICE:555-5555\nEND:VCARD\n");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExportProfile {
+    /// Google Contacts, which ignores KIND, MEMBER, SOURCE and XML, expects uppercase TYPE values and limits the size of
+    /// inline photos.
+    GoogleContacts,
+}
+
+impl ExportProfile {
+    /// Get the adjustments of the profile.
+    pub fn spec(&self) -> &'static ExportProfileSpec {
+        match self {
+            ExportProfile::GoogleContacts => &GOOGLE_CONTACTS,
+        }
+    }
+}
+
+/// The adjustments of the Google Contacts profile.
+const GOOGLE_CONTACTS: ExportProfileSpec = ExportProfileSpec {
+    exclude_properties: &[
+        PropertyName::KIND,
+        PropertyName::MEMBER,
+        PropertyName::SOURCE,
+        PropertyName::XML,
+    ],
+    uppercase_types: true,
+    max_photo_bytes: Some(1024 * 1024),
+    fold_lines: true,
+    parameter_style: ParameterStyle::Merged,
+};
+
+/// The adjustments of an [ExportProfile].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExportProfileSpec {
+    /// Property names left out, matched like [`ExportOptions::exclude_properties`].
+    pub exclude_properties: &'static [&'static str],
+    /// Write TYPE parameter values in uppercase, e.g. "WORK" instead of "work".
+    pub uppercase_types: bool,
+    /// Leave out PHOTO properties with inline data larger than this number of bytes.
+    pub max_photo_bytes: Option<usize>,
+    /// Fold lines longer than 75 octets.
+    pub fold_lines: bool,
+    /// How TYPE and PID parameters with several values are written.
+    pub parameter_style: ParameterStyle,
+}
+
+impl ExportProfileSpec {
+    /// Get the export options of the profile.
+    pub fn options(&self) -> ExportOptions {
+        ExportOptions {
+            exclude_properties: self.exclude_properties.iter().map(|name| name.to_string()).collect(),
+            fold_lines: self.fold_lines,
+            parameter_style: self.parameter_style,
+            ..ExportOptions::default()
+        }
+    }
+
+    /// Get a copy of a property adjusted for the profile, or None if the property is left out.
+    pub fn adjust(&self, property: &Property) -> Option<Property> {
+        if let (Some(max_photo_bytes), Value::ValueUri(uri)) = (self.max_photo_bytes, property.get_value()) {
+            if property.name() == PropertyName::PHOTO && uri.decode_data().is_some_and(|(_, bytes)| bytes.len() > max_photo_bytes) {
+                return None;
+            }
+        }
+
+        let mut property = property.clone();
+        if self.uppercase_types {
+            let parameters = property
+                .get_parameters()
+                .into_iter()
+                .map(|parameter| match (&parameter, parameter.get_value()) {
+                    (Parameter::ParameterType(_), Value::ValueTextList(list)) => Parameter::try_from((ParameterName::TYPE, list.value.join(",").to_uppercase().as_str())).unwrap_or(parameter),
+                    _ => parameter,
+                })
+                .collect();
+            property.set_parameters(parameters);
+        }
+
+        Some(property)
+    }
+}
+
+/// Extension point for normalizing TEL values, e.g. to [E.164](https://www.itu.int/rec/T-REC-E.164) format.
+pub trait TelNormalizer: Debug + Send + Sync {
+    /// Takes a phone number, without any tel uri scheme or parameters, and returns the normalized number.
+    fn normalize(&self, str: &str) -> Result<String, VcardError>;
+}
+
+/// Basic E.164 normalizer which strips punctuation and applies a default country code to numbers without one.
+///
+/// Numbers that begin with "+" or the "00" international prefix keep their country code. Otherwise a leading
+/// trunk prefix "0" is removed and the default country code is prepended.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::options::{E164TelNormalizer, TelNormalizer};
+///
+/// let normalizer = E164TelNormalizer { default_country_code: Some(44) };
+/// assert_eq!(normalizer.normalize("020 7946 0958").unwrap(), "+442079460958");
+/// assert_eq!(normalizer.normalize("+1 (555) 555-5555").unwrap(), "+15555555555");
+/// assert!(normalizer.normalize("call me").is_err());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct E164TelNormalizer {
+    pub default_country_code: Option<u16>,
+}
+
+impl TelNormalizer for E164TelNormalizer {
+    fn normalize(&self, str: &str) -> Result<String, VcardError> {
+        let trimmed = str.trim();
+        let digits: String = trimmed.chars().filter(|c| c.is_ascii_digit()).collect();
+
+        if trimmed.chars().any(|c| !c.is_ascii_digit() && !"+-. ()/".contains(c)) {
+            return Err(VcardError::ValueInvalid(str.to_string(), PropertyName::TEL.to_string()));
+        }
+
+        let number = if trimmed.starts_with('+') {
+            digits
+        } else if let Some(digits) = digits.strip_prefix("00") {
+            digits.to_string()
+        } else if let Some(country_code) = self.default_country_code {
+            format!("{}{}", country_code, digits.strip_prefix('0').unwrap_or(digits.as_str()))
+        } else {
+            return Err(VcardError::ValueInvalid(str.to_string(), PropertyName::TEL.to_string()));
+        };
+
+        if number.is_empty() || number.len() > 15 || number.starts_with('0') {
+            return Err(VcardError::ValueInvalid(str.to_string(), PropertyName::TEL.to_string()));
+        }
+
+        Ok(format!("+{}", number))
+    }
+}
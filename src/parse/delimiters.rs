@@ -1,7 +1,8 @@
 //! Delimiter parsing functions.
 
+use nom::branch::alt;
 use nom::bytes::complete::tag;
-use nom::character::complete::{line_ending, space1};
+use nom::character::complete::space1;
 use nom::error::context;
 use nom::sequence::tuple;
 use nom::IResult;
@@ -38,6 +39,15 @@ pub fn equals(i: Data) -> IResult<Data, Data, VcardError> {
     }
 }
 
+/// Any line ending, CRLF as required by [RFC 6350 3.2](https://datatracker.ietf.org/doc/html/rfc6350#section-3.2),
+/// LF as commonly written on Unix or a lone CR as written on old Mac systems.
+pub fn line_ending(i: Data) -> IResult<Data, Data, VcardError> {
+    match context(VcardParseError::DELIMITER_LINE_ENDING, alt((tag("\r\n"), tag("\n"), tag("\r"))))(i) {
+        Ok(data) => Ok(data),
+        Err(err) => Err(err),
+    }
+}
+
 pub fn semicolon(i: Data) -> IResult<Data, Data, VcardError> {
     match context(VcardParseError::DELIMITER_SEMI_COLON, tag(";"))(i) {
         Ok(data) => Ok(data),
@@ -49,7 +59,7 @@ pub fn semicolon(i: Data) -> IResult<Data, Data, VcardError> {
 mod tests {
     use nom::Parser;
 
-    use crate::parse::delimiters::{colon, comma, equals, fold, semicolon};
+    use crate::parse::delimiters::{colon, comma, equals, fold, line_ending, semicolon};
 
     #[test]
     fn parse_delimiters() {
@@ -58,6 +68,11 @@ mod tests {
         assert_eq!(String::from_utf8(equals.parse("=".as_bytes()).unwrap().1.to_vec()).unwrap(), "=");
         assert_eq!(String::from_utf8(fold.parse("\n\t".as_bytes()).unwrap().1.to_vec()).unwrap(), "\t");
         assert_eq!(String::from_utf8(fold.parse("\n ".as_bytes()).unwrap().1.to_vec()).unwrap(), " ");
+        assert_eq!(String::from_utf8(fold.parse("\r ".as_bytes()).unwrap().1.to_vec()).unwrap(), " ");
+        assert_eq!(line_ending.parse("\r\nA".as_bytes()).unwrap(), ("A".as_bytes(), "\r\n".as_bytes()));
+        assert_eq!(line_ending.parse("\n\r".as_bytes()).unwrap(), ("\r".as_bytes(), "\n".as_bytes()));
+        assert_eq!(line_ending.parse("\r\r\n".as_bytes()).unwrap(), ("\r\n".as_bytes(), "\r".as_bytes()));
+        assert!(line_ending.parse("A\n".as_bytes()).is_err());
         assert_eq!(String::from_utf8(semicolon.parse(";".as_bytes()).unwrap().1.to_vec()).unwrap(), ";");
     }
 }
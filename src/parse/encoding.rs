@@ -1,10 +1,12 @@
 //! Escaping and unescaping functions.
 
+use std::fmt::Write;
+
 use crate::constants::Encoding;
 
 // TODO: Replace with nom and differentiate by property, param, and value types when needed.
 pub fn escape(str: &str) -> String {
-    let mut string = String::new();
+    let mut string = String::with_capacity(str.len());
 
     for char in str.chars() {
         match char {
@@ -22,7 +24,7 @@ pub fn escape(str: &str) -> String {
 
 // TODO: Replace with nom and differentiate by property, param, and value types when needed.
 pub fn unescape(str: &str) -> String {
-    let mut string = String::new();
+    let mut string = String::with_capacity(str.len());
 
     let mut chars = str.chars().peekable();
     while let Some(char) = chars.next() {
@@ -51,9 +53,251 @@ pub fn unescape(str: &str) -> String {
     string
 }
 
+/// Encode a parameter value for writing, see [RFC 6868](https://datatracker.ietf.org/doc/html/rfc6868) and [RFC 6350 3.3](https://datatracker.ietf.org/doc/html/rfc6350#section-3.3).
+///
+/// Carets, line feeds and double quotes are encoded as "^^", "^n" and "^'". The value is double-quoted when it
+/// contains a colon, semicolon or, unless commas separate the values of a list, a comma.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::parse::encoding::encode_parameter_value;
+///
+/// assert_eq!(encode_parameter_value("work,voice", true), "work,voice");
+/// assert_eq!(encode_parameter_value("geo:37.386013,-122.082932", false), "\"geo:37.386013,-122.082932\"");
+/// assert_eq!(encode_parameter_value("Say \"hi\"\n", false), "Say ^'hi^'^n");
+/// ```
+pub fn encode_parameter_value(str: &str, list: bool) -> String {
+    let mut string = String::with_capacity(str.len() + 2);
+
+    for char in str.chars() {
+        match char {
+            '^' => string.push_str("^^"),
+            Encoding::UNESCAPED_LF => string.push_str("^n"),
+            '"' => string.push_str("^'"),
+            _ => string.push(char),
+        }
+    }
+
+    if string.contains([
+        Encoding::UNESCAPED_COLON,
+        Encoding::UNESCAPED_SEMICOLON,
+    ]) || (!list && string.contains(Encoding::UNESCAPED_COMMA))
+    {
+        string.insert(0, '"');
+        string.push('"');
+    }
+
+    string
+}
+
+/// Decode a written parameter value, removing surrounding double quotes and decoding [RFC 6868](https://datatracker.ietf.org/doc/html/rfc6868) escapes.
+///
+/// The values of a comma separated list may each be quoted on their own, the decoded values are joined by commas.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::parse::encoding::decode_parameter_value;
+///
+/// assert_eq!(decode_parameter_value("\"text/plain; charset=utf-8\""), "text/plain; charset=utf-8");
+/// assert_eq!(decode_parameter_value("\"work\",\"voice\",cell"), "work,voice,cell");
+/// assert_eq!(decode_parameter_value("Say ^'hi^'^n^^_^"), "Say \"hi\"\n^_^");
+/// ```
+pub fn decode_parameter_value(str: &str) -> String {
+    let joined = split_parameter_values(str).map(|values| values.join(","));
+    let str = match joined.as_deref() {
+        Some(joined) => joined,
+        None => str.strip_prefix('"').and_then(|str| str.strip_suffix('"')).unwrap_or(str),
+    };
+    let mut string = String::with_capacity(str.len());
+
+    let mut chars = str.chars().peekable();
+    while let Some(char) = chars.next() {
+        match (char, chars.peek()) {
+            ('^', Some('^')) => string.push('^'),
+            ('^', Some('n' | 'N')) => string.push(Encoding::UNESCAPED_LF),
+            ('^', Some('\'')) => string.push('"'),
+            _ => {
+                string.push(char);
+                continue;
+            }
+        }
+        chars.next();
+    }
+
+    string
+}
+
+/// Helper function splitting a written list of values quoted on their own, returning None unless every value is
+/// either quoted or free of double quotes.
+fn split_parameter_values(str: &str) -> Option<Vec<&str>> {
+    let mut values = Vec::new();
+    let mut rest = str;
+    loop {
+        let (value, next) = match rest.strip_prefix('"') {
+            Some(quoted) => {
+                let end = quoted.find('"')?;
+                (&quoted[..end], &quoted[end + 1..])
+            }
+            None => {
+                let end = rest.find(',').unwrap_or(rest.len());
+                (&rest[..end], &rest[end..])
+            }
+        };
+        if !rest.starts_with('"') && value.contains('"') {
+            return None;
+        }
+        values.push(value);
+        match next.strip_prefix(',') {
+            Some(next) => rest = next,
+            None if next.is_empty() => return Some(values),
+            None => return None,
+        }
+    }
+}
+
+/// The maximum length of a line in octets, excluding the line break, see [RFC 6350 3.2](https://datatracker.ietf.org/doc/html/rfc6350#section-3.2).
+pub const FOLD_LENGTH: usize = 75;
+
+/// Fold a content line into lines of at most 75 octets, joined by CRLF and a single space.
+///
+/// Lines are never folded inside a UTF-8 sequence or an escape pair, and not before whitespace, because the parser
+/// strips all leading whitespace of a folded line. The input shouldn't contain a line break.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::parse::encoding::fold_line;
+///
+/// let line = format!("NOTE:{}", "é".repeat(40));
+/// let folded = fold_line(line.as_str());
+/// assert!(folded.split("\r\n").all(|line| line.len() <= 75));
+/// assert_eq!(folded.replace("\r\n ", ""), line);
+/// ```
+pub fn fold_line(str: &str) -> String {
+    let mut string = String::with_capacity(str.len() + str.len() / (FOLD_LENGTH - 1) * 3);
+    let mut rest = str;
+    let mut limit = FOLD_LENGTH;
+
+    while rest.len() > limit {
+        // The last unit boundary within the limit, preferring boundaries that aren't followed by whitespace.
+        let (mut index, mut fallback) = (0, 0);
+        let mut chars = rest.char_indices().peekable();
+        while let Some((i, char)) = chars.next() {
+            if i > limit {
+                break;
+            }
+            if i > 0 {
+                fallback = i;
+                if !matches!(char, ' ' | '\t') {
+                    index = i;
+                }
+            }
+            if char == Encoding::UNESCAPED_BACKSLASH {
+                chars.next();
+            }
+        }
+        if index == 0 {
+            index = fallback;
+        }
+
+        string.push_str(&rest[..index]);
+        string.push_str("\r\n ");
+        rest = &rest[index..];
+        limit = FOLD_LENGTH - 1;
+    }
+    string.push_str(rest);
+
+    string
+}
+
+/// Write a JSON string literal, see [RFC 8259 Section 7](https://datatracker.ietf.org/doc/html/rfc8259#section-7).
+pub(crate) fn json_string(str: &str) -> String {
+    let mut string = String::with_capacity(str.len() + 2);
+    string.push('"');
+
+    for char in str.chars() {
+        match char {
+            '"' => string.push_str("\\\""),
+            '\\' => string.push_str("\\\\"),
+            '\n' => string.push_str("\\n"),
+            '\r' => string.push_str("\\r"),
+            '\t' => string.push_str("\\t"),
+            char if char.is_control() => {
+                // Writing to a string can't fail.
+                let _ = write!(string, "\\u{:04x}", char as u32);
+            }
+            char => string.push(char),
+        }
+    }
+
+    string.push('"');
+    string
+}
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encode bytes as standard padded base64, see [RFC 4648 Section 4](https://datatracker.ietf.org/doc/html/rfc4648#section-4).
+pub fn base64_encode(bytes: &[u8]) -> String {
+    let mut string = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let n = (chunk[0] as u32) << 16 | (*chunk.get(1).unwrap_or(&0) as u32) << 8 | *chunk.get(2).unwrap_or(&0) as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                string.push(BASE64_ALPHABET[(n >> (18 - i * 6) & 63) as usize] as char);
+            } else {
+                string.push('=');
+            }
+        }
+    }
+
+    string
+}
+
+/// Decode standard base64, ignoring whitespace and optional padding. Returns None for invalid input.
+pub fn base64_decode(str: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(str.len() / 4 * 3);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+
+    for char in str.trim_end_matches(|c: char| c == '=' || c.is_whitespace()).bytes().filter(|c| !c.is_ascii_whitespace()) {
+        let value = BASE64_ALPHABET.iter().position(|a| *a == char)? as u32;
+        buffer = buffer << 6 | value;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits & 0xFF) as u8);
+        }
+    }
+
+    if bits >= 6 {
+        return None;
+    }
+
+    Some(bytes)
+}
+
+/// Decode percent-encoded octets, see [RFC 3986 Section 2.1](https://datatracker.ietf.org/doc/html/rfc3986#section-2.1).
+pub fn percent_decode(str: &str) -> Vec<u8> {
+    let bytes = str.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() && bytes[i + 1].is_ascii_hexdigit() && bytes[i + 2].is_ascii_hexdigit() {
+            decoded.push(u8::from_str_radix(&str[i + 1..i + 3], 16).unwrap_or_default());
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    decoded
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::parse::encoding::{escape, unescape};
+    use crate::parse::encoding::{base64_decode, base64_encode, escape, fold_line, percent_decode, unescape};
 
     #[test]
     fn parse_encoding() {
@@ -75,4 +319,36 @@ mod tests {
         "#;
         assert_eq!(unescape(escape(text).as_str()), text);
     }
+
+    #[test]
+    fn parse_fold_line() {
+        assert_eq!(fold_line("NOTE:short"), "NOTE:short");
+        assert_eq!(fold_line(&"A".repeat(75)), "A".repeat(75));
+        assert_eq!(fold_line(&"A".repeat(150)), format!("{}\r\n {}\r\n {}", "A".repeat(75), "A".repeat(74), "A"));
+
+        // UTF-8 sequences, escape pairs and whitespace aren't split from the preceding character.
+        assert_eq!(fold_line(&format!("{}é", "A".repeat(74))), format!("{}\r\n é", "A".repeat(74)));
+        assert_eq!(fold_line(&format!("{}\\,", "A".repeat(74))), format!("{}\r\n \\,", "A".repeat(74)));
+        assert_eq!(fold_line(&format!("{}B C", "A".repeat(74))), format!("{}\r\n B C", "A".repeat(74)));
+        assert_eq!(fold_line(&" ".repeat(80)), format!("{}\r\n {}", " ".repeat(75), " ".repeat(5)));
+    }
+
+    #[test]
+    fn parse_base64() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64_decode("Zg==").unwrap(), b"f");
+        assert_eq!(base64_decode("Zm8").unwrap(), b"fo");
+        assert_eq!(base64_decode("Zm9v\n YmFy").unwrap(), b"foobar");
+        assert!(base64_decode("Zm9v!").is_none());
+        assert!(base64_decode("Z").is_none());
+    }
+
+    #[test]
+    fn parse_percent_decode() {
+        assert_eq!(percent_decode("a%20b%2Fc%"), b"a b/c%");
+    }
 }
@@ -1,11 +1,19 @@
 //! Parsing module that relies on nom for heavy lifting.
 
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+use crate::VcardError;
+
 pub mod delimiters;
 pub mod encoding;
 pub mod parameter;
 pub mod property;
+pub mod push;
 pub mod value;
 pub mod vcard;
+#[cfg(any(test, feature = "xml"))]
+pub mod xml;
 
 /// Represents basic data type that nom will parse.
 pub type Data<'a> = &'a [u8];
@@ -27,3 +35,105 @@ pub type ValueFoldedData<'a> = (ValueData<'a>, Option<Vec<ValueData<'a>>>);
 pub type ValueData<'a> = Data<'a>;
 /// Represents a parsed vCard.
 pub type VcardData<'a> = Vec<PropertyData<'a>>;
+/// Represents a vCard parsed by the lenient parser, with its VERSION value and skipped content lines, each with the
+/// number of parsed content lines before it.
+pub type VcardLenientData<'a> = (ValueData<'a>, VcardData<'a>, Vec<(usize, ValueFoldedData<'a>)>);
+
+/// A content line split into its group, name, parameters and value, see [content_line](content_line).
+///
+/// Names and values are kept as written: parameter values keep their quotes and RFC 6868 escapes, see
+/// [decode_parameter_value](encoding::decode_parameter_value), and the value is unfolded but not unescaped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContentLine {
+    pub group: Option<String>,
+    pub name: String,
+    pub parameters: Vec<(String, String)>,
+    pub value: String,
+}
+
+impl ContentLine {
+    /// Get the written value of the first parameter with a name, the name is compared case-insensitively.
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        self.parameters.iter().find(|(other, _)| other.eq_ignore_ascii_case(name)).map(|(_, value)| value.as_str())
+    }
+}
+
+impl Display for ContentLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(group) = &self.group {
+            write!(f, "{}.", group)?;
+        }
+        write!(f, "{}", self.name)?;
+        for (name, value) in self.parameters.iter() {
+            write!(f, ";{}={}", name, value)?;
+        }
+        write!(f, ":{}", self.value)
+    }
+}
+
+/// Parse a single content line without creating a [Property](crate::vcard::property::Property), e.g. for storing
+/// properties as written. The line may be folded, and the trailing line break is optional.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::parse::content_line;
+///
+/// let line = content_line("item1.TEL;TYPE=\"work,voice\";VALUE=uri:tel:+1-555-555-\r\n 5555").expect("Unable to parse content line.");
+/// assert_eq!(line.group.as_deref(), Some("item1"));
+/// assert_eq!(line.name, "TEL");
+/// assert_eq!(line.parameter("type"), Some("\"work,voice\""));
+/// assert_eq!(line.value, "tel:+1-555-555-5555");
+/// assert_eq!(line.to_string(), "item1.TEL;TYPE=\"work,voice\";VALUE=uri:tel:+1-555-555-5555");
+/// ```
+pub fn content_line(str: &str) -> Result<ContentLine, VcardError> {
+    let line = if str.ends_with('\n') { Cow::Borrowed(str) } else { Cow::Owned(format!("{}\n", str)) };
+
+    let (rest, ((group, name), parameters, (value, folds))) = property::property(line.as_bytes())?;
+    if !rest.is_empty() {
+        return Err(VcardError::ValueMalformed(str.to_string()));
+    }
+
+    let mut string = value::utf8_to_string(value)?;
+    for fold in folds.unwrap_or_default() {
+        string.push_str(value::utf8_to_str(fold)?);
+    }
+
+    Ok(ContentLine {
+        group: group.map(value::utf8_to_string).transpose()?,
+        name: value::utf8_to_string(name)?,
+        parameters: parameters.into_iter().map(|(name, value)| Ok((value::utf8_to_string(name)?, value::utf8_to_string(value)?))).collect::<Result<_, VcardError>>()?,
+        value: string,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{content_line, ContentLine};
+    use crate::vcard::property::Property;
+
+    #[test]
+    fn parse_content_line() {
+        let line = content_line("X-CUSTOM;X-A=1;x-a=\"b:c\";LANGUAGE=en:Hello\\, world\n").unwrap();
+        assert_eq!(
+            line,
+            ContentLine {
+                group: None,
+                name: String::from("X-CUSTOM"),
+                parameters: Vec::from([
+                    (String::from("X-A"), String::from("1")),
+                    (String::from("x-a"), String::from("\"b:c\"")),
+                    (String::from("LANGUAGE"), String::from("en"))
+                ]),
+                value: String::from("Hello\\, world"),
+            }
+        );
+        assert_eq!(line.parameter("X-A"), Some("1"));
+        assert_eq!(line.parameter("PREF"), None);
+        assert_eq!(Property::try_from(format!("{}\n", line).as_str()).unwrap().to_string(), "X-CUSTOM;X-A=1;x-a=\"b:c\";LANGUAGE=en:Hello\\, world\n");
+
+        assert_eq!(content_line("NOTE:").unwrap().value, "");
+        assert!(content_line("NOTE").is_err());
+        assert!(content_line("FN:John\nNOTE:Two lines\n").is_err());
+        assert!(content_line("").is_err());
+    }
+}
@@ -2,8 +2,10 @@
 
 use nom::branch::alt;
 use nom::bytes::complete::{tag_no_case, take_while1};
+use nom::character::complete::char;
 use nom::combinator::recognize;
-use nom::error::context;
+use nom::error::{context, ErrorKind, ParseError};
+use nom::multi::many0;
 use nom::sequence::tuple;
 use nom::IResult;
 
@@ -21,9 +23,9 @@ pub fn parameter(i: Data) -> IResult<Data, ParameterData, VcardError> {
     }
 }
 
-/// Parse parameter value.
+/// Parse parameter value, a comma separated list may quote each value on its own.
 pub fn parameter_value(i: Data) -> IResult<Data, Data, VcardError> {
-    match context(VcardParseError::PARAMETER_VALUE, alt((value_qsafe, value_safe)))(i) {
+    match context(VcardParseError::PARAMETER_VALUE, recognize(tuple((alt((value_qsafe, value_safe)), many0(tuple((char(','), alt((value_qsafe, value_safe)))))))))(i) {
         Ok(data) => Ok(data),
         Err(err) => Err(err),
     }
@@ -31,33 +33,42 @@ pub fn parameter_value(i: Data) -> IResult<Data, Data, VcardError> {
 
 /// Parse parameter name, including x-names.
 pub fn parameter_name(i: Data) -> IResult<Data, Data, VcardError> {
-    match context(
-        "Unable to parse parameter type.",
-        alt((
-            parameter_name_altid,
-            parameter_name_calscale,
-            parameter_name_cc,
-            parameter_name_geo,
-            parameter_name_index,
-            parameter_name_label,
-            parameter_name_language,
-            parameter_name_level,
-            parameter_name_mediatype,
-            parameter_name_pid,
-            parameter_name_pref,
-            parameter_name_sortas,
-            parameter_name_type,
-            parameter_name_tz,
-            parameter_name_value,
-            parameter_x_name,
-        )),
-    )(i)
-    {
+    match context("Unable to parse parameter type.", alt((parameter_name_known, parameter_x_name)))(i) {
         Ok(data) => Ok(data),
         Err(err) => Err(err),
     }
 }
 
+/// Known parameter names, in matching order.
+const PARAMETER_NAMES: [&str; 15] = [
+    ParameterName::ALTID,
+    ParameterName::CALSCALE,
+    ParameterName::CC,
+    ParameterName::GEO,
+    ParameterName::INDEX,
+    ParameterName::LABEL,
+    ParameterName::LANGUAGE,
+    ParameterName::LEVEL,
+    ParameterName::MEDIATYPE,
+    ParameterName::PID,
+    ParameterName::PREF,
+    ParameterName::SORTAS,
+    ParameterName::TYPE,
+    ParameterName::TZ,
+    ParameterName::VALUE,
+];
+
+/// Parse known parameter names, equivalent to trying each parameter_name_* function in turn without creating an error for every failed name.
+pub fn parameter_name_known(i: Data) -> IResult<Data, Data, VcardError> {
+    for name in PARAMETER_NAMES {
+        if i.len() >= name.len() && i[..name.len()].eq_ignore_ascii_case(name.as_bytes()) {
+            return Ok((&i[name.len()..], &i[..name.len()]));
+        }
+    }
+
+    Err(nom::Err::Error(VcardError::from_error_kind(i, ErrorKind::Tag)))
+}
+
 /// Parse ALTID parameter name.
 pub fn parameter_name_altid(i: Data) -> IResult<Data, Data, VcardError> {
     match tag_no_case(ParameterName::ALTID)(i) {
@@ -199,6 +210,7 @@ mod tests {
         assert_eq!(String::from_utf8(parameter.parse(r#";ALTID=1"#.as_bytes()).unwrap().1 .1.to_vec()).unwrap(), r#"1"#);
         assert_eq!(String::from_utf8(parameter.parse(r#";ALTID="1"#.as_bytes()).unwrap().1 .1.to_vec()).unwrap(), r#""#);
         assert_eq!(String::from_utf8(parameter.parse(r#";ALTID="1""#.as_bytes()).unwrap().1 .1.to_vec()).unwrap(), r#""1""#);
+        assert_eq!(String::from_utf8(parameter.parse(r#";SORT-AS="Harten","Rene":"#.as_bytes()).unwrap().1 .1.to_vec()).unwrap(), r#""Harten","Rene""#);
     }
 
     #[test]
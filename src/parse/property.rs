@@ -2,15 +2,14 @@
 
 use nom::branch::alt;
 use nom::bytes::complete::{tag, tag_no_case, take_while1};
-use nom::character::complete::line_ending;
 use nom::combinator::{not, opt, peek, recognize};
-use nom::error::context;
+use nom::error::{context, ErrorKind, ParseError};
 use nom::multi::many0;
 use nom::sequence::tuple;
 use nom::{IResult, Parser};
 
 use crate::constants::{PropertyName, VcardParseError};
-use crate::parse::delimiters::colon;
+use crate::parse::delimiters::{colon, line_ending};
 use crate::parse::parameter::parameter;
 use crate::parse::value::{is_alphanumeric_dash, value};
 use crate::parse::{Data, PropertyData, PropertyNameData, PropertyNameWithGroupData, PropertyParametersData, ValueData, ValueFoldedData};
@@ -59,23 +58,68 @@ pub fn property_value(i: Data) -> IResult<Data, ValueFoldedData, VcardError> {
 
 /// Parse property names, including x-name and iana-tokens.
 pub fn property_name(i: Data) -> IResult<Data, PropertyNameWithGroupData, VcardError> {
-    match context(
-        VcardParseError::PROPERTY_NAME,
-        tuple((
-            opt(property_group),
-            alt((
-                alt((property_name_adr, property_name_anniversary, property_name_bday, property_name_birthplace, property_name_caladruri, property_name_caluri, property_name_categories, property_name_clientpidmap, property_name_contacturi, property_name_deathdate, property_name_deathplace)),
-                alt((property_name_email, property_name_expertise, property_name_fburl, property_name_fn, property_name_gender, property_name_geo, property_name_hobby, property_name_impp, property_name_interest, property_name_key, property_name_kind)),
-                alt((property_name_lang, property_name_logo, property_name_member, property_name_nickname, property_name_note, property_name_n, property_name_orgdirectory, property_name_org, property_name_photo, property_name_prodid, property_name_related)),
-                alt((property_name_rev, property_name_role, property_name_sound, property_name_source, property_name_tel, property_name_title, property_name_tz, property_name_uid, property_name_url, property_name_xml)),
-                alt((property_x_name, property_iana_token)),
-            )),
-        )),
-    )(i)
-    {
-        Ok(data) => Ok(data),
-        Err(err) => Err(err),
+    match context(VcardParseError::PROPERTY_NAME, tuple((opt(property_group), alt((property_name_known, property_x_name, property_iana_token)))))(i) {
+        Ok(data) => Ok(data),
+        Err(err) => Err(err),
+    }
+}
+
+/// Known property names, in matching order, e.g. NOTE is matched before N.
+const PROPERTY_NAMES: [&str; 43] = [
+    PropertyName::ADR,
+    PropertyName::ANNIVERSARY,
+    PropertyName::BDAY,
+    PropertyName::BIRTHPLACE,
+    PropertyName::CALADRURI,
+    PropertyName::CALURI,
+    PropertyName::CATEGORIES,
+    PropertyName::CLIENTPIDMAP,
+    PropertyName::CONTACTURI,
+    PropertyName::DEATHDATE,
+    PropertyName::DEATHPLACE,
+    PropertyName::EMAIL,
+    PropertyName::EXPERTISE,
+    PropertyName::FBURL,
+    PropertyName::FN,
+    PropertyName::GENDER,
+    PropertyName::GEO,
+    PropertyName::HOBBY,
+    PropertyName::IMPP,
+    PropertyName::INTEREST,
+    PropertyName::KEY,
+    PropertyName::KIND,
+    PropertyName::LANG,
+    PropertyName::LOGO,
+    PropertyName::MEMBER,
+    PropertyName::NICKNAME,
+    PropertyName::NOTE,
+    PropertyName::N,
+    PropertyName::ORGDIRECTORY,
+    PropertyName::ORG,
+    PropertyName::PHOTO,
+    PropertyName::PRODID,
+    PropertyName::RELATED,
+    PropertyName::REV,
+    PropertyName::ROLE,
+    PropertyName::SOUND,
+    PropertyName::SOURCE,
+    PropertyName::TEL,
+    PropertyName::TITLE,
+    PropertyName::TZ,
+    PropertyName::UID,
+    PropertyName::URL,
+    PropertyName::XML,
+];
+
+/// Parse known property names, equivalent to trying each property_name_* function in turn without creating an error for every failed name.
+pub fn property_name_known(i: Data) -> IResult<Data, Data, VcardError> {
+    for name in PROPERTY_NAMES {
+        if i.len() >= name.len() && i[..name.len()].eq_ignore_ascii_case(name.as_bytes()) {
+            return Ok((&i[name.len()..], &i[..name.len()]));
+        }
     }
+
+    Err(nom::Err::Error(VcardError::from_error_kind(i, ErrorKind::Tag)))
 }
 
 /// Parse property group name.
@@ -475,7 +519,7 @@ mod tests {
     use nom::{AsBytes, Parser};
 
     use crate::constants::{PropertyName, TestDataPropertyValues};
-    use crate::parse::property::{property, property_begin, property_iana_token, property_x_name};
+    use crate::parse::property::{property, property_begin, property_iana_token, property_name_known, property_x_name};
 
     #[test]
     fn parse_property() {
@@ -484,6 +528,14 @@ mod tests {
         assert!(property.parse("IANA-ETC:John Doe\n".as_bytes()).is_ok());
     }
 
+    #[test]
+    fn parse_property_name_known() {
+        assert_eq!(property_name_known.parse("note:".as_bytes()).unwrap(), (":".as_bytes(), "note".as_bytes()));
+        assert_eq!(property_name_known.parse("N:".as_bytes()).unwrap(), (":".as_bytes(), "N".as_bytes()));
+        assert_eq!(property_name_known.parse("ORG-DIRECTORY:".as_bytes()).unwrap().1, "ORG-DIRECTORY".as_bytes());
+        assert!(property_name_known.parse("X-FN:".as_bytes()).is_err());
+    }
+
     #[test]
     fn parse_property_basic() {
         let ((_, property_name), property_parameters, (property_value, _)) = property.parse("FN:John Doe\n".as_bytes()).unwrap().1;
@@ -0,0 +1,348 @@
+//! Incremental push parsing, emitting [events](ParseEvent) as content lines complete.
+//!
+//! [`PushParser`] accepts chunks of bytes of any size, e.g. as they are read from a socket, and returns the events for
+//! the lines completed by each chunk. It doesn't depend on any I/O traits, so it can drive blocking, async or
+//! low-memory consumers alike. Only the current line is buffered.
+//!
+//! A content line is complete once the next line starts without folding whitespace, END:VCARD lines are emitted
+//! immediately. Lines that can't be parsed are reported as [error events](ParseEvent::Error) in input order and
+//! parsing continues with the next line.
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::parse::push::{ParseEvent, PushParser};
+//!
+//! let mut parser = PushParser::new();
+//! let mut events = parser.feed(b"BEGIN:VCARD\nVERSION:4.0\nFN:John");
+//! events.extend(parser.feed(b" Doe\nEND:VCARD\n"));
+//! events.extend(parser.finish());
+//!
+//! assert_eq!(events.len(), 4);
+//! assert!(matches!(&events[2], ParseEvent::Property(line) if line.name == "FN" && line.value == "John Doe"));
+//! assert_eq!(events[3], ParseEvent::End);
+//! ```
+
+use std::fmt::{Display, Formatter};
+
+use crate::constants::{PropertyName, VcardParseError};
+use crate::parse::property::{property, property_begin, property_end, property_version};
+use crate::parse::{Data, PropertyData};
+use crate::vcard::parameter::Parameter;
+use crate::{Property, VcardError};
+
+/// A content line split into its raw parts, see [RFC 6350 3.3](https://datatracker.ietf.org/doc/html/rfc6350#section-3.3).
+///
+/// Parameter values are kept as written, including any quotes, and the value is unfolded but still escaped.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContentLine {
+    pub group: Option<String>,
+    pub name: String,
+    pub parameters: Vec<(String, String)>,
+    pub value: String,
+}
+
+impl ContentLine {
+    /// Create a property from the content line.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::parse::push::ContentLine;
+    ///
+    /// let line = ContentLine { name: String::from("NOTE"), value: String::from("Hello\\, world"), ..ContentLine::default() };
+    /// assert_eq!(line.to_property().expect("Unable to create property.").export(), "NOTE:Hello\\, world\n");
+    /// ```
+    pub fn to_property(&self) -> Result<Property, VcardError> {
+        let mut parameters = Vec::with_capacity(self.parameters.len());
+        for (name, value) in self.parameters.iter() {
+            parameters.push(Parameter::try_from((name.as_bytes(), value.as_bytes()))?);
+        }
+
+        Property::try_from((self.group.clone(), self.name.as_str(), parameters, self.value.as_str()))
+    }
+}
+
+impl Display for ContentLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(group) = &self.group {
+            write!(f, "{}.", group)?;
+        }
+        write!(f, "{}", self.name)?;
+        for (name, value) in self.parameters.iter() {
+            write!(f, ";{}={}", name, value)?;
+        }
+        write!(f, ":{}", self.value)
+    }
+}
+
+impl<'a> TryFrom<PropertyData<'a>> for ContentLine {
+    type Error = VcardError;
+    fn try_from(((group, name), parameters, (value, folds)): PropertyData<'a>) -> Result<Self, Self::Error> {
+        let string = |data: Data| String::from_utf8(data.to_vec()).map_err(|_| VcardError::ConversionFailure);
+
+        let mut line = ContentLine {
+            group: group.map(string).transpose()?,
+            name: string(name)?.to_uppercase(),
+            parameters: Vec::with_capacity(parameters.len()),
+            value: string(value)?,
+        };
+        for (name, value) in parameters {
+            line.parameters.push((string(name)?.to_uppercase(), string(value)?));
+        }
+        for fold in folds.unwrap_or_default() {
+            line.value.push_str(&string(fold)?);
+        }
+
+        Ok(line)
+    }
+}
+
+/// An event emitted by the [push parser](PushParser).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseEvent {
+    /// A BEGIN:VCARD line.
+    Begin,
+    /// Any content line between BEGIN:VCARD and END:VCARD, including VERSION.
+    Property(ContentLine),
+    /// An END:VCARD line.
+    End,
+    /// A line that can't be parsed, a line outside of BEGIN:VCARD and END:VCARD or a missing END:VCARD line.
+    Error(VcardError),
+}
+
+/// An incremental parser accepting chunks of bytes and returning [events](ParseEvent).
+///
+/// Errors are emitted as events for lines that can't be parsed and for lines outside of BEGIN:VCARD and END:VCARD. The
+/// parser continues with the next line after an error, a BEGIN:VCARD line of an unterminated vCard starts a new vCard.
+#[derive(Clone, Debug, Default)]
+pub struct PushParser {
+    /// The bytes of the current physical line.
+    buffer: Vec<u8>,
+    /// The physical lines of the current content line, including folds.
+    pending: Vec<u8>,
+    /// Whether a BEGIN:VCARD line was parsed without a matching END:VCARD line.
+    open: bool,
+}
+
+impl PushParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed a chunk of bytes, returning the events for all content lines completed by the chunk.
+    ///
+    /// Lines may end with CRLF, LF or a lone CR, a line ending with a lone CR is completed by the next byte.
+    pub fn feed(&mut self, bytes: &[u8]) -> Vec<ParseEvent> {
+        let mut events = Vec::new();
+
+        for byte in bytes.iter() {
+            if self.buffer.last() == Some(&b'\r') && *byte != b'\n' {
+                let line = std::mem::take(&mut self.buffer);
+                self.line(line, &mut events);
+            }
+            self.buffer.push(*byte);
+            if *byte == b'\n' {
+                let line = std::mem::take(&mut self.buffer);
+                self.line(line, &mut events);
+            }
+        }
+
+        events
+    }
+
+    /// Signal the end of input, returning the events for the last content line.
+    ///
+    /// An error event is emitted when the last vCard has no END:VCARD line.
+    pub fn finish(&mut self) -> Vec<ParseEvent> {
+        let mut events = Vec::new();
+
+        if !self.buffer.is_empty() {
+            let mut line = std::mem::take(&mut self.buffer);
+            line.push(b'\n');
+            self.line(line, &mut events);
+        }
+        self.flush(&mut events);
+
+        if self.open {
+            self.open = false;
+            events.push(ParseEvent::Error(structure_error(PropertyName::END, VcardParseError::PROPERTY_END_MISSING)));
+        }
+
+        events
+    }
+
+    /// Helper function handling a physical line, including its line ending.
+    fn line(&mut self, line: Vec<u8>, events: &mut Vec<ParseEvent>) {
+        if line.starts_with(b" ") || line.starts_with(b"\t") {
+            if !self.pending.is_empty() {
+                self.pending.extend(line);
+            }
+        } else {
+            self.flush(events);
+            if !line.trim_ascii().is_empty() {
+                self.pending = line;
+            }
+        }
+
+        if property_end(&self.pending).is_ok() {
+            self.flush(events);
+        }
+    }
+
+    /// Helper function parsing the pending content line.
+    fn flush(&mut self, events: &mut Vec<ParseEvent>) {
+        if self.pending.is_empty() {
+            return;
+        }
+        let pending = std::mem::take(&mut self.pending);
+
+        if property_begin(&pending).is_ok() {
+            if self.open {
+                events.push(ParseEvent::Error(structure_error(PropertyName::BEGIN, VcardParseError::PROPERTY_END_MISSING)));
+            }
+            self.open = true;
+            events.push(ParseEvent::Begin);
+            return;
+        }
+
+        if !self.open {
+            events.push(ParseEvent::Error(structure_error(&String::from_utf8_lossy(&pending), VcardParseError::PROPERTY_BEGIN_MISSING)));
+            return;
+        }
+
+        if property_end(&pending).is_ok() {
+            self.open = false;
+            events.push(ParseEvent::End);
+        } else if let Ok((_, (_, _, value))) = property_version(&pending) {
+            events.push(ParseEvent::Property(ContentLine {
+                name: String::from(PropertyName::VERSION),
+                value: String::from_utf8_lossy(value).to_string(),
+                ..ContentLine::default()
+            }));
+        } else {
+            match property(&pending).map_err(VcardError::from).and_then(|(_, data)| ContentLine::try_from(data)) {
+                Ok(line) => events.push(ParseEvent::Property(line)),
+                Err(err) => events.push(ParseEvent::Error(err)),
+            }
+        }
+    }
+}
+
+/// Helper function creating a parse error for misplaced lines, matching the errors of the vCard parser.
+pub(crate) fn structure_error(line: &str, error: &str) -> VcardError {
+    VcardError::ParseError(Vec::from([
+        line.trim().to_string(),
+        error.to_string(),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::VcardParseError;
+    use crate::parse::push::{ContentLine, ParseEvent, PushParser};
+
+    #[test]
+    fn parse_push_events() {
+        let input = b"\r\nBEGIN:VCARD\r\nVERSION:4.0\r\nitem1.EMAIL;TYPE=work;PREF=1:john@example.com\r\nNOTE:Line\r\n  one\\, \xc3\xa9\r\nX-CUSTOM;X-PARAM=\"a,b\":value\r\nEND:VCARD\r\nBEGIN:VCARD\r\nVERSION:4.0\r\nFN:Jane\r\nEND:VCARD";
+
+        // Feeding one byte at a time checks that lines and characters split across chunks are joined.
+        let mut parser = PushParser::new();
+        let mut events = Vec::new();
+        for byte in input.iter() {
+            events.extend(parser.feed(&[*byte]));
+        }
+        assert_eq!(events.len(), 8);
+        events.extend(parser.finish());
+        assert_eq!(events.len(), 10);
+
+        assert_eq!(events[0], ParseEvent::Begin);
+        assert_eq!(
+            events[1],
+            ParseEvent::Property(ContentLine {
+                name: String::from("VERSION"),
+                value: String::from("4.0"),
+                ..ContentLine::default()
+            })
+        );
+        let ParseEvent::Property(email) = &events[2] else { panic!("Expected property.") };
+        assert_eq!(email.group.as_deref(), Some("item1"));
+        assert_eq!(
+            email.parameters,
+            Vec::from([
+                (String::from("TYPE"), String::from("work")),
+                (String::from("PREF"), String::from("1")),
+            ])
+        );
+        assert_eq!(email.to_string(), "item1.EMAIL;TYPE=work;PREF=1:john@example.com");
+        assert_eq!(email.to_property().unwrap().export(), "item1.EMAIL;TYPE=work;PREF=1:john@example.com\n");
+        assert!(matches!(&events[3], ParseEvent::Property(line) if line.value == "Lineone\\, é"));
+        assert!(matches!(&events[4], ParseEvent::Property(line) if line.parameters[0].1 == "\"a,b\""));
+        assert_eq!(events[5], ParseEvent::End);
+        assert_eq!(events[6], ParseEvent::Begin);
+        assert!(matches!(&events[8], ParseEvent::Property(line) if line.name == "FN"));
+        assert_eq!(events[9], ParseEvent::End);
+    }
+
+    #[test]
+    fn parse_push_errors() {
+        let error = |events: &[ParseEvent], index: usize| match &events[index] {
+            ParseEvent::Error(err) => err.parse_error(),
+            event => panic!("Expected error, got {:?}.", event),
+        };
+
+        // Lines after an error, including the line flushing it, are still parsed.
+        let mut parser = PushParser::new();
+        let events = parser.feed(b"FN:stray\nBEGIN:VCARD\nVERSION:4.0\nFN:John\nEND:VCARD\n");
+        assert_eq!(events.len(), 5);
+        assert_eq!(error(&events, 0), VcardParseError::PROPERTY_BEGIN_MISSING);
+        assert_eq!(events[1], ParseEvent::Begin);
+        assert!(matches!(&events[3], ParseEvent::Property(line) if line.value == "John"));
+        assert_eq!(events[4], ParseEvent::End);
+        assert_eq!(parser.finish(), Vec::new());
+
+        // A bad line inside a vCard doesn't lose the vCard or the next one.
+        let mut parser = PushParser::new();
+        assert_eq!(parser.feed(b"BEGIN:VCARD\nVERSION:4.0\nFN"), Vec::from([ParseEvent::Begin]));
+        let mut events = parser.feed(b";=:\nFN:John\nEND:VCARD\nBEGIN:VCARD\nFN:Jane\nEND:VCARD\n");
+        events.extend(parser.finish());
+        assert_eq!(events.len(), 7);
+        assert!(matches!(&events[0], ParseEvent::Property(line) if line.name == "VERSION"));
+        assert!(matches!(&events[1], ParseEvent::Error(_)));
+        assert!(matches!(&events[2], ParseEvent::Property(line) if line.value == "John"));
+        assert_eq!(events[3], ParseEvent::End);
+        assert_eq!(events[4], ParseEvent::Begin);
+        assert!(matches!(&events[5], ParseEvent::Property(line) if line.value == "Jane"));
+        assert_eq!(events[6], ParseEvent::End);
+
+        // The same applies to lines ended by a lone CR.
+        let mut parser = PushParser::new();
+        let events = parser.feed(b"BEGIN:VCARD\r;=:\rFN:John\rEND:VCARD\r");
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[1], ParseEvent::Error(_)));
+        let events = parser.finish();
+        assert!(matches!(&events[0], ParseEvent::Property(line) if line.value == "John"));
+        assert_eq!(events[1], ParseEvent::End);
+
+        let mut parser = PushParser::new();
+        parser.feed(b"BEGIN:VCARD\nFN:John\n");
+        let events = parser.finish();
+        assert!(matches!(&events[0], ParseEvent::Property(line) if line.value == "John"));
+        assert_eq!(error(&events, 1), VcardParseError::PROPERTY_END_MISSING);
+
+        let mut parser = PushParser::new();
+        let events = parser.feed(b"BEGIN:VCARD\nFN:John\nBEGIN:VCARD\nFN:Jane\nEND:VCARD\n");
+        assert_eq!(error(&events, 2), VcardParseError::PROPERTY_END_MISSING);
+        assert_eq!(events[3], ParseEvent::Begin);
+        assert!(matches!(&events[4], ParseEvent::Property(line) if line.value == "Jane"));
+
+        let mut parser = PushParser::new();
+        assert_eq!(parser.feed(b"BEGIN:VCARD\rVERSION:4.0\rFN:John \r"), Vec::from([ParseEvent::Begin]));
+        assert_eq!(parser.feed(b"\n Doe\rEND:VCARD\r").len(), 1);
+        let events = parser.finish();
+        assert!(matches!(&events[0], ParseEvent::Property(line) if line.value == "John Doe"));
+        assert_eq!(events[1], ParseEvent::End);
+
+        let mut parser = PushParser::new();
+        let events = parser.feed(b"BEGIN:VCARD\nEND:VCARD\nEND:VCARD\n");
+        assert_eq!(error(&events, 2), VcardParseError::PROPERTY_BEGIN_MISSING);
+    }
+}
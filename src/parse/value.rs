@@ -91,11 +91,12 @@ pub fn is_alphanumeric_dash(c: u8) -> bool {
 }
 
 pub fn utf8_to_string(u8: &[u8]) -> Result<String, VcardError> {
-    if let Ok(string) = String::from_utf8(u8.to_vec()) {
-        Ok(string)
-    } else {
-        Err(VcardError::ConversionFailure)
-    }
+    utf8_to_str(u8).map(String::from)
+}
+
+/// Borrow a u8 array as a string slice without allocating.
+pub fn utf8_to_str(u8: &[u8]) -> Result<&str, VcardError> {
+    std::str::from_utf8(u8).map_err(|_| VcardError::ConversionFailure)
 }
 
 #[cfg(test)]
@@ -6,19 +6,192 @@ use nom::sequence::tuple;
 use nom::IResult;
 
 use crate::constants::VcardParseError;
+use crate::parse::delimiters::line_ending;
 use crate::parse::property::{property, property_begin, property_end, property_version};
-use crate::parse::VcardData;
+use crate::parse::value::value;
+use crate::parse::{ValueData, VcardData, VcardLenientData};
 use crate::VcardError;
 
 /// Parse a vcard string and return an array of content properties.
-pub fn vcards(i: &[u8]) -> IResult<&[u8], Vec<VcardData>, VcardError> {
-    context(VcardParseError::VCARDS, many1(vcard))(i)
+pub fn vcards(i: &[u8]) -> IResult<&[u8], Vec<VcardData<'_>>, VcardError> {
+    match vcards_versioned(i) {
+        Ok((i, vcards)) => Ok((i, vcards.into_iter().map(|(_, properties)| properties).collect())),
+        Err(err) => Err(err),
+    }
+}
+
+/// Parse a vcard string and return an array of VERSION values with content properties.
+pub fn vcards_versioned(i: &[u8]) -> IResult<&[u8], Vec<(ValueData<'_>, VcardData<'_>)>, VcardError> {
+    context(VcardParseError::VCARDS, many1(vcard_versioned))(i)
+}
+
+/// Parse a vcard string like [`vcards_versioned`], using [`vcard_lenient`] for each vCard.
+pub fn vcards_lenient(i: &[u8]) -> IResult<&[u8], Vec<VcardLenientData<'_>>, VcardError> {
+    context(VcardParseError::VCARDS, many1(vcard_lenient))(i)
+}
+
+/// Parse a vcard string like [`vcard_versioned`], skipping content lines that can't be parsed and returning them separately.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::parse::vcard::vcard_lenient;
+///
+/// let (_, (_, properties, skipped)) = vcard_lenient(b"BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nX-MS-OL-DESIGN;CHARSET=utf-8:<card/>\nEND:VCARD\n").expect("Unable to parse vCard.");
+/// assert_eq!(properties.len(), 1);
+/// assert_eq!(skipped, Vec::from([(1, (b"X-MS-OL-DESIGN;CHARSET=utf-8:<card/>".as_slice(), Some(Vec::new())))]));
+/// ```
+pub fn vcard_lenient(i: &[u8]) -> IResult<&[u8], VcardLenientData<'_>, VcardError> {
+    let (mut i, (_, (_, _, version))) = context(VcardParseError::VCARD, tuple((property_begin, property_version)))(i)?;
+
+    let mut properties = Vec::new();
+    let mut skipped = Vec::new();
+
+    loop {
+        if let Ok((rest, _)) = property_end(i) {
+            return Ok((rest, (version, properties, skipped)));
+        }
+
+        if let Ok((rest, data)) = property(i) {
+            properties.push(data);
+            i = rest;
+            continue;
+        }
+
+        // A nested BEGIN line or an unterminated line means the END line is missing.
+        match tuple((value, line_ending))(i) {
+            Ok((rest, (line, _))) if property_begin(i).is_err() => {
+                if !line.0.is_empty() {
+                    skipped.push((properties.len(), line));
+                }
+                i = rest;
+            }
+            _ => {
+                let (rest, _) = context(VcardParseError::VCARD, property_end)(i)?;
+                return Ok((rest, (version, properties, skipped)));
+            }
+        }
+    }
+}
+
+/// A vCard skipped by [`vcards_recover`], with the byte offset of its first line and the parse error.
+#[derive(Debug, PartialEq, Eq)]
+pub struct VcardFailure {
+    pub offset: usize,
+    pub error: VcardError,
+}
+
+/// Parse a vcard string like [`vcards`], recovering from structural errors such as a missing END:VCARD line.
+///
+/// When a vCard fails to parse, the failure is recorded and parsing continues at the next line starting with
+/// BEGIN:VCARD, so a truncated vCard doesn't abort the whole input.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::parse::vcard::vcards_recover;
+///
+/// let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nEND:VCARD\n";
+/// let (vcards, failures) = vcards_recover(input.as_bytes());
+/// assert_eq!(vcards.len(), 1);
+/// assert_eq!(failures.len(), 1);
+/// assert_eq!(failures[0].offset, 0);
+/// ```
+pub fn vcards_recover(i: &[u8]) -> (Vec<VcardData<'_>>, Vec<VcardFailure>) {
+    let mut vcards = Vec::new();
+    let mut failures = Vec::new();
+
+    let mut offset = i.iter().position(|c| !c.is_ascii_whitespace()).unwrap_or(i.len());
+    while offset < i.len() {
+        match vcard(&i[offset..]) {
+            Ok((rest, data)) => {
+                vcards.push(data);
+                offset = i.len() - rest.len();
+            }
+            Err(err) => {
+                failures.push(VcardFailure {
+                    offset,
+                    error: VcardError::from(err),
+                });
+                match next_begin(i, offset + 1) {
+                    Some(next) => offset = next,
+                    None => break,
+                }
+                continue;
+            }
+        }
+
+        // Anything between vCards is ignored, as with vcards.
+        match next_begin(i, offset) {
+            Some(next) => offset = next,
+            None => break,
+        }
+    }
+
+    (vcards, failures)
+}
+
+/// Helper function returning the offset of the next line starting with BEGIN:VCARD, from a given offset.
+fn next_begin(i: &[u8], from: usize) -> Option<usize> {
+    (from..i.len()).find(|&n| (n == 0 || i[n - 1] == b'\n' || i[n - 1] == b'\r') && i.len() - n >= 11 && i[n..n + 11].eq_ignore_ascii_case(b"BEGIN:VCARD"))
 }
 
 /// Parse a vcard string and return an array of content properties.
-pub fn vcard(i: &[u8]) -> IResult<&[u8], VcardData, VcardError> {
+pub fn vcard(i: &[u8]) -> IResult<&[u8], VcardData<'_>, VcardError> {
+    match vcard_versioned(i) {
+        Ok((i, (_, properties))) => Ok((i, properties)),
+        Err(err) => Err(err),
+    }
+}
+
+/// Parse a vcard string and return the VERSION value with an array of content properties.
+pub fn vcard_versioned(i: &[u8]) -> IResult<&[u8], (ValueData<'_>, VcardData<'_>), VcardError> {
     match context(VcardParseError::VCARD, tuple((property_begin, property_version, many0(property), property_end)))(i) {
-        Ok((i, (_, _, properties, _))) => Ok((i, properties)),
+        Ok((i, (_, (_, _, version), properties, _))) => Ok((i, (version, properties))),
         Err(err) => Err(err),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::VcardParseError;
+    use crate::parse::vcard::{vcard_lenient, vcards, vcards_recover};
+
+    #[test]
+    fn parse_vcards_recover() {
+        let input = "BEGIN:VCARD\r\nVERSION:4.0\r\nFN:John Doe\r\nBEGIN:VCARD\r\nVERSION:4.0\r\nFN:Jane Roe\r\nEND:VCARD\r\n\r\nBEGIN:VCARD\r\nFN:No version\r\nEND:VCARD\r\nBEGIN:VCARD\r\nVERSION:4.0\r\nFN:Richard Roe\r\nEND:VCARD\r\nBEGIN:VCARD\r\nVERSION:4.0\r\n";
+        assert!(vcards(input.as_bytes()).is_err());
+
+        let (parsed, failures) = vcards_recover(input.as_bytes());
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(failures.iter().map(|failure| failure.offset).collect::<Vec<usize>>(), Vec::from([0, 91, 183]));
+        assert_eq!(failures[0].error.parse_error(), VcardParseError::PROPERTY_END_MISSING);
+        assert_eq!(failures[1].error.parse_error(), VcardParseError::PROPERTY_VERSION_MISSING);
+        assert_eq!(failures[2].error.parse_error(), VcardParseError::PROPERTY_END_MISSING);
+
+        assert_eq!(vcards_recover(b"\n\n").0.len(), 0);
+        assert_eq!(vcards_recover(b"\n\n").1.len(), 0);
+        assert_eq!(vcards_recover(b"garbage\n").1.len(), 1);
+    }
+
+    #[test]
+    fn parse_vcard_lenient() {
+        let (rest, (version, properties, skipped)) = vcard_lenient(b"BEGIN:VCARD\r\nVERSION:4.0\r\nFN:John Doe\r\nX-ONE;=:a\r\n  b\r\n\r\nNOTE:Kept\r\nX-TWO;CHARSET=utf-8:c\r\nEND:VCARD\r\nBEGIN:VCARD\r\n").unwrap();
+        assert_eq!(rest, b"BEGIN:VCARD\r\n");
+        assert_eq!(version, b"4.0");
+        assert_eq!(properties.len(), 2);
+        assert_eq!(
+            skipped,
+            Vec::from([
+                (1, (b"X-ONE;=:a".as_slice(), Some(Vec::from([b"b".as_slice()])))),
+                (2, (b"X-TWO;CHARSET=utf-8:c".as_slice(), Some(Vec::new())))
+            ])
+        );
+
+        for input in [
+            b"BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nX-ONE;=:a\n".as_slice(),
+            b"BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nX-ONE;=:a".as_slice(),
+            b"BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nEND:VCARD\n".as_slice(),
+        ] {
+            assert_eq!(crate::VcardError::from(vcard_lenient(input).unwrap_err()).parse_error(), VcardParseError::PROPERTY_END_MISSING);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! XML functions, checking that a value is a single well-formed XML element as required by the XML property.
+//!
+//! This is a small subset of [XML 1.0](https://www.w3.org/TR/xml/): DTDs aren't supported, and entity references are
+//! checked for syntax only.
+
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_until, take_while, take_while1};
+use nom::character::complete::{char, digit1, hex_digit1, multispace0, multispace1};
+use nom::combinator::{all_consuming, map, opt, recognize, verify};
+use nom::multi::many0;
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
+use nom::IResult;
+
+use crate::parse::Data;
+use crate::VcardError;
+
+/// Parse an XML document consisting of an optional prolog and a single root element.
+pub fn xml_document(i: Data) -> IResult<Data, Data, VcardError> {
+    all_consuming(recognize(tuple((opt(xml_declaration), many0(xml_misc), xml_element, many0(xml_misc)))))(i)
+}
+
+/// Parse an XML element, including its content and a matching end tag.
+pub fn xml_element(i: Data) -> IResult<Data, Data, VcardError> {
+    let start = i;
+    let (i, (_, name, attributes, _)) = tuple((char('<'), xml_name, many0(preceded(multispace1, xml_attribute)), multispace0))(i)?;
+
+    // Attribute names must be unique within an element.
+    for (n, attribute) in attributes.iter().enumerate() {
+        if attributes[..n].contains(attribute) {
+            return Err(nom::Err::Error(VcardError::from(String::from_utf8_lossy(attribute).to_string())));
+        }
+    }
+
+    if let Ok((i, _)) = tag::<_, _, VcardError>("/>")(i) {
+        return Ok((i, &start[..start.len() - i.len()]));
+    }
+
+    let (i, _) = terminated(char('>'), many0(xml_content))(i)?;
+    let (i, _) = delimited(tag("</"), verify(xml_name, |end: &[u8]| end == name), pair(multispace0, char('>')))(i)?;
+
+    Ok((i, &start[..start.len() - i.len()]))
+}
+
+/// Parse element content, character data, references, CDATA sections, comments, processing instructions or child elements.
+fn xml_content(i: Data) -> IResult<Data, Data, VcardError> {
+    alt((xml_chardata, xml_reference, xml_cdata, xml_comment, xml_pi, xml_element))(i)
+}
+
+/// Parse an attribute, returning the attribute name.
+fn xml_attribute(i: Data) -> IResult<Data, Data, VcardError> {
+    let attribute_value = |quote: char| delimited(char(quote), many0(alt((take_while1(move |c: u8| c != quote as u8 && c != b'<' && c != b'&'), xml_reference))), char(quote));
+    map(tuple((xml_name, multispace0, char('='), multispace0, alt((attribute_value('"'), attribute_value('\''))))), |(name, _, _, _, _)| name)(i)
+}
+
+/// Parse an XML name, see [XML 1.0 2.3](https://www.w3.org/TR/xml/#NT-Name).
+fn xml_name(i: Data) -> IResult<Data, Data, VcardError> {
+    recognize(pair(take_while1(|c: u8| c.is_ascii_alphabetic() || c == b'_' || c == b':' || c >= 0x80), take_while(|c: u8| c.is_ascii_alphanumeric() || b"_:-.".contains(&c) || c >= 0x80)))(i)
+}
+
+/// Parse character data, which must not contain "]]>".
+fn xml_chardata(i: Data) -> IResult<Data, Data, VcardError> {
+    verify(take_while1(|c: u8| c != b'<' && c != b'&'), |data: &[u8]| !data.windows(3).any(|w| w == b"]]>"))(i)
+}
+
+/// Parse an entity or character reference, e.g. "&amp;", "&#60;" or "&#x3C;".
+fn xml_reference(i: Data) -> IResult<Data, Data, VcardError> {
+    recognize(delimited(char('&'), alt((preceded(tag("#x"), hex_digit1), preceded(char('#'), digit1), xml_name)), char(';')))(i)
+}
+
+/// Parse a CDATA section.
+fn xml_cdata(i: Data) -> IResult<Data, Data, VcardError> {
+    recognize(delimited(tag("<![CDATA["), take_until("]]>"), tag("]]>")))(i)
+}
+
+/// Parse a comment, which must not contain "--".
+fn xml_comment(i: Data) -> IResult<Data, Data, VcardError> {
+    recognize(delimited(tag("<!--"), verify(take_until("-->"), |data: &[u8]| !data.windows(2).any(|w| w == b"--") && !data.ends_with(b"-")), tag("-->")))(i)
+}
+
+/// Parse a processing instruction, excluding the XML declaration.
+fn xml_pi(i: Data) -> IResult<Data, Data, VcardError> {
+    recognize(delimited(tag("<?"), pair(verify(xml_name, |name: &[u8]| !name.eq_ignore_ascii_case(b"xml")), take_until("?>")), tag("?>")))(i)
+}
+
+/// Parse the XML declaration.
+fn xml_declaration(i: Data) -> IResult<Data, Data, VcardError> {
+    recognize(tuple((tag("<?xml"), many0(preceded(multispace1, xml_attribute)), multispace0, tag("?>"))))(i)
+}
+
+/// Parse whitespace, comments or processing instructions around the root element.
+fn xml_misc(i: Data) -> IResult<Data, Data, VcardError> {
+    alt((multispace1, xml_comment, xml_pi))(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::xml::xml_document;
+
+    #[test]
+    fn parse_xml_document() {
+        for valid in [
+            "<b>Not an xCard XML element</b>",
+            "<a/>",
+            "<a href='x' title=\"&quot;y&#34;&#x22;\"/>",
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!-- comment --><vcards xmlns=\"urn:ietf:params:xml:ns:vcard-4.0\"><vcard><fn><text>J &amp; D</text></fn><![CDATA[<raw>]]></vcard></vcards>\n",
+            "<ns:a><?target data?><b >text</b ></ns:a>",
+        ] {
+            assert!(xml_document(valid.as_bytes()).is_ok(), "{}", valid);
+        }
+
+        for invalid in [
+            "",
+            "text",
+            "<a>",
+            "<a></b>",
+            "<a/><b/>",
+            "<a x='1' x='2'/>",
+            "<a>&amp</a>",
+            "<a><b></a></b>",
+            "<a>]]></a>",
+            "<a><!-- -- --></a>",
+            "<a x=1/>",
+            "<a></a>trailing",
+        ] {
+            assert!(xml_document(invalid.as_bytes()).is_err(), "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! Streaming parsing of vCards from a reader, without buffering the whole input.
+//!
+//! [`VcardReader`] feeds the input to a [push parser](crate::parse::push) and parses each vCard as soon as its
+//! END:VCARD line is read, so memory use is bounded by the largest vCard rather than the input. It is the building
+//! block for parsing uploads as they arrive, e.g. in a CardDAV server.
+//!
+//! # Examples
+//! ```
+//! use std::io::Cursor;
+//! use vcard_parser::reader::VcardReader;
+//!
+//! let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nEND:VCARD\n";
+//!
+//! let mut names = Vec::new();
+//! for vcard in VcardReader::new(Cursor::new(input)) {
+//!     names.push(vcard.expect("Unable to parse vCard.").display_name());
+//! }
+//! assert_eq!(names, Vec::from([String::from("John Doe"), String::from("Jane Roe")]));
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::io::{BufRead, ErrorKind};
+
+use crate::constants::{PropertyName, VcardParseError};
+use crate::options::ParserOptions;
+use crate::parse::push::{structure_error, ParseEvent, PushParser};
+use crate::vcard::Vcard;
+use crate::{parse_vcards_with_options, VcardError};
+
+/// An iterator parsing vCards from a [BufRead](BufRead) one at a time.
+///
+/// Each item is the next vCard, or the error for an invalid vCard or for lines outside of a vCard. Iteration continues
+/// after an error, so callers can skip invalid vCards. I/O errors end the iteration.
+#[derive(Debug)]
+pub struct VcardReader<R: BufRead> {
+    reader: Option<R>,
+    vcards: VcardAssembler,
+}
+
+impl<R: BufRead> VcardReader<R> {
+    /// Create a reader using the default [ParserOptions](ParserOptions).
+    pub fn new(reader: R) -> Self {
+        Self::with_options(reader, ParserOptions::default())
+    }
+
+    /// Create a reader keeping [ParserOptions](ParserOptions) on each vCard.
+    pub fn with_options(reader: R, options: ParserOptions) -> Self {
+        Self {
+            reader: Some(reader),
+            vcards: VcardAssembler::new(options),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for VcardReader<R> {
+    type Item = Result<Vcard, VcardError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(vcard) = self.vcards.pop() {
+                return Some(vcard);
+            }
+
+            let reader = self.reader.as_mut()?;
+            let length = match reader.fill_buf() {
+                Ok(bytes) => {
+                    self.vcards.feed(bytes);
+                    bytes.len()
+                }
+                Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(err) => {
+                    self.reader = None;
+                    return Some(Err(VcardError::from(err.to_string())));
+                }
+            };
+
+            if length == 0 {
+                self.reader = None;
+                self.vcards.finish();
+            } else {
+                reader.consume(length);
+            }
+        }
+    }
+}
+
+/// Helper collecting the [events](ParseEvent) of a push parser into parsed vCards, for readers fed chunk by chunk.
+#[derive(Debug)]
+pub(crate) struct VcardAssembler {
+    options: ParserOptions,
+    parser: PushParser,
+    /// The content lines of the current vCard, if a BEGIN:VCARD line was read.
+    text: Option<String>,
+    /// The first error of the current vCard.
+    error: Option<VcardError>,
+    vcards: VecDeque<Result<Vcard, VcardError>>,
+}
+
+impl VcardAssembler {
+    pub(crate) fn new(options: ParserOptions) -> Self {
+        Self {
+            options,
+            parser: PushParser::new(),
+            text: None,
+            error: None,
+            vcards: VecDeque::new(),
+        }
+    }
+
+    /// Feed a chunk of bytes, parsing the vCards it completes.
+    pub(crate) fn feed(&mut self, bytes: &[u8]) {
+        for event in self.parser.feed(bytes) {
+            self.event(event);
+        }
+    }
+
+    /// Signal the end of input, an unterminated vCard becomes an error.
+    pub(crate) fn finish(&mut self) {
+        for event in self.parser.finish() {
+            self.event(event);
+        }
+        if self.text.take().is_some() {
+            let error = self.error.take().unwrap_or_else(|| structure_error(PropertyName::END, VcardParseError::PROPERTY_END_MISSING));
+            self.vcards.push_back(Err(error));
+        }
+    }
+
+    /// Take the next parsed vCard or error.
+    pub(crate) fn pop(&mut self) -> Option<Result<Vcard, VcardError>> {
+        self.vcards.pop_front()
+    }
+
+    /// Helper function handling an event of the push parser.
+    fn event(&mut self, event: ParseEvent) {
+        match (event, self.text.as_mut()) {
+            (ParseEvent::Begin, text) => {
+                // A BEGIN:VCARD line inside a vCard follows the error for the missing END:VCARD line.
+                if text.is_some() {
+                    let error = self.error.take().unwrap_or_else(|| structure_error(PropertyName::BEGIN, VcardParseError::PROPERTY_END_MISSING));
+                    self.vcards.push_back(Err(error));
+                }
+                self.text = Some(String::from("BEGIN:VCARD\n"));
+                self.error = None;
+            }
+            (ParseEvent::Property(line), Some(text)) => {
+                // Writing to a string can't fail.
+                let _ = writeln!(text, "{}", line);
+            }
+            (ParseEvent::Property(_), None) => {}
+            (ParseEvent::End, _) => {
+                if let Some(mut text) = self.text.take() {
+                    text.push_str("END:VCARD\n");
+                    let vcard = match self.error.take() {
+                        Some(error) => Err(error),
+                        None => self.parse(text.as_str()),
+                    };
+                    self.vcards.push_back(vcard);
+                }
+            }
+            (ParseEvent::Error(error), Some(_)) => {
+                self.error.get_or_insert(error);
+            }
+            (ParseEvent::Error(error), None) => self.vcards.push_back(Err(error)),
+        }
+    }
+
+    /// Helper function parsing the text of one vCard.
+    fn parse(&self, text: &str) -> Result<Vcard, VcardError> {
+        let vcard = parse_vcards_with_options(text, &self.options)?.pop();
+        vcard.ok_or_else(|| structure_error(PropertyName::BEGIN, VcardParseError::PROPERTY_BEGIN_MISSING))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, Cursor};
+
+    use crate::constants::VcardParseError;
+    use crate::reader::VcardReader;
+
+    #[test]
+    fn reader_vcards() {
+        let input = "\r\nBEGIN:VCARD\r\nVERSION:4.0\r\nFN:John D\r\n oe\r\nEND:VCARD\r\nBEGIN:VCARD\r\nFN:Missing version\r\nEND:VCARD\r\nBEGIN:VCARD\r\nVERSION:4.0\r\nFN:Jane Roe\r\nEND:VCARD\r\n\r\n";
+
+        // A one byte buffer checks that lines split across reads are joined.
+        let results: Vec<_> = VcardReader::new(BufReader::with_capacity(1, Cursor::new(input))).collect();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().display_name(), "John Doe");
+        assert_eq!(results[1].as_ref().unwrap_err().parse_error(), VcardParseError::PROPERTY_VERSION_MISSING);
+        assert_eq!(results[2].as_ref().unwrap().display_name(), "Jane Roe");
+
+        let mut reader = VcardReader::new(Cursor::new("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\n"));
+        assert_eq!(reader.next().unwrap().unwrap_err().parse_error(), VcardParseError::PROPERTY_END_MISSING);
+        assert!(reader.next().is_none());
+
+        assert!(VcardReader::new(Cursor::new(b"BEGIN:VCARD\nFN:\xff\nEND:VCARD\n".as_slice())).next().unwrap().is_err());
+        assert!(VcardReader::new(Cursor::new("")).next().is_none());
+
+        // Lines outside of a vCard and an unterminated vCard are errors of their own, the next vCard is kept.
+        let results: Vec<_> = VcardReader::new(Cursor::new("FN:stray\nBEGIN:VCARD\nVERSION:4.0\nFN:John\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane\nEND:VCARD\n")).collect();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap_err().parse_error(), VcardParseError::PROPERTY_BEGIN_MISSING);
+        assert_eq!(results[1].as_ref().unwrap_err().parse_error(), VcardParseError::PROPERTY_END_MISSING);
+        assert_eq!(results[2].as_ref().unwrap().display_name(), "Jane");
+
+        for ending in ["\r\n", "\n", "\r"] {
+            let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John D\n oe\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nEND:VCARD\n".replace('\n', ending);
+            let names: Vec<String> = VcardReader::new(BufReader::with_capacity(1, Cursor::new(input))).map(|vcard| vcard.unwrap().display_name()).collect();
+            assert_eq!(names, ["John Doe", "Jane Roe"]);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! Redaction of sensitive vCard data, for producing shareable test fixtures from real address books.
+//!
+//! A [`RedactionPolicy`] maps property names to a [`RedactionAction`]. Hashed values are replaced with a stable
+//! 64-bit FNV-1a digest shaped like the original value, e.g. hashed emails remain email addresses. The digest is
+//! meant to keep test data distinguishable, it doesn't protect against guessing the original values.
+//!
+//! For logging, [`Vcard::to_log_string`](crate::vcard::Vcard::to_log_string) masks all values instead.
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::redact::{RedactionAction, RedactionPolicy};
+//! use vcard_parser::vcard::Vcard;
+//!
+//! let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL:john@example.com\nBDAY:19700101\nEND:VCARD\n").expect("Unable to parse vCard.");
+//! let policy = RedactionPolicy::default().with("FN", RedactionAction::Hash);
+//!
+//! let redacted = vcard.redact(&policy).expect("Unable to redact vCard.");
+//! assert!(!redacted.export().contains("John"));
+//! assert!(!redacted.export().contains("john@example.com"));
+//! assert!(redacted.get_property_by_name("BDAY").is_none());
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::constants::PropertyName;
+use crate::traits::HasGroup;
+use crate::vcard::value::value_component::Component;
+use crate::vcard::value::value_date::ValueDateData;
+use crate::vcard::value::value_listcomponent::ValueListComponentData;
+use crate::vcard::value::value_text::ValueTextData;
+use crate::vcard::value::value_textlist::ValueTextListData;
+use crate::vcard::value::value_uri::ValueUriData;
+use crate::vcard::value::Value;
+use crate::vcard::Vcard;
+use crate::{HasName, HasParameters, HasValue};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RedactionAction {
+    /// Keep the property as is.
+    Keep,
+    /// Remove the property, a required FN property is emptied instead.
+    Remove,
+    /// Replace the value with a hash of the value, values that can't be hashed are removed.
+    Hash,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RedactionPolicy {
+    /// Action for properties without an entry in actions.
+    pub default: RedactionAction,
+    /// Actions keyed by uppercase property name.
+    pub actions: HashMap<String, RedactionAction>,
+}
+
+impl RedactionPolicy {
+    /// Set the action for a property name, returning the updated policy.
+    pub fn with(mut self, name: &str, action: RedactionAction) -> Self {
+        self.actions.insert(name.to_uppercase(), action);
+        self
+    }
+
+    /// Get the action for a property name.
+    pub fn action(&self, name: &str) -> RedactionAction {
+        *self.actions.get(name.to_uppercase().as_str()).unwrap_or(&self.default)
+    }
+}
+
+/// Hashes TEL, EMAIL, ADR and NOTE values, removes BDAY and PHOTO properties, and keeps everything else.
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        Self {
+            default: RedactionAction::Keep,
+            actions: HashMap::from([
+                (PropertyName::ADR.to_string(), RedactionAction::Hash),
+                (PropertyName::BDAY.to_string(), RedactionAction::Remove),
+                (PropertyName::EMAIL.to_string(), RedactionAction::Hash),
+                (PropertyName::NOTE.to_string(), RedactionAction::Hash),
+                (PropertyName::PHOTO.to_string(), RedactionAction::Remove),
+                (PropertyName::TEL.to_string(), RedactionAction::Hash),
+            ]),
+        }
+    }
+}
+
+/// Write the structure of a vCard with every parameter value and property value replaced by its length in characters
+/// as written, e.g. "EMAIL;TYPE=<4>:<16>", see [`Vcard::to_log_string`].
+pub fn log_string(vcard: &Vcard) -> String {
+    let mut string = String::new();
+
+    // Writing to a string can't fail.
+    let _ = writeln!(string, "BEGIN:VCARD");
+    let _ = writeln!(string, "VERSION:{}", vcard.version());
+    for property in vcard.get_properties().iter() {
+        if let Some(group) = property.group() {
+            let _ = write!(string, "{}.", group);
+        }
+        let _ = write!(string, "{}", property.name());
+        for parameter in property.get_parameters() {
+            let _ = write!(string, ";{}=<{}>", parameter.name(), parameter.get_value().to_string().chars().count());
+        }
+        let _ = writeln!(string, ":<{}>", property.get_value().to_string().chars().count());
+    }
+    let _ = writeln!(string, "END:VCARD");
+
+    string
+}
+
+/// Helper function returning a hashed copy of a value, or None if the value type can't be hashed.
+pub(crate) fn hash_value(name: &str, value: &Value) -> Option<Value> {
+    let value = match value {
+        Value::ValueText(text) if text.value.is_empty() => Value::ValueText(text.clone()),
+        Value::ValueText(text) => Value::from(ValueTextData {
+            value: hash_text(name, text.value.as_str()),
+        }),
+        Value::ValueTextList(list) => Value::from(ValueTextListData {
+            delimiter: list.delimiter,
+            value: list.value.iter().map(|s| if s.is_empty() { String::new() } else { hash_text(name, s) }).collect(),
+        }),
+        Value::ValueListComponent(list) => Value::from(ValueListComponentData {
+            delimiter_child: list.delimiter_child,
+            delimiter_parent: list.delimiter_parent,
+            value: list.value.iter().map(|v| v.iter().map(|s| Component::from(if s.is_empty() { String::new() } else { hash_text(name, s) })).collect()).collect(),
+        }),
+        Value::ValueUri(uri) => {
+            let hash = fnv1a(uri.value.as_str());
+            let string = match uri.scheme().as_str() {
+                "mailto" => format!("mailto:{:016x}@example.invalid", hash),
+                "tel" => format!("tel:+{}", hash_digits(hash)),
+                "geo" => String::from("geo:0,0"),
+                _ => format!("urn:redacted:{:016x}", hash),
+            };
+            Value::from(ValueUriData::try_from(string.as_str()).ok()?)
+        }
+        Value::ValueDate(date) => {
+            let hash = fnv1a(date.to_string().as_str());
+            Value::from(ValueDateData {
+                day: (hash % 28) as u8 + 1,
+                month: (hash / 28 % 12) as u8 + 1,
+                year: 1900 + (hash / 336 % 100) as i32,
+            })
+        }
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+/// Helper function hashing text, shaped like the original for emails and phone numbers.
+fn hash_text(name: &str, str: &str) -> String {
+    let hash = fnv1a(str);
+    match name {
+        PropertyName::EMAIL => format!("{:016x}@example.invalid", hash),
+        PropertyName::TEL => format!("+{}", hash_digits(hash)),
+        _ => format!("{:016x}", hash),
+    }
+}
+
+/// Helper function returning 12 digits for use in hashed phone numbers.
+fn hash_digits(hash: u64) -> String {
+    format!("{:012}", hash % 1_000_000_000_000)
+}
+
+/// Helper function computing the 64-bit FNV-1a hash, which is stable across platforms and releases.
+fn fnv1a(str: &str) -> u64 {
+    str.bytes().fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
@@ -0,0 +1,224 @@
+//! Summary reports of a collection of vCards, e.g. for import preview screens.
+//!
+//! A [`CollectionReport`] counts the vCards, the properties used by name, the lint issues by rule and the unparsed
+//! lines kept by lenient parsing, and groups vCards which are likely duplicates. vCards are likely duplicates when
+//! they share a UID, an email address compared case-insensitively, a phone number of at least seven digits compared
+//! by its digits, or a formatted name compared case-insensitively with collapsed whitespace.
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::lint::LintRule;
+//! use vcard_parser::parse_vcards;
+//! use vcard_parser::report::CollectionReport;
+//!
+//! let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL:john@example.com\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Johnny\nEMAIL:JOHN@example.com\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nTEL;TYPE=pref:555-5555\nEND:VCARD\n";
+//! let vcards = parse_vcards(input).expect("Unable to parse text.");
+//!
+//! let report = CollectionReport::build(&vcards);
+//! assert_eq!(report.cards, 3);
+//! assert_eq!(report.properties.get("EMAIL"), Some(&2));
+//! assert_eq!(report.duplicates, [[0, 1]]);
+//! assert_eq!(report.count(LintRule::TypePref), 1);
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+use crate::collection::ContactPoint;
+use crate::constants::PropertyName;
+use crate::lint::{lint_with_options, LintRule, Severity};
+use crate::parse::encoding::json_string;
+use crate::vcard::Vcard;
+use crate::HasValue;
+
+/// Summary of a collection of vCards, see the [report](crate::report) module.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CollectionReport {
+    /// Number of vCards.
+    pub cards: usize,
+    /// Number of properties across all vCards keyed by uppercase property name.
+    pub properties: BTreeMap<String, usize>,
+    /// Indexes of vCards which are likely duplicates, see [duplicate_clusters](duplicate_clusters).
+    pub duplicates: Vec<Vec<usize>>,
+    /// Number of lint issues of the written vCards by rule.
+    pub issues: BTreeMap<LintRule, usize>,
+    /// Number of content lines kept as [unparsed lines](Vcard::unparsed_lines).
+    pub unparsed_lines: usize,
+}
+
+impl CollectionReport {
+    /// Build a report of an array of vCards, each vCard is linted as written with its own parser options.
+    pub fn build(vcards: &[Vcard]) -> Self {
+        let mut report = CollectionReport {
+            cards: vcards.len(),
+            duplicates: duplicate_clusters(vcards),
+            ..CollectionReport::default()
+        };
+
+        for vcard in vcards {
+            for (name, count) in vcard.stats().counts {
+                *report.properties.entry(name).or_default() += count;
+            }
+            for issue in lint_with_options(vcard.to_string().as_str(), vcard.get_options()) {
+                *report.issues.entry(issue.rule).or_default() += 1;
+            }
+            report.unparsed_lines += vcard.unparsed_lines().len();
+        }
+
+        report
+    }
+
+    /// Get the number of lint issues reported by a rule.
+    pub fn count(&self, rule: LintRule) -> usize {
+        self.issues.get(&rule).copied().unwrap_or_default()
+    }
+
+    /// Get the number of lint issues with a severity.
+    pub fn count_severity(&self, severity: Severity) -> usize {
+        self.issues.iter().filter(|(rule, _)| rule.severity() == severity).map(|(_, count)| count).sum()
+    }
+
+    /// Write the report as a JSON object, issues are keyed by rule name and summed by severity.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::report::CollectionReport;
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let report = CollectionReport::build(&[Vcard::new("John Doe")]);
+    /// assert_eq!(report.to_json(), r#"{"cards":1,"properties":{"FN":1},"duplicates":[],"issues":{},"severities":{"info":0,"warning":0,"error":0},"unparsed_lines":0}"#);
+    /// ```
+    pub fn to_json(&self) -> String {
+        let properties: Vec<String> = self.properties.iter().map(|(name, count)| format!("{}:{}", json_string(name), count)).collect();
+        let duplicates: Vec<String> = self.duplicates.iter().map(|cluster| format!("[{}]", cluster.iter().map(|index| index.to_string()).collect::<Vec<String>>().join(","))).collect();
+        let issues: Vec<String> = self.issues.iter().map(|(rule, count)| format!("{}:{}", json_string(format!("{:?}", rule).as_str()), count)).collect();
+
+        let mut string = String::new();
+        // Writing to a string can't fail.
+        let _ = write!(
+            string,
+            "{{\"cards\":{},\"properties\":{{{}}},\"duplicates\":[{}],\"issues\":{{{}}},\"severities\":{{\"info\":{},\"warning\":{},\"error\":{}}},\"unparsed_lines\":{}}}",
+            self.cards,
+            properties.join(","),
+            duplicates.join(","),
+            issues.join(","),
+            self.count_severity(Severity::Info),
+            self.count_severity(Severity::Warning),
+            self.count_severity(Severity::Error),
+            self.unparsed_lines
+        );
+        string
+    }
+}
+
+/// Group the indexes of vCards which are likely duplicates, see the [report](crate::report) module.
+///
+/// Only groups of two or more vCards are returned, each sorted and in order of their first index.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::parse_vcards;
+/// use vcard_parser::report::duplicate_clusters;
+///
+/// let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:+1 555 555 5555\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:J. Doe\nTEL:(555) 555-5555\nEND:VCARD\n";
+/// let vcards = parse_vcards(input).expect("Unable to parse text.");
+/// assert_eq!(duplicate_clusters(&vcards), [[0, 2]]);
+/// ```
+pub fn duplicate_clusters(vcards: &[Vcard]) -> Vec<Vec<usize>> {
+    let mut parents: Vec<usize> = (0..vcards.len()).collect();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+
+    for (index, vcard) in vcards.iter().enumerate() {
+        for key in duplicate_keys(vcard) {
+            match seen.get(&key) {
+                Some(other) => {
+                    let (a, b) = (root(&parents, index), root(&parents, *other));
+                    parents[a.max(b)] = a.min(b);
+                }
+                None => {
+                    seen.insert(key, index);
+                }
+            }
+        }
+    }
+
+    let mut clusters: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for index in 0..vcards.len() {
+        clusters.entry(root(&parents, index)).or_default().push(index);
+    }
+
+    clusters.into_values().filter(|cluster| cluster.len() > 1).collect()
+}
+
+/// Helper function getting the keys a vCard is matched with, prefixed by their kind.
+fn duplicate_keys(vcard: &Vcard) -> Vec<String> {
+    let mut keys = Vec::new();
+
+    if let Some(uid) = vcard.get_property_by_name(PropertyName::UID) {
+        keys.push(format!("uid:{}", uid.get_value()));
+    }
+
+    let name = vcard.display_name().split_whitespace().collect::<Vec<&str>>().join(" ").to_lowercase();
+    if !name.is_empty() {
+        keys.push(format!("fn:{}", name));
+    }
+
+    for point in vcard.contact_points() {
+        match point {
+            ContactPoint::Email(data) if !data.value.is_empty() => keys.push(format!("email:{}", data.value.to_lowercase())),
+            ContactPoint::Tel(data) => {
+                let digits: String = data.value.chars().filter(char::is_ascii_digit).collect();
+                // Compare the last ten digits, so numbers with and without a country code match.
+                if digits.len() >= 7 {
+                    keys.push(format!("tel:{}", &digits[digits.len().saturating_sub(10)..]));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    keys
+}
+
+/// Helper function finding the root of a vCard index in the duplicate clusters.
+fn root(parents: &[usize], mut index: usize) -> usize {
+    while parents[index] != index {
+        index = parents[index];
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lint::{LintRule, Severity};
+    use crate::parse_vcards;
+    use crate::report::{duplicate_clusters, CollectionReport};
+    use crate::vcard::Vcard;
+
+    #[test]
+    pub fn report_collection() {
+        let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nUID:urn:uuid:1\nTEL:555-5555\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nEMAIL:jane@example.com\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:john  doe\nNOTE:Duplicate by name\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Someone Else\nUID:urn:uuid:1\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Roe\nEMAIL:Jane@Example.com\nTEL;TYPE=pref:555-1234\nEND:VCARD\n";
+        let vcards = parse_vcards(input).unwrap();
+
+        assert_eq!(duplicate_clusters(&vcards), [vec![0, 2, 3], vec![1, 4]]);
+        assert!(duplicate_clusters(&[]).is_empty());
+        assert!(duplicate_clusters(&[Vcard::new("A"), Vcard::new("B")]).is_empty());
+
+        let report = CollectionReport::build(&vcards);
+        assert_eq!(report.cards, 5);
+        assert_eq!(report.properties.get("FN"), Some(&5));
+        assert_eq!(report.properties.get("EMAIL"), Some(&2));
+        assert_eq!(report.properties.get("TEL"), Some(&2));
+        assert_eq!(report.count(LintRule::TypePref), 1);
+        assert_eq!(report.count(LintRule::CommaUnescaped), 0);
+        assert_eq!(report.count_severity(Severity::Info), 1);
+        assert_eq!(report.count_severity(Severity::Error), 0);
+        assert_eq!(report.unparsed_lines, 0);
+
+        let json = report.to_json();
+        assert!(json.starts_with("{\"cards\":5,\"properties\":{\"EMAIL\":2,\"FN\":5,"));
+        assert!(json.contains("\"duplicates\":[[0,2,3],[1,4]],\"issues\":{\"TypePref\":1},\"severities\":{\"info\":1,\"warning\":0,\"error\":0}"));
+
+        assert_eq!(CollectionReport::build(&[]), CollectionReport::default());
+    }
+}
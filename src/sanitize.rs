@@ -0,0 +1,177 @@
+//! Presets preparing vCards for import by specific applications, see [`Vcard::sanitize`].
+//!
+//! Each [`Preset`] keeps the properties the target is known to import, removes embedded photos above a size cap, and
+//! provides [export options](Preset::export_options) with line folding and without PID metadata. Each preset also has
+//! the [version](Preset::version) preferred by the target, which is written when it is a [supported version](VcardVersion).
+//! Only vCard 4.0 is supported, targets preferring vCard 3.0 generally import 4.0 cards restricted to these properties.
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::sanitize::Preset;
+//! use vcard_parser::vcard::Vcard;
+//!
+//! let mut vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nGENDER:M\nX-CUSTOM:1\nEND:VCARD\n").expect("Unable to parse vCard.");
+//!
+//! let removed = vcard.sanitize(Preset::Minimal).expect("Unable to sanitize vCard.");
+//! assert_eq!(removed.len(), 2);
+//! assert_eq!(vcard.export_with(&Preset::Minimal.export_options()), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nEND:VCARD\n");
+//! ```
+
+use crate::constants::{PropertyName, VcardVersion};
+use crate::options::{matches_name, ExportOptions};
+use crate::vcard::value::Value;
+use crate::{HasName, HasValue, Property};
+
+/// Known import targets, see the [sanitize](crate::sanitize) module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Preset {
+    /// Contacts on iOS and macOS, keeping the X-AB* properties used for labels and related names.
+    IosImport,
+    /// Google Contacts, keeping the X-GOOGLE-* properties.
+    GoogleImport,
+    /// Only the name, UID and the most common ways of contacting a person or organization, without photos.
+    Minimal,
+}
+
+impl Preset {
+    /// Get the names of the properties kept by the preset, a name ending with "*" matches every name starting with the rest of the name.
+    pub fn properties(&self) -> &'static [&'static str] {
+        match self {
+            Preset::IosImport => &[
+                PropertyName::ADR,
+                PropertyName::BDAY,
+                PropertyName::EMAIL,
+                PropertyName::FN,
+                PropertyName::IMPP,
+                PropertyName::N,
+                PropertyName::NICKNAME,
+                PropertyName::NOTE,
+                PropertyName::ORG,
+                PropertyName::PHOTO,
+                PropertyName::ROLE,
+                PropertyName::TEL,
+                PropertyName::TITLE,
+                PropertyName::UID,
+                PropertyName::URL,
+                "X-AB*",
+                "X-SOCIALPROFILE",
+            ],
+            Preset::GoogleImport => &[
+                PropertyName::ADR,
+                PropertyName::ANNIVERSARY,
+                PropertyName::BDAY,
+                PropertyName::CATEGORIES,
+                PropertyName::EMAIL,
+                PropertyName::FN,
+                PropertyName::GENDER,
+                PropertyName::IMPP,
+                PropertyName::N,
+                PropertyName::NICKNAME,
+                PropertyName::NOTE,
+                PropertyName::ORG,
+                PropertyName::PHOTO,
+                PropertyName::RELATED,
+                PropertyName::ROLE,
+                PropertyName::TEL,
+                PropertyName::TITLE,
+                PropertyName::UID,
+                PropertyName::URL,
+                "X-GOOGLE-*",
+            ],
+            Preset::Minimal => &[
+                PropertyName::ADR,
+                PropertyName::EMAIL,
+                PropertyName::FN,
+                PropertyName::N,
+                PropertyName::ORG,
+                PropertyName::TEL,
+                PropertyName::TITLE,
+                PropertyName::UID,
+            ],
+        }
+    }
+
+    /// Get the maximum size in bytes of embedded photos kept by the preset, photos referenced by an external uri are kept.
+    pub fn max_photo_size(&self) -> usize {
+        match self {
+            Preset::IosImport => 256 * 1024,
+            Preset::GoogleImport => 2 * 1024 * 1024,
+            Preset::Minimal => 0,
+        }
+    }
+
+    /// Get the vCard version preferred by the target, the vCard is only [sanitized](crate::vcard::Vcard::sanitize) to it
+    /// if it is a [supported version](VcardVersion).
+    pub fn version(&self) -> &'static str {
+        match self {
+            Preset::IosImport => VcardVersion::V3,
+            Preset::GoogleImport => VcardVersion::V3,
+            Preset::Minimal => VcardVersion::V4,
+        }
+    }
+
+    /// Get the options for exporting vCards sanitized with the preset, restricted to the kept properties and with folded lines.
+    pub fn export_options(&self) -> ExportOptions {
+        ExportOptions {
+            include_properties: Some(self.properties().iter().map(|name| name.to_string()).collect()),
+            fold_lines: true,
+            ..ExportOptions::default()
+        }
+    }
+
+    /// Check if the preset keeps a property.
+    pub fn keeps(&self, property: &Property) -> bool {
+        if !self.properties().iter().any(|pattern| matches_name(pattern, property.name())) {
+            return false;
+        }
+
+        if property.name() == PropertyName::PHOTO {
+            if let Value::ValueUri(uri) = property.get_value() {
+                if let Some((_, bytes)) = uri.decode_data() {
+                    return bytes.len() <= self.max_photo_size();
+                }
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sanitize::Preset;
+    use crate::vcard::Vcard;
+    use crate::HasName;
+
+    #[test]
+    pub fn sanitize_presets() {
+        let photo = format!("data:image/png;base64,{}", "A".repeat(400 * 1024));
+        let text = format!("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nN:Doe;John;;;\nGENDER:M\nPHOTO:{}\nPHOTO:https://example.com/a.png\nitem1.X-ABLABEL:Work\nX-GOOGLE-TALK:john\nNOTE:{}\nEND:VCARD\n", photo, "A".repeat(100));
+        let vcard = Vcard::try_from(text.as_str()).unwrap();
+
+        let mut ios = vcard.clone();
+        let removed = ios.sanitize(Preset::IosImport).unwrap();
+        assert_eq!(
+            removed.iter().map(|p| p.name().to_string()).collect::<Vec<String>>(),
+            [
+                "GENDER",
+                "PHOTO",
+                "X-GOOGLE-TALK"
+            ]
+        );
+
+        let mut google = vcard.clone();
+        let removed = google.sanitize(Preset::GoogleImport).unwrap();
+        assert_eq!(removed.iter().map(|p| p.name().to_string()).collect::<Vec<String>>(), ["X-ABLABEL"]);
+
+        let export = google.export_with(&Preset::GoogleImport.export_options());
+        assert!(export.lines().all(|line| line.len() <= 75));
+        assert_eq!(Vcard::try_from(export.as_str()).unwrap().export(), google.export());
+
+        let mut minimal = vcard.clone();
+        minimal.sanitize(Preset::Minimal).unwrap();
+        assert_eq!(Preset::IosImport.version(), "3.0");
+        assert_eq!(ios.version(), "4.0");
+        assert_eq!(minimal.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nN:Doe;John;;;\nEND:VCARD\n");
+    }
+}
@@ -0,0 +1,306 @@
+//! Detection of social network profiles, e.g. for showing profile links with the icon of their service.
+//!
+//! Profiles are read from URL, SOCIALPROFILE and X-SOCIALPROFILE properties. The service is taken from the TYPE or
+//! X-SERVICE-TYPE parameter written by Apple and Google exports, or detected from the URL, and the handle from the
+//! X-USER parameter or the URL. Twitter/X, LinkedIn, GitHub and Mastodon profiles are recognized, see
+//! [`Vcard::social_profiles`].
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::social::{SocialProfile, SocialService};
+//! use vcard_parser::vcard::Vcard;
+//!
+//! let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nURL:https://github.com/johndoe\nX-SOCIALPROFILE;TYPE=twitter;X-USER=jdoe:https://twitter.com/jdoe\nURL:https://example.com\nEND:VCARD\n";
+//! let mut vcard = Vcard::try_from(input).expect("Unable to parse vCard.");
+//!
+//! let profiles: Vec<(SocialService, String)> = vcard.social_profiles().into_iter().map(|profile| (profile.service, profile.handle)).collect();
+//! assert_eq!(profiles, [(SocialService::GitHub, String::from("johndoe")), (SocialService::Twitter, String::from("jdoe"))]);
+//!
+//! let profile = SocialProfile::new(SocialService::Mastodon, "@john@mastodon.social").expect("Unable to create profile.");
+//! let property = profile.to_property().expect("Unable to create property.");
+//! assert_eq!(property.export(), "X-SOCIALPROFILE;TYPE=mastodon;X-USER=john@mastodon.social:https://mastodon.social/@john\n");
+//! ```
+
+use crate::constants::{ParameterName, PropertyName};
+use crate::parse::encoding::escape;
+use crate::vcard::parameter::Parameter;
+use crate::vcard::value::Value;
+use crate::vcard::Vcard;
+use crate::{HasName, HasParameters, HasValue, Property, VcardError};
+
+/// The property name used by Apple and Google exports for social profiles.
+pub const X_SOCIALPROFILE: &str = "X-SOCIALPROFILE";
+
+/// The property name for social profiles, see [RFC 9554 3.3](https://datatracker.ietf.org/doc/html/rfc9554#section-3.3).
+pub const SOCIALPROFILE: &str = "SOCIALPROFILE";
+
+/// The parameter written by Apple exports for the handle of a social profile.
+pub const X_USER: &str = "X-USER";
+
+/// The parameter written by Apple exports for the service of an instant messaging or social profile.
+pub const X_SERVICE_TYPE: &str = "X-SERVICE-TYPE";
+
+/// The social networks recognized by [detect_profile].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SocialService {
+    /// Twitter, including x.com URLs.
+    Twitter,
+    /// LinkedIn member profiles.
+    LinkedIn,
+    /// GitHub user and organization profiles.
+    GitHub,
+    /// Mastodon and other fediverse servers using "/@user" profile URLs, handles include the server, e.g. "@john@mastodon.social".
+    Mastodon,
+}
+
+impl SocialService {
+    /// Get the lowercase name written in TYPE parameters, e.g. "twitter".
+    pub fn name(&self) -> &'static str {
+        match self {
+            SocialService::Twitter => "twitter",
+            SocialService::LinkedIn => "linkedin",
+            SocialService::GitHub => "github",
+            SocialService::Mastodon => "mastodon",
+        }
+    }
+
+    /// Get a service from a TYPE or X-SERVICE-TYPE parameter value, ignoring case.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::social::SocialService;
+    ///
+    /// assert_eq!(SocialService::from_name("Twitter"), Some(SocialService::Twitter));
+    /// assert_eq!(SocialService::from_name("x"), Some(SocialService::Twitter));
+    /// assert_eq!(SocialService::from_name("myspace"), None);
+    /// ```
+    pub fn from_name(str: &str) -> Option<Self> {
+        match str.trim().to_lowercase().as_str() {
+            "twitter" | "x" => Some(SocialService::Twitter),
+            "linkedin" => Some(SocialService::LinkedIn),
+            "github" => Some(SocialService::GitHub),
+            "mastodon" => Some(SocialService::Mastodon),
+            _ => None,
+        }
+    }
+}
+
+/// A social network profile, see the [social](crate::social) module.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SocialProfile {
+    /// The social network.
+    pub service: SocialService,
+    /// The handle without a leading "@", except for Mastodon handles which are written as "@user@server".
+    pub handle: String,
+    /// The profile URL.
+    pub url: String,
+}
+
+impl SocialProfile {
+    /// Create a profile from a handle, building the profile URL of the service.
+    ///
+    /// A leading "@" is removed from handles, Mastodon handles must include the server, e.g. "john@mastodon.social".
+    pub fn new(service: SocialService, handle: &str) -> Result<Self, VcardError> {
+        let invalid = || VcardError::ValueInvalid(handle.to_string(), X_SOCIALPROFILE.to_string());
+
+        let trimmed = handle.trim().trim_start_matches('@');
+        if trimmed.is_empty() || trimmed.contains(['/', '?', '#']) || trimmed.contains(char::is_whitespace) {
+            return Err(invalid());
+        }
+
+        let (handle, url) = match service {
+            SocialService::Twitter => (trimmed.to_string(), format!("https://twitter.com/{}", trimmed)),
+            SocialService::LinkedIn => (trimmed.to_string(), format!("https://www.linkedin.com/in/{}", trimmed)),
+            SocialService::GitHub => (trimmed.to_string(), format!("https://github.com/{}", trimmed)),
+            SocialService::Mastodon => {
+                let (user, server) = trimmed.split_once('@').filter(|(user, server)| !user.is_empty() && server.contains('.')).ok_or_else(invalid)?;
+                (format!("@{}@{}", user, server.to_lowercase()), format!("https://{}/@{}", server.to_lowercase(), user))
+            }
+        };
+
+        Ok(Self { service, handle, url })
+    }
+
+    /// Create an X-SOCIALPROFILE property with TYPE and X-USER parameters, as written by Apple and Google exports.
+    pub fn to_property(&self) -> Result<Property, VcardError> {
+        let parameters = Vec::from([
+            Parameter::try_from((ParameterName::TYPE, self.service.name()))?,
+            Parameter::try_from((X_USER, self.handle.trim_start_matches('@')))?,
+        ]);
+        Property::create((None, X_SOCIALPROFILE, parameters, escape(self.url.as_str()).as_str()))
+    }
+}
+
+/// Well-known Mastodon servers, other servers are only trusted when a property declares the Mastodon service.
+pub const MASTODON_SERVERS: [&str; 8] = [
+    "fosstodon.org",
+    "hachyderm.io",
+    "infosec.exchange",
+    "mas.to",
+    "mastodon.online",
+    "mastodon.social",
+    "mstdn.social",
+    "techhub.social",
+];
+
+/// Detect the service and handle of a profile URL, returning None for URLs of other sites and non-profile pages.
+///
+/// Mastodon profiles are only detected on [well-known servers](MASTODON_SERVERS) and servers named "mastodon." or
+/// "mstdn.", as other sites such as YouTube use the same "/@name" paths.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::social::{detect_profile, SocialService};
+///
+/// assert_eq!(detect_profile("https://x.com/jdoe"), Some((SocialService::Twitter, String::from("jdoe"))));
+/// assert_eq!(detect_profile("https://www.linkedin.com/in/john-doe/"), Some((SocialService::LinkedIn, String::from("john-doe"))));
+/// assert_eq!(detect_profile("https://fosstodon.org/@john"), Some((SocialService::Mastodon, String::from("@john@fosstodon.org"))));
+/// assert_eq!(detect_profile("https://www.youtube.com/@john"), None);
+/// assert_eq!(detect_profile("https://github.com/johndoe/project"), None);
+/// assert_eq!(detect_profile("https://example.com/john"), None);
+/// ```
+pub fn detect_profile(url: &str) -> Option<(SocialService, String)> {
+    let (host, segments) = split_url(url)?;
+
+    match (host.as_str(), segments.as_slice()) {
+        ("twitter.com" | "x.com", [handle]) if !matches!(handle.to_lowercase().as_str(), "home" | "i" | "intent" | "search" | "share" | "explore") => Some((SocialService::Twitter, handle.trim_start_matches('@').to_string())),
+        ("linkedin.com", ["in", handle]) => Some((SocialService::LinkedIn, handle.to_string())),
+        ("github.com", [handle]) if !matches!(handle.to_lowercase().as_str(), "orgs" | "settings" | "explore" | "topics") => Some((SocialService::GitHub, handle.to_string())),
+        (server, _) if MASTODON_SERVERS.contains(&server) || server.starts_with("mastodon.") || server.starts_with("mstdn.") => mastodon_handle(url).map(|handle| (SocialService::Mastodon, handle)),
+        _ => None,
+    }
+}
+
+/// Helper function getting the lowercase host, without "www." and "mobile.", and the path segments of an http(s) URL.
+fn split_url(url: &str) -> Option<(String, Vec<&str>)> {
+    let (scheme, rest) = url.trim().split_once("://")?;
+    if !scheme.eq_ignore_ascii_case("http") && !scheme.eq_ignore_ascii_case("https") {
+        return None;
+    }
+
+    let rest = rest.split(['?', '#']).next().unwrap_or_default();
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+    let host = host.to_lowercase();
+    let host = host.trim_start_matches("www.").trim_start_matches("mobile.").to_string();
+    Some((host, path.split('/').filter(|segment| !segment.is_empty()).collect()))
+}
+
+/// Helper function getting the "@name@server" handle of a "https://server/@name" URL on any server.
+fn mastodon_handle(url: &str) -> Option<String> {
+    let (server, segments) = split_url(url)?;
+    match segments.as_slice() {
+        [handle] if handle.len() > 1 && handle.starts_with('@') && server.contains('.') => Some(format!("{}@{}", handle, server)),
+        _ => None,
+    }
+}
+
+/// Get the social profiles of a vCard, in property order, see [`Vcard::social_profiles`].
+pub fn social_profiles(vcard: &Vcard) -> Vec<SocialProfile> {
+    vcard.get_properties().iter().filter_map(social_profile).collect()
+}
+
+/// Helper function reading a social profile from a URL, SOCIALPROFILE or X-SOCIALPROFILE property.
+fn social_profile(property: &Property) -> Option<SocialProfile> {
+    let name = property.name();
+    let social = name.eq_ignore_ascii_case(X_SOCIALPROFILE) || name.eq_ignore_ascii_case(SOCIALPROFILE);
+    if !social && name != PropertyName::URL {
+        return None;
+    }
+
+    let url = match property.get_value() {
+        Value::ValueText(text) => text.value.clone(),
+        Value::ValueUri(uri) => uri.value.clone(),
+        _ => return None,
+    };
+    let detected = detect_profile(url.as_str());
+
+    if !social {
+        return detected.map(|(service, handle)| SocialProfile { service, handle, url });
+    }
+
+    let mut service = None;
+    let mut user = None;
+    for parameter in property.get_parameters() {
+        match (&parameter, parameter.get_value()) {
+            (Parameter::ParameterType(_), Value::ValueTextList(list)) => service = service.or_else(|| list.value.iter().find_map(|value| SocialService::from_name(value))),
+            (Parameter::ParameterXName(_), Value::ValueText(text)) if parameter.name().eq_ignore_ascii_case(X_SERVICE_TYPE) => service = service.or_else(|| SocialService::from_name(text.value.as_str())),
+            (Parameter::ParameterXName(_), Value::ValueText(text)) if parameter.name().eq_ignore_ascii_case(X_USER) && !text.value.is_empty() => user = Some(text.value.clone()),
+            _ => {}
+        }
+    }
+
+    let service = service.or(detected.as_ref().map(|(service, _)| *service))?;
+    let handle = match (user, detected) {
+        (Some(user), _) if service == SocialService::Mastodon && !user.starts_with('@') => format!("@{}", user),
+        (Some(user), _) => user,
+        (None, Some((detected, handle))) if detected == service => handle,
+        (None, _) if service == SocialService::Mastodon => mastodon_handle(url.as_str())?,
+        _ => return None,
+    };
+
+    Some(SocialProfile { service, handle, url })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::social::{detect_profile, SocialProfile, SocialService};
+    use crate::vcard::Vcard;
+    use crate::HasName;
+
+    #[test]
+    pub fn social_detect() {
+        assert_eq!(detect_profile("HTTP://Mobile.Twitter.com/@jdoe?lang=en"), Some((SocialService::Twitter, String::from("jdoe"))));
+        assert_eq!(detect_profile("https://github.com/johndoe#readme"), Some((SocialService::GitHub, String::from("johndoe"))));
+        assert_eq!(detect_profile("https://twitter.com/home"), None);
+        assert_eq!(detect_profile("https://linkedin.com/company/example"), None);
+        assert_eq!(detect_profile("https://mastodon.example/@john"), Some((SocialService::Mastodon, String::from("@john@mastodon.example"))));
+        assert_eq!(detect_profile("https://mastodon.social/@john/109"), None);
+        assert_eq!(detect_profile("https://localhost/@john"), None);
+        for url in [
+            "https://youtube.com/@john",
+            "https://medium.com/@john",
+            "https://www.tiktok.com/@john",
+            "https://example.com/@john",
+        ] {
+            assert_eq!(detect_profile(url), None);
+        }
+        assert_eq!(detect_profile("ftp://github.com/johndoe"), None);
+        assert_eq!(detect_profile("not a url"), None);
+    }
+
+    #[test]
+    pub fn social_profiles() {
+        let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nitem1.X-SOCIALPROFILE;type=linkedin:x-apple:johndoe\nX-SOCIALPROFILE;TYPE=twitter;X-USER=jdoe:x-apple:jdoe\nX-SOCIALPROFILE;X-SERVICE-TYPE=Mastodon;X-USER=john@fosstodon.org:https://fosstodon.org/@john\nSOCIALPROFILE:https://x.com/johnd\nX-SOCIALPROFILE;TYPE=github:https://twitter.com/jdoe\nX-SOCIALPROFILE;TYPE=facebook:https://facebook.com/jdoe\nURL:https://example.com/@\nURL:https://youtube.com/@john\nX-SOCIALPROFILE;TYPE=mastodon:https://social.example/@jane\nEND:VCARD\n";
+        let vcard = Vcard::try_from(input).unwrap();
+
+        let profiles: Vec<(SocialService, String)> = vcard.social_profiles().into_iter().map(|profile| (profile.service, profile.handle)).collect();
+        assert_eq!(
+            profiles,
+            [
+                (SocialService::Twitter, String::from("jdoe")),
+                (SocialService::Mastodon, String::from("@john@fosstodon.org")),
+                (SocialService::Twitter, String::from("johnd")),
+                (SocialService::Mastodon, String::from("@jane@social.example")),
+            ]
+        );
+
+        for (service, handle) in [
+            (SocialService::Twitter, "@jdoe"),
+            (SocialService::LinkedIn, "john-doe"),
+            (SocialService::GitHub, "johndoe"),
+            (SocialService::Mastodon, "john@Fosstodon.org"),
+        ] {
+            let profile = SocialProfile::new(service, handle).unwrap();
+            let property = profile.to_property().unwrap();
+            assert_eq!(property.name(), "X-SOCIALPROFILE");
+
+            let mut vcard = Vcard::new("John Doe");
+            vcard.set_property(&property).unwrap();
+            assert_eq!(vcard.social_profiles(), [profile]);
+        }
+
+        assert!(SocialProfile::new(SocialService::Mastodon, "john").is_err());
+        assert!(SocialProfile::new(SocialService::GitHub, "@").is_err());
+        assert!(SocialProfile::new(SocialService::Twitter, "john doe").is_err());
+    }
+}
@@ -0,0 +1,96 @@
+//! Size and property statistics of a vCard, e.g. for enforcing storage quotas, see [`Vcard::stats`].
+//!
+//! Sizes are in bytes of the vCard as written by its [Display](std::fmt::Display) implementation, including PID
+//! parameters and CLIENTPIDMAP properties.
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::traits::HasName;
+//! use vcard_parser::vcard::Vcard;
+//!
+//! let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL:john@example.com\nNOTE:Call after 5pm\nX-SKYPE:johndoe\nEND:VCARD\n").expect("Unable to parse vCard.");
+//!
+//! let stats = vcard.stats();
+//! assert_eq!(stats.count("email"), 1);
+//! assert_eq!(stats.size, vcard.to_string().len());
+//! assert_eq!(stats.x_properties, 1);
+//! assert!(matches!(&stats.largest, Some((property, _)) if property.name() == "EMAIL"));
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::vcard::Vcard;
+use crate::{HasName, Property};
+
+/// Statistics of a single vCard, see [`Vcard::stats`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VcardStats {
+    /// Number of properties keyed by uppercase property name.
+    pub counts: BTreeMap<String, usize>,
+    /// Size of the written vCard.
+    pub size: usize,
+    /// The largest property and its written size, the first one written if several have the same size.
+    pub largest: Option<(Property, usize)>,
+    /// Number of extended properties, whose name begins with "X-".
+    pub x_properties: usize,
+}
+
+impl VcardStats {
+    /// Get the number of properties with a name, the name is compared case-insensitively.
+    pub fn count(&self, name: &str) -> usize {
+        self.counts.get(name.to_uppercase().as_str()).copied().unwrap_or_default()
+    }
+}
+
+impl From<&Vcard> for VcardStats {
+    fn from(vcard: &Vcard) -> Self {
+        let mut stats = VcardStats {
+            size: vcard.to_string().len(),
+            ..VcardStats::default()
+        };
+
+        for property in vcard.get_properties() {
+            let name = property.name().to_uppercase();
+            if name.starts_with("X-") {
+                stats.x_properties += 1;
+            }
+            *stats.counts.entry(name).or_default() += 1;
+
+            let size = property.to_string().len();
+            if stats.largest.as_ref().map_or(true, |(_, largest)| size > *largest) {
+                stats.largest = Some((property, size));
+            }
+        }
+
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stats::VcardStats;
+    use crate::vcard::Vcard;
+    use crate::HasName;
+
+    #[test]
+    pub fn stats_vcard() {
+        let photo = format!("PHOTO:data:image/png;base64,{}\n", "A".repeat(4096));
+        let text = format!("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nTEL:555-5556\n{}x-custom:1\nX-OTHER:2\nEND:VCARD\n", photo);
+        let vcard = Vcard::try_from(("urn:uuid:some-uuid", text.as_str())).unwrap();
+
+        let stats = vcard.stats();
+        assert_eq!(stats.count("TEL"), 2);
+        assert_eq!(stats.count("tel"), 2);
+        assert_eq!(stats.count("X-CUSTOM"), 1);
+        assert_eq!(stats.count("EMAIL"), 0);
+        assert_eq!(stats.counts.values().sum::<usize>(), vcard.get_properties().len());
+        assert_eq!(stats.x_properties, 2);
+        assert_eq!(stats.size, vcard.to_string().len());
+        assert!(stats.size > text.len());
+        assert!(matches!(&stats.largest, Some((property, size)) if property.name() == "PHOTO" && *size == property.to_string().len() && *size > photo.len()));
+
+        let vcard = Vcard::new("");
+        assert_eq!(VcardStats::from(&vcard).x_properties, 0);
+        assert!(VcardStats::from(&vcard).largest.is_some());
+    }
+}
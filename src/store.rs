@@ -0,0 +1,192 @@
+//! A thread-safe collection of vCards for server use.
+//!
+//! [`VcardStore`] wraps its vCards in an `Arc<RwLock<...>>`, clones of a store share the same vCards. The vCard,
+//! property, parameter and value types are all `Send + Sync`, including observers and normalizers attached via
+//! [options](crate::options) and [events](crate::events), which are required to be `Send + Sync` themselves.
+//!
+//! # Examples
+//! ```
+//! use std::thread;
+//! use vcard_parser::store::VcardStore;
+//!
+//! let store = VcardStore::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nUID:urn:uuid:1\nEND:VCARD\n").expect("Unable to parse text.");
+//!
+//! let shared = store.clone();
+//! thread::spawn(move || shared.extend_from_str("BEGIN:VCARD\nVERSION:4.0\nFN:Jane Doe\nUID:urn:uuid:2\nEND:VCARD\n").unwrap()).join().unwrap();
+//!
+//! assert_eq!(store.len(), 2);
+//! assert!(store.get_by_uid("urn:uuid:2").is_some());
+//! ```
+
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::constants::PropertyName;
+use crate::vcard::value::Value;
+use crate::vcard::Vcard;
+use crate::{parse_vcards, HasValue, VcardError};
+
+#[derive(Clone, Debug, Default)]
+pub struct VcardStore {
+    vcards: Arc<RwLock<Vec<Vcard>>>,
+}
+
+impl VcardStore {
+    /// Parse vCards and add them to the store, replacing vCards with matching UIDs. Returns the number of parsed vCards.
+    pub fn extend_from_str(&self, input: &str) -> Result<usize, VcardError> {
+        let vcards = parse_vcards(input)?;
+        let count = vcards.len();
+
+        for vcard in vcards {
+            self.insert(vcard);
+        }
+
+        Ok(count)
+    }
+
+    /// Export all vCards without any clientpidmap or pid information.
+    pub fn export(&self) -> String {
+        self.read().iter().map(|vcard| vcard.export()).collect()
+    }
+
+    /// Get a cloned copy of the vCard with a matching UID.
+    pub fn get_by_uid(&self, uid: &str) -> Option<Vcard> {
+        self.read().iter().find(|vcard| get_uid(vcard).as_deref() == Some(uid)).cloned()
+    }
+
+    /// Get a cloned copy of all vCards.
+    pub fn get_vcards(&self) -> Vec<Vcard> {
+        self.read().clone()
+    }
+
+    /// Add a vCard, replacing an existing vCard with the same UID. vCards without a UID are always added.
+    pub fn insert(&self, vcard: Vcard) {
+        let mut vcards = self.write();
+
+        if let Some(uid) = get_uid(&vcard) {
+            if let Some(existing) = vcards.iter_mut().find(|v| get_uid(v).as_deref() == Some(uid.as_str())) {
+                *existing = vcard;
+                return;
+            }
+        }
+
+        vcards.push(vcard);
+    }
+
+    /// Check if the store contains any vCards.
+    pub fn is_empty(&self) -> bool {
+        self.read().is_empty()
+    }
+
+    /// Get the number of vCards in the store.
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    /// Remove and return the vCard with a matching UID.
+    pub fn remove_by_uid(&self, uid: &str) -> Option<Vcard> {
+        let mut vcards = self.write();
+        let index = vcards.iter().position(|vcard| get_uid(vcard).as_deref() == Some(uid))?;
+        Some(vcards.remove(index))
+    }
+
+    /// Update the vCard with a matching UID in place, returning the closure result or None if there was no match.
+    pub fn update_by_uid<F, R>(&self, uid: &str, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut Vcard) -> R,
+    {
+        self.write().iter_mut().find(|vcard| get_uid(vcard).as_deref() == Some(uid)).map(f)
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, Vec<Vcard>> {
+        self.vcards.read().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Vec<Vcard>> {
+        self.vcards.write().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl TryFrom<&str> for VcardStore {
+    type Error = VcardError;
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        let store = Self::default();
+        store.extend_from_str(str)?;
+        Ok(store)
+    }
+}
+
+impl From<Vec<Vcard>> for VcardStore {
+    fn from(vcards: Vec<Vcard>) -> Self {
+        let store = Self::default();
+        for vcard in vcards {
+            store.insert(vcard);
+        }
+        store
+    }
+}
+
+/// Helper function returning the UID of a vCard as a string.
+fn get_uid(vcard: &Vcard) -> Option<String> {
+    vcard.get_property_by_name(PropertyName::UID).map(|uid| match uid.get_value() {
+        Value::ValueText(text) => text.value.clone(),
+        value => value.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::events::VcardEvent;
+    use crate::options::ParserOptions;
+    use crate::store::VcardStore;
+    use crate::vcard::parameter::Parameter;
+    use crate::vcard::property::Property;
+    use crate::vcard::value::Value;
+    use crate::vcard::Vcard;
+    use crate::VcardError;
+
+    #[test]
+    fn store_send_sync() {
+        fn _assert<T: Send + Sync>() {}
+
+        _assert::<Vcard>();
+        _assert::<Property>();
+        _assert::<Parameter>();
+        _assert::<Value>();
+        _assert::<VcardError>();
+        _assert::<VcardEvent>();
+        _assert::<ParserOptions>();
+        _assert::<VcardStore>();
+    }
+
+    #[test]
+    fn store_operations() {
+        let store = VcardStore::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nUID:urn:uuid:1\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:No Uid\nEND:VCARD\n").unwrap();
+        assert_eq!(store.len(), 2);
+
+        let handles: Vec<_> = (2..6)
+            .map(|i| {
+                let store = store.clone();
+                thread::spawn(move || store.extend_from_str(format!("BEGIN:VCARD\nVERSION:4.0\nFN:Contact {}\nUID:urn:uuid:{}\nEND:VCARD\n", i, i).as_str()).unwrap())
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(store.len(), 6);
+
+        store.extend_from_str("BEGIN:VCARD\nVERSION:4.0\nFN:Johnny Doe\nUID:urn:uuid:1\nEND:VCARD\n").unwrap();
+        assert_eq!(store.len(), 6);
+        assert_eq!(store.get_by_uid("urn:uuid:1").unwrap().display_name(), "Johnny Doe");
+
+        let updated = store.update_by_uid("urn:uuid:2", |vcard| vcard.set_property(&Property::try_from("NICKNAME:Two\n").unwrap()).is_ok());
+        assert_eq!(updated, Some(true));
+        assert!(store.export().contains("NICKNAME:Two\n"));
+        assert_eq!(store.update_by_uid("urn:uuid:missing", |_| ()), None);
+
+        assert!(store.remove_by_uid("urn:uuid:3").is_some());
+        assert!(store.remove_by_uid("urn:uuid:3").is_none());
+        assert_eq!(store.get_vcards().len(), 5);
+    }
+}
@@ -0,0 +1,190 @@
+//! Asynchronous streaming parsing of vCards, enabled with the `stream` feature.
+//!
+//! [`parse_vcards_stream`] parses vCards from an [AsyncBufRead] as bytes arrive, e.g. the body of a CardDAV upload,
+//! feeding them to a [push parser](crate::parse::push) like the [reader](crate::reader) module, so only the current
+//! vCard is buffered. The returned [VcardStream] implements the futures [Stream] trait and works with any executor,
+//! tokio readers are adapted with the `compat` module of tokio-util.
+//!
+//! # Examples
+//! ```
+//! use std::future::{poll_fn, Future};
+//! use std::pin::{pin, Pin};
+//! use std::sync::Arc;
+//! use std::task::{Context, Poll, Wake, Waker};
+//! use futures_core::Stream;
+//! use vcard_parser::stream::parse_vcards_stream;
+//!
+//! struct Noop;
+//! impl Wake for Noop {
+//!     fn wake(self: Arc<Self>) {}
+//! }
+//!
+//! let input = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nEND:VCARD\n";
+//! let mut names = pin!(async {
+//!     let mut names = Vec::new();
+//!     let mut stream = parse_vcards_stream(input.as_bytes());
+//!     while let Some(vcard) = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await {
+//!         names.push(vcard.expect("Unable to parse vCard.").display_name());
+//!     }
+//!     names
+//! });
+//!
+//! // Byte slices are always ready, so a single poll completes the future.
+//! let waker = Waker::from(Arc::new(Noop));
+//! let Poll::Ready(names) = names.as_mut().poll(&mut Context::from_waker(&waker)) else { panic!("Expected names.") };
+//! assert_eq!(names, Vec::from([String::from("John Doe"), String::from("Jane Roe")]));
+//! ```
+
+use std::io::ErrorKind;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use futures_io::AsyncBufRead;
+
+use crate::options::ParserOptions;
+use crate::reader::VcardAssembler;
+use crate::vcard::Vcard;
+use crate::VcardError;
+
+/// A stream of vCards parsed from an [AsyncBufRead], created with [parse_vcards_stream].
+///
+/// Each item is the next vCard, or the error for an invalid vCard or for lines outside of a vCard, like the items of a
+/// [VcardReader](crate::reader::VcardReader). I/O errors end the stream.
+#[derive(Debug)]
+pub struct VcardStream<R> {
+    reader: Option<R>,
+    vcards: VcardAssembler,
+}
+
+impl<R: AsyncBufRead + Unpin> VcardStream<R> {
+    /// Create a stream keeping [ParserOptions](ParserOptions) on each vCard.
+    pub fn with_options(reader: R, options: ParserOptions) -> Self {
+        Self {
+            reader: Some(reader),
+            vcards: VcardAssembler::new(options),
+        }
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> Stream for VcardStream<R> {
+    type Item = Result<Vcard, VcardError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let stream = self.get_mut();
+
+        loop {
+            if let Some(vcard) = stream.vcards.pop() {
+                return Poll::Ready(Some(vcard));
+            }
+
+            let Some(reader) = stream.reader.as_mut() else {
+                return Poll::Ready(None);
+            };
+            let length = match Pin::new(&mut *reader).poll_fill_buf(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Ok(bytes)) => {
+                    stream.vcards.feed(bytes);
+                    bytes.len()
+                }
+                Poll::Ready(Err(err)) if err.kind() == ErrorKind::Interrupted => continue,
+                Poll::Ready(Err(err)) => {
+                    stream.reader = None;
+                    return Poll::Ready(Some(Err(VcardError::from(err.to_string()))));
+                }
+            };
+
+            if length == 0 {
+                stream.reader = None;
+                stream.vcards.finish();
+            } else {
+                Pin::new(reader).consume(length);
+            }
+        }
+    }
+}
+
+/// Parse vCards from an [AsyncBufRead] as bytes arrive, using the default [ParserOptions](ParserOptions), see the
+/// [module](self) documentation.
+pub fn parse_vcards_stream<R: AsyncBufRead + Unpin>(reader: R) -> VcardStream<R> {
+    VcardStream::with_options(reader, ParserOptions::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::{poll_fn, Future};
+    use std::pin::{pin, Pin};
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    use futures_core::Stream;
+    use futures_io::{AsyncBufRead, AsyncRead};
+
+    use crate::constants::VcardParseError;
+    use crate::stream::parse_vcards_stream;
+
+    struct Noop;
+
+    impl Wake for Noop {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    /// Reader returning one byte per fill, pending before each byte.
+    struct Trickle<'a> {
+        bytes: &'a [u8],
+        pending: bool,
+    }
+
+    impl AsyncRead for Trickle<'_> {
+        fn poll_read(self: Pin<&mut Self>, _: &mut Context<'_>, _: &mut [u8]) -> Poll<std::io::Result<usize>> {
+            unreachable!("Only read through AsyncBufRead.")
+        }
+    }
+
+    impl AsyncBufRead for Trickle<'_> {
+        fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<&[u8]>> {
+            let trickle = self.get_mut();
+            trickle.pending = !trickle.pending;
+            if trickle.pending {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            Poll::Ready(Ok(&trickle.bytes[..trickle.bytes.len().min(1)]))
+        }
+
+        fn consume(mut self: Pin<&mut Self>, amount: usize) {
+            self.bytes = &self.bytes[amount..];
+        }
+    }
+
+    #[test]
+    fn stream_vcards() {
+        let input = b"\r\nBEGIN:VCARD\r\nVERSION:4.0\r\nFN:John D\r\n oe\r\nEND:VCARD\r\nFN:stray\r\nBEGIN:VCARD\r\nFN:Missing version\r\nEND:VCARD\r\nBEGIN:VCARD\r\nVERSION:4.0\r\nFN:Jane Roe\r\n";
+
+        let mut future = pin!(async {
+            let mut results = Vec::new();
+            let mut stream = parse_vcards_stream(Trickle { bytes: input, pending: false });
+            while let Some(result) = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await {
+                results.push(result);
+            }
+            results
+        });
+
+        let waker = Waker::from(Arc::new(Noop));
+        let mut cx = Context::from_waker(&waker);
+        let mut polls = 1;
+        let results = loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(results) => break results,
+                Poll::Pending => polls += 1,
+            }
+        };
+
+        assert!(polls > input.len());
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap().display_name(), "John Doe");
+        assert_eq!(results[1].as_ref().unwrap_err().parse_error(), VcardParseError::PROPERTY_BEGIN_MISSING);
+        assert_eq!(results[2].as_ref().unwrap_err().parse_error(), VcardParseError::PROPERTY_VERSION_MISSING);
+        assert_eq!(results[3].as_ref().unwrap_err().parse_error(), VcardParseError::PROPERTY_END_MISSING);
+    }
+}
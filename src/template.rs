@@ -0,0 +1,199 @@
+//! Templates filling placeholder values of a vCard skeleton, e.g. for generating employee cards from HR data.
+//!
+//! Placeholders are written as `{{name}}` in property values, and are replaced with escaped text. A property whose
+//! value is empty after filling is left out, so optional fields can be filled with an empty string. Filled vCards
+//! without a UID get a [generated](crate::vcard::property::property_uid::PropertyUidData::generate) one.
+//!
+//! # Examples
+//! ```
+//! use std::collections::HashMap;
+//! use vcard_parser::template::VcardTemplate;
+//!
+//! let template = VcardTemplate::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:{{first}} {{last}}\nEMAIL;TYPE=work:{{email}}\nTEL;TYPE=work:{{phone}}\nORG:Example Inc.\nEND:VCARD\n").expect("Unable to parse template.");
+//! assert_eq!(template.placeholders(), ["email", "first", "last", "phone"]);
+//!
+//! let values = HashMap::from([("first", "John"), ("last", "Doe"), ("email", "john@example.com"), ("phone", "")]);
+//! let vcard = template.fill(&values).expect("Unable to fill template.");
+//! assert_eq!(vcard.display_name(), "John Doe");
+//! assert!(vcard.get_property_by_name("TEL").is_none());
+//! assert!(vcard.get_property_by_name("UID").is_some());
+//! ```
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::constants::PropertyName;
+use crate::parse::encoding::escape;
+use crate::vcard::property::property_uid::PropertyUidData;
+use crate::vcard::Vcard;
+use crate::{Property, VcardError};
+
+/// A vCard skeleton with placeholder values, see the [template](crate::template) module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VcardTemplate {
+    text: String,
+}
+
+impl VcardTemplate {
+    /// Get the names of the placeholders in the template, in alphabetical order.
+    pub fn placeholders(&self) -> Vec<String> {
+        let mut names = BTreeSet::new();
+
+        for line in self.text.lines() {
+            let mut rest = &line[value_index(line)..];
+            while let Some((name, after)) = next_placeholder(rest) {
+                names.insert(name.to_string());
+                rest = after;
+            }
+        }
+
+        names.into_iter().collect()
+    }
+
+    /// Fill the placeholders of the template, returning an error if a value is missing or the filled vCard is invalid.
+    pub fn fill<K: AsRef<str>, V: AsRef<str>>(&self, values: &HashMap<K, V>) -> Result<Vcard, VcardError> {
+        let values: HashMap<&str, &str> = values.iter().map(|(key, value)| (key.as_ref(), value.as_ref())).collect();
+        let mut text = String::with_capacity(self.text.len());
+
+        for line in self.text.lines() {
+            let index = value_index(line);
+            let (head, mut rest) = line.split_at(index);
+            if next_placeholder(rest).is_none() {
+                text.push_str(line);
+                text.push('\n');
+                continue;
+            }
+
+            let mut value = String::new();
+            while let Some(start) = rest.find("{{") {
+                let Some((name, after)) = next_placeholder(&rest[start..]) else {
+                    break;
+                };
+                let Some(filled) = values.get(name) else {
+                    return Err(VcardError::TemplateFieldMissing(name.to_string()));
+                };
+                value.push_str(&rest[..start]);
+                value.push_str(escape(filled).as_str());
+                rest = after;
+            }
+            value.push_str(rest);
+
+            if value.trim_start_matches(':').chars().all(|c| c == ';' || c.is_whitespace()) {
+                continue;
+            }
+
+            text.push_str(head);
+            text.push_str(value.as_str());
+            text.push('\n');
+        }
+
+        let mut vcard = Vcard::try_from(text.as_str())?;
+        if vcard.get_property_by_name(PropertyName::UID).is_none() {
+            vcard.set_property(&Property::PropertyUid(PropertyUidData::generate()))?;
+        }
+
+        Ok(vcard)
+    }
+
+    /// Fill the template once for every set of values, e.g. one per row of a spreadsheet.
+    pub fn fill_all<K: AsRef<str>, V: AsRef<str>>(&self, rows: &[HashMap<K, V>]) -> Result<Vec<Vcard>, VcardError> {
+        rows.iter().map(|values| self.fill(values)).collect()
+    }
+}
+
+impl TryFrom<&str> for VcardTemplate {
+    type Error = VcardError;
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        let text = str.replace("\r\n", "\n");
+        if !text.trim_start().to_uppercase().starts_with("BEGIN:VCARD") || !text.trim_end().to_uppercase().ends_with("END:VCARD") {
+            return Err(VcardError::ValueMalformed(str.to_string()));
+        }
+
+        Ok(Self { text })
+    }
+}
+
+impl From<&Vcard> for VcardTemplate {
+    fn from(vcard: &Vcard) -> Self {
+        Self { text: vcard.export() }
+    }
+}
+
+/// Helper function finding the index of the value separator of a content line, skipping quoted parameter values.
+fn value_index(line: &str) -> usize {
+    let mut quoted = false;
+    for (index, char) in line.char_indices() {
+        match char {
+            '"' => quoted = !quoted,
+            ':' if !quoted => return index,
+            _ => {}
+        }
+    }
+    line.len()
+}
+
+/// Helper function finding the next placeholder, returning its trimmed name and the text after it.
+fn next_placeholder(str: &str) -> Option<(&str, &str)> {
+    let start = str.find("{{")?;
+    let end = str[start..].find("}}")? + start;
+    Some((str[start + 2..end].trim(), &str[end + 2..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::template::VcardTemplate;
+    use crate::vcard::Vcard;
+    use crate::VcardError;
+
+    #[test]
+    pub fn template_fill() {
+        let template = VcardTemplate::try_from("BEGIN:VCARD\r\nVERSION:4.0\r\nFN:{{ name }}\r\nN:{{last}};{{first}};;;\r\nNOTE;LANGUAGE=en:Desk {{desk}}, floor {{floor}}\r\nBDAY:{{birthday}}\r\nUID:urn:uuid:{{id}}\r\nEND:VCARD\r\n").unwrap();
+        assert_eq!(template.placeholders(), ["birthday", "desk", "first", "floor", "id", "last", "name"]);
+
+        let rows = Vec::from([
+            HashMap::from([
+                ("name", "John Doe"),
+                ("first", "John"),
+                ("last", "Doe"),
+                ("desk", "4;B"),
+                ("floor", "2"),
+                ("birthday", "1970-01-01"),
+                ("id", "1"),
+            ]),
+            HashMap::from([
+                ("name", "Jane Roe"),
+                ("first", ""),
+                ("last", ""),
+                ("desk", "1"),
+                ("floor", "3"),
+                ("birthday", ""),
+                ("id", "2"),
+            ]),
+        ]);
+        let vcards = template.fill_all(&rows).unwrap();
+        assert_eq!(vcards[0].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nN:Doe;John;;;\nNOTE;LANGUAGE=en:Desk 4\\;B\\, floor 2\nBDAY:1970-01-01\nUID:urn:uuid:1\nEND:VCARD\n");
+        assert_eq!(vcards[1].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nNOTE;LANGUAGE=en:Desk 1\\, floor 3\nUID:urn:uuid:2\nEND:VCARD\n");
+
+        assert_eq!(template.fill(&HashMap::from([("name", "John Doe")])).unwrap_err(), VcardError::TemplateFieldMissing(String::from("last")));
+        assert_eq!(
+            template
+                .fill(&HashMap::from([
+                    ("name", ""),
+                    ("first", ""),
+                    ("last", ""),
+                    ("desk", ""),
+                    ("floor", ""),
+                    ("birthday", ""),
+                    ("id", "1")
+                ]))
+                .unwrap_err(),
+            VcardError::PropertyFnMissing
+        );
+
+        let skeleton = Vcard::new("{{name}}");
+        let vcard = VcardTemplate::from(&skeleton).fill(&HashMap::from([("name", "Jane Roe")])).unwrap();
+        assert_eq!(vcard.display_name(), "Jane Roe");
+        assert!(VcardTemplate::try_from("FN:{{name}}\n").is_err());
+    }
+}
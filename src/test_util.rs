@@ -0,0 +1,91 @@
+//! Helpers for testing code that reads and writes vCards, e.g. round trips through another format.
+//!
+//! Enabled with the `test-util` feature. vCards are compared by their [canonical form](crate::fingerprint::canonical),
+//! which leaves out PID parameters and the CLIENTPIDMAP, PRODID and REV properties, and ignores property order and
+//! how repeated parameters are written.
+//!
+//! # Examples
+//! ```
+//! use vcard_parser::test_util::{assert_vcard_semantic_eq, semantic_diff};
+//! use vcard_parser::vcard::Vcard;
+//!
+//! let a = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;PID=1.1:555-5555\nNOTE:Hello\nEND:VCARD\n").expect("Unable to parse vCard.");
+//! let b = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nNOTE:Hello\nFN:John Doe\nTEL:555-5555\nEND:VCARD\n").expect("Unable to parse vCard.");
+//! assert_vcard_semantic_eq(&a, &b);
+//!
+//! let c = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-1234\nNOTE:Hello\nEND:VCARD\n").expect("Unable to parse vCard.");
+//! assert_eq!(semantic_diff(&a, &c), ["- TEL:555-5555", "+ TEL:555-1234"]);
+//! ```
+
+use crate::fingerprint::canonical;
+use crate::vcard::Vcard;
+
+/// Get the content lines of the canonical forms that differ between two vCards, lines only in the first vCard are
+/// prefixed with "- " and lines only in the second vCard with "+ ".
+pub fn semantic_diff(a: &Vcard, b: &Vcard) -> Vec<String> {
+    let (a, b) = (canonical(a), canonical(b));
+    let mut removed: Vec<&str> = a.lines().collect();
+    let mut added = Vec::new();
+
+    for line in b.lines() {
+        match removed.iter().position(|other| *other == line) {
+            Some(index) => {
+                removed.remove(index);
+            }
+            None => added.push(line),
+        }
+    }
+
+    removed.into_iter().map(|line| format!("- {}", line)).chain(added.into_iter().map(|line| format!("+ {}", line))).collect()
+}
+
+/// Assert that two vCards are semantically equal, panicking with the [differing lines](semantic_diff) if they aren't.
+#[track_caller]
+pub fn assert_vcard_semantic_eq(a: &Vcard, b: &Vcard) {
+    let diff = semantic_diff(a, b);
+    if !diff.is_empty() {
+        panic!("vCards are not semantically equal:\n{}", diff.join("\n"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::{assert_vcard_semantic_eq, semantic_diff};
+    use crate::vcard::Vcard;
+
+    #[test]
+    pub fn test_util_semantic_diff() {
+        let a = Vcard::try_from(("urn:uuid:client", "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;TYPE=home;TYPE=voice:555-5555\nTEL:555-5555\nREV:20240101T000000Z\nEND:VCARD\n")).unwrap();
+        let b = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\ntel;TYPE=home,voice:555-5555\nFN:John Doe\nPRODID:-//Example//EN\nTEL:555-5555\nEND:VCARD\n").unwrap();
+        assert!(semantic_diff(&a, &b).is_empty());
+        assert_vcard_semantic_eq(&a, &b);
+
+        let d = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;PREF=1;TYPE=VOICE,Home:555-5555\nTEL:555-5555\nEND:VCARD\n").unwrap();
+        let e = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;TYPE=home;TYPE=voice;PREF=1:555-5555\nTEL:555-5555\nEND:VCARD\n").unwrap();
+        assert!(semantic_diff(&d, &e).is_empty());
+
+        let c = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;TYPE=home,voice:555-5555\nEMAIL:john@example.com\nEND:VCARD\n").unwrap();
+        assert_eq!(
+            semantic_diff(&a, &c),
+            [
+                "- TEL:555-5555",
+                "+ EMAIL:john@example.com"
+            ]
+        );
+        assert_eq!(
+            semantic_diff(&c, &a),
+            [
+                "- EMAIL:john@example.com",
+                "+ TEL:555-5555"
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "vCards are not semantically equal:\n- NOTE:Hello\n+ NOTE:Goodbye")]
+    pub fn test_util_assert_semantic_eq() {
+        let a = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nNOTE:Hello\nEND:VCARD\n").unwrap();
+        let b = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nNOTE:Goodbye\nEND:VCARD\n").unwrap();
+        assert_vcard_semantic_eq(&a, &b);
+    }
+}
@@ -1,6 +1,7 @@
 //! Utility traits.
 
 use crate::constants::{Cardinality, ParameterName};
+use crate::vcard::parameter::parameter_mediatype::MediaType;
 use crate::vcard::parameter::Parameter;
 use crate::vcard::value::Value;
 use crate::VcardError;
@@ -33,11 +34,13 @@ pub trait HasParameters: HasName {
         }
         Ok(())
     }
+    /// Add a parameter if the property allows it. The error of a disallowed parameter has no group, callers knowing
+    /// the group of the property add it.
     fn add_parameter(&mut self, parameter: Parameter) -> Result<(), VcardError> {
         let mut parameters = self.get_parameters();
 
         if !self.allowed_parameters().contains(&parameter.name()) && !matches!(parameter, Parameter::ParameterXName(_)) && !self.allowed_parameters().contains(&ParameterName::ANY) {
-            return Err(VcardError::ParameterTypeNotAllowed(parameter.name().to_string(), self.name().to_string()));
+            return Err(VcardError::ParameterTypeNotAllowed(parameter.to_string().trim_start_matches(';').to_string(), self.name().to_string(), None));
         }
 
         parameters.push(parameter);
@@ -53,8 +56,17 @@ pub trait HasParameters: HasName {
 
         Ok(())
     }
+    fn has_mediatype(&self) -> Option<String> {
+        self.get_parameters().into_iter().find(|p| p.name() == ParameterName::MEDIATYPE).map(|parameter| parameter.get_value().to_string())
+    }
+    fn media_type(&self) -> Option<MediaType> {
+        self.get_parameters().into_iter().find_map(|parameter| match parameter {
+            Parameter::ParameterMediaType(data) => data.media_type(),
+            _ => None,
+        })
+    }
     fn has_value_type(&mut self) -> Option<String> {
-        self.get_parameters().iter().cloned().find(|p| p.name() == ParameterName::VALUE).map(|parameter| parameter.get_value().to_string())
+        self.get_parameters().into_iter().find(|p| p.name() == ParameterName::VALUE).map(|parameter| parameter.get_value().to_string())
     }
 }
 
@@ -28,14 +28,40 @@
 //! let mut vcard = Vcard::try_from(("urn:uuid:some-uuid", text)).expect("Unable to parse input.");
 //! ```
 
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
+use std::sync::Arc;
 
-use crate::constants::{ParameterName, PropertyName};
-use crate::parse::VcardData;
+use time::OffsetDateTime;
+
+use crate::collection::ContactPoint;
+use crate::constants::{ParameterName, PropertyName, VcardVersion};
+use crate::events::{VcardEvent, VcardObserver};
+use crate::media::{externalize_property, inline_property, MediaResolver, MediaStore};
+use crate::options::{ExportOptions, ExportProfile, ParserOptions, ProdIdPolicy};
+use crate::parse::encoding::fold_line;
+use crate::parse::value::utf8_to_str;
+use crate::parse::{PropertyData, ValueFoldedData, VcardData};
+use crate::redact::{hash_value, RedactionAction, RedactionPolicy};
+use crate::sanitize::Preset;
+use crate::social::SocialProfile;
+use crate::stats::VcardStats;
+use crate::traits::HasGroup;
+use crate::vcard::parameter::parameter_pid::ParameterPidData;
 use crate::vcard::parameter::Parameter;
 use crate::vcard::property::property_fn::PropertyFnData;
+use crate::vcard::property::property_n::{NameParts, PropertyNData};
+use crate::vcard::property::property_prodid::PropertyProdIdData;
+use crate::vcard::property::property_raw::PropertyRawData;
+use crate::vcard::property::property_uid::PropertyUidData;
+use crate::vcard::property::PropertyKind;
 use crate::vcard::value::value_clientpidmap::ValueClientPidMapData;
-use crate::vcard::value::Value::ValueClientPidMap;
+use crate::vcard::value::value_component::Component;
+use crate::vcard::value::value_geo::{distance_meters, ValueGeoData};
+use crate::vcard::value::value_pid::{Pid, ValuePidData};
+use crate::vcard::value::value_text::ValueTextData;
+use crate::vcard::value::Value;
+use crate::vcard::value::Value::{ValueClientPidMap, ValueListComponent, ValuePid, ValueText, ValueTextList, ValueTimestamp, ValueUri};
 use crate::Property::PropertyFn;
 use crate::{parse, HasCardinality, HasName, HasParameters, HasValue, Property, VcardError};
 
@@ -43,10 +69,26 @@ pub mod parameter;
 pub mod property;
 pub mod value;
 
+/// The keys vCards can be ordered by, see [cmp_by](Vcard::cmp_by).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SortBy {
+    /// The [display name](Vcard::display_name), compared case-insensitively.
+    DisplayName,
+    /// The [sort key](Vcard::sort_key), ties are ordered by display name.
+    SortKey,
+    /// The [REV timestamp](Vcard::rev), vCards without a REV property are ordered first.
+    Rev,
+}
+
 #[derive(Clone, Debug)]
 pub struct Vcard {
     client: Option<String>,
+    observers: Vec<Arc<dyn VcardObserver>>,
+    options: ParserOptions,
     properties: Vec<Property>,
+    unparsed: Vec<String>,
+    version: String,
+    warnings: Vec<String>,
 }
 
 impl Vcard {
@@ -62,9 +104,63 @@ impl Vcard {
     pub fn new(str: &str) -> Self {
         Vcard {
             client: None,
+            observers: Vec::new(),
+            options: ParserOptions::default(),
             properties: Vec::from([PropertyFn(
                 PropertyFnData::from(str),
             )]),
+            unparsed: Vec::new(),
+            version: VcardVersion::V4.to_string(),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Create a new vCard from the FN and UID properties.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let vcard = Vcard::new_with_uid("John Doe", "urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6");
+    /// assert_eq!(vcard.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nUID:urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6\nEND:VCARD\n");
+    /// ```
+    pub fn new_with_uid(str: &str, uid: &str) -> Self {
+        let mut vcard = Self::new(str);
+        vcard.properties.push(Property::PropertyUid(PropertyUidData::from(uid)));
+        vcard
+    }
+
+    /// Create a new vCard for an individual from the components of their name.
+    ///
+    /// The FN property is composed from the [name parts](NameParts::formatted), and a UID is [generated](PropertyUidData::generate).
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::property_n::NameParts;
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let vcard = Vcard::new_individual(NameParts { given: "John", family: "Doe", ..NameParts::default() });
+    /// assert_eq!(vcard.display_name(), "John Doe");
+    /// assert_eq!(vcard.get_property_by_name("N").expect("Missing N property.").export(), "N:Doe;John;;;\n");
+    /// assert!(vcard.get_property_by_name("UID").is_some());
+    /// ```
+    pub fn new_individual(parts: NameParts) -> Self {
+        let mut fullname = PropertyFnData::default();
+        // Setting a text value without a VALUE parameter can't fail.
+        let _ = fullname.set_value(Value::from(ValueTextData { value: parts.formatted() }));
+
+        Vcard {
+            client: None,
+            observers: Vec::new(),
+            options: ParserOptions::default(),
+            properties: Vec::from([
+                PropertyFn(fullname),
+                Property::PropertyN(PropertyNData::from(parts)),
+                Property::PropertyUid(PropertyUidData::generate()),
+            ]),
+            unparsed: Vec::new(),
+            version: VcardVersion::V4.to_string(),
+            warnings: Vec::new(),
         }
     }
 
@@ -80,14 +176,74 @@ impl Vcard {
     /// assert_eq!(vcard.export(), text);
     /// ```
     pub fn export(&self) -> String {
+        self.export_with(&ExportOptions::default())
+    }
+
+    /// Export the vCard, choosing which metadata and properties to write with [ExportOptions](ExportOptions).
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::options::ExportOptions;
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nNOTE:Private\nREV:20000101T000000Z\nEND:VCARD\n";
+    /// let vcard = Vcard::try_from(("urn:uuid:some-uuid", text)).expect("Unable to parse vCard.");
+    ///
+    /// let options = ExportOptions { include_pids: true, include_rev: false, exclude_properties: Vec::from([String::from("NOTE")]), ..ExportOptions::default() };
+    /// assert_eq!(vcard.export_with(&options), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;PID=1.1:555-5555\nEND:VCARD\n");
+    /// assert_eq!(vcard.export_with(&ExportOptions::all()), vcard.to_string());
+    /// ```
+    pub fn export_with(&self, options: &ExportOptions) -> String {
+        self.export_filtered_with(options, |_| true)
+    }
+
+    /// Export the vCard like [export](Vcard::export), only writing properties matching a predicate.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::constants::PropertyName;
+    /// use vcard_parser::vcard::Vcard;
+    /// use vcard_parser::traits::HasName;
+    ///
+    /// let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nBDAY:2000-01-01\nNOTE:Private\nX-SECRET:Private\nEND:VCARD\n";
+    /// let vcard = Vcard::try_from(text).expect("Unable to parse vCard.");
+    ///
+    /// let export = vcard.export_filtered(|p| p.name() != PropertyName::NOTE && p.name() != PropertyName::BDAY && !p.name().starts_with("X-"));
+    /// assert_eq!(export, "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEND:VCARD\n");
+    /// assert_eq!(vcard.export(), text);
+    /// ```
+    pub fn export_filtered<F: Fn(&Property) -> bool>(&self, predicate: F) -> String {
+        self.export_filtered_with(&ExportOptions::default(), predicate)
+    }
+
+    /// Export the vCard like [export_with](Vcard::export_with), only writing properties matching a predicate.
+    pub fn export_filtered_with<F: Fn(&Property) -> bool>(&self, options: &ExportOptions, predicate: F) -> String {
         let mut string = String::new();
 
         string.push_str("BEGIN:VCARD\n");
-        string.push_str("VERSION:4.0\n");
+        string.push_str("VERSION:");
+        string.push_str(self.version.as_str());
+        string.push('\n');
 
-        for property in self.get_properties().iter() {
-            if property.name() != PropertyName::CLIENTPIDMAP {
-                string.push_str(&property.export())
+        if let ProdIdPolicy::Stamp(prodid) = &options.prodid {
+            // Writing to a string can't fail.
+            let _ = Property::PropertyProdId(PropertyProdIdData::from(prodid.as_str())).write_to(&mut string, false, options.parameter_style);
+        }
+
+        let mut properties: Vec<&Property> = self.properties.iter().filter(|p| options.allows_property(p.name()) && (options.include_empty || p.name() == PropertyName::FN || !p.get_value().is_empty()) && predicate(p)).collect();
+        if options.sort_properties {
+            properties.sort();
+        }
+
+        for property in properties {
+            // Writing to a string can't fail.
+            if options.fold_lines {
+                let mut line = String::new();
+                let _ = property.write_to(&mut line, options.include_pids, options.parameter_style);
+                string.push_str(fold_line(line.trim_end_matches('\n')).replace("\r\n", "\n").as_str());
+                string.push('\n');
+            } else {
+                let _ = property.write_to(&mut string, options.include_pids, options.parameter_style);
             }
         }
 
@@ -96,6 +252,653 @@ impl Vcard {
         string
     }
 
+    /// Export the vCard for importing into an application with its own quirks, see [ExportProfile](ExportProfile).
+    pub fn export_profile(&self, profile: ExportProfile) -> String {
+        let spec = profile.spec();
+        let vcard = Self {
+            observers: Vec::new(),
+            properties: self.properties.iter().filter_map(|property| spec.adjust(property)).collect(),
+            ..self.clone()
+        };
+        vcard.export_with(&spec.options())
+    }
+
+    /// Get the VERSION of the vCard, see [RFC 6350 6.7.9](https://datatracker.ietf.org/doc/html/rfc6350#section-6.7.9).
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// assert_eq!(vcard.version(), "4.0");
+    /// ```
+    pub fn version(&self) -> &str {
+        self.version.as_str()
+    }
+
+    /// Set the VERSION written when exporting the vCard, only [supported versions](VcardVersion) are allowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let mut vcard = Vcard::new("John Doe");
+    /// assert!(vcard.set_version("4.0").is_ok());
+    /// assert!(vcard.set_version("2.1").is_err());
+    /// ```
+    pub fn set_version(&mut self, version: &str) -> Result<(), VcardError> {
+        if !VcardVersion::TYPES.contains(&version) {
+            return Err(VcardError::ValueInvalid(version.to_string(), PropertyName::VERSION.to_string()));
+        }
+
+        self.version = version.to_string();
+
+        Ok(())
+    }
+
+    /// Get the content lines that were skipped when parsing with [lenient](ParserOptions::lenient) options.
+    ///
+    /// Lines that fail to parse or validate are unfolded but otherwise kept as written, in the order they appear in the
+    /// input, and are not included when exporting the vCard.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::options::ParserOptions;
+    /// use vcard_parser::parse_vcards_with_options;
+    ///
+    /// let options = ParserOptions { lenient: true, ..ParserOptions::default() };
+    /// let vcards = parse_vcards_with_options("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nGEO:not a uri\nEND:VCARD\n", &options).expect("Unable to parse text.");
+    /// assert_eq!(vcards[0].unparsed_lines(), &[String::from("GEO:not a uri")]);
+    /// assert_eq!(vcards[0].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEND:VCARD\n");
+    /// ```
+    pub fn unparsed_lines(&self) -> &[String] {
+        self.unparsed.as_slice()
+    }
+
+    /// Get the warnings of parsing, e.g. for properties left out because their [empty value](crate::vcard::value::EmptyValue)
+    /// is treated as unset, or parameters dropped with [lenient](ParserOptions::lenient) options because the property
+    /// doesn't allow them.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::options::ParserOptions;
+    /// use vcard_parser::parse_vcards_with_options;
+    ///
+    /// let options = ParserOptions { lenient: true, ..ParserOptions::default() };
+    /// let vcards = parse_vcards_with_options("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nitem1.HOBBY;TZ=UTC:reading\nEND:VCARD\n", &options).expect("Unable to parse text.");
+    /// assert_eq!(vcards[0].warnings(), &[String::from("Parameter TZ=UTC is not allowed for item1.HOBBY.")]);
+    /// assert_eq!(vcards[0].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nitem1.HOBBY:reading\nEND:VCARD\n");
+    /// ```
+    pub fn warnings(&self) -> &[String] {
+        self.warnings.as_slice()
+    }
+
+    /// Helper function creating a vCard from parsed content lines, keeping the lines skipped by the
+    /// [lenient parser](crate::parse::vcard::vcard_lenient) before the content line at their position.
+    pub(crate) fn from_data(client: Option<String>, options: ParserOptions, data: VcardData, skipped: Vec<(usize, ValueFoldedData)>) -> Result<Self, VcardError> {
+        let mut vcard = Self::with_client(client, options)?;
+        let mut skipped = skipped.into_iter().peekable();
+
+        for (i, datum) in data.into_iter().enumerate() {
+            while let Some((_, line)) = skipped.next_if(|(position, _)| *position <= i) {
+                vcard.unparsed.push(folded_line(line));
+            }
+
+            if !vcard.options.lenient {
+                match Property::create_from_data(datum) {
+                    Ok(property) => vcard.set_parsed_property(&property, String::new)?,
+                    Err(err @ VcardError::ValueEmpty(_)) => vcard.warnings.push(err.to_string()),
+                    Err(err) => return Err(err),
+                }
+                continue;
+            }
+
+            match Property::create_from_data_with(datum.clone(), true) {
+                Ok((property, dropped)) => {
+                    for parameter in dropped {
+                        let parameter = parameter.to_string().trim_start_matches(';').to_string();
+                        vcard.warnings.push(VcardError::ParameterTypeNotAllowed(parameter, property.name().to_string(), property.group().clone()).to_string());
+                    }
+                    vcard.set_parsed_property(&property, || content_line(datum))?;
+                }
+                Err(err @ VcardError::ValueEmpty(_)) => vcard.warnings.push(err.to_string()),
+                // Replace invalid UTF-8 and parse the content line again.
+                Err(VcardError::ConversionFailure) => {
+                    let line = content_line(datum);
+                    match Property::try_from(format!("{}\n", line).as_str()) {
+                        Ok(property) => {
+                            vcard.warnings.push(format!("Invalid UTF-8 in {} was replaced.", property.name()));
+                            vcard.set_parsed_property(&property, || line.clone())?;
+                        }
+                        Err(_) => vcard.unparsed.push(line),
+                    }
+                }
+                Err(_) => vcard.unparsed.push(content_line(datum)),
+            }
+        }
+        vcard.unparsed.extend(skipped.map(|(_, line)| folded_line(line)));
+
+        vcard.require_fn()
+    }
+
+    /// Helper function creating an empty vCard, with a CLIENTPIDMAP if a client is given.
+    fn with_client(client: Option<String>, options: ParserOptions) -> Result<Self, VcardError> {
+        let mut vcard = Self {
+            client,
+            observers: Vec::new(),
+            options,
+            properties: Vec::new(),
+            unparsed: Vec::new(),
+            version: VcardVersion::V4.to_string(),
+            warnings: Vec::new(),
+        };
+
+        if let Some(client) = &vcard.client {
+            vcard.set_property(&Property::create_from_str(format!("CLIENTPIDMAP:1;{}\n", client).as_str())?)?;
+        }
+
+        Ok(vcard)
+    }
+
+    /// Helper function setting a parsed property, keeping its content line as unparsed if it fails with lenient options.
+    fn set_parsed_property<F: FnOnce() -> String>(&mut self, property: &Property, line: F) -> Result<(), VcardError> {
+        match self.set_property(property) {
+            Ok(_) => Ok(()),
+            Err(_) if self.options.lenient => {
+                self.unparsed.push(line());
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Helper function returning the vCard if it has an FN property.
+    fn require_fn(self) -> Result<Self, VcardError> {
+        if self.get_property_by_name(PropertyName::FN).is_none() {
+            return Err(VcardError::PropertyFnMissing);
+        }
+        Ok(self)
+    }
+
+    /// Get the options used to validate properties set on the vCard.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let vcard = Vcard::new("John Doe");
+    /// assert!(!vcard.get_options().strict);
+    /// ```
+    pub fn get_options(&self) -> &ParserOptions {
+        &self.options
+    }
+
+    /// Set the options used to validate properties set on the vCard. Existing properties are not revalidated.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::options::ParserOptions;
+    /// use vcard_parser::vcard::property::Property;
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let mut vcard = Vcard::new("John Doe");
+    /// vcard.set_options(ParserOptions { strict: true, ..ParserOptions::default() });
+    /// assert!(vcard.set_property(&Property::try_from("LANG:zz\n").unwrap()).is_err());
+    /// ```
+    pub fn set_options(&mut self, options: ParserOptions) {
+        self.options = options;
+    }
+
+    /// Compose a display name for the vCard, using the first non-empty value in this order:
+    ///
+    /// 1. FN text.
+    /// 2. N components, formatted as "prefixes given additional family suffixes".
+    /// 3. The organization name, the first ORG component.
+    /// 4. The first EMAIL address.
+    /// 5. The first TEL number.
+    ///
+    /// An empty string is returned if none of these are set.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:\nN:Doe;John;Quinlan;Dr.;Jr.\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// assert_eq!(vcard.display_name(), "Dr. John Quinlan Doe Jr.");
+    ///
+    /// let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:\nEMAIL:john@example.com\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// assert_eq!(vcard.display_name(), "john@example.com");
+    /// ```
+    pub fn display_name(&self) -> String {
+        let mut fallbacks = [
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+        ];
+
+        for property in self.properties.iter() {
+            let (i, candidate) = match (property, property.get_value()) {
+                (PropertyFn(_), ValueText(text)) => (0, text.value.clone()),
+                (Property::PropertyN(_), ValueListComponent(list)) => {
+                    let order = [3, 1, 2, 0, 4];
+                    (1, order.iter().filter_map(|i| list.value.get(*i)).flatten().filter(|s| !s.is_empty()).map(Component::as_str).collect::<Vec<&str>>().join(" "))
+                }
+                (Property::PropertyOrg(_), ValueTextList(list)) => (2, list.value.first().cloned().unwrap_or_default()),
+                (Property::PropertyEmail(email), _) => (3, email.address()),
+                (Property::PropertyTel(_), ValueText(text)) => (4, text.value.clone()),
+                (Property::PropertyTel(_), ValueUri(uri)) => (4, uri.value.trim_start_matches("tel:").to_string()),
+                _ => continue,
+            };
+            if fallbacks[i].is_empty() {
+                fallbacks[i] = candidate.trim().to_string();
+            }
+        }
+
+        fallbacks.into_iter().find(|s| !s.is_empty()).unwrap_or_default()
+    }
+
+    /// Generate a key for sorting vCards, see [RFC 6350 5.9](https://datatracker.ietf.org/doc/html/rfc6350#section-5.9).
+    ///
+    /// The SORT-AS parameter of N is preferred, followed by the N family and given names, the SORT-AS parameter of ORG,
+    /// the first ORG component, and finally the [display name](Vcard::display_name). The key is lowercased so sorting
+    /// is case-insensitive, locale specific collation is left to the caller.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let a = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:Rene van der Harten\nN;SORT-AS=\"Harten,Rene\":van der Harten;Rene;;;\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// let b = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:Jane Doe\nN:Doe;Jane;;;\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// assert_eq!(a.sort_key(), "harten rene");
+    /// assert_eq!(b.sort_key(), "doe jane");
+    /// assert!(b.sort_key() < a.sort_key());
+    /// ```
+    pub fn sort_key(&self) -> String {
+        fn sort_as(property: &Property) -> Option<String> {
+            property.get_parameters().into_iter().find_map(|parameter| match parameter {
+                Parameter::ParameterSortAs(data) => Some(data.components().iter().map(|s| s.trim()).filter(|s| !s.is_empty()).collect::<Vec<&str>>().join(" ")),
+                _ => None,
+            })
+        }
+
+        let mut key = String::new();
+
+        if let Some(n) = self.get_property_by_name(PropertyName::N) {
+            key = sort_as(&n).unwrap_or_default();
+            if key.is_empty() {
+                if let ValueListComponent(list) = n.get_value() {
+                    key = list.value.iter().take(2).flatten().filter(|s| !s.is_empty()).map(Component::as_str).collect::<Vec<&str>>().join(" ");
+                }
+            }
+        }
+
+        if key.is_empty() {
+            if let Some(org) = self.get_properties_by_name(PropertyName::ORG).first() {
+                key = sort_as(org).unwrap_or_default();
+                if key.is_empty() {
+                    if let ValueTextList(list) = org.get_value() {
+                        key = list.value.first().cloned().unwrap_or_default();
+                    }
+                }
+            }
+        }
+
+        if key.trim().is_empty() {
+            key = self.display_name();
+        }
+
+        key.trim().to_lowercase()
+    }
+
+    /// Get the REV timestamp of the vCard, see [RFC 6350 6.7.4](https://datatracker.ietf.org/doc/html/rfc6350#section-6.7.4).
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nREV:19951031T222710Z\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// assert_eq!(vcard.rev().map(|rev| rev.year()), Some(1995));
+    /// assert!(Vcard::new("John Doe").rev().is_none());
+    /// ```
+    pub fn rev(&self) -> Option<OffsetDateTime> {
+        self.properties.iter().find(|p| p.name() == PropertyName::REV).and_then(|property| match property.get_value() {
+            ValueTimestamp(data) => Some(data.value),
+            _ => None,
+        })
+    }
+
+    /// Check if the vCard was revised after another vCard, for resolving conflicts where the latest REV wins.
+    ///
+    /// A vCard with a REV property is newer than a vCard without one, and neither of two vCards without REV properties
+    /// is newer than the other, so the other vCard is kept when the revisions can't be compared.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let old = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nREV:20000101T000000Z\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// let new = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:Johnny Doe\nREV:20100101T000000Z\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// assert!(new.newer_than(&old));
+    /// assert!(!old.newer_than(&new));
+    /// assert!(old.newer_than(&Vcard::new("John Doe")));
+    /// ```
+    pub fn newer_than(&self, other: &Vcard) -> bool {
+        match (self.rev(), other.rev()) {
+            (Some(a), Some(b)) => a > b,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+
+    /// Compare the vCard with another vCard by a [sort key](SortBy), for ordering collections.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::{SortBy, Vcard};
+    ///
+    /// let mut vcards = Vec::from([
+    ///     Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nN:Doe;John;;;\nREV:20100101T000000Z\nEND:VCARD\n").expect("Unable to parse vCard."),
+    ///     Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:Alice Roe\nN:Roe;Alice;;;\nEND:VCARD\n").expect("Unable to parse vCard."),
+    ///     Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:bob Smith\nN:Smith;Bob;;;\nREV:20000101T000000Z\nEND:VCARD\n").expect("Unable to parse vCard."),
+    /// ]);
+    ///
+    /// vcards.sort_by(|a, b| a.cmp_by(b, SortBy::DisplayName));
+    /// assert_eq!(vcards.iter().map(Vcard::display_name).collect::<Vec<String>>(), ["Alice Roe", "bob Smith", "John Doe"]);
+    ///
+    /// vcards.sort_by(|a, b| a.cmp_by(b, SortBy::SortKey));
+    /// assert_eq!(vcards.iter().map(Vcard::display_name).collect::<Vec<String>>(), ["John Doe", "Alice Roe", "bob Smith"]);
+    ///
+    /// vcards.sort_by(|a, b| a.cmp_by(b, SortBy::Rev));
+    /// assert_eq!(vcards.iter().map(Vcard::display_name).collect::<Vec<String>>(), ["Alice Roe", "bob Smith", "John Doe"]);
+    /// ```
+    pub fn cmp_by(&self, other: &Vcard, by: SortBy) -> Ordering {
+        let display_name = |a: &Vcard, b: &Vcard| {
+            let (a, b) = (a.display_name(), b.display_name());
+            a.to_lowercase().cmp(&b.to_lowercase()).then_with(|| a.cmp(&b))
+        };
+
+        match by {
+            SortBy::DisplayName => display_name(self, other),
+            SortBy::SortKey => self.sort_key().cmp(&other.sort_key()).then_with(|| display_name(self, other)),
+            SortBy::Rev => self.rev().cmp(&other.rev()),
+        }
+    }
+
+    /// Get the coordinates of the vCard, from the first GEO property with a geo uri or else the first GEO parameter of
+    /// an ADR property, see [RFC 6350 6.5.2](https://datatracker.ietf.org/doc/html/rfc6350#section-6.5.2).
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::value::value_geo::ValueGeoData;
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nADR;GEO=\"geo:46.77,-71.28\":;;;Quebec;;;\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// assert_eq!(vcard.geo(), Some(ValueGeoData::from((46.77, -71.28))));
+    /// assert!(Vcard::new("John Doe").geo().is_none());
+    /// ```
+    pub fn geo(&self) -> Option<ValueGeoData> {
+        let property = self.properties.iter().find_map(|property| match property {
+            Property::PropertyGeo(data) => data.geo(),
+            _ => None,
+        });
+
+        property.or_else(|| {
+            self.get_properties_by_name(PropertyName::ADR).iter().flat_map(|adr| adr.get_parameters()).find_map(|parameter| match parameter {
+                Parameter::ParameterGeo(data) => data.geo(),
+                _ => None,
+            })
+        })
+    }
+
+    /// Find the vCard nearest to coordinates by their [geo](Vcard::geo) location, returning it with its distance in
+    /// meters, see [distance_meters](distance_meters). vCards without coordinates are skipped.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::parse_vcards;
+    /// use vcard_parser::vcard::value::value_geo::ValueGeoData;
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let input = "BEGIN:VCARD\nVERSION:4.0\nFN:Paris\nGEO:geo:48.8566,2.3522\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:London\nGEO:geo:51.5074,-0.1278\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Nowhere\nEND:VCARD\n";
+    /// let vcards = parse_vcards(input).expect("Unable to parse text.");
+    ///
+    /// let (vcard, distance) = Vcard::nearest(&vcards, &ValueGeoData::from((51.752, -1.2577))).expect("No vCard with coordinates.");
+    /// assert_eq!(vcard.display_name(), "London");
+    /// assert!(distance > 80_000.0 && distance < 90_000.0);
+    /// ```
+    pub fn nearest<'a>(vcards: &'a [Vcard], to: &ValueGeoData) -> Option<(&'a Vcard, f64)> {
+        vcards.iter().filter_map(|vcard| vcard.geo().map(|geo| (vcard, distance_meters(&geo, to)))).min_by(|(_, a), (_, b)| a.total_cmp(b))
+    }
+
+    /// Get the EMAIL, IMPP, TEL and URL properties as [contact points](ContactPoint), in property order.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::collection::ContactPoint;
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;TYPE=cell;PREF=1:555-5555\nEMAIL:john@example.com\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// let points = vcard.contact_points();
+    /// assert!(matches!(&points[0], ContactPoint::Tel(data) if data.value == "555-5555" && data.has_type("cell") && data.pref == Some(1)));
+    /// assert!(matches!(&points[1], ContactPoint::Email(data) if data.value == "john@example.com"));
+    /// ```
+    pub fn contact_points(&self) -> Vec<ContactPoint> {
+        self.properties.iter().filter_map(ContactPoint::from_property).collect()
+    }
+
+    /// Get the Twitter/X, LinkedIn, GitHub and Mastodon profiles of the URL, SOCIALPROFILE and X-SOCIALPROFILE
+    /// properties, in property order, see [social](crate::social).
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::social::SocialService;
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nURL:https://www.linkedin.com/in/john-doe\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// let profiles = vcard.social_profiles();
+    /// assert_eq!((profiles[0].service, profiles[0].handle.as_str()), (SocialService::LinkedIn, "john-doe"));
+    /// ```
+    pub fn social_profiles(&self) -> Vec<SocialProfile> {
+        crate::social::social_profiles(self)
+    }
+
+    /// Render the vCard as a MECARD for QR codes, mapping the N, TEL, EMAIL, ADR, URL and NOTE properties, see [mecard](crate::mecard).
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nN:Doe;John;;;\nTEL:555-5555\nEMAIL:john@example.com\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// assert_eq!(vcard.to_mecard(), "MECARD:N:Doe,John;TEL:555-5555;EMAIL:john@example.com;;");
+    /// ```
+    pub fn to_mecard(&self) -> String {
+        crate::mecard::to_mecard(self)
+    }
+
+    /// Parse a MECARD, ignoring unknown fields and fields with invalid values, see [mecard](crate::mecard).
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let vcard = Vcard::from_mecard("MECARD:N:Doe,John;TEL:555-5555;;").expect("Unable to parse MECARD.");
+    /// assert_eq!(vcard.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nN:Doe;John;;;\nTEL:555-5555\nEND:VCARD\n");
+    /// ```
+    pub fn from_mecard(str: &str) -> Result<Self, VcardError> {
+        crate::mecard::parse_mecard(str)
+    }
+
+    /// Write the vCard for logging, keeping groups and the names of properties and parameters but replacing every value
+    /// with its length in characters, so the structure can be logged without personal data, see [redact](crate::redact).
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nitem1.EMAIL;TYPE=work:john@example.com\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// assert_eq!(vcard.to_log_string(), "BEGIN:VCARD\nVERSION:4.0\nFN:<8>\nitem1.EMAIL;TYPE=<4>;PID=<1>:<16>\nEND:VCARD\n");
+    /// ```
+    pub fn to_log_string(&self) -> String {
+        crate::redact::log_string(self)
+    }
+
+    /// Get a redacted copy of the vCard, with each property kept, removed or hashed according to the [policy](RedactionPolicy).
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::redact::RedactionPolicy;
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// let redacted = vcard.redact(&RedactionPolicy::default()).expect("Unable to redact vCard.");
+    /// assert_eq!(redacted.get_properties().len(), 2);
+    /// assert!(!redacted.export().contains("555-5555"));
+    /// ```
+    pub fn redact(&self, policy: &RedactionPolicy) -> Result<Vcard, VcardError> {
+        let mut vcard = Self {
+            properties: Vec::new(),
+            ..self.clone()
+        };
+
+        for property in self.properties.iter() {
+            let mut property = property.clone();
+
+            let value = match policy.action(property.name()) {
+                RedactionAction::Keep => Some(property.get_value().clone()),
+                RedactionAction::Remove => None,
+                RedactionAction::Hash => hash_value(property.name(), property.get_value()),
+            };
+
+            match value {
+                Some(value) => property.set_value(value)?,
+                None if property.name() == PropertyName::FN => property.set_value(Value::from(ValueTextData::default()))?,
+                None => continue,
+            }
+
+            vcard.properties.push(property);
+        }
+
+        Ok(vcard)
+    }
+
+    /// Get a copy of the vCard without sync metadata, e.g. for sharing it or importing it under a new identity.
+    ///
+    /// The copy has no client or observers, and leaves out PID parameters and the CLIENTPIDMAP, PRODID and REV
+    /// properties.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let vcard = Vcard::try_from(("urn:uuid:client", "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nPRODID:-//Example//EN\nREV:20240101T000000Z\nEND:VCARD\n")).expect("Unable to parse vCard.");
+    /// let clean = vcard.clone_clean();
+    /// assert_eq!(clean.to_string(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nEND:VCARD\n");
+    /// ```
+    pub fn clone_clean(&self) -> Vcard {
+        let properties = self.properties.iter().filter(|property| !matches!(property.name().to_uppercase().as_str(), PropertyName::CLIENTPIDMAP | PropertyName::PRODID | PropertyName::REV)).map(without_pids).collect();
+
+        Self {
+            client: None,
+            observers: Vec::new(),
+            properties,
+            ..self.clone()
+        }
+    }
+
+    /// Replace external uris of PHOTO, LOGO and SOUND properties with data uris, fetching the media with a [resolver](MediaResolver).
+    ///
+    /// Returns the number of replaced properties. Nothing is replaced if any of the media can't be resolved or any replaced
+    /// property fails the [options](Vcard::get_options) of the vCard, see the [media](crate::media) module.
+    pub fn inline_media<R: MediaResolver + ?Sized>(&mut self, resolver: &R) -> Result<usize, VcardError> {
+        let mut replacements = Vec::new();
+        for (i, property) in self.properties.iter().enumerate() {
+            if let Some(property) = inline_property(property, resolver)? {
+                replacements.push((i, property));
+            }
+        }
+        self.replace_properties(replacements)
+    }
+
+    /// Number the INDEX parameters of the properties with a name from 1, e.g. after inserting or removing instances,
+    /// see [RFC 6715 3.1](https://datatracker.ietf.org/doc/html/rfc6715#section-3.1).
+    ///
+    /// Properties keep the order of their current INDEX, properties without an INDEX come after them in the order they
+    /// appear in the vCard. Returns the number of replaced properties.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let mut vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nHOBBY;INDEX=5:reading\nHOBBY:sailing\nHOBBY;INDEX=2:chess\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// assert_eq!(vcard.reindex_properties("HOBBY").expect("Unable to reindex properties."), 3);
+    /// assert_eq!(vcard.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nHOBBY;INDEX=2:reading\nHOBBY;INDEX=3:sailing\nHOBBY;INDEX=1:chess\nEND:VCARD\n");
+    /// ```
+    pub fn reindex_properties(&mut self, name: &str) -> Result<usize, VcardError> {
+        let mut positions: Vec<usize> = (0..self.properties.len()).filter(|i| self.properties[*i].name().eq_ignore_ascii_case(name)).collect();
+        positions.sort_by_key(|i| (self.properties[*i].index().unwrap_or(i64::MAX), *i));
+
+        let mut replacements = Vec::new();
+        for (index, i) in (1..).zip(positions) {
+            if self.properties[i].index() != Some(index) {
+                let mut property = self.properties[i].clone();
+                property.set_index(index)?;
+                replacements.push((i, property));
+            }
+        }
+
+        self.replace_properties(replacements)
+    }
+
+    /// Replace data uris of PHOTO, LOGO and SOUND properties with uris of media added to a [store](MediaStore).
+    ///
+    /// Returns the number of replaced properties. Nothing is replaced if any of the media can't be stored or any replaced
+    /// property fails the [options](Vcard::get_options) of the vCard, media stored before the failure is left in the
+    /// store, see the [media](crate::media) module.
+    pub fn externalize_media<S: MediaStore + ?Sized>(&mut self, store: &mut S) -> Result<usize, VcardError> {
+        let mut replacements = Vec::new();
+        for (i, property) in self.properties.iter().enumerate() {
+            if let Some(property) = externalize_property(property, store)? {
+                replacements.push((i, property));
+            }
+        }
+        self.replace_properties(replacements)
+    }
+
+    /// Remove the properties a [preset](Preset) doesn't keep, preparing the vCard for import by a specific application.
+    ///
+    /// Returns the removed properties, export the vCard with the [options of the preset](Preset::export_options). The
+    /// VERSION is set to the [version of the preset](Preset::version) when it is supported. See the
+    /// [sanitize](crate::sanitize) module.
+    pub fn sanitize(&mut self, preset: Preset) -> Result<Vec<Property>, VcardError> {
+        if VcardVersion::TYPES.contains(&preset.version()) {
+            self.set_version(preset.version())?;
+        }
+        self.retain(|property| preset.keeps(property))
+    }
+
+    /// Get the [statistics](VcardStats) of the vCard, with the number of properties per name, the written size and the largest property.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nTEL:555-5556\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// assert_eq!(vcard.stats().count("TEL"), 2);
+    /// ```
+    pub fn stats(&self) -> VcardStats {
+        VcardStats::from(self)
+    }
+
+    /// Get the stable fingerprint of the vCard, see the [fingerprint](crate::fingerprint) module.
+    ///
+    /// The fingerprint can be used as a weak ETag, e.g. `W/"<fingerprint>"`, as vCards with the same logical
+    /// content but a different REV, property order or PID parameters share a fingerprint.
+    pub fn fingerprint(&self) -> String {
+        crate::fingerprint::fingerprint(self)
+    }
+
     /// Get a single cloned property from the vCard.
     ///
     /// # Examples
@@ -185,40 +988,287 @@ impl Vcard {
     ///
     /// # Examples
     /// ```
-    /// use vcard_parser::vcard::property::Property;
-    /// use vcard_parser::vcard::Vcard;
-    ///
-    /// let mut vcard = Vcard::new("John Doe");
-    ///
-    /// let property = Property::try_from("NICKNAME:Johnny\n").expect("Unable to parse property string.");
-    /// let property = vcard.set_property(&property).expect("Unable to add property.");
-    /// let properties = vcard.get_properties_by_name("NICKNAME");
-    /// assert_eq!(properties.len(), 1);
+    /// use vcard_parser::vcard::property::Property;
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let mut vcard = Vcard::new("John Doe");
+    ///
+    /// let property = Property::try_from("NICKNAME:Johnny\n").expect("Unable to parse property string.");
+    /// let property = vcard.set_property(&property).expect("Unable to add property.");
+    /// let properties = vcard.get_properties_by_name("NICKNAME");
+    /// assert_eq!(properties.len(), 1);
+    ///
+    /// let property = Property::try_from("NICKNAME:Jonathon\n").expect("Unable to parse property string.");
+    /// let property = vcard.set_property(&property).expect("Unable to add property.");
+    /// let properties = vcard.get_properties_by_name("NICKNAME");
+    /// assert_eq!(properties.len(), 2);
+    /// ```
+    pub fn get_properties_by_name(&self, str: &str) -> Vec<Property> {
+        self.properties.iter().filter(|p| p.name() == str && p.is_multiple()).cloned().collect()
+    }
+
+    /// Get references to the properties of a kind from the vCard, of any cardinality.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::traits::HasValue;
+    /// use vcard_parser::vcard::property::PropertyKind;
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL:john@example.com\nEMAIL:jdoe@example.com\nX-CUSTOM:1\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// let emails = vcard.properties_of(PropertyKind::Email);
+    /// assert_eq!(emails.len(), 2);
+    /// assert_eq!(emails[0].get_value().to_string(), "john@example.com");
+    /// assert_eq!(vcard.properties_of(PropertyKind::Fn).len(), 1);
+    /// assert_eq!(vcard.properties_of(PropertyKind::XName).len(), 1);
+    /// ```
+    pub fn properties_of(&self, kind: PropertyKind) -> Vec<&Property> {
+        self.properties.iter().filter(|p| p.kind() == kind).collect()
+    }
+
+    /// Get a cloned copy of all properties from the vCard.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let mut vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// let properties = vcard.get_properties();
+    /// assert_eq!(properties.len(), 1);
+    /// ```
+    pub fn get_properties(&self) -> Vec<Property> {
+        self.properties.clone()
+    }
+
+    /// Remove a property from the vCard.
+    ///
+    /// A CLIENTPIDMAP is only removed if it has the same source identifier and client uri, and returns
+    /// [`VcardError::PropertyClientPidMapReferenced`] while PID parameters still reference its source, see
+    /// [`Vcard::remove_clientpidmap`].
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::Property;
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let mut vcard = Vcard::new("John Doe");
+    /// let property = Property::try_from("NICKNAME:Johnny\n").expect("Unable to parse property string.");
+    /// let property = vcard.set_property(&property).expect("Unable to add property.");
+    /// if vcard.remove_property(&property).expect("Unable to remove property.") {
+    ///     assert!(vcard.get_property(&property).is_none());
+    /// }
+    /// ```
+    pub fn remove_property(&mut self, property: &Property) -> Result<bool, VcardError> {
+        if property.name() == PropertyName::FN {
+            return Err(VcardError::PropertyFnRequired);
+        }
+
+        // CLIENTPIDMAP properties are removed through retain, which refuses to remove a source still referenced by PIDs.
+        if let (Some(_), Some(index)) = (clientpidmap_id(property), self.get_property_index(property)) {
+            let mut i = 0;
+            self.retain(|_| {
+                i += 1;
+                i - 1 != index
+            })?;
+            return Ok(true);
+        }
+
+        if let Some(index) = self.get_property_index(property) {
+            let before = self.properties.remove(index);
+            self.notify(VcardEvent::PropertyRemoved { before });
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+
+    /// Remove all properties with the given name from the vCard, returning the removed properties.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let mut vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nTEL:555-1234\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// let removed = vcard.remove_properties_by_name("TEL").expect("Unable to remove properties.");
+    /// assert_eq!(removed.len(), 2);
+    /// assert!(vcard.get_properties_by_name("TEL").is_empty());
+    /// assert!(vcard.remove_properties_by_name("FN").is_err());
+    /// ```
+    pub fn remove_properties_by_name(&mut self, str: &str) -> Result<Vec<Property>, VcardError> {
+        self.retain(|property| property.name() != str)
+    }
+
+    /// Remove the property with the given name and pid, e.g. "1" or "1.2", returning the removed property.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let mut vcard = Vcard::try_from(("urn:uuid:client", "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nTEL:555-1234\nEND:VCARD\n")).expect("Unable to parse vCard.");
+    /// let removed = vcard.remove_property_by_pid("TEL", "2.1").expect("Unable to remove property.");
+    /// assert_eq!(removed.expect("No property removed.").to_string(), "TEL;PID=2.1:555-1234\n");
+    /// assert_eq!(vcard.get_properties_by_name("TEL").len(), 1);
+    /// ```
+    pub fn remove_property_by_pid(&mut self, name: &str, pid: &str) -> Result<Option<Property>, VcardError> {
+        let pids = ValuePidData::try_from(pid)?;
+
+        let index = self.properties.iter().position(|property| property.name() == name && property.get_parameters().iter().any(|parameter| matches!(parameter.get_value(), ValuePid(data) if parameter.name() == ParameterName::PID && data.value.iter().any(|pid| pids.value.contains(pid)))));
+
+        match index {
+            Some(_) if name == PropertyName::FN => Err(VcardError::PropertyFnRequired),
+            Some(index) => {
+                let before = self.properties.remove(index);
+                self.notify(VcardEvent::PropertyRemoved { before: before.clone() });
+                Ok(Some(before))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Keep only the properties matching the predicate, returning the removed properties.
+    ///
+    /// Nothing is removed if the predicate rejects an FN property, or a CLIENTPIDMAP whose source is still referenced by
+    /// the PID parameters of a kept property, see [`Vcard::remove_clientpidmap`].
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::traits::HasName;
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let mut vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nNOTE:Call later\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// vcard.retain(|property| property.name() != "NOTE").expect("Unable to remove properties.");
+    /// assert_eq!(vcard.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nEND:VCARD\n");
+    /// ```
+    pub fn retain<F: FnMut(&Property) -> bool>(&mut self, mut predicate: F) -> Result<Vec<Property>, VcardError> {
+        let keep: Vec<bool> = self.properties.iter().map(&mut predicate).collect();
+        if self.properties.iter().zip(keep.iter()).any(|(property, keep)| !keep && property.name() == PropertyName::FN) {
+            return Err(VcardError::PropertyFnRequired);
+        }
+
+        let kept_ids: Vec<i64> = self.properties.iter().zip(keep.iter()).filter(|(_, keep)| **keep).filter_map(|(property, _)| clientpidmap_id(property)).collect();
+        for (property, _) in self.properties.iter().zip(keep.iter()).filter(|(_, keep)| !**keep) {
+            if let Some(id) = clientpidmap_id(property).filter(|id| !kept_ids.contains(id)) {
+                if self.properties.iter().zip(keep.iter()).any(|(other, keep)| *keep && pid_sources(other).contains(&id)) {
+                    return Err(VcardError::PropertyClientPidMapReferenced(id));
+                }
+            }
+        }
+
+        let mut removed = Vec::new();
+        for (property, keep) in std::mem::take(&mut self.properties).into_iter().zip(keep) {
+            if keep {
+                self.properties.push(property);
+            } else {
+                removed.push(property);
+            }
+        }
+
+        for before in removed.iter() {
+            self.notify(VcardEvent::PropertyRemoved { before: before.clone() });
+        }
+
+        Ok(removed)
+    }
+
+    /// Remove the CLIENTPIDMAP properties with a source identifier, stripping the PID values referencing the source from
+    /// other properties and dropping PID parameters left empty. Returns the removed CLIENTPIDMAP properties.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nCLIENTPIDMAP:1;urn:uuid:client\nCLIENTPIDMAP:2;urn:uuid:other\nTEL;PID=1.1,1.2:555-5555\nEMAIL;PID=1.2:john@example.com\nEND:VCARD\n";
+    /// let mut vcard = Vcard::try_from(text).expect("Unable to parse vCard.");
+    /// assert!(vcard.remove_properties_by_name("CLIENTPIDMAP").is_err());
+    ///
+    /// let removed = vcard.remove_clientpidmap(2).expect("Unable to remove CLIENTPIDMAP.");
+    /// assert_eq!(removed.len(), 1);
+    /// assert!(vcard.get_properties_by_name("TEL")[0].to_string().starts_with("TEL;PID=1.1;"));
+    /// assert!(!vcard.to_string().contains(".2"));
+    /// ```
+    pub fn remove_clientpidmap(&mut self, id: i64) -> Result<Vec<Property>, VcardError> {
+        let replacements = self.properties.iter().enumerate().filter_map(|(i, property)| map_pids(property, |pid| (pid.source != Some(id)).then_some(pid)).map(|property| (i, property))).collect();
+        self.replace_properties(replacements)?;
+        self.retain(|property| clientpidmap_id(property) != Some(id))
+    }
+
+    /// Change the source identifier of the CLIENTPIDMAP properties with a source identifier, rewriting the PID values
+    /// referencing the source. Returns the number of updated properties.
+    ///
+    /// Fails with [PropertyClientPidMapConflict](VcardError::PropertyClientPidMapConflict) if the new identifier is
+    /// already used by a CLIENTPIDMAP or PID value.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let mut vcard = Vcard::try_from(("urn:uuid:client", "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nEND:VCARD\n")).expect("Unable to parse vCard.");
+    /// assert_eq!(vcard.renumber_clientpidmap(1, 3).expect("Unable to renumber CLIENTPIDMAP."), 2);
+    /// assert_eq!(vcard.get_properties_by_name("CLIENTPIDMAP")[0].to_string(), "CLIENTPIDMAP:3;urn:uuid:client\n");
+    /// assert_eq!(vcard.get_properties_by_name("TEL")[0].to_string(), "TEL;PID=1.3:555-5555\n");
+    /// ```
+    pub fn renumber_clientpidmap(&mut self, id: i64, new_id: i64) -> Result<usize, VcardError> {
+        if id == new_id {
+            return Ok(0);
+        }
+        if self.properties.iter().any(|property| clientpidmap_id(property) == Some(new_id) || pid_sources(property).contains(&new_id)) {
+            return Err(VcardError::PropertyClientPidMapConflict(new_id));
+        }
+
+        let mut replacements = Vec::new();
+        for (i, property) in self.properties.iter().enumerate() {
+            match property.get_value() {
+                ValueClientPidMap(data) if data.id == id => {
+                    let mut property = property.clone();
+                    property.set_value(ValueClientPidMap(ValueClientPidMapData {
+                        id: new_id,
+                        client: data.client.clone(),
+                    }))?;
+                    replacements.push((i, property));
+                }
+                _ => {
+                    if let Some(property) = map_pids(property, |pid| Some(if pid.source == Some(id) { Pid { source: Some(new_id), ..pid } } else { pid })) {
+                        replacements.push((i, property));
+                    }
+                }
+            }
+        }
+
+        self.replace_properties(replacements)
+    }
+
+    /// Check if the vCard has extended properties with a vendor prefix, see [`PropertyXNameData::vendor_namespace`](crate::vcard::property::property_xname::PropertyXNameData::vendor_namespace).
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::parse_vcards;
     ///
-    /// let property = Property::try_from("NICKNAME:Jonathon\n").expect("Unable to parse property string.");
-    /// let property = vcard.set_property(&property).expect("Unable to add property.");
-    /// let properties = vcard.get_properties_by_name("NICKNAME");
-    /// assert_eq!(properties.len(), 2);
+    /// let vcards = parse_vcards("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nX-GOOGLE-TALK:john\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Doe\nEND:VCARD\n").expect("Unable to parse text.");
+    /// assert_eq!(vcards.iter().filter(|vcard| vcard.has_vendor_properties("google")).count(), 1);
     /// ```
-    pub fn get_properties_by_name(&self, str: &str) -> Vec<Property> {
-        self.get_properties().iter().cloned().filter(|p| p.name() == str && p.is_multiple()).collect()
+    pub fn has_vendor_properties(&self, vendor: &str) -> bool {
+        self.properties.iter().any(|property| matches!(property, Property::PropertyXName(data) if data.has_vendor(vendor)))
     }
 
-    /// Get a cloned copy of all properties from the vCard.
+    /// Remove all extended properties with a vendor prefix, returning the removed properties.
     ///
     /// # Examples
     /// ```
     /// use vcard_parser::vcard::Vcard;
     ///
-    /// let mut vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEND:VCARD\n").expect("Unable to parse vCard.");
-    /// let properties = vcard.get_properties();
-    /// assert_eq!(properties.len(), 1);
+    /// let mut vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nX-MS-OL-DESIGN:<card/>\nX-MS-CARDPICTURE:data\nX-SKYPE:john\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// let removed = vcard.remove_vendor_properties("MS").expect("Unable to remove properties.");
+    /// assert_eq!(removed.len(), 2);
+    /// assert_eq!(vcard.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nX-SKYPE:john\nEND:VCARD\n");
     /// ```
-    pub fn get_properties(&self) -> Vec<Property> {
-        self.properties.clone()
+    pub fn remove_vendor_properties(&mut self, vendor: &str) -> Result<Vec<Property>, VcardError> {
+        self.retain(|property| !matches!(property, Property::PropertyXName(data) if data.has_vendor(vendor)))
     }
 
-    /// Remove a property from the vCard.
+    /// Sets a property. If the property matches an existing property, the existing property will be replaced.
+    /// If there is no match, a new property will be added.
+    ///
+    /// Returns a clone of the property which will include pid information for later matching.
     ///
     /// # Examples
     /// ```
@@ -228,27 +1278,21 @@ impl Vcard {
     /// let mut vcard = Vcard::new("John Doe");
     /// let property = Property::try_from("NICKNAME:Johnny\n").expect("Unable to parse property string.");
     /// let property = vcard.set_property(&property).expect("Unable to add property.");
-    /// if vcard.remove_property(&property).expect("Unable to remove property.") {
-    ///     assert!(vcard.get_property(&property).is_none());
-    /// }
+    /// assert!(vcard.get_property(&property).is_some());
     /// ```
-    pub fn remove_property(&mut self, property: &Property) -> Result<bool, VcardError> {
-        if property.name() == PropertyName::FN {
-            return Err(VcardError::PropertyFnRequired);
-        }
+    pub fn set_property(&mut self, property: &Property) -> Result<Property, VcardError> {
+        let (before, property) = self.apply_property(property)?;
 
-        if let Some(index) = self.get_property_index(property) {
-            self.properties.remove(index);
-            return Ok(true);
+        if !self.observers.is_empty() {
+            self.notify(VcardEvent::PropertySet { before, after: property.clone() });
         }
 
-        Ok(false)
+        Ok(property)
     }
 
-    /// Sets a property. If the property matches an existing property, the existing property will be replaced.
-    /// If there is no match, a new property will be added.
+    /// Sets multiple properties as with [`Vcard::set_property`], either setting all properties or none.
     ///
-    /// Returns a clone of the property which will include pid information for later matching.
+    /// Returns clones of the properties in the given order, observers are only notified once all properties are set.
     ///
     /// # Examples
     /// ```
@@ -256,16 +1300,60 @@ impl Vcard {
     /// use vcard_parser::vcard::Vcard;
     ///
     /// let mut vcard = Vcard::new("John Doe");
-    /// let property = Property::try_from("NICKNAME:Johnny\n").expect("Unable to parse property string.");
-    /// let property = vcard.set_property(&property).expect("Unable to add property.");
-    /// assert!(vcard.get_property(&property).is_some());
+    /// let nickname = Property::try_from("NICKNAME:Johnny\n").expect("Unable to parse property string.");
+    /// let name = Property::try_from("FN:Johnny Doe\n").expect("Unable to parse property string.");
+    /// let properties = vcard.set_properties(&[nickname, name]).expect("Unable to set properties.");
+    /// assert_eq!(properties.len(), 2);
+    /// assert_eq!(vcard.display_name(), "Johnny Doe");
     /// ```
-    pub fn set_property(&mut self, property: &Property) -> Result<Property, VcardError> {
-        let mut property = property.clone();
+    pub fn set_properties(&mut self, properties: &[Property]) -> Result<Vec<Property>, VcardError> {
+        let backup = self.properties.clone();
+        let mut events = Vec::with_capacity(properties.len());
+
+        for property in properties {
+            match self.apply_property(property) {
+                Ok(event) => events.push(event),
+                Err(err) => {
+                    self.properties = backup;
+                    return Err(err);
+                }
+            }
+        }
+
+        let mut properties = Vec::with_capacity(events.len());
+        for (before, after) in events {
+            if !self.observers.is_empty() {
+                self.notify(VcardEvent::PropertySet { before, after: after.clone() });
+            }
+            properties.push(after);
+        }
+
+        Ok(properties)
+    }
+
+    /// Helper function for setting a property without notifying observers, returning the replaced and set property.
+    fn apply_property(&mut self, property: &Property) -> Result<(Option<Property>, Property), VcardError> {
+        let mut property = self.options.normalize(property)?;
+        self.options.validate(&property)?;
+
+        // A CLIENTPIDMAP source identifier can only map to a single client, see Vcard::renumber_clientpidmap.
+        if let Some(id) = clientpidmap_id(&property) {
+            if self.get_property_index(&property).is_none() && self.properties.iter().any(|other| clientpidmap_id(other) == Some(id)) {
+                return Err(VcardError::PropertyClientPidMapConflict(id));
+            }
+        }
+
+        // Check the count limit of the name if the property doesn't match an existing property.
+        if let Some(max_count) = self.options.max_count(property.name()) {
+            let count = self.properties.iter().filter(|p| p.name().eq_ignore_ascii_case(property.name())).count();
+            if count >= max_count && self.get_property_index(&property).is_none() {
+                return Err(VcardError::PropertyLimitExceeded(property.name().to_uppercase(), max_count));
+            }
+        }
 
         // Add pid information to the property if it doesn't match an existing property.
-        if property.is_multiple() && property.name() != PropertyName::CLIENTPIDMAP && property.allowed_parameters().contains(&ParameterName::PID) && None == self.get_property_index(&property) {
-            let count = self.get_properties_by_name(property.name()).len();
+        if property.is_multiple() && property.name() != PropertyName::CLIENTPIDMAP && property.allowed_parameters().contains(&ParameterName::PID) && self.get_property_index(&property).is_none() {
+            let count = self.properties.iter().filter(|p| p.name() == property.name() && p.is_multiple()).count();
             let string = {
                 if let Some(clientpidmap) = self.get_clientpidmap() {
                     format!(";PID={}.{}", count + 1, clientpidmap.id)
@@ -277,19 +1365,59 @@ impl Vcard {
         }
 
         // Update or add property depending on match.
-        if let Some(i) = self.get_property_index(&property) {
-            self.properties[i] = property.clone();
-            Ok(property)
+        let before = if let Some(i) = self.get_property_index(&property) {
+            Some(std::mem::replace(&mut self.properties[i], property.clone()))
         } else {
             self.properties.push(property.clone());
-            Ok(property)
+            None
+        };
+
+        Ok((before, property))
+    }
+
+    /// Helper function replacing properties by index and notifying observers, returning the number of replaced properties.
+    ///
+    /// Replacements are normalized and validated with the options of the vCard first, nothing is replaced if any of them
+    /// fails.
+    fn replace_properties(&mut self, replacements: Vec<(usize, Property)>) -> Result<usize, VcardError> {
+        let mut normalized = Vec::with_capacity(replacements.len());
+        for (i, property) in replacements {
+            let property = self.options.normalize(&property)?;
+            self.options.validate(&property)?;
+            normalized.push((i, property));
+        }
+
+        let count = normalized.len();
+        for (i, after) in normalized {
+            let before = std::mem::replace(&mut self.properties[i], after.clone());
+            if !self.observers.is_empty() {
+                self.notify(VcardEvent::PropertySet { before: Some(before), after });
+            }
+        }
+        Ok(count)
+    }
+
+    /// Attach an observer which is notified after properties are set or removed, see the [events](crate::events) module.
+    pub fn add_observer(&mut self, observer: Arc<dyn VcardObserver>) {
+        self.observers.push(observer);
+    }
+
+    /// Helper function for notifying observers of a change.
+    fn notify(&self, event: VcardEvent) {
+        for observer in self.observers.iter() {
+            observer.notify(&event);
         }
     }
 
     /// Helper function for matching properties and returning their index in the properties array.
     fn get_property_index(&self, property: &Property) -> Option<usize> {
+        // CLIENTPIDMAP properties never match with Property::matches, only the same source and client are the same map.
+        if clientpidmap_id(property).is_some() {
+            return self.properties.iter().position(|other| clientpidmap_id(other).is_some() && other.get_value() == property.get_value());
+        }
+
         for (i, other) in self.properties.iter().enumerate() {
-            if property == other {
+            if property.matches(other) {
                 return Some(i);
             }
         }
@@ -311,60 +1439,175 @@ impl Vcard {
     }
 }
 
+/// Helper function getting a copy of a property without PID parameters.
+fn without_pids(property: &Property) -> Property {
+    match property {
+        Property::PropertyRaw(data) => {
+            let parameters = data.raw_parameters().iter().filter(|(name, _)| !name.eq_ignore_ascii_case(ParameterName::PID)).cloned().collect();
+            Property::PropertyRaw(PropertyRawData::new(data.group().clone(), data.name(), parameters, data.value()))
+        }
+        _ => {
+            let mut property = property.clone();
+            let parameters = property.get_parameters().into_iter().filter(|parameter| !matches!(parameter, Parameter::ParameterPid(_))).collect();
+            property.set_parameters(parameters);
+            property
+        }
+    }
+}
+
+/// Helper function getting the source identifier of a CLIENTPIDMAP property.
+fn clientpidmap_id(property: &Property) -> Option<i64> {
+    match property.get_value() {
+        ValueClientPidMap(data) if property.name() == PropertyName::CLIENTPIDMAP => Some(data.id),
+        _ => None,
+    }
+}
+
+/// Helper function getting the CLIENTPIDMAP source identifiers referenced by the PID parameters of a property.
+fn pid_sources(property: &Property) -> Vec<i64> {
+    let mut sources = Vec::new();
+    for parameter in property.get_parameters() {
+        if let (Parameter::ParameterPid(_), ValuePid(data)) = (&parameter, parameter.get_value()) {
+            sources.extend(data.value.iter().filter_map(|(_, source)| *source));
+        }
+    }
+    sources
+}
+
+/// Helper function getting a copy of a property with its PID values mapped, leaving out values mapped to None and PID
+/// parameters without values. Returns None if no PID value changed.
+fn map_pids<F: FnMut(Pid) -> Option<Pid>>(property: &Property, mut f: F) -> Option<Property> {
+    let mut changed = false;
+    let mut map = |data: &ValuePidData| {
+        let pids: Vec<Pid> = data.pids().into_iter().filter_map(&mut f).collect();
+        changed |= pids != data.pids();
+        (!pids.is_empty()).then(|| ValuePidData::from(pids))
+    };
+
+    let property = match property {
+        Property::PropertyRaw(data) => {
+            let mut parameters = Vec::new();
+            for (name, value) in data.raw_parameters() {
+                match ValuePidData::try_from(value.as_str()) {
+                    Ok(pids) if name.eq_ignore_ascii_case(ParameterName::PID) => parameters.extend(map(&pids).map(|pids| (name.clone(), pids.to_string()))),
+                    _ => parameters.push((name.clone(), value.clone())),
+                }
+            }
+            Property::PropertyRaw(PropertyRawData::new(data.group().clone(), data.name(), parameters, data.value()))
+        }
+        _ => {
+            let mut parameters = Vec::new();
+            for parameter in property.get_parameters() {
+                match (&parameter, parameter.get_value()) {
+                    (Parameter::ParameterPid(_), ValuePid(data)) => parameters.extend(map(data).map(|pids| Parameter::ParameterPid(ParameterPidData { value: ValuePid(pids) }))),
+                    _ => parameters.push(parameter.clone()),
+                }
+            }
+            let mut property = property.clone();
+            property.set_parameters(parameters);
+            property
+        }
+    };
+
+    changed.then_some(property)
+}
+
 impl TryFrom<&str> for Vcard {
     type Error = VcardError;
     fn try_from(str: &str) -> Result<Self, Self::Error> {
-        let (_, properties) = parse::vcard::vcard(str.as_bytes())?;
-        Self::try_from((None, properties))
+        Self::try_from(str.as_bytes())
+    }
+}
+
+impl TryFrom<&[u8]> for Vcard {
+    type Error = VcardError;
+    fn try_from(u8: &[u8]) -> Result<Self, Self::Error> {
+        let (_, (version, properties)) = parse::vcard::vcard_versioned(u8)?;
+        let mut vcard = Self::try_from((None, properties))?;
+        vcard.set_version(utf8_to_str(version)?)?;
+        Ok(vcard)
     }
 }
 
 impl TryFrom<(&str, &str)> for Vcard {
     type Error = VcardError;
     fn try_from((client, str): (&str, &str)) -> Result<Self, Self::Error> {
-        let (_, properties) = parse::vcard::vcard(str.as_bytes())?;
-        Self::try_from((Some(client.to_string()), properties))
+        let (_, (version, properties)) = parse::vcard::vcard_versioned(str.as_bytes())?;
+        let mut vcard = Self::try_from((Some(client.to_string()), properties))?;
+        vcard.set_version(utf8_to_str(version)?)?;
+        Ok(vcard)
     }
 }
 
 impl<'a> TryFrom<(Option<String>, VcardData<'a>)> for Vcard {
     type Error = VcardError;
     fn try_from((client, data): (Option<String>, VcardData<'a>)) -> Result<Self, Self::Error> {
-        let mut properties = Vec::new();
-
-        for datum in data {
-            properties.push(Property::create_from_data(datum)?)
-        }
+        Self::try_from((client, ParserOptions::default(), data))
+    }
+}
 
-        Self::try_from((client, properties))
+impl<'a> TryFrom<(Option<String>, ParserOptions, VcardData<'a>)> for Vcard {
+    type Error = VcardError;
+    fn try_from((client, options, data): (Option<String>, ParserOptions, VcardData<'a>)) -> Result<Self, Self::Error> {
+        Self::from_data(client, options, data, Vec::new())
     }
 }
 
 impl TryFrom<(Option<String>, Vec<Property>)> for Vcard {
     type Error = VcardError;
     fn try_from((client, properties): (Option<String>, Vec<Property>)) -> Result<Self, Self::Error> {
-        let mut vcard = Self { client, properties: Vec::new() };
-
-        if let Some(client) = &vcard.client {
-            vcard.set_property(&Property::create_from_str(format!("CLIENTPIDMAP:1;{}\n", client).as_str())?)?;
-        }
+        Self::try_from((client, ParserOptions::default(), properties))
+    }
+}
 
+impl TryFrom<(Option<String>, ParserOptions, Vec<Property>)> for Vcard {
+    type Error = VcardError;
+    fn try_from((client, options, properties): (Option<String>, ParserOptions, Vec<Property>)) -> Result<Self, Self::Error> {
+        let mut vcard = Self::with_client(client, options)?;
         for property in properties {
-            vcard.set_property(&property)?;
+            vcard.set_parsed_property(&property, || property.to_string().trim_end().to_string())?;
         }
+        vcard.require_fn()
+    }
+}
 
-        if vcard.get_property_by_name(PropertyName::FN).is_none() {
-            return Err(VcardError::PropertyFnMissing);
-        }
+/// Helper function joining a skipped line with its folds.
+fn folded_line((value, folds): ValueFoldedData) -> String {
+    let mut string = String::from_utf8_lossy(value).to_string();
+    for fold in folds.unwrap_or_default() {
+        string.push_str(&String::from_utf8_lossy(fold));
+    }
+    string
+}
 
-        Ok(vcard)
+/// Helper function writing parsed property data back into an unfolded content line.
+fn content_line(((group, name), parameters, (value, folds)): PropertyData) -> String {
+    let mut string = String::new();
+
+    if let Some(group) = group {
+        string.push_str(&String::from_utf8_lossy(group));
+        string.push('.');
     }
+    string.push_str(&String::from_utf8_lossy(name));
+    for (name, value) in parameters {
+        string.push(';');
+        string.push_str(&String::from_utf8_lossy(name));
+        string.push('=');
+        string.push_str(&String::from_utf8_lossy(value));
+    }
+    string.push(':');
+    string.push_str(&String::from_utf8_lossy(value));
+    for fold in folds.unwrap_or_default() {
+        string.push_str(&String::from_utf8_lossy(fold));
+    }
+
+    string
 }
 
 impl Display for Vcard {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "BEGIN:VCARD")?;
-        writeln!(f, "VERSION:4.0")?;
+        writeln!(f, "VERSION:{}", self.version)?;
         for property in self.get_properties().iter() {
             write!(f, "{}", property)?;
         }
@@ -375,20 +1618,547 @@ impl Display for Vcard {
 
 #[cfg(test)]
 mod tests {
+    use std::cmp::Ordering;
+    use std::sync::{Arc, Mutex};
+
     use crate::constants::ValueName;
+    use crate::events::{VcardEvent, VcardObserver};
+    use crate::options::{ExportOptions, ExportProfile, LengthAction, ParserOptions, ProdIdPolicy, ValueLimit};
+    use crate::redact::{RedactionAction, RedactionPolicy};
+    use crate::vcard::property::property_n::NameParts;
+    use crate::vcard::property::property_raw::PropertyRawData;
+    use crate::vcard::value::value_geo::ValueGeoData;
     use crate::vcard::value::Value;
-    use crate::{HasValue, Property, Vcard};
+    use crate::vcard::SortBy;
+    use crate::{parse_vcards, parse_vcards_with_options, HasName, HasValue, Property, Vcard, VcardError};
 
     #[test]
     pub fn vcard_new() {
         assert_eq!(Vcard::new("John Doe").to_string(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEND:VCARD\n");
     }
 
+    #[test]
+    pub fn vcard_new_individual() {
+        let parts = NameParts {
+            family: "Doe",
+            given: "John",
+            additional: "Quinlan",
+            prefixes: "Dr.",
+            suffixes: "",
+        };
+        let vcard = Vcard::new_individual(parts);
+        let uid = vcard.get_property_by_name("UID").unwrap().get_value().to_string();
+        assert_eq!(vcard.export(), format!("BEGIN:VCARD\nVERSION:4.0\nFN:Dr. John Quinlan Doe\nN:Doe;John;Quinlan;Dr.;\nUID:{}\nEND:VCARD\n", uid));
+        assert_eq!(Vcard::try_from(vcard.export().as_str()).unwrap().export(), vcard.export());
+
+        let bytes = uid.trim_start_matches("urn:uuid:").as_bytes();
+        assert_eq!(bytes.len(), 36);
+        assert_eq!(bytes[14], b'4');
+        assert!(matches!(bytes[19], b'8' | b'9' | b'a' | b'b'));
+        assert_ne!(uid, Vcard::new_individual(parts).get_property_by_name("UID").unwrap().get_value().to_string());
+
+        let vcard = Vcard::new_individual(NameParts {
+            family: "Doe, Sr.",
+            ..NameParts::default()
+        });
+        assert_eq!(vcard.export(), format!("BEGIN:VCARD\nVERSION:4.0\nFN:Doe\\, Sr.\nN:Doe\\, Sr.;;;;\nUID:{}\nEND:VCARD\n", vcard.get_property_by_name("UID").unwrap().get_value()));
+    }
+
     #[test]
     pub fn vcard_export() {
         assert_eq!(Vcard::new("John Doe").export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEND:VCARD\n");
     }
 
+    #[test]
+    pub fn vcard_export_profile() {
+        let mut vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nitem1.EMAIL;TYPE=home;TYPE=Pref:john@example.com\nMEMBER:urn:uuid:other\nPHOTO:data:image/png;base64,AAAA\nEND:VCARD\n").unwrap();
+        let exported = vcard.export_profile(ExportProfile::GoogleContacts);
+        assert!(exported.contains("item1.EMAIL;TYPE=HOME,PREF:john@example.com\n"));
+        assert!(exported.contains("PHOTO:data:image/png;base64,AAAA\n"));
+        assert!(!exported.contains("MEMBER"));
+
+        let photo = format!("PHOTO:data:image/png;base64,{}\n", "AAAA".repeat(1024 * 1024 / 3 + 1));
+        vcard.set_property(&Property::try_from(photo.as_str()).unwrap()).unwrap();
+        assert_eq!(vcard.export_profile(ExportProfile::GoogleContacts).matches("PHOTO").count(), 1);
+        assert_eq!(vcard.export().matches("PHOTO").count(), 2);
+    }
+
+    #[test]
+    pub fn vcard_version() {
+        let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEND:VCARD\n";
+        let mut vcard = Vcard::try_from(text).unwrap();
+        assert_eq!(vcard.version(), "4.0");
+        assert_eq!(parse_vcards(text).unwrap()[0].version(), "4.0");
+
+        assert!(matches!(vcard.set_version("3.0"), Err(VcardError::ValueInvalid(_, _))));
+        assert_eq!(vcard.version(), "4.0");
+        assert_eq!(vcard.export(), text);
+        assert_eq!(vcard.to_string(), text);
+    }
+
+    #[test]
+    pub fn vcard_unparsed_lines() {
+        let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nitem1.X-MS-OL-DESIGN;CHARSET=utf-8:<card xmlns=\"http://schemas.microsoft.com/office/outlook/12/electronicbusinesscards\">\nGEO;TYPE=work:\n https://example.com\nEMAIL:not an address\nEND:VCARD\n";
+        assert!(Vcard::try_from(text).is_err());
+
+        let options = ParserOptions {
+            lenient: true,
+            ..ParserOptions::default()
+        };
+        let vcard = parse_vcards_with_options(text, &options).unwrap().pop().unwrap();
+        assert_eq!(vcard.unparsed_lines().len(), 2);
+        assert_eq!(vcard.get_properties().len(), 2);
+
+        let options = ParserOptions { strict: true, ..options };
+        let vcard = parse_vcards_with_options(text, &options).unwrap().pop().unwrap();
+        assert_eq!(
+            vcard.unparsed_lines(),
+            &[
+                String::from("item1.X-MS-OL-DESIGN;CHARSET=utf-8:<card xmlns=\"http://schemas.microsoft.com/office/outlook/12/electronicbusinesscards\">"),
+                String::from("GEO;TYPE=work:https://example.com"),
+                String::from("EMAIL:not an address")
+            ]
+        );
+        assert_eq!(vcard.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEND:VCARD\n");
+
+        assert!(Vcard::new("John Doe").unparsed_lines().is_empty());
+
+        // Lines failing validation are kept as written, all lines in input order.
+        let options = ParserOptions {
+            lenient: true,
+            value_limits: Vec::from([ValueLimit::new(
+                "NOTE",
+                4,
+                LengthAction::Reject,
+            )]),
+            ..ParserOptions::default()
+        };
+        let vcard = parse_vcards_with_options("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nnote;language=en:Too\\, long\nX-A;=:b\nNOTE:Kept\nEND:VCARD\n", &options).unwrap().pop().unwrap();
+        assert_eq!(
+            vcard.unparsed_lines(),
+            &[
+                String::from("note;language=en:Too\\, long"),
+                String::from("X-A;=:b")
+            ]
+        );
+        assert_eq!(vcard.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nNOTE:Kept\nEND:VCARD\n");
+
+        let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nHOBBY;LANGUAGE=en;MEDIATYPE=text/plain;TZ=UTC:reading\nX-CUSTOM;TZ=UTC:kept\nEND:VCARD\n";
+        assert!(matches!(Vcard::try_from(text), Err(VcardError::ParameterTypeNotAllowed(_, _, None))));
+        let vcard = parse_vcards_with_options(text, &options).unwrap().pop().unwrap();
+        assert_eq!(
+            vcard.warnings(),
+            &[
+                String::from("Parameter MEDIATYPE=text/plain is not allowed for HOBBY."),
+                String::from("Parameter TZ=UTC is not allowed for HOBBY.")
+            ]
+        );
+        assert!(vcard.unparsed_lines().is_empty());
+        assert_eq!(vcard.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nHOBBY;LANGUAGE=en:reading\nX-CUSTOM;TZ=UTC:kept\nEND:VCARD\n");
+    }
+
+    #[test]
+    pub fn vcard_export_with() {
+        let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL:john@example.com\nREV:20000101T000000Z\nEND:VCARD\n";
+        let vcard = Vcard::try_from(("urn:uuid:some-uuid", text)).unwrap();
+
+        assert_eq!(vcard.export_with(&ExportOptions::default()), vcard.export());
+        assert_eq!(vcard.export_with(&ExportOptions::all()), vcard.to_string());
+        assert_eq!(
+            vcard.export_with(&ExportOptions {
+                include_clientpidmap: true,
+                include_pids: true,
+                include_rev: false,
+                ..ExportOptions::default()
+            }),
+            "BEGIN:VCARD\nVERSION:4.0\nCLIENTPIDMAP:1;urn:uuid:some-uuid\nFN:John Doe\nEMAIL;PID=1.1:john@example.com\nEND:VCARD\n"
+        );
+        assert_eq!(
+            vcard.export_with(&ExportOptions {
+                include_clientpidmap: false,
+                include_pids: false,
+                include_rev: false,
+                ..ExportOptions::default()
+            }),
+            "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL:john@example.com\nEND:VCARD\n"
+        );
+
+        let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;PREF=2:555-5556\nitem1.NOTE:Work\nEMAIL:john@example.com\nTEL;PREF=1:555-5555\nNOTE:Home\nEND:VCARD\n";
+        let vcard = Vcard::try_from(text).unwrap();
+        let options = ExportOptions {
+            sort_properties: true,
+            ..ExportOptions::default()
+        };
+        assert_eq!(vcard.export_with(&options), "BEGIN:VCARD\nVERSION:4.0\nEMAIL:john@example.com\nFN:John Doe\nNOTE:Home\nitem1.NOTE:Work\nTEL;PREF=1:555-5555\nTEL;PREF=2:555-5556\nEND:VCARD\n");
+        assert_eq!(vcard.export(), text);
+
+        let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nPRODID:-//Example//Contacts//EN\nEND:VCARD\n";
+        let vcard = Vcard::try_from(text).unwrap();
+        let options = ExportOptions {
+            prodid: ProdIdPolicy::this_crate(),
+            ..ExportOptions::default()
+        };
+        assert_eq!(vcard.export_with(&options), format!("BEGIN:VCARD\nVERSION:4.0\nPRODID:-//vcard_parser//vcard_parser {}//EN\nFN:John Doe\nEND:VCARD\n", env!("CARGO_PKG_VERSION")));
+        let options = ExportOptions {
+            prodid: ProdIdPolicy::Stamp(String::from("Sync, version 2")),
+            ..ExportOptions::default()
+        };
+        assert_eq!(vcard.export_with(&options), "BEGIN:VCARD\nVERSION:4.0\nPRODID:Sync\\, version 2\nFN:John Doe\nEND:VCARD\n");
+        assert_eq!(Vcard::try_from(vcard.export_with(&options).as_str()).unwrap().get_property_by_name("PRODID").unwrap().get_value().to_string(), "Sync\\, version 2");
+        assert_eq!(vcard.export_with(&ExportOptions::default()), text);
+    }
+
+    #[test]
+    pub fn vcard_export_filtered() {
+        let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL:john@example.com\nBDAY:2000-01-01\nNOTE:Private\nX-SECRET:Private\nx-other:Private\nEND:VCARD\n";
+        let vcard = Vcard::try_from(text).unwrap();
+
+        let options = ExportOptions {
+            exclude_properties: Vec::from([
+                String::from("bday"),
+                String::from("NOTE"),
+                String::from("X-*"),
+            ]),
+            ..ExportOptions::default()
+        };
+        assert_eq!(vcard.export_with(&options), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL:john@example.com\nEND:VCARD\n");
+
+        let options = ExportOptions {
+            include_properties: Some(Vec::from([
+                String::from("FN"),
+                String::from("X-*"),
+            ])),
+            exclude_properties: Vec::from([String::from("X-SECRET")]),
+            ..ExportOptions::default()
+        };
+        assert_eq!(vcard.export_with(&options), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nx-other:Private\nEND:VCARD\n");
+        assert_eq!(vcard.export_filtered_with(&options, |p| p.name() != "FN"), "BEGIN:VCARD\nVERSION:4.0\nx-other:Private\nEND:VCARD\n");
+
+        assert_eq!(vcard.export_filtered(|_| true), vcard.export());
+        assert_eq!(vcard.export_filtered(|_| false), "BEGIN:VCARD\nVERSION:4.0\nEND:VCARD\n");
+        assert_eq!(vcard.export(), text);
+    }
+
+    #[test]
+    pub fn vcard_display_name() {
+        fn _display_name(str: &str) -> String {
+            Vcard::try_from(format!("BEGIN:VCARD\nVERSION:4.0\n{}END:VCARD\n", str).as_str()).unwrap().display_name()
+        }
+
+        assert_eq!(_display_name("FN:John Doe\nN:Public;John;Quinlan;;\n"), "John Doe");
+        assert_eq!(_display_name("FN:\nN:Public;John;Quinlan;;\n"), "John Quinlan Public");
+        assert_eq!(_display_name("FN: \nN:;;;;\nORG:ABC\\, Inc.;North American Division\n"), "ABC, Inc.");
+        assert_eq!(_display_name("FN:\nTEL:tel:+1-555-555-5555\nEMAIL:jdoe@example.com\n"), "jdoe@example.com");
+        assert_eq!(_display_name("FN:\nTEL;VALUE=URI:tel:+1-555-555-5555\n"), "+1-555-555-5555");
+        assert_eq!(_display_name("FN:\n"), "");
+    }
+
+    #[test]
+    pub fn vcard_sort_key() {
+        fn _sort_key(str: &str) -> String {
+            Vcard::try_from(format!("BEGIN:VCARD\nVERSION:4.0\n{}END:VCARD\n", str).as_str()).unwrap().sort_key()
+        }
+
+        assert_eq!(_sort_key("FN:John Doe\nN:Doe;John;;;\n"), "doe john");
+        assert_eq!(_sort_key("FN:John Doe\nN;SORT-AS=Roe:Doe;John;;;\n"), "roe");
+        assert_eq!(_sort_key("FN:ACME\nORG;SORT-AS=\"Acme Corp\":The ACME Corporation\n"), "acme corp");
+        assert_eq!(_sort_key("FN:ACME\nORG:The ACME Corporation;Sales\n"), "the acme corporation");
+        assert_eq!(_sort_key("FN:Johnny\n"), "johnny");
+    }
+
+    #[test]
+    pub fn vcard_vendor_properties() {
+        let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nX-GOOGLE-TALK:john\nx-google-other:1\nX-GOOGLEPLUS:john\nX-ABLABEL:Work\nEND:VCARD\n";
+        let mut vcard = Vcard::try_from(text).unwrap();
+
+        assert!(vcard.has_vendor_properties("Google"));
+        assert!(vcard.has_vendor_properties("ABLABEL"));
+        assert!(!vcard.has_vendor_properties("MS"));
+
+        let removed = vcard.remove_vendor_properties("GOOGLE").unwrap();
+        assert_eq!(
+            removed.iter().map(|p| p.name().to_string()).collect::<Vec<String>>(),
+            [
+                "X-GOOGLE-TALK",
+                "x-google-other"
+            ]
+        );
+        assert!(!vcard.has_vendor_properties("GOOGLE"));
+        assert_eq!(vcard.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nX-GOOGLEPLUS:john\nX-ABLABEL:Work\nEND:VCARD\n");
+    }
+
+    #[test]
+    pub fn vcard_reindex_properties() {
+        let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEXPERTISE;INDEX=3:chemistry\nEXPERTISE;INDEX=1:physics\nNOTE;INDEX=4:Hello\nEND:VCARD\n";
+        let mut vcard = Vcard::try_from(text).unwrap();
+
+        assert_eq!(vcard.reindex_properties("expertise").unwrap(), 1);
+        assert_eq!(vcard.get_properties_by_name("EXPERTISE").iter().map(|p| p.index()).collect::<Vec<Option<i64>>>(), [Some(2), Some(1)]);
+        assert_eq!(vcard.reindex_properties("EXPERTISE").unwrap(), 0);
+
+        let mut removed = vcard.clone();
+        removed.remove_property_by_pid("EXPERTISE", "2").unwrap();
+        removed.set_property(&Property::try_from("EXPERTISE:biology\n").unwrap()).unwrap();
+        assert_eq!(removed.reindex_properties("EXPERTISE").unwrap(), 2);
+        assert_eq!(removed.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEXPERTISE;INDEX=1:chemistry\nNOTE;INDEX=4:Hello\nEXPERTISE;INDEX=2:biology\nEND:VCARD\n");
+
+        assert_eq!(vcard.reindex_properties("NOTE").unwrap(), 1);
+        assert_eq!(vcard.reindex_properties("TEL").unwrap(), 0);
+
+        assert!(Vcard::try_from(text.replace("INDEX=3", "INDEX=0").as_str()).is_err());
+        assert!(Vcard::try_from(text.replace("INDEX=3", "INDEX=first").as_str()).is_err());
+    }
+
+    #[test]
+    pub fn vcard_rev() {
+        let a = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nREV:2000-01-01T12:00:00+02:00\nEND:VCARD\n").unwrap();
+        let b = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nREV:20000101T110000Z\nEND:VCARD\n").unwrap();
+        let c = Vcard::new("John Doe");
+
+        assert_eq!(a.rev().map(|rev| rev.unix_timestamp()), Some(946720800));
+        assert!(b.newer_than(&a));
+        assert!(!a.newer_than(&b));
+        assert!(!a.newer_than(&a));
+        assert!(a.newer_than(&c));
+        assert!(!c.newer_than(&a));
+        assert!(!c.newer_than(&c.clone()));
+    }
+
+    #[test]
+    pub fn vcard_redact() {
+        let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nN:Doe;John;;;\nEMAIL:john@example.com\nEMAIL:mailto:jdoe@example.com\nTEL;VALUE=URI:tel:+1-555-555-5555\nADR:;;123 Main Street;Any Town;CA;91921-1234;U.S.A.\nBDAY:19700101\nNOTE:Secret\nEND:VCARD\n";
+        let vcard = Vcard::try_from(text).unwrap();
+
+        let redacted = vcard.redact(&RedactionPolicy::default()).unwrap();
+        let export = redacted.export();
+        assert_eq!(redacted.get_properties().len(), vcard.get_properties().len() - 1);
+        assert!(export.contains("FN:John Doe\nN:Doe;John;;;\n"));
+        assert!(export.contains("@example.invalid\n"));
+        assert!(export.contains("EMAIL:mailto:"));
+        assert!(export.contains("TEL;VALUE=URI:tel:+"));
+        assert!(export.contains("ADR:;;"));
+        for secret in [
+            "john@",
+            "jdoe@",
+            "555",
+            "Main Street",
+            "91921",
+            "BDAY",
+            "Secret",
+        ] {
+            assert!(!export.contains(secret), "{} was not redacted", secret);
+        }
+        assert_eq!(export, vcard.redact(&RedactionPolicy::default()).unwrap().export());
+
+        let policy = RedactionPolicy::default().with("fn", RedactionAction::Remove).with("n", RedactionAction::Hash).with("BDAY", RedactionAction::Hash);
+        let redacted = vcard.redact(&policy).unwrap();
+        assert_eq!(redacted.get_property_by_name("FN").unwrap().export(), "FN:\n");
+        assert!(!redacted.get_property_by_name("N").unwrap().export().contains("Doe"));
+        assert!(redacted.get_property_by_name("BDAY").is_some());
+
+        let log = vcard.to_log_string();
+        assert_eq!(log.lines().count(), vcard.export().lines().count());
+        assert!(log.contains("\nN:<11>\nEMAIL;PID=<1>:<16>\nEMAIL;PID=<1>:<23>\nTEL;VALUE=<3>;PID=<1>:<19>\nADR;PID=<1>:<47>\n"));
+        for secret in [
+            "John", "example", "555", "Main", "1970", "Secret", "URI",
+        ] {
+            assert!(!log.contains(secret), "{} was logged", secret);
+        }
+    }
+
+    #[test]
+    pub fn vcard_observer() {
+        #[derive(Debug, Default)]
+        struct Events(Mutex<Vec<VcardEvent>>);
+
+        impl VcardObserver for Events {
+            fn notify(&self, event: &VcardEvent) {
+                self.0.lock().unwrap().push(event.clone());
+            }
+        }
+
+        let events = Arc::new(Events::default());
+        let mut vcard = Vcard::new("John Doe");
+        vcard.add_observer(events.clone());
+
+        let note = vcard.set_property(&Property::try_from("NOTE:Johnny\n").unwrap()).unwrap();
+        let mut updated = note.clone();
+        updated.set_value(Value::try_from((ValueName::TEXT, "Jonathan")).unwrap()).unwrap();
+        vcard.set_property(&updated).unwrap();
+        vcard.remove_property(&updated).unwrap();
+        assert!(vcard.remove_property(&updated).is_ok());
+
+        let events = events.0.lock().unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(
+            events[0],
+            VcardEvent::PropertySet {
+                before: None,
+                after: note.clone()
+            }
+        );
+        assert_eq!(
+            events[1],
+            VcardEvent::PropertySet {
+                before: Some(note),
+                after: updated.clone()
+            }
+        );
+        assert_eq!(events[2], VcardEvent::PropertyRemoved { before: updated });
+    }
+
+    #[test]
+    pub fn vcard_remove_properties() {
+        let mut vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nTEL:555-1234\nEMAIL:john@example.com\nEND:VCARD\n").unwrap();
+        let export = vcard.export();
+
+        assert!(vcard.retain(|property| property.name() == "TEL").is_err());
+        assert_eq!(vcard.export(), export);
+
+        assert!(vcard.remove_property_by_pid("TEL", "3").unwrap().is_none());
+        assert!(vcard.remove_property_by_pid("EMAIL", "2").unwrap().is_none());
+        assert!(vcard.remove_property_by_pid("TEL", "one").is_err());
+        assert_eq!(vcard.remove_property_by_pid("TEL", "1").unwrap().unwrap().to_string(), "TEL;PID=1:555-5555\n");
+
+        assert_eq!(vcard.remove_properties_by_name("TEL").unwrap().len(), 1);
+        assert!(vcard.remove_properties_by_name("TEL").unwrap().is_empty());
+        assert_eq!(vcard.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL:john@example.com\nEND:VCARD\n");
+    }
+
+    #[test]
+    pub fn vcard_clone_clean() {
+        let mut vcard = Vcard::try_from(("urn:uuid:client", "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;TYPE=work:555-5555\nEMAIL;PID=4.1,5:john@example.com\nREV:20240101T000000Z\nPRODID:-//Example//EN\nEND:VCARD\n")).unwrap();
+        let raw = Property::PropertyRaw(PropertyRawData::try_from("X-RAW;pid=1;X-FOO=bar:value").unwrap());
+        vcard.set_property(&raw).unwrap();
+
+        let clean = vcard.clone_clean();
+        assert_eq!(clean.to_string(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;TYPE=work:555-5555\nEMAIL:john@example.com\nX-RAW;X-FOO=bar:value\nEND:VCARD\n");
+        assert!(clean.get_properties_by_name("TEL")[0].pids().is_empty());
+        assert!(vcard.get_properties_by_name("CLIENTPIDMAP").len() == 1);
+        assert_eq!(clean.fingerprint(), vcard.fingerprint());
+
+        let mut clean = clean;
+        clean.set_property(&Property::try_from("TEL:555-1234\n").unwrap()).unwrap();
+        assert_eq!(clean.get_properties_by_name("TEL")[1].to_string(), "TEL;PID=2:555-1234\n");
+    }
+
+    #[test]
+    pub fn vcard_empty_values() {
+        let input = "BEGIN:VCARD\nVERSION:4.0\nFN:\nN:\nADR;TYPE=home:\nNOTE:\nBDAY:\nBDAY;VALUE=date:\nPHOTO:\nREV:\nX-FOO:\nEND:VCARD\n";
+        let vcard = Vcard::try_from(input).unwrap();
+        assert_eq!(vcard.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:\nN:;;;;\nADR;TYPE=home:;;;;;;\nNOTE:\nBDAY:\nX-FOO:\nEND:VCARD\n");
+        assert_eq!(
+            vcard.warnings(),
+            [
+                "Value for BDAY is empty.",
+                "Value for PHOTO is empty.",
+                "Value for REV is empty."
+            ]
+        );
+        assert_eq!(Property::try_from("URL:\n").unwrap_err(), VcardError::ValueEmpty(String::from("URL")));
+        assert!(Property::try_from("CLIENTPIDMAP:\n").is_err());
+        assert!(Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nCLIENTPIDMAP:\nEND:VCARD\n").is_err());
+
+        let options = ExportOptions {
+            include_empty: false,
+            ..ExportOptions::default()
+        };
+        assert_eq!(vcard.export_with(&options), "BEGIN:VCARD\nVERSION:4.0\nFN:\nEND:VCARD\n");
+
+        let options = ParserOptions {
+            lenient: true,
+            ..ParserOptions::default()
+        };
+        let vcard = parse_vcards_with_options(input, &options).unwrap().remove(0);
+        assert!(vcard.unparsed_lines().is_empty());
+        assert_eq!(vcard.warnings().len(), 3);
+    }
+
+    #[test]
+    pub fn vcard_source_client() {
+        let vcard = Vcard::try_from(("urn:uuid:client", "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nEND:VCARD\n")).unwrap();
+        let tel = vcard.get_properties_by_name("TEL").pop().unwrap();
+        assert_eq!(tel.source_client(&vcard), Some("urn:uuid:client"));
+        assert_eq!(tel.source_client(&Vcard::new("John Doe")), None);
+
+        let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;PID=1:555-5555\nEND:VCARD\n").unwrap();
+        assert_eq!(vcard.get_properties_by_name("TEL")[0].source_client(&vcard), None);
+    }
+
+    #[test]
+    pub fn vcard_clientpidmap() {
+        let mut vcard = Vcard::try_from(("urn:uuid:client", "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nEND:VCARD\n")).unwrap();
+        vcard.set_property(&Property::PropertyRaw(PropertyRawData::try_from("X-RAW;PID=4.1;X-A=b:raw").unwrap())).unwrap();
+        assert_eq!(vcard.retain(|property| property.name() != "CLIENTPIDMAP"), Err(VcardError::PropertyClientPidMapReferenced(1)));
+        assert_eq!(vcard.renumber_clientpidmap(1, 1), Ok(0));
+
+        vcard.set_property(&Property::try_from("CLIENTPIDMAP:2;urn:uuid:other\n").unwrap()).unwrap();
+        assert_eq!(vcard.renumber_clientpidmap(1, 2), Err(VcardError::PropertyClientPidMapConflict(2)));
+        assert_eq!(vcard.renumber_clientpidmap(1, 5), Ok(3));
+        assert!(vcard.to_string().contains("X-RAW;PID=4.5;X-A=b:raw\n"));
+
+        assert_eq!(vcard.remove_clientpidmap(5).unwrap().len(), 1);
+        assert!(vcard.to_string().contains("X-RAW;X-A=b:raw\n"));
+        assert_eq!(vcard.get_properties_by_name("CLIENTPIDMAP").len(), 1);
+        assert_eq!(vcard.remove_clientpidmap(7), Ok(Vec::new()));
+        assert_eq!(vcard.remove_properties_by_name("CLIENTPIDMAP").unwrap().len(), 1);
+
+        // A source identifier maps to a single client, setting the same map again replaces it.
+        let mut vcard = Vcard::try_from(("urn:uuid:client", "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nEND:VCARD\n")).unwrap();
+        let other = Property::try_from("CLIENTPIDMAP:1;urn:uuid:other\n").unwrap();
+        assert_eq!(vcard.set_property(&other), Err(VcardError::PropertyClientPidMapConflict(1)));
+        assert_eq!(vcard.set_properties(&[other]), Err(VcardError::PropertyClientPidMapConflict(1)));
+        let same = Property::try_from("CLIENTPIDMAP:1;urn:uuid:client\n").unwrap();
+        vcard.set_property(&same).unwrap();
+        assert_eq!(vcard.get_properties_by_name("CLIENTPIDMAP").len(), 1);
+        assert!(vcard.get_property(&same).is_some());
+
+        // A CLIENTPIDMAP is only removed once no PID references its source.
+        assert_eq!(vcard.remove_property(&same), Err(VcardError::PropertyClientPidMapReferenced(1)));
+        assert_eq!(vcard.remove_property(&Property::try_from("CLIENTPIDMAP:2;urn:uuid:client\n").unwrap()), Ok(false));
+        vcard.remove_properties_by_name("TEL").unwrap();
+        assert_eq!(vcard.remove_property(&same), Ok(true));
+        assert!(vcard.get_properties_by_name("CLIENTPIDMAP").is_empty());
+    }
+
+    #[test]
+    pub fn vcard_set_properties() {
+        #[derive(Debug, Default)]
+        struct Events(Mutex<Vec<VcardEvent>>);
+
+        impl VcardObserver for Events {
+            fn notify(&self, event: &VcardEvent) {
+                self.0.lock().unwrap().push(event.clone());
+            }
+        }
+
+        let events = Arc::new(Events::default());
+        let mut vcard = Vcard::new("John Doe");
+        vcard.set_options(ParserOptions {
+            strict: true,
+            ..ParserOptions::default()
+        });
+        vcard.add_observer(events.clone());
+        let export = vcard.export();
+
+        let properties = [
+            Property::try_from("NICKNAME:Johnny\n").unwrap(),
+            Property::try_from("FN:Johnny Doe\n").unwrap(),
+            Property::try_from("EMAIL:not an address\n").unwrap(),
+            Property::try_from("NOTE:Never set\n").unwrap(),
+        ];
+        assert!(vcard.set_properties(&properties).is_err());
+        assert_eq!(vcard.export(), export);
+        assert!(events.0.lock().unwrap().is_empty());
+
+        let set = vcard.set_properties(&properties[..2]).unwrap();
+        assert_eq!(set.len(), 2);
+        assert_eq!(vcard.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:Johnny Doe\nNICKNAME:Johnny\nEND:VCARD\n");
+        let events = events.0.lock().unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[1], VcardEvent::PropertySet { before: Some(_), after } if *after == set[1]));
+    }
+
     #[test]
     pub fn vcard_property_operations() {
         let mut vcard = Vcard::new("John Doe");
@@ -418,4 +2188,36 @@ mod tests {
         // Test removing a fn property.
         assert!(Vcard::new("John Doe").remove_property(&vcard.get_property_by_name("FN").unwrap()).is_err());
     }
+
+    #[test]
+    pub fn vcard_cmp_by() {
+        let vcards = parse_vcards("BEGIN:VCARD\nVERSION:4.0\nFN:Doe\nORG:Zeta Inc.\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:doe\nORG:Alpha Inc.\nREV:20000101T000000Z\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Zed\nORG:Alpha Inc.\nEND:VCARD\n").unwrap();
+        let (a, b, c) = (&vcards[0], &vcards[1], &vcards[2]);
+
+        assert_eq!(a.cmp_by(b, SortBy::DisplayName), Ordering::Less);
+        assert_eq!(a.cmp_by(a, SortBy::DisplayName), Ordering::Equal);
+        assert_eq!(b.cmp_by(c, SortBy::DisplayName), Ordering::Less);
+
+        assert_eq!(a.cmp_by(b, SortBy::SortKey), Ordering::Greater);
+        assert_eq!(b.cmp_by(c, SortBy::SortKey), Ordering::Less);
+        assert_eq!(c.cmp_by(a, SortBy::SortKey), Ordering::Less);
+
+        assert_eq!(a.cmp_by(b, SortBy::Rev), Ordering::Less);
+        assert_eq!(a.cmp_by(c, SortBy::Rev), Ordering::Equal);
+    }
+
+    #[test]
+    pub fn vcard_nearest() {
+        let vcards = parse_vcards("BEGIN:VCARD\nVERSION:4.0\nFN:Both\nADR;GEO=\"geo:0,0\":;;;;;;\nGEO:geo:10,10\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Address\nADR;GEO=\"geo:1,1\":;;;;;;\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:None\nADR:;;;;;;\nEND:VCARD\n").unwrap();
+
+        assert_eq!(vcards[0].geo().map(|geo| geo.latitude), Some(10.0));
+        assert_eq!(vcards[1].geo().map(|geo| geo.latitude), Some(1.0));
+        assert!(vcards[2].geo().is_none());
+
+        let origin = ValueGeoData::from((0.0, 0.0));
+        assert_eq!(Vcard::nearest(&vcards, &origin).map(|(vcard, _)| vcard.display_name()), Some(String::from("Address")));
+        assert_eq!(Vcard::nearest(&vcards, &ValueGeoData::from((10.0, 10.0))).map(|(_, distance)| distance), Some(0.0));
+        assert!(Vcard::nearest(&vcards[2..], &origin).is_none());
+        assert!(Vcard::nearest(&[], &origin).is_none());
+    }
 }
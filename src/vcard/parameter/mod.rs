@@ -36,7 +36,8 @@
 use std::fmt::{Display, Formatter};
 
 use crate::constants::ParameterName;
-use crate::parse::value::utf8_to_string;
+use crate::parse::encoding::{decode_parameter_value, encode_parameter_value};
+use crate::parse::value::utf8_to_str;
 use crate::vcard::parameter::parameter_altid::ParameterAltIdData;
 use crate::vcard::parameter::parameter_calscale::ParameterCalScaleData;
 use crate::vcard::parameter::parameter_cc::ParameterCcData;
@@ -73,7 +74,7 @@ pub mod parameter_tz;
 pub mod parameter_value;
 pub mod parameter_xname;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub enum Parameter {
     /// Represents an ALTID parameter, see [RFC 6350 5.4](https://datatracker.ietf.org/doc/html/rfc6350#section-5.4).
     ParameterAltId(ParameterAltIdData),
@@ -111,7 +112,7 @@ pub enum Parameter {
 
 impl Parameter {
     pub fn default(name: &str) -> Self {
-        match name.to_uppercase().as_str() {
+        match ParameterName::intern(name).unwrap_or_default() {
             ParameterName::ALTID => Self::ParameterAltId(ParameterAltIdData::default()),
             ParameterName::CALSCALE => Self::ParameterCalScale(ParameterCalScaleData::default()),
             ParameterName::CC => Self::ParameterCc(ParameterCcData::default()),
@@ -136,21 +137,24 @@ impl TryFrom<&str> for Parameter {
     type Error = VcardError;
     fn try_from(str: &str) -> Result<Self, Self::Error> {
         let (_, (parameter_name, parameter_value)) = parse::parameter::parameter(str.as_bytes())?;
-        Parameter::try_from((utf8_to_string(parameter_name)?.as_str(), utf8_to_string(parameter_value)?.as_str()))
+        Parameter::try_from((utf8_to_str(parameter_name)?, utf8_to_str(parameter_value)?))
     }
 }
 
 impl TryFrom<(&[u8], &[u8])> for Parameter {
     type Error = VcardError;
     fn try_from((parameter_name, parameter_value): (&[u8], &[u8])) -> Result<Self, Self::Error> {
-        Parameter::try_from((utf8_to_string(parameter_name)?.as_str(), utf8_to_string(parameter_value)?.as_str()))
+        Parameter::try_from((utf8_to_str(parameter_name)?, utf8_to_str(parameter_value)?))
     }
 }
 
 impl TryFrom<(&str, &str)> for Parameter {
     type Error = VcardError;
-    fn try_from((parameter_name, parameter_value): (&str, &str)) -> Result<Self, Self::Error> {
-        match parameter_name.to_uppercase().as_str() {
+    fn try_from((parameter_name, written_value): (&str, &str)) -> Result<Self, Self::Error> {
+        let parameter_value = decode_parameter_value(written_value);
+        let parameter_value = parameter_value.as_str();
+
+        match ParameterName::intern(parameter_name).unwrap_or_default() {
             ParameterName::ALTID => Ok(Self::ParameterAltId(ParameterAltIdData::try_from(parameter_value)?)),
             ParameterName::CALSCALE => Ok(Self::ParameterCalScale(ParameterCalScaleData::try_from(parameter_value)?)),
             ParameterName::CC => Ok(Self::ParameterCc(ParameterCcData::try_from(parameter_value)?)),
@@ -162,7 +166,8 @@ impl TryFrom<(&str, &str)> for Parameter {
             ParameterName::MEDIATYPE => Ok(Self::ParameterMediaType(ParameterMediaTypeData::try_from(parameter_value)?)),
             ParameterName::PID => Ok(Self::ParameterPid(ParameterPidData::try_from(parameter_value)?)),
             ParameterName::PREF => Ok(Self::ParameterPref(ParameterPrefData::try_from(parameter_value)?)),
-            ParameterName::SORTAS => Ok(Self::ParameterSortAs(ParameterSortAsData::try_from(parameter_value)?)),
+            // Each component of SORT-AS may be quoted on its own, so the written value is split before decoding.
+            ParameterName::SORTAS => Ok(Self::ParameterSortAs(ParameterSortAsData::try_from(written_value)?)),
             ParameterName::TYPE => Ok(Self::ParameterType(ParameterTypeData::try_from(parameter_value)?)),
             ParameterName::TZ => Ok(Self::ParameterTz(ParameterTzData::try_from(parameter_value)?)),
             ParameterName::VALUE => Ok(Self::ParameterValue(ValueParameterData::try_from(parameter_value)?)),
@@ -173,7 +178,18 @@ impl TryFrom<(&str, &str)> for Parameter {
 
 impl Display for Parameter {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, ";{}={}", self.name(), self.get_value())
+        let value = match (self, self.get_value()) {
+            (Parameter::ParameterLabel(parameter), _) => parameter.to_string(),
+            (_, Value::ValueText(text)) => text.value.clone(),
+            (_, value) => value.to_string(),
+        };
+        let list = matches!(self.get_value(), Value::ValueTextList(_) | Value::ValuePid(_));
+
+        match self {
+            // The GEO parameter value is always quoted, see RFC 6350 5.10.
+            Parameter::ParameterGeo(_) => write!(f, ";{}=\"{}\"", self.name(), value),
+            _ => write!(f, ";{}={}", self.name(), encode_parameter_value(value.as_str(), list)),
+        }
     }
 }
 
@@ -246,7 +262,16 @@ impl HasValue for Parameter {
 
 #[cfg(test)]
 mod tests {
+    use crate::options::ParserOptions;
+    use crate::vcard::parameter::parameter_calscale::CalScale;
+    use crate::vcard::parameter::parameter_label::ParameterLabelData;
+    use crate::vcard::parameter::parameter_mediatype::MediaType;
     use crate::vcard::parameter::Parameter;
+    use crate::vcard::property::Property;
+    use crate::vcard::value::value_integer::ValueIntegerData;
+    use crate::vcard::value::value_textlist::ValueTextListData;
+    use crate::vcard::value::Value;
+    use crate::{HasParameters, HasValue};
 
     #[test]
     fn parameter_try_from() {
@@ -254,11 +279,13 @@ mod tests {
         assert!(Parameter::try_from(";CALSCALE=gregorian").is_ok());
         assert!(Parameter::try_from(";CC=us").is_ok());
         assert!(Parameter::try_from(";GEO=\"geo:0.0,-0.0\"").is_ok());
+        assert_eq!(Parameter::try_from(";GEO=\"geo:0.0,-0.0\"").unwrap().to_string(), ";GEO=\"geo:0.0,-0.0\"");
         assert!(Parameter::try_from(";INDEX=1").is_ok());
         assert!(Parameter::try_from(";LABEL=WORK").is_ok());
         assert!(Parameter::try_from(";LANGUAGE=en").is_ok());
-        assert!(Parameter::try_from(";LEVEL=1").is_ok());
-        assert!(Parameter::try_from(";MEDIATYPE=1").is_ok());
+        assert!(Parameter::try_from(";LEVEL=high").is_ok());
+        assert!(Parameter::try_from(";LEVEL=1").is_err());
+        assert!(Parameter::try_from(";MEDIATYPE=audio/ogg").is_ok());
         assert!(Parameter::try_from(";PID=1").is_ok());
         assert!(Parameter::try_from(";PREF=1").is_ok());
         assert!(Parameter::try_from(";SORT-AS=1").is_ok());
@@ -267,4 +294,213 @@ mod tests {
         assert!(Parameter::try_from(";VALUE=1").is_ok());
         assert!(Parameter::try_from(";X-VALUE=1").is_ok());
     }
+
+    #[test]
+    fn parameter_quoting() {
+        let roundtrip = |str: &str| Parameter::try_from(str).unwrap().to_string();
+
+        assert_eq!(roundtrip(";GEO=\"geo:37.386013,-122.082932\""), ";GEO=\"geo:37.386013,-122.082932\"");
+        assert_eq!(roundtrip(";LABEL=\"123 Main St.\\nAny Town, CA\""), ";LABEL=\"123 Main St.\\nAny Town, CA\"");
+        assert_eq!(roundtrip(";LABEL=\"123 Main St.\\nAny Town\""), ";LABEL=123 Main St.\\nAny Town");
+        assert_eq!(roundtrip(";TYPE=work,voice"), ";TYPE=work,voice");
+        assert_eq!(roundtrip(";TYPE=\"work,voice\""), ";TYPE=work,voice");
+        assert_eq!(roundtrip(";TYPE=\"x-a:b,voice\""), ";TYPE=\"x-a:b,voice\"");
+        assert_eq!(roundtrip(";TZ=-0500"), ";TZ=-0500");
+        assert_eq!(roundtrip(";TZ=\"-0500\""), ";TZ=-0500");
+        assert_eq!(roundtrip(";MEDIATYPE=\"text/plain; charset=utf-8\""), ";MEDIATYPE=\"text/plain; charset=utf-8\"");
+        assert_eq!(roundtrip(";X-NOTE=\"Say ^'hi^', ok\""), ";X-NOTE=\"Say ^'hi^', ok\"");
+
+        let Parameter::ParameterType(data) = Parameter::try_from(";TYPE=\"work,voice\"").unwrap() else { panic!("Expected TYPE parameter.") };
+        assert_eq!(
+            data.value,
+            Value::from(ValueTextListData {
+                delimiter: ',',
+                value: Vec::from([
+                    String::from("work"),
+                    String::from("voice")
+                ])
+            })
+        );
+
+        let property = Property::try_from("TEL;TYPE=\"work,voice\";X-NOTE=\"a:b\":+1-555-555-5555\n").unwrap();
+        assert_eq!(property.to_string(), "TEL;TYPE=work,voice;X-NOTE=\"a:b\":+1-555-555-5555\n");
+        assert_eq!(Property::try_from(property.to_string().as_str()).unwrap(), property);
+    }
+
+    #[test]
+    fn parameter_label() {
+        let property = Property::try_from("ADR;LABEL=\"Suite 100\\n123 Main St.\\nAny Town, CA\":;;123 Main St.;Any Town;CA;;\n").unwrap();
+        let Some(Parameter::ParameterLabel(label)) = property.get_parameters().into_iter().next() else { panic!("Missing LABEL parameter.") };
+        assert_eq!(
+            label.lines(),
+            [
+                "Suite 100",
+                "123 Main St.",
+                "Any Town, CA"
+            ]
+        );
+        assert_eq!(property.to_string(), "ADR;LABEL=\"Suite 100\\n123 Main St.\\nAny Town, CA\":;;123 Main St.;Any Town;CA;;\n");
+
+        let mut label = label.clone();
+        label.set_lines(&["Say \"hi\" ^_^", "Line\nbreak"]);
+        assert_eq!(label.lines(), ["Say \"hi\" ^_^", "Line break"]);
+        assert_eq!(Parameter::ParameterLabel(label.clone()).to_string(), ";LABEL=Say ^'hi^' ^^_^^\\nLine break");
+        assert_eq!(ParameterLabelData::try_from(label.to_string().as_str()).unwrap(), label);
+
+        let Parameter::ParameterLabel(label) = Parameter::try_from(";LABEL=One^nTwo\\NThree\\, Four").unwrap() else { panic!("Expected LABEL parameter.") };
+        assert_eq!(label.lines(), ["One", "Two", "Three, Four"]);
+        assert!(ParameterLabelData::default().lines().is_empty());
+    }
+
+    #[test]
+    fn parameter_pref_bounds() {
+        assert!(Parameter::try_from(";PREF=1").is_ok());
+        assert!(Parameter::try_from(";PREF=100").is_ok());
+        assert!(Parameter::try_from(";PREF=0").is_err());
+        assert!(Parameter::try_from(";PREF=101").is_err());
+        assert!(Parameter::try_from(";PREF=-1").is_err());
+        assert!(Property::try_from("TEL;PREF=200:555-5555\n").is_err());
+
+        let Parameter::ParameterPref(mut pref) = Parameter::try_from(";PREF=42").unwrap() else {
+            panic!("Expected a PREF parameter.");
+        };
+        assert_eq!(pref.pref(), 42);
+        assert!(pref.set_value(Value::from(ValueIntegerData::from(0))).is_err());
+        pref.value = Value::from(ValueIntegerData::from(500));
+        assert_eq!(pref.pref(), 100);
+    }
+
+    #[test]
+    fn parameter_calscale() {
+        let Parameter::ParameterCalScale(data) = Parameter::try_from(";CALSCALE=Gregorian").unwrap() else {
+            panic!("Expected a CALSCALE parameter.");
+        };
+        assert_eq!(data.calscale(), CalScale::Gregorian);
+        assert!(Parameter::try_from(";CALSCALE=x-chinese").is_ok());
+        assert!(Parameter::try_from(";CALSCALE=\"not a scale\"").is_err());
+        assert!(Parameter::try_from(";CALSCALE=").is_err());
+
+        let relaxed = ParserOptions::default();
+        let strict = ParserOptions {
+            strict: true,
+            ..ParserOptions::default()
+        };
+        for (text, valid) in [
+            ("BDAY;CALSCALE=gregorian:19700101\n", true),
+            ("BDAY;CALSCALE=X-HEBREW:19700101\n", true),
+            ("BDAY;CALSCALE=gregorain:19700101\n", false),
+        ] {
+            let property = Property::try_from(text).unwrap();
+            assert!(relaxed.validate(&property).is_ok());
+            assert_eq!(strict.validate(&property).is_ok(), valid);
+        }
+    }
+
+    #[test]
+    fn parameter_mediatype() {
+        let property = Property::try_from("PHOTO;MEDIATYPE=\"image/jpeg;q=0.9\":https://example.com/a.jpg\n").unwrap();
+        let mediatype = property.media_type().unwrap();
+        assert_eq!(mediatype.essence(), "image/jpeg");
+        assert_eq!(mediatype.parameter("q"), Some("0.9"));
+        assert_eq!(property.to_string(), "PHOTO;MEDIATYPE=\"image/jpeg;q=0.9\":https://example.com/a.jpg\n");
+        assert!(Property::try_from("PHOTO:https://example.com/a.jpg\n").unwrap().media_type().is_none());
+
+        let mediatype = MediaType::try_from("text/plain; charset=\"UTF-8\"").unwrap();
+        assert_eq!(
+            mediatype,
+            MediaType {
+                kind: String::from("text"),
+                subtype: String::from("plain"),
+                parameters: Vec::from([(String::from("charset"), String::from("UTF-8"))])
+            }
+        );
+        assert_eq!(MediaType::try_from("application/vnd.api+json").unwrap().subtype, "vnd.api+json");
+
+        for text in [
+            "1",
+            "image",
+            "image/",
+            "/jpeg",
+            "image/jpeg/x",
+            "image/jp eg",
+            "image/jpeg;q",
+            "image/jpeg;=1",
+            "image/jpeg;q=",
+        ] {
+            assert!(MediaType::try_from(text).is_err(), "{}", text);
+        }
+        assert!(Parameter::try_from(";MEDIATYPE=1").is_err());
+    }
+
+    #[test]
+    fn parameter_sortas() {
+        let components = |str: &str| match Property::try_from(str).unwrap().get_parameters().into_iter().next() {
+            Some(Parameter::ParameterSortAs(data)) => data.components(),
+            _ => panic!("Expected a SORT-AS parameter."),
+        };
+
+        assert_eq!(components("N;SORT-AS=\"Harten,Rene\":van der Harten;Rene;;;\n"), ["Harten", "Rene"]);
+        assert_eq!(components("N;SORT-AS=\"Harten\",\"Rene\":van der Harten;Rene;;;\n"), ["Harten", "Rene"]);
+        assert_eq!(components("N;SORT-AS=Harten,Rene:van der Harten;Rene;;;\n"), ["Harten", "Rene"]);
+        assert_eq!(components("N;SORT-AS=\"Harten: van der\",Rene:van der Harten;Rene;;;\n"), ["Harten: van der", "Rene"]);
+        assert_eq!(components("ORG;SORT-AS=ACME,,Sales:The ACME Corporation;Marketing;Sales\n"), ["ACME", "", "Sales"]);
+        assert_eq!(components("ORG;SORT-AS=^'ACME^':ACME\n"), ["\"ACME\""]);
+
+        let property = Property::try_from("N;SORT-AS=\"Harten\",\"Rene\":van der Harten;Rene;;;\n").unwrap();
+        assert_eq!(property.to_string(), "N;SORT-AS=Harten,Rene:van der Harten;Rene;;;\n");
+        assert_eq!(Property::try_from(property.to_string().as_str()).unwrap(), property);
+    }
+
+    #[test]
+    fn parameter_quoted_list() {
+        for (input, output) in [
+            ("TEL;TYPE=\"work\",\"voice\":555\n", "TEL;TYPE=work,voice:555\n"),
+            ("TEL;TYPE=\"work,voice\",cell:555\n", "TEL;TYPE=work,voice,cell:555\n"),
+            ("EMAIL;PID=\"1.1\",\"2\":john@example.com\n", "EMAIL;PID=1.1,2:john@example.com\n"),
+            ("EMAIL;X-A=\"a\",\"b\":john@example.com\n", "EMAIL;X-A=\"a,b\":john@example.com\n"),
+            ("EMAIL;X-A=\"a:b\",c:john@example.com\n", "EMAIL;X-A=\"a:b,c\":john@example.com\n"),
+            ("ADR;LABEL=\"1 Main St\",\"Springfield\":;;1 Main St;Springfield;;;\n", "ADR;LABEL=\"1 Main St,Springfield\":;;1 Main St;Springfield;;;\n"),
+        ] {
+            let property = Property::try_from(input).unwrap();
+            assert_eq!(property.to_string(), output);
+            assert_eq!(Property::try_from(property.to_string().as_str()).unwrap().to_string(), output);
+        }
+    }
+
+    #[test]
+    fn parameter_tz() {
+        let tz = |str: &str| match Parameter::try_from(str).unwrap() {
+            Parameter::ParameterTz(data) => data.value,
+            _ => panic!("Expected a TZ parameter."),
+        };
+
+        assert!(matches!(tz(";TZ=America/New_York"), Value::ValueText(_)));
+        assert!(matches!(tz(";TZ=-05:00"), Value::ValueUtcOffset(_)));
+        assert!(matches!(tz(";TZ=\"https://example.com/tz/America-New_York\""), Value::ValueUri(_)));
+        assert_eq!(Parameter::try_from(";TZ=\"https://example.com/tz/America-New_York\"").unwrap().to_string(), ";TZ=\"https://example.com/tz/America-New_York\"");
+
+        for (property, parameter) in [
+            ("TZ:America/New_York\n", ";TZ=America/New_York"),
+            ("TZ:+0500\n", ";TZ=+0500"),
+            ("TZ:https://example.com/tz\n", ";TZ=\"https://example.com/tz\""),
+        ] {
+            assert_eq!(Property::try_from(property).unwrap().get_value(), &tz(parameter));
+        }
+        assert!(Property::try_from("TZ;VALUE=utc-offset:America/New_York\n").is_err());
+
+        let mut parameter = Parameter::try_from(";TZ=UTC").unwrap();
+        assert!(parameter.set_value(Value::from(ValueIntegerData { value: 1 })).is_err());
+    }
+
+    #[test]
+    fn parameter_geo() {
+        let property = Property::try_from("ADR;GEO=\"geo:46.772673,-71.282945\":;;123 Main St.;Any Town;CA;;\n").unwrap();
+        let Some(Parameter::ParameterGeo(parameter)) = property.get_parameters().into_iter().next() else { panic!("Missing GEO parameter.") };
+        let Property::PropertyGeo(geo) = Property::try_from("GEO:geo:46.772673,-71.282945\n").unwrap() else { panic!("Expected a GEO property.") };
+        assert_eq!(parameter.geo(), geo.geo());
+        assert_eq!(parameter.geo().unwrap().latitude, 46.772673);
+
+        assert!(Parameter::try_from(";GEO=\"geo:91,0\"").is_err());
+        assert!(Property::try_from("GEO:geo:0,200\n").is_err());
+    }
 }
@@ -5,7 +5,7 @@ use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueInteger;
 use crate::{HasValue, VcardError};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ParameterAltIdData {
     pub value: Value,
 }
@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter};
+
 use crate::constants::ParameterName;
 use crate::traits::HasName;
 use crate::vcard::value::value_text::ValueTextData;
@@ -5,11 +7,63 @@ use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueText;
 use crate::{HasValue, VcardError};
 
-#[derive(Clone, Debug, PartialEq)]
+/// A calendar scale of the CALSCALE parameter, see [RFC 6350 5.8](https://datatracker.ietf.org/doc/html/rfc6350#section-5.8).
+///
+/// # Examples
+/// ```
+/// use vcard_parser::vcard::parameter::parameter_calscale::CalScale;
+///
+/// assert_eq!(CalScale::from("GREGORIAN"), CalScale::Gregorian);
+/// assert_eq!(CalScale::from("x-chinese"), CalScale::Other(String::from("x-chinese")));
+/// assert!(CalScale::from("x-chinese").is_extension());
+/// assert!(!CalScale::from("gregorain").is_extension());
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum CalScale {
+    /// The Gregorian calendar, the only registered scale.
+    Gregorian,
+    /// An iana-token or x-name scale.
+    Other(String),
+}
+
+impl CalScale {
+    /// Check if the scale is an x-name extension, strict options reject other unregistered scales as likely misspellings.
+    pub fn is_extension(&self) -> bool {
+        matches!(self, CalScale::Other(name) if name.len() > 2 && name[..2].eq_ignore_ascii_case("X-"))
+    }
+}
+
+impl From<&str> for CalScale {
+    fn from(str: &str) -> Self {
+        if str.eq_ignore_ascii_case("gregorian") {
+            CalScale::Gregorian
+        } else {
+            CalScale::Other(str.to_string())
+        }
+    }
+}
+
+impl Display for CalScale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CalScale::Gregorian => write!(f, "gregorian"),
+            CalScale::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ParameterCalScaleData {
     pub value: Value,
 }
 
+impl ParameterCalScaleData {
+    /// Get the calendar scale.
+    pub fn calscale(&self) -> CalScale {
+        CalScale::from(self.value.to_string().as_str())
+    }
+}
+
 impl HasName for ParameterCalScaleData {
     fn name(&self) -> &str {
         ParameterName::CALSCALE
@@ -26,8 +80,9 @@ impl HasValue for ParameterCalScaleData {
             return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
         }
 
+        // Only the registered scale, iana-tokens and x-names are allowed.
         if let ValueText(text) = &value {
-            if text.value != "gregorian" {
+            if text.value.is_empty() || !text.value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
                 return Err(VcardError::ValueInvalid(value.to_string(), self.name().to_string()));
             }
         }
@@ -49,8 +104,8 @@ impl Default for ParameterCalScaleData {
 impl TryFrom<&str> for ParameterCalScaleData {
     type Error = VcardError;
     fn try_from(str: &str) -> Result<Self, Self::Error> {
-        Ok(Self {
-            value: ValueText(ValueTextData::from(str)),
-        })
+        let mut data = Self::default();
+        data.set_value(ValueText(ValueTextData::from(str)))?;
+        Ok(data)
     }
 }
@@ -5,7 +5,7 @@ use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueText;
 use crate::{HasValue, VcardError};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ParameterCcData {
     pub value: Value,
 }
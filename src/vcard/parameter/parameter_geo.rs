@@ -1,15 +1,48 @@
-use crate::constants::ParameterName;
+use crate::constants::{ParameterName, PropertyUriSchemes};
 use crate::traits::HasName;
+use crate::vcard::value::value_geo::ValueGeoData;
 use crate::vcard::value::value_uri::ValueUriData;
 use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueUri;
 use crate::{HasValue, VcardError};
 
-#[derive(Clone, Debug, PartialEq)]
+/// The GEO parameter, a geo uri of the location of an address, see [RFC 6350 5.10](https://datatracker.ietf.org/doc/html/rfc6350#section-5.10).
+///
+/// # Examples
+/// ```
+/// use vcard_parser::vcard::parameter::parameter_geo::ParameterGeoData;
+/// use vcard_parser::vcard::parameter::Parameter;
+/// use vcard_parser::vcard::value::value_geo::ValueGeoData;
+///
+/// let Parameter::ParameterGeo(geo) = Parameter::try_from(";GEO=\"geo:46.77,-71.28\"").expect("Unable to parse parameter.") else { panic!() };
+/// assert_eq!(geo.geo(), Some(ValueGeoData::from((46.77, -71.28))));
+///
+/// let parameter = Parameter::ParameterGeo(ParameterGeoData::from(ValueGeoData::from((46.77, -71.28))));
+/// assert_eq!(parameter.to_string(), ";GEO=\"geo:46.77,-71.28\"");
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ParameterGeoData {
     pub value: Value,
 }
 
+impl ParameterGeoData {
+    /// Get the coordinates of the geo uri.
+    pub fn geo(&self) -> Option<ValueGeoData> {
+        match &self.value {
+            ValueUri(uri) => ValueGeoData::try_from(uri).ok(),
+            _ => None,
+        }
+    }
+}
+
+impl From<ValueGeoData> for ParameterGeoData {
+    fn from(geo: ValueGeoData) -> Self {
+        Self {
+            value: ValueUri(ValueUriData { value: geo.to_string() }),
+        }
+    }
+}
+
 impl HasName for ParameterGeoData {
     fn name(&self) -> &str {
         ParameterName::GEO
@@ -26,6 +59,11 @@ impl HasValue for ParameterGeoData {
             return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
         }
 
+        if let ValueUri(uri) = &value {
+            uri.validate_scheme(&PropertyUriSchemes::GEO, self.name())?;
+            ValueGeoData::try_from(uri)?;
+        }
+
         self.value = value;
 
         Ok(())
@@ -43,9 +81,8 @@ impl Default for ParameterGeoData {
 impl TryFrom<&str> for ParameterGeoData {
     type Error = VcardError;
     fn try_from(str: &str) -> Result<Self, Self::Error> {
-        // TODO: Remove trim when proper escaping is done.
-        Ok(Self {
-            value: ValueUri(ValueUriData::try_from(str.trim_matches(|c| c == '"'))?),
-        })
+        let mut data = Self::default();
+        data.set_value(ValueUri(ValueUriData::try_from(str)?))?;
+        Ok(data)
     }
 }
@@ -5,11 +5,27 @@ use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueInteger;
 use crate::{HasValue, VcardError};
 
-#[derive(Clone, Debug, PartialEq)]
+/// The INDEX parameter, a positive integer ordering multiple instances of a property, see [RFC 6715 3.1](https://datatracker.ietf.org/doc/html/rfc6715#section-3.1).
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ParameterIndexData {
     pub value: Value,
 }
 
+impl ParameterIndexData {
+    /// Get the index, values below 1 are clamped.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::parameter::parameter_index::ParameterIndexData;
+    ///
+    /// assert_eq!(ParameterIndexData::try_from("3").expect("Unable to parse parameter.").index(), 3);
+    /// assert!(ParameterIndexData::try_from("0").is_err());
+    /// ```
+    pub fn index(&self) -> i64 {
+        self.value.as_integer().unwrap_or(1).max(1)
+    }
+}
+
 impl HasName for ParameterIndexData {
     fn name(&self) -> &str {
         ParameterName::INDEX
@@ -26,6 +42,10 @@ impl HasValue for ParameterIndexData {
             return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
         }
 
+        if value.as_integer().is_some_and(|index| index < 1) {
+            return Err(VcardError::ValueInvalid(value.to_string(), self.name().to_string()));
+        }
+
         self.value = value;
 
         Ok(())
@@ -43,8 +63,8 @@ impl Default for ParameterIndexData {
 impl TryFrom<&str> for ParameterIndexData {
     type Error = VcardError;
     fn try_from(str: &str) -> Result<Self, Self::Error> {
-        Ok(Self {
-            value: ValueInteger(ValueIntegerData::try_from(str)?),
-        })
+        let mut data = Self::default();
+        data.set_value(ValueInteger(ValueIntegerData::try_from(str)?))?;
+        Ok(data)
     }
 }
@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter};
+
 use crate::constants::ParameterName;
 use crate::traits::HasName;
 use crate::vcard::value::value_text::ValueTextData;
@@ -5,11 +7,46 @@ use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueText;
 use crate::{HasValue, VcardError};
 
-#[derive(Clone, Debug, PartialEq)]
+/// The LABEL parameter of an ADR property, holding the formatted address, see [RFC 6350 6.3.1](https://datatracker.ietf.org/doc/html/rfc6350#section-6.3.1).
+///
+/// The value is kept unescaped, with lines separated by a line feed. Lines are written separated by "\n", and the
+/// parameter is quoted when the value contains a semicolon, colon or comma.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::vcard::parameter::parameter_label::ParameterLabelData;
+/// use vcard_parser::vcard::parameter::Parameter;
+///
+/// let mut label = ParameterLabelData::try_from("\"Mr. John Q. Public, Esq.\\n123 Main Street\\nAny Town, CA  91921-1234\"").expect("Unable to parse label.");
+/// assert_eq!(label.lines(), ["Mr. John Q. Public, Esq.", "123 Main Street", "Any Town, CA  91921-1234"]);
+///
+/// label.set_lines(&["123 Main Street", "Any Town"]);
+/// assert_eq!(label.to_string(), "123 Main Street\\nAny Town");
+///
+/// label.set_lines(&["Suite 100; 123 Main Street", "Any Town"]);
+/// assert_eq!(Parameter::ParameterLabel(label).to_string(), ";LABEL=\"Suite 100; 123 Main Street\\nAny Town\"");
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ParameterLabelData {
     pub value: Value,
 }
 
+impl ParameterLabelData {
+    /// Get the lines of the formatted address.
+    pub fn lines(&self) -> Vec<&str> {
+        match &self.value {
+            ValueText(text) if !text.value.is_empty() => text.value.split('\n').collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Set the lines of the formatted address, line breaks within a line are replaced with spaces.
+    pub fn set_lines(&mut self, lines: &[&str]) {
+        let lines: Vec<String> = lines.iter().map(|line| line.replace("\r\n", " ").replace(['\r', '\n'], " ")).collect();
+        self.value = ValueText(ValueTextData { value: lines.join("\n") });
+    }
+}
+
 impl HasName for ParameterLabelData {
     fn name(&self) -> &str {
         ParameterName::LABEL
@@ -40,11 +77,38 @@ impl Default for ParameterLabelData {
     }
 }
 
+impl Display for ParameterLabelData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let text = match &self.value {
+            ValueText(text) => text.value.as_str(),
+            _ => "",
+        };
+
+        write!(f, "{}", text.replace('\\', "\\\\").replace('\n', "\\n"))
+    }
+}
+
 impl TryFrom<&str> for ParameterLabelData {
     type Error = VcardError;
     fn try_from(str: &str) -> Result<Self, Self::Error> {
+        let str = str.strip_prefix('"').and_then(|str| str.strip_suffix('"')).unwrap_or(str);
+
+        let mut value = String::with_capacity(str.len());
+        let mut chars = str.chars().peekable();
+        while let Some(char) = chars.next() {
+            match (char, chars.peek()) {
+                ('\\', Some('n' | 'N')) => value.push('\n'),
+                ('\\', Some(escaped @ ('\\' | ',' | ';'))) => value.push(*escaped),
+                _ => {
+                    value.push(char);
+                    continue;
+                }
+            }
+            chars.next();
+        }
+
         Ok(Self {
-            value: ValueText(ValueTextData::from(str)),
+            value: ValueText(ValueTextData { value }),
         })
     }
 }
@@ -1,15 +1,93 @@
-use crate::constants::ParameterName;
+use std::fmt::{Display, Formatter};
+
+use crate::constants::{ParameterName, PropertyExpertiseValues, PropertyHobbyValues};
 use crate::traits::HasName;
 use crate::vcard::value::value_text::ValueTextData;
 use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueText;
 use crate::{HasValue, VcardError};
 
-#[derive(Clone, Debug, PartialEq)]
+/// A value of the LEVEL parameter, see [RFC 6715 3.2](https://datatracker.ietf.org/doc/html/rfc6715#section-3.2).
+///
+/// EXPERTISE properties use beginner, average and expert, while HOBBY and INTEREST properties use low, medium and high.
+/// The level set of a property is enforced when the parameter is added to it.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::vcard::parameter::parameter_level::Level;
+/// use vcard_parser::vcard::property::Property;
+///
+/// let property = Property::try_from("EXPERTISE;LEVEL=Expert:chemistry\n").expect("Unable to parse property.");
+/// assert_eq!(property.level(), Some(Level::Expert));
+/// assert!(Level::Expert.is_expertise());
+/// assert!(Property::try_from("HOBBY;LEVEL=expert:reading\n").is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Level {
+    Beginner,
+    Average,
+    Expert,
+    Low,
+    Medium,
+    High,
+}
+
+impl Level {
+    /// Check if the level is one of the EXPERTISE levels, otherwise it is a HOBBY and INTEREST level.
+    pub fn is_expertise(&self) -> bool {
+        matches!(self, Level::Beginner | Level::Average | Level::Expert)
+    }
+}
+
+impl TryFrom<&str> for Level {
+    type Error = VcardError;
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        match str.to_uppercase().as_str() {
+            PropertyExpertiseValues::BEGINNER => Ok(Level::Beginner),
+            PropertyExpertiseValues::AVERAGE => Ok(Level::Average),
+            PropertyExpertiseValues::EXPERT => Ok(Level::Expert),
+            PropertyHobbyValues::LOW => Ok(Level::Low),
+            PropertyHobbyValues::MEDIUM => Ok(Level::Medium),
+            PropertyHobbyValues::HIGH => Ok(Level::High),
+            _ => Err(VcardError::ValueInvalid(str.to_string(), ParameterName::LEVEL.to_string())),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Level::Beginner => PropertyExpertiseValues::BEGINNER,
+            Level::Average => PropertyExpertiseValues::AVERAGE,
+            Level::Expert => PropertyExpertiseValues::EXPERT,
+            Level::Low => PropertyHobbyValues::LOW,
+            Level::Medium => PropertyHobbyValues::MEDIUM,
+            Level::High => PropertyHobbyValues::HIGH,
+        };
+        write!(f, "{}", str.to_lowercase())
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ParameterLevelData {
     pub value: Value,
 }
 
+impl ParameterLevelData {
+    /// Get the level, or None if the value was set directly to an unknown level.
+    pub fn level(&self) -> Option<Level> {
+        Level::try_from(self.value.as_str()?).ok()
+    }
+}
+
+impl From<Level> for ParameterLevelData {
+    fn from(level: Level) -> Self {
+        Self {
+            value: ValueText(ValueTextData::from(level.to_string().as_str())),
+        }
+    }
+}
+
 impl HasName for ParameterLevelData {
     fn name(&self) -> &str {
         ParameterName::LEVEL
@@ -26,6 +104,8 @@ impl HasValue for ParameterLevelData {
             return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
         }
 
+        Level::try_from(value.as_str().unwrap_or_default())?;
+
         self.value = value;
 
         Ok(())
@@ -43,8 +123,8 @@ impl Default for ParameterLevelData {
 impl TryFrom<&str> for ParameterLevelData {
     type Error = VcardError;
     fn try_from(str: &str) -> Result<Self, Self::Error> {
-        Ok(Self {
-            value: ValueText(ValueTextData::from(str)),
-        })
+        let mut data = Self::default();
+        data.set_value(ValueText(ValueTextData::from(str)))?;
+        Ok(data)
     }
 }
@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter};
+
 use crate::constants::ParameterName;
 use crate::traits::HasName;
 use crate::vcard::value::value_text::ValueTextData;
@@ -5,11 +7,100 @@ use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueText;
 use crate::{HasValue, VcardError};
 
-#[derive(Clone, Debug, PartialEq)]
+/// A media type of the MEDIATYPE parameter, see [RFC 6350 5.7](https://datatracker.ietf.org/doc/html/rfc6350#section-5.7) and [RFC 6838 4.2](https://datatracker.ietf.org/doc/html/rfc6838#section-4.2).
+///
+/// The type, subtype and parameter names are case-insensitive and kept in lowercase, parameter values are kept as is.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::vcard::parameter::parameter_mediatype::MediaType;
+///
+/// let mediatype = MediaType::try_from("Image/JPEG; q=0.9").expect("Unable to parse media type.");
+/// assert_eq!(mediatype.kind, "image");
+/// assert_eq!(mediatype.subtype, "jpeg");
+/// assert_eq!(mediatype.essence(), "image/jpeg");
+/// assert_eq!(mediatype.parameter("Q"), Some("0.9"));
+/// assert_eq!(mediatype.to_string(), "image/jpeg;q=0.9");
+/// assert!(MediaType::try_from("image").is_err());
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct MediaType {
+    /// The top-level type, e.g. "image".
+    pub kind: String,
+    /// The subtype, e.g. "jpeg".
+    pub subtype: String,
+    /// The parameters as name and value pairs, e.g. "charset" and "utf-8".
+    pub parameters: Vec<(String, String)>,
+}
+
+impl MediaType {
+    /// Get the type and subtype without parameters, e.g. "image/jpeg".
+    pub fn essence(&self) -> String {
+        format!("{}/{}", self.kind, self.subtype)
+    }
+
+    /// Get the value of a parameter, the name is compared case-insensitively.
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        self.parameters.iter().find(|(other, _)| other.eq_ignore_ascii_case(name)).map(|(_, value)| value.as_str())
+    }
+}
+
+impl TryFrom<&str> for MediaType {
+    type Error = VcardError;
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        let malformed = || VcardError::ValueMalformed(str.to_string());
+
+        let mut parts = str.split(';');
+        let (kind, subtype) = parts.next().and_then(|essence| essence.trim().split_once('/')).ok_or_else(malformed)?;
+        if !is_restricted_name(kind) || !is_restricted_name(subtype) {
+            return Err(malformed());
+        }
+
+        let mut parameters = Vec::new();
+        for parameter in parts {
+            let (name, value) = parameter.trim().split_once('=').ok_or_else(malformed)?;
+            let value = value.strip_prefix('"').and_then(|value| value.strip_suffix('"')).unwrap_or(value);
+            if !is_restricted_name(name) || value.is_empty() || value.chars().any(|c| c.is_control()) {
+                return Err(malformed());
+            }
+            parameters.push((name.to_ascii_lowercase(), value.to_string()));
+        }
+
+        Ok(Self {
+            kind: kind.to_ascii_lowercase(),
+            subtype: subtype.to_ascii_lowercase(),
+            parameters,
+        })
+    }
+}
+
+impl Display for MediaType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.kind, self.subtype)?;
+        for (name, value) in self.parameters.iter() {
+            write!(f, ";{}={}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Helper function checking a restricted name of RFC 6838, which starts with a letter or digit.
+fn is_restricted_name(str: &str) -> bool {
+    str.len() <= 127 && str.starts_with(|c: char| c.is_ascii_alphanumeric()) && str.chars().all(|c| c.is_ascii_alphanumeric() || "!#$&-^_.+".contains(c))
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ParameterMediaTypeData {
     pub value: Value,
 }
 
+impl ParameterMediaTypeData {
+    /// Get the media type, or None if the value was set directly to a malformed media type.
+    pub fn media_type(&self) -> Option<MediaType> {
+        MediaType::try_from(self.value.as_str()?).ok()
+    }
+}
+
 impl HasName for ParameterMediaTypeData {
     fn name(&self) -> &str {
         ParameterName::MEDIATYPE
@@ -26,6 +117,8 @@ impl HasValue for ParameterMediaTypeData {
             return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
         }
 
+        MediaType::try_from(value.as_str().unwrap_or_default())?;
+
         self.value = value;
 
         Ok(())
@@ -43,8 +136,8 @@ impl Default for ParameterMediaTypeData {
 impl TryFrom<&str> for ParameterMediaTypeData {
     type Error = VcardError;
     fn try_from(str: &str) -> Result<Self, Self::Error> {
-        Ok(Self {
-            value: ValueText(ValueTextData::from(str)),
-        })
+        let mut data = Self::default();
+        data.set_value(ValueText(ValueTextData { value: str.to_string() }))?;
+        Ok(data)
     }
 }
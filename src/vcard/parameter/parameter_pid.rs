@@ -5,7 +5,7 @@ use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValuePid;
 use crate::{HasValue, VcardError};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ParameterPidData {
     pub value: Value,
 }
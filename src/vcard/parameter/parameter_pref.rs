@@ -5,11 +5,28 @@ use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueInteger;
 use crate::{HasValue, VcardError};
 
-#[derive(Clone, Debug, PartialEq)]
+/// The PREF parameter, an integer between 1 and 100 where 1 is the most preferred, see [RFC 6350 5.3](https://datatracker.ietf.org/doc/html/rfc6350#section-5.3).
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ParameterPrefData {
     pub value: Value,
 }
 
+impl ParameterPrefData {
+    /// Get the preference, lowest first when sorted, values outside of 1 to 100 are clamped.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::parameter::parameter_pref::ParameterPrefData;
+    ///
+    /// assert_eq!(ParameterPrefData::try_from("20").expect("Unable to parse parameter.").pref(), 20);
+    /// assert!(ParameterPrefData::try_from("0").is_err());
+    /// assert!(ParameterPrefData::try_from("101").is_err());
+    /// ```
+    pub fn pref(&self) -> u8 {
+        self.value.as_integer().map(|pref| pref.clamp(1, 100) as u8).unwrap_or(100)
+    }
+}
+
 impl HasName for ParameterPrefData {
     fn name(&self) -> &str {
         ParameterName::PREF
@@ -49,8 +66,8 @@ impl Default for ParameterPrefData {
 impl TryFrom<&str> for ParameterPrefData {
     type Error = VcardError;
     fn try_from(str: &str) -> Result<Self, Self::Error> {
-        Ok(Self {
-            value: ValueInteger(ValueIntegerData::try_from(str)?),
-        })
+        let mut data = Self::default();
+        data.set_value(ValueInteger(ValueIntegerData::try_from(str)?))?;
+        Ok(data)
     }
 }
@@ -1,15 +1,52 @@
 use crate::constants::ParameterName;
+use crate::parse::encoding::{decode_parameter_value, unescape};
 use crate::traits::HasName;
 use crate::vcard::value::value_textlist::ValueTextListData;
 use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueTextList;
 use crate::{HasValue, VcardError};
 
-#[derive(Clone, Debug, PartialEq)]
+/// The SORT-AS parameter, see [RFC 6350 5.9](https://datatracker.ietf.org/doc/html/rfc6350#section-5.9).
+///
+/// The components pair up with the components of the property: on N the first component sorts by family name and the
+/// second by given name, on ORG the first sorts by organization name and the rest by unit names. Components are
+/// separated by commas, and may be quoted as a whole or each on its own.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::vcard::parameter::parameter_sortas::ParameterSortAsData;
+///
+/// let sort_as = ParameterSortAsData::try_from("\"Harten\",\"Rene\"").expect("Unable to parse SORT-AS.");
+/// assert_eq!(sort_as.components(), ["Harten", "Rene"]);
+/// assert_eq!(ParameterSortAsData::try_from("\"Harten,Rene\"").expect("Unable to parse SORT-AS."), sort_as);
+/// assert_eq!(ParameterSortAsData::from(Vec::from([String::from("Harten"), String::from("Rene")])), sort_as);
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ParameterSortAsData {
     pub value: Value,
 }
 
+impl ParameterSortAsData {
+    /// Get the components in order, paired with the components of the N or ORG property.
+    pub fn components(&self) -> Vec<String> {
+        match &self.value {
+            ValueTextList(list) => list.value.clone(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl From<Vec<String>> for ParameterSortAsData {
+    fn from(components: Vec<String>) -> Self {
+        Self {
+            value: ValueTextList(ValueTextListData {
+                delimiter: ',',
+                value: components,
+            }),
+        }
+    }
+}
+
 impl HasName for ParameterSortAsData {
     fn name(&self) -> &str {
         ParameterName::SORTAS
@@ -43,8 +80,24 @@ impl Default for ParameterSortAsData {
 impl TryFrom<&str> for ParameterSortAsData {
     type Error = VcardError;
     fn try_from(str: &str) -> Result<Self, Self::Error> {
-        Ok(Self {
-            value: ValueTextList(ValueTextListData::from((str, ','))),
-        })
+        // Quotes never appear inside a written component, a double quote is written as ^'.
+        let mut components = Vec::new();
+        let mut component = String::new();
+        let mut chars = str.chars().filter(|char| *char != '"');
+        while let Some(char) = chars.next() {
+            match char {
+                '\\' => {
+                    component.push(char);
+                    if let Some(next) = chars.next() {
+                        component.push(next);
+                    }
+                }
+                ',' => components.push(unescape(decode_parameter_value(std::mem::take(&mut component).as_str()).as_str())),
+                _ => component.push(char),
+            }
+        }
+        components.push(unescape(decode_parameter_value(component.as_str()).as_str()));
+
+        Ok(Self::from(components))
     }
 }
@@ -1,11 +1,23 @@
 use crate::constants::ParameterName;
 use crate::traits::HasName;
-use crate::vcard::value::value_utcoffset::ValueUtcOffsetData;
+use crate::vcard::property::property_tz::tz_value;
+use crate::vcard::value::value_text::ValueTextData;
 use crate::vcard::value::Value;
-use crate::vcard::value::Value::ValueUtcOffset;
+use crate::vcard::value::Value::{ValueText, ValueUri, ValueUtcOffset};
 use crate::{HasValue, VcardError};
 
-#[derive(Clone, Debug, PartialEq)]
+/// The TZ parameter, a time zone name, uri or utc-offset converted like the value of the TZ property, see [RFC 6350 5.11](https://datatracker.ietf.org/doc/html/rfc6350#section-5.11).
+///
+/// # Examples
+/// ```
+/// use vcard_parser::vcard::parameter::parameter_tz::ParameterTzData;
+/// use vcard_parser::vcard::value::Value;
+///
+/// assert!(matches!(ParameterTzData::try_from("America/New_York").unwrap().value, Value::ValueText(_)));
+/// assert!(matches!(ParameterTzData::try_from("-0500").unwrap().value, Value::ValueUtcOffset(_)));
+/// assert!(matches!(ParameterTzData::try_from("https://example.com/tz/America-New_York").unwrap().value, Value::ValueUri(_)));
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ParameterTzData {
     pub value: Value,
 }
@@ -22,7 +34,7 @@ impl HasValue for ParameterTzData {
     }
 
     fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        if !matches!(value, ValueUtcOffset(_)) {
+        if !matches!(value, ValueText(_) | ValueUri(_) | ValueUtcOffset(_)) {
             return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
         }
 
@@ -35,7 +47,7 @@ impl HasValue for ParameterTzData {
 impl Default for ParameterTzData {
     fn default() -> Self {
         Self {
-            value: ValueUtcOffset(ValueUtcOffsetData::default()),
+            value: ValueText(ValueTextData::default()),
         }
     }
 }
@@ -43,8 +55,6 @@ impl Default for ParameterTzData {
 impl TryFrom<&str> for ParameterTzData {
     type Error = VcardError;
     fn try_from(str: &str) -> Result<Self, Self::Error> {
-        Ok(Self {
-            value: ValueUtcOffset(ValueUtcOffsetData::try_from(str)?),
-        })
+        Ok(Self { value: tz_value(None, str)? })
     }
 }
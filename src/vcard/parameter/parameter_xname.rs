@@ -3,7 +3,7 @@ use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueText;
 use crate::{HasName, HasValue, VcardError};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct XNameParameterData {
     pub name: String,
     pub value: Value,
@@ -45,7 +45,7 @@ impl TryFrom<(&str, &str)> for XNameParameterData {
     fn try_from((name, value): (&str, &str)) -> Result<Self, Self::Error> {
         Ok(Self {
             name: name.to_string(),
-            value: ValueText(ValueTextData::from(value)),
+            value: ValueText(ValueTextData { value: value.to_string() }),
         })
     }
 }
@@ -35,201 +35,547 @@
 //! assert_eq!(property.get_value().to_string(), "Johnny Be Good");
 //! ```
 
-use std::fmt::{Debug, Display, Formatter};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter, Write};
 
-use crate::constants::{ParameterName, PropertyName};
-use crate::parse::value::utf8_to_string;
+use crate::constants::{ParameterName, PropertyName, ValueType};
+use crate::options::ParameterStyle;
+use crate::parse::value::{utf8_to_str, utf8_to_string};
 use crate::parse::PropertyData;
 use crate::traits::HasGroup;
+use crate::vcard::parameter::parameter_index::ParameterIndexData;
+use crate::vcard::parameter::parameter_level::Level;
+use crate::vcard::parameter::parameter_pid::ParameterPidData;
 use crate::vcard::parameter::Parameter;
-use crate::vcard::property::property_adr::PropertyAdrData;
-use crate::vcard::property::property_anniversary::PropertyAnniversaryData;
-use crate::vcard::property::property_bday::PropertyBDayData;
-use crate::vcard::property::property_birthplace::PropertyBirthPlaceData;
-use crate::vcard::property::property_caladruri::PropertyCalAdrUriData;
-use crate::vcard::property::property_caluri::PropertyCalUriData;
-use crate::vcard::property::property_categories::PropertyCategoriesData;
-use crate::vcard::property::property_clientpidmap::PropertyClientPidMapData;
-use crate::vcard::property::property_contacturi::PropertyContactUriData;
-use crate::vcard::property::property_deathdate::PropertyDeathDateData;
-use crate::vcard::property::property_deathplace::PropertyDeathPlaceData;
-use crate::vcard::property::property_email::PropertyEmailData;
-use crate::vcard::property::property_expertise::PropertyExpertiseData;
-use crate::vcard::property::property_fburl::PropertyFbUrlData;
-use crate::vcard::property::property_fn::PropertyFnData;
-use crate::vcard::property::property_gender::PropertyGenderData;
-use crate::vcard::property::property_geo::PropertyGeoData;
-use crate::vcard::property::property_hobby::PropertyHobbyData;
-use crate::vcard::property::property_impp::PropertyImppData;
-use crate::vcard::property::property_interest::PropertyInterestData;
-use crate::vcard::property::property_key::PropertyKeyData;
-use crate::vcard::property::property_kind::PropertyKindData;
-use crate::vcard::property::property_lang::PropertyLangData;
-use crate::vcard::property::property_logo::PropertyLogoData;
-use crate::vcard::property::property_member::PropertyMemberData;
-use crate::vcard::property::property_n::PropertyNData;
-use crate::vcard::property::property_nickname::PropertyNickNameData;
-use crate::vcard::property::property_note::PropertyNoteData;
-use crate::vcard::property::property_org::PropertyOrgData;
-use crate::vcard::property::property_orgdirectory::PropertyOrgDirectoryData;
-use crate::vcard::property::property_photo::PropertyPhotoData;
-use crate::vcard::property::property_prodid::PropertyProdIdData;
-use crate::vcard::property::property_related::PropertyRelatedData;
-use crate::vcard::property::property_rev::PropertyRevData;
-use crate::vcard::property::property_role::PropertyRoleData;
-use crate::vcard::property::property_sound::PropertySoundData;
-use crate::vcard::property::property_source::PropertySourceData;
-use crate::vcard::property::property_tel::PropertyTelData;
-use crate::vcard::property::property_title::PropertyTitleData;
-use crate::vcard::property::property_tz::PropertyTzData;
-use crate::vcard::property::property_uid::PropertyUidData;
-use crate::vcard::property::property_url::PropertyUrlData;
-use crate::vcard::property::property_xml::PropertyXmlData;
-use crate::vcard::property::property_xname::PropertyXNameData;
-use crate::vcard::value::Value;
-use crate::vcard::value::Value::ValuePid;
+use crate::vcard::property::property_adr::AddressParts;
+use crate::vcard::value::value_float::ValueFloatData;
+use crate::vcard::value::value_integer::ValueIntegerData;
+use crate::vcard::value::value_pid::{Pid, ValuePidData};
+use crate::vcard::value::value_text::ValueTextData;
+use crate::vcard::value::value_textlist::ValueTextListData;
+use crate::vcard::value::value_uri::ValueUriData;
+use crate::vcard::value::Value::{ValueClientPidMap, ValueFloat, ValueLanguageTag, ValuePid, ValueText, ValueTextList, ValueTimestamp, ValueUri, ValueUtcOffset};
+use crate::vcard::value::{EmptyValue, Value};
+use crate::vcard::Vcard;
 use crate::{parse, HasCardinality, HasName, HasParameters, HasValue, VcardError};
 
-pub mod property_adr;
-pub mod property_anniversary;
-pub mod property_bday;
-pub mod property_birthplace;
-pub mod property_caladruri;
-pub mod property_caluri;
-pub mod property_categories;
-pub mod property_clientpidmap;
-pub mod property_contacturi;
-pub mod property_deathdate;
-pub mod property_deathplace;
-pub mod property_email;
-pub mod property_expertise;
-pub mod property_fburl;
-pub mod property_fn;
-pub mod property_gender;
-pub mod property_geo;
-pub mod property_hobby;
-pub mod property_impp;
-pub mod property_interest;
-pub mod property_key;
-pub mod property_kind;
-pub mod property_lang;
-pub mod property_logo;
-pub mod property_member;
-pub mod property_n;
-pub mod property_nickname;
-pub mod property_note;
-pub mod property_org;
-pub mod property_orgdirectory;
-pub mod property_photo;
-pub mod property_prodid;
-pub mod property_related;
-pub mod property_rev;
-pub mod property_role;
-pub mod property_sound;
-pub mod property_source;
-pub mod property_tel;
-pub mod property_title;
-pub mod property_tz;
-pub mod property_uid;
-pub mod property_url;
-pub mod property_xml;
-pub mod property_xname;
-
-#[derive(Clone, Debug)]
-pub enum Property {
+/// Implemented by all property data types, used for dispatching trait methods from [`Property`].
+trait PropertyDataTrait: HasCardinality + HasGroup + HasParameters + HasValue {}
+
+impl<T: HasCardinality + HasGroup + HasParameters + HasValue> PropertyDataTrait for T {}
+
+/// The allowed parameters and values of a property, see [`PropertySpec::for_name`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PropertySpec {
+    /// The property name, e.g. TEL.
+    pub name: &'static str,
+    /// The property cardinality, see [Cardinality](crate::constants::Cardinality).
+    pub cardinality: &'static str,
+    /// Allowed parameter names, ANY allows any parameter. X-name parameters are always allowed.
+    pub parameters: &'static [&'static str],
+    /// Allowed values, in the order they are tried when parsing a value without a VALUE parameter.
+    pub values: &'static [ValueSpec],
+    /// Allowed LEVEL parameter values, or None if LEVEL values aren't restricted.
+    pub levels: Option<&'static [&'static str]>,
+}
+
+impl PropertySpec {
+    /// Check if a parameter is allowed for the property.
+    pub fn allows_parameter(&self, name: &str) -> bool {
+        name.get(..2).is_some_and(|prefix| prefix.eq_ignore_ascii_case("X-")) || self.parameters.contains(&ParameterName::ANY) || ParameterName::intern(name).is_some_and(|name| self.parameters.contains(&name))
+    }
+
+    /// Get the value of a [default property](Property::default).
+    ///
+    /// Values are empty, except for KIND which defaults to individual as per [RFC 6350 6.1.4](https://datatracker.ietf.org/doc/html/rfc6350#section-6.1.4)
+    /// and REV which defaults to the current time. GENDER defaults to an empty sex component, no gender is assumed.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::PropertySpec;
+    ///
+    /// assert_eq!(PropertySpec::for_name("KIND").expect("Unknown property.").default_value().to_string(), "individual");
+    /// assert_eq!(PropertySpec::for_name("TZ").expect("Unknown property.").default_value().to_string(), "");
+    /// assert_eq!(PropertySpec::for_name("GENDER").expect("Unknown property.").default_value().to_string(), "");
+    /// ```
+    pub fn default_value(&self) -> Value {
+        Property::default(self.name).get_value().clone()
+    }
+
+    /// Check if a value is allowed for the property, by [value name](crate::constants::ValueName).
+    pub fn allows_value(&self, name: &str) -> bool {
+        self.values.iter().any(|value| value.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Get the [value name](crate::constants::ValueName) a value is parsed as, by the type of its VALUE parameter.
+    ///
+    /// Without a VALUE parameter the first allowed value is tried first. Returns None if no allowed value is checked
+    /// against the VALUE parameter type.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::PropertySpec;
+    ///
+    /// let spec = PropertySpec::for_name("BDAY").expect("Unknown property.");
+    /// assert_eq!(spec.value_name(None), Some("TEXT"));
+    /// assert_eq!(spec.value_name(Some("date-and-or-time")), Some("DATE"));
+    /// assert_eq!(spec.value_name(Some("uri")), None);
+    /// ```
+    pub fn value_name(&self, value_type: Option<&str>) -> Option<&'static str> {
+        let value = match value_type {
+            Some(value_type) => self.values.iter().find(|value| value.value_types.is_some_and(|types| types.iter().any(|allowed| allowed.eq_ignore_ascii_case(value_type)))),
+            None => self.values.first(),
+        };
+        value.map(|value| value.name)
+    }
+}
+
+/// An allowed value of a property, see [`PropertySpec`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValueSpec {
+    /// The value name, see [ValueName](crate::constants::ValueName).
+    pub name: &'static str,
+    /// VALUE parameter types the value may be used with, or None if the VALUE parameter isn't checked.
+    pub value_types: Option<&'static [&'static str]>,
+}
+
+/// Helper macro returning the value name of a value variant.
+macro_rules! value_name {
+    (ValueBoolean) => {
+        $crate::constants::ValueName::BOOLEAN
+    };
+    (ValueClientPidMap) => {
+        $crate::constants::ValueName::CLIENTPIDMAP
+    };
+    (ValueDate) => {
+        $crate::constants::ValueName::DATE
+    };
+    (ValueFloat) => {
+        $crate::constants::ValueName::FLOAT
+    };
+    (ValueInteger) => {
+        $crate::constants::ValueName::INTEGER
+    };
+    (ValueLanguageTag) => {
+        $crate::constants::ValueName::LANGUAGE_TAG
+    };
+    (ValueListComponent) => {
+        $crate::constants::ValueName::LISTCOMPONENT
+    };
+    (ValuePid) => {
+        $crate::constants::ValueName::PID
+    };
+    (ValueText) => {
+        $crate::constants::ValueName::TEXT
+    };
+    (ValueTextList) => {
+        $crate::constants::ValueName::TEXTLIST
+    };
+    (ValueTimestamp) => {
+        $crate::constants::ValueName::TIMESTAMP
+    };
+    (ValueUri) => {
+        $crate::constants::ValueName::URI
+    };
+    (ValueUtcOffset) => {
+        $crate::constants::ValueName::UTCOFFSET
+    };
+}
+
+/// Helper macro wrapping an optional macro argument in an option.
+macro_rules! optional {
+    () => {
+        None
+    };
+    ($e:expr) => {
+        Some($e)
+    };
+}
+
+/// Defines a property data type from its name, cardinality, allowed parameters, and allowed values.
+///
+/// Each entry in values is an allowed value variant with the VALUE parameter types it may be used with, entries without
+/// types accept any VALUE parameter. The optional levels array restricts LEVEL parameters, and the optional validate
+/// function is called with the property name and value after the value type checks.
+///
+/// ```text
+/// define_property! {
+///     PropertyTzData {
+///         name: TZ,
+///         cardinality: MULTIPLE,
+///         parameters: [ALTID, ANY, INDEX, MEDIATYPE, PID, PREF, TYPE, VALUE],
+///         values: [ValueText => [TEXT], ValueUtcOffset => [UTC_OFFSET], ValueUri => [URI]],
+///         default: ValueText(ValueTextData::default()),
+///     }
+/// }
+/// ```
+macro_rules! define_property {
+    (
+        $data:ident {
+            name: $name:ident,
+            cardinality: $cardinality:ident,
+            parameters: [$($parameter:ident),* $(,)?],
+            values: [$($value:ident $(=> [$($value_type:ident),* $(,)?])?),* $(,)?],
+            default: $default:expr,
+            $(levels: $levels:expr,)?
+            $(validate: $validate:path,)?
+        }
+    ) => {
+        #[derive(Clone, Debug, Hash, PartialEq, Eq)]
+        pub struct $data {
+            group: Option<String>,
+            parameters: Vec<$crate::vcard::parameter::Parameter>,
+            value: $crate::vcard::value::Value,
+        }
+
+        impl $data {
+            /// The allowed parameters and values of the property.
+            pub const SPEC: $crate::vcard::property::PropertySpec = $crate::vcard::property::PropertySpec {
+                name: $crate::constants::PropertyName::$name,
+                cardinality: $crate::constants::Cardinality::$cardinality,
+                parameters: &[$($crate::constants::ParameterName::$parameter),*],
+                values: &[$($crate::vcard::property::ValueSpec {
+                    name: value_name!($value),
+                    value_types: optional!($(&[$($crate::constants::ValueType::$value_type),*])?),
+                }),*],
+                levels: optional!($(&$levels)?),
+            };
+        }
+
+        impl $crate::traits::HasCardinality for $data {
+            fn cardinality(&self) -> &str {
+                Self::SPEC.cardinality
+            }
+        }
+
+        impl $crate::traits::HasGroup for $data {
+            fn group(&self) -> &Option<String> {
+                &self.group
+            }
+        }
+
+        impl $crate::traits::HasName for $data {
+            fn name(&self) -> &str {
+                $crate::constants::PropertyName::$name
+            }
+        }
+
+        impl $crate::traits::HasParameters for $data {
+            fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
+                Self::SPEC.parameters.to_vec()
+            }
+
+            fn get_parameters(&self) -> Vec<$crate::vcard::parameter::Parameter> {
+                self.parameters.clone()
+            }
+
+            fn set_parameters(&mut self, parameters: Vec<$crate::vcard::parameter::Parameter>) {
+                self.parameters = parameters;
+            }
+
+            $(
+                fn add_parameter(&mut self, parameter: $crate::vcard::parameter::Parameter) -> Result<(), $crate::VcardError> {
+                    use $crate::traits::HasName;
+
+                    if let $crate::vcard::parameter::Parameter::ParameterLevel(data) = &parameter {
+                        if let $crate::vcard::value::Value::ValueText(text) = &data.value {
+                            if !$levels.contains(&text.value.to_uppercase().as_str()) {
+                                return Err($crate::VcardError::ValueInvalid(data.value.to_string(), self.name().to_string()));
+                            }
+                        }
+                    }
+
+                    if !self.allowed_parameters().contains(&parameter.name()) && !matches!(parameter, $crate::vcard::parameter::Parameter::ParameterXName(_)) && !self.allowed_parameters().contains(&$crate::constants::ParameterName::ANY) {
+                        return Err($crate::VcardError::ParameterTypeNotAllowed(parameter.to_string().trim_start_matches(';').to_string(), self.name().to_string(), self.group.clone()));
+                    }
+
+                    self.parameters.push(parameter);
+
+                    Ok(())
+                }
+            )?
+        }
+
+        impl $crate::traits::HasValue for $data {
+            fn get_value(&self) -> &$crate::vcard::value::Value {
+                &self.value
+            }
+
+            fn set_value(&mut self, value: $crate::vcard::value::Value) -> Result<(), $crate::VcardError> {
+                use $crate::traits::{HasName, HasParameters};
+
+                if !($(matches!(value, $crate::vcard::value::Value::$value(_)))||*) {
+                    return Err($crate::VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
+                }
+
+                #[allow(unused_variables)]
+                if let Some(value_type) = self.has_value_type() {
+                    $($(
+                        if matches!(value, $crate::vcard::value::Value::$value(_)) && ![$($crate::constants::ValueType::$value_type),*].iter().any(|allowed| allowed.eq_ignore_ascii_case(value_type.as_str())) {
+                            return Err($crate::VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
+                        }
+                    )?)*
+                }
+
+                $($validate(self.name(), &value)?;)?
+
+                self.value = value;
+
+                Ok(())
+            }
+        }
+
+        impl Default for $data {
+            fn default() -> Self {
+                Self {
+                    group: None,
+                    parameters: Vec::new(),
+                    value: $default,
+                }
+            }
+        }
+    };
+}
+
+/// Declares the property modules and generates the [`Property`] enum with name matching and dispatch.
+///
+/// Each registered property is a module with a data type implementing `TryFrom<(Option<String>, &str, Vec<Parameter>)>`
+/// and `Default`, listed with its property name. The next entry is used for any unregistered names, and the last entry
+/// is the raw property, which is only created explicitly.
+macro_rules! properties {
+    ($($(#[$doc:meta])* $variant:ident($module:ident::$data:ident) => $name:ident as $kind:ident,)* ; $(#[$xdoc:meta])* $xvariant:ident($xmodule:ident::$xdata:ident) as $xkind:ident, $(#[$rdoc:meta])* $rvariant:ident($rmodule:ident::$rdata:ident) as $rkind:ident,) => {
+        $(pub mod $module;)*
+        pub mod $xmodule;
+        pub mod $rmodule;
+
+        $(use crate::vcard::property::$module::$data;)*
+        use crate::vcard::property::$xmodule::$xdata;
+        use crate::vcard::property::$rmodule::$rdata;
+
+        #[derive(Clone, Debug, Hash, PartialEq, Eq)]
+        pub enum Property {
+            $($(#[$doc])* $variant($data),)*
+            $(#[$xdoc])* $xvariant($xdata),
+            $(#[$rdoc])* $rvariant($rdata),
+        }
+
+        /// The kind of a [Property] without its data, for typed lookups, see [`Vcard::properties_of`](crate::vcard::Vcard::properties_of).
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub enum PropertyKind {
+            $($(#[$doc])* $kind,)*
+            $(#[$xdoc])* $xkind,
+            $(#[$rdoc])* $rkind,
+        }
+
+        impl PropertyKind {
+            /// Get the kind of a property name, names of unregistered properties are x-name and iana-token properties.
+            ///
+            /// # Examples
+            /// ```
+            /// use vcard_parser::vcard::property::PropertyKind;
+            ///
+            /// assert_eq!(PropertyKind::from_name("email"), PropertyKind::Email);
+            /// assert_eq!(PropertyKind::from_name("X-CUSTOM"), PropertyKind::XName);
+            /// assert_eq!(PropertyKind::Email.name(), Some("EMAIL"));
+            /// assert_eq!(PropertyKind::XName.name(), None);
+            /// ```
+            pub fn from_name(name: &str) -> Self {
+                match PropertyName::intern(name).unwrap_or_default() {
+                    $(PropertyName::$name => PropertyKind::$kind,)*
+                    _ => PropertyKind::$xkind,
+                }
+            }
+
+            /// Get the name of the kind, or None for x-name, iana-token and raw properties.
+            pub fn name(&self) -> Option<&'static str> {
+                match self {
+                    $(PropertyKind::$kind => Some(PropertyName::$name),)*
+                    PropertyKind::$xkind | PropertyKind::$rkind => None,
+                }
+            }
+        }
+
+        impl Property {
+            /// Get the kind of the property.
+            pub fn kind(&self) -> PropertyKind {
+                match self {
+                    $(Property::$variant(_) => PropertyKind::$kind,)*
+                    Property::$xvariant(_) => PropertyKind::$xkind,
+                    Property::$rvariant(_) => PropertyKind::$rkind,
+                }
+            }
+
+            /// Helper function creating a property by matching the name against registered properties.
+            fn create_registered(group: Option<String>, name: &str, parameters: Vec<Parameter>, value: &str) -> Result<Self, VcardError> {
+                match PropertyName::intern(name).unwrap_or_default() {
+                    $(PropertyName::$name => Ok(Property::$variant($data::try_from((group, value, parameters))?)),)*
+                    _ => Ok(Property::$xvariant($xdata::try_from((group, name, value, parameters))?)),
+                }
+            }
+
+            /// Helper function creating a default property by matching the name against registered properties.
+            fn default_registered(name: &str) -> Self {
+                match PropertyName::intern(name).unwrap_or_default() {
+                    $(PropertyName::$name => Property::$variant($data::default()),)*
+                    _ => Property::$xvariant($xdata::default(name)),
+                }
+            }
+
+            /// Get the allowed parameters and values of the property, or None for x-name, iana-token and raw properties.
+            pub fn spec(&self) -> Option<&'static PropertySpec> {
+                match self {
+                    $(Property::$variant(_) => Some(&$data::SPEC),)*
+                    Property::$xvariant(_) | Property::$rvariant(_) => None,
+                }
+            }
+
+            /// Helper function returning the inner property data.
+            fn data(&self) -> &dyn PropertyDataTrait {
+                match self {
+                    $(Property::$variant(data) => data,)*
+                    Property::$xvariant(data) => data,
+                    Property::$rvariant(data) => data,
+                }
+            }
+
+            /// Helper function returning the inner property data mutably.
+            fn data_mut(&mut self) -> &mut dyn PropertyDataTrait {
+                match self {
+                    $(Property::$variant(data) => data,)*
+                    Property::$xvariant(data) => data,
+                    Property::$rvariant(data) => data,
+                }
+            }
+        }
+
+        impl PropertySpec {
+            /// Specs of all registered properties.
+            pub const ALL: &'static [&'static PropertySpec] = &[$(&$data::SPEC),*];
+
+            /// Get the spec of a registered property by name, or None for x-name and iana-token properties.
+            ///
+            /// # Examples
+            /// ```
+            /// use vcard_parser::constants::{Cardinality, ValueName};
+            /// use vcard_parser::vcard::property::PropertySpec;
+            ///
+            /// let spec = PropertySpec::for_name("tel").expect("Unknown property.");
+            /// assert_eq!(spec.cardinality, Cardinality::MULTIPLE);
+            /// assert!(spec.allows_parameter("TYPE"));
+            /// assert!(spec.allows_value(ValueName::URI));
+            /// assert!(PropertySpec::for_name("X-CUSTOM").is_none());
+            /// ```
+            pub fn for_name(name: &str) -> Option<&'static PropertySpec> {
+                match PropertyName::intern(name).unwrap_or_default() {
+                    $(PropertyName::$name => Some(&$data::SPEC),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+properties! {
     /// Represents an ADR parameter, see [RFC 6350 6.3.1](https://datatracker.ietf.org/doc/html/rfc6350#section-6.3.1).
-    PropertyAdr(PropertyAdrData),
+    PropertyAdr(property_adr::PropertyAdrData) => ADR as Adr,
     /// Represents an ANNIVERSARY parameter, see [RFC 6350 6.2.6](https://datatracker.ietf.org/doc/html/rfc6350#section-6.2.6).
-    PropertyAnniversary(PropertyAnniversaryData),
+    PropertyAnniversary(property_anniversary::PropertyAnniversaryData) => ANNIVERSARY as Anniversary,
     /// Represents an BDAY parameter, see [RFC 6350 6.2.5](https://datatracker.ietf.org/doc/html/rfc6350#section-6.2.5).
-    PropertyBDay(PropertyBDayData),
+    PropertyBDay(property_bday::PropertyBDayData) => BDAY as BDay,
     /// Represents an BIRTHPLACE parameter, see [RFC 6474 2.1](https://datatracker.ietf.org/doc/html/rfc6474#section-2.1).
-    PropertyBirthPlace(PropertyBirthPlaceData),
+    PropertyBirthPlace(property_birthplace::PropertyBirthPlaceData) => BIRTHPLACE as BirthPlace,
     /// Represents an CALADRURI parameter, see [RFC 6350 6.9.2](https://datatracker.ietf.org/doc/html/rfc6350#section-6.9.2).
-    PropertyCalAdrUri(PropertyCalAdrUriData),
+    PropertyCalAdrUri(property_caladruri::PropertyCalAdrUriData) => CALADRURI as CalAdrUri,
     /// Represents an CALURI parameter, see [RFC 6350 6.9.3](https://datatracker.ietf.org/doc/html/rfc6350#section-6.9.3).
-    PropertyCalUri(PropertyCalUriData),
+    PropertyCalUri(property_caluri::PropertyCalUriData) => CALURI as CalUri,
     /// Represents an CATEGORIES parameter, see [RFC 6350 6.7.1](https://datatracker.ietf.org/doc/html/rfc6350#section-6.7.1).
-    PropertyCategories(PropertyCategoriesData),
+    PropertyCategories(property_categories::PropertyCategoriesData) => CATEGORIES as Categories,
     /// Represents an CLIENTPIDMAP parameter, see [RFC 6350 6.7.1](https://datatracker.ietf.org/doc/html/rfc6350#section-6.7.1).
-    PropertyClientPidMap(PropertyClientPidMapData),
+    PropertyClientPidMap(property_clientpidmap::PropertyClientPidMapData) => CLIENTPIDMAP as ClientPidMap,
     /// Represents an CONTACT parameter, see [RFC 8605 2.1](https://datatracker.ietf.org/doc/html/rfc8605#section-2.1).
-    PropertyContactUri(PropertyContactUriData),
+    PropertyContactUri(property_contacturi::PropertyContactUriData) => CONTACTURI as ContactUri,
     /// Represents an DEATHDATE parameter, see [RFC 6474 2.3](https://datatracker.ietf.org/doc/html/rfc6474#section-2.3).
-    PropertyDeathDate(PropertyDeathDateData),
+    PropertyDeathDate(property_deathdate::PropertyDeathDateData) => DEATHDATE as DeathDate,
     /// Represents an DEATHPLACE parameter, see [RFC 6474 2.2](https://datatracker.ietf.org/doc/html/rfc6474#section-2.2).
-    PropertyDeathPlace(PropertyDeathPlaceData),
+    PropertyDeathPlace(property_deathplace::PropertyDeathPlaceData) => DEATHPLACE as DeathPlace,
     /// Represents an EMAIL parameter, see [RFC 6350 6.4.2](https://datatracker.ietf.org/doc/html/rfc6350#section-6.4.2).
-    PropertyEmail(PropertyEmailData),
+    PropertyEmail(property_email::PropertyEmailData) => EMAIL as Email,
     /// Represents an EXPERTISE parameter, see [RFC 6715 2.1](https://datatracker.ietf.org/doc/html/rfc6715#section-2.1).
-    PropertyExpertise(PropertyExpertiseData),
+    PropertyExpertise(property_expertise::PropertyExpertiseData) => EXPERTISE as Expertise,
     /// Represents an FBURL parameter, see [RFC 6350 6.9.1](https://datatracker.ietf.org/doc/html/rfc6350#section-6.9.1).
-    PropertyFbUrl(PropertyFbUrlData),
+    PropertyFbUrl(property_fburl::PropertyFbUrlData) => FBURL as FbUrl,
     /// Represents an FN parameter, see [RFC 6350 6.2.1](https://datatracker.ietf.org/doc/html/rfc6350#section-6.2.1).
-    PropertyFn(PropertyFnData),
+    PropertyFn(property_fn::PropertyFnData) => FN as Fn,
     /// Represents an GENDER parameter, see [RFC 6350 6.2.7](https://datatracker.ietf.org/doc/html/rfc6350#section-6.2.7).
-    PropertyGender(PropertyGenderData),
+    PropertyGender(property_gender::PropertyGenderData) => GENDER as Gender,
     /// Represents an GEO parameter, see [RFC 6350 6.5.2](https://datatracker.ietf.org/doc/html/rfc6350#section-6.5.2).
-    PropertyGeo(PropertyGeoData),
+    PropertyGeo(property_geo::PropertyGeoData) => GEO as Geo,
     /// Represents an HOBBY parameter, see [RFC 6715 2.2](https://datatracker.ietf.org/doc/html/rfc6715#section-2.2).
-    PropertyHobby(PropertyHobbyData),
+    PropertyHobby(property_hobby::PropertyHobbyData) => HOBBY as Hobby,
     /// Represents an IMPP parameter, see [RFC 6350 6.4.3](https://datatracker.ietf.org/doc/html/rfc6350#section-6.4.3).
-    PropertyImpp(PropertyImppData),
+    PropertyImpp(property_impp::PropertyImppData) => IMPP as Impp,
     /// Represents an INTEREST parameter, see [RFC 6715 2.3](https://datatracker.ietf.org/doc/html/rfc6715#section-2.3).
-    PropertyInterest(PropertyInterestData),
+    PropertyInterest(property_interest::PropertyInterestData) => INTEREST as Interest,
     /// Represents an KEY parameter, see [RFC 6350 6.8.1](https://datatracker.ietf.org/doc/html/rfc6350#section-6.8.1).
-    PropertyKey(PropertyKeyData),
+    PropertyKey(property_key::PropertyKeyData) => KEY as Key,
     /// Represents an KIND parameter, see [RFC 6350 6.1.4](https://datatracker.ietf.org/doc/html/rfc6350#section-6.1.4).
-    PropertyKind(PropertyKindData),
+    PropertyKind(property_kind::PropertyKindData) => KIND as Kind,
     /// Represents an LANG parameter, see [RFC 6350 6.4.4](https://datatracker.ietf.org/doc/html/rfc6350#section-6.4.4).
-    PropertyLang(PropertyLangData),
+    PropertyLang(property_lang::PropertyLangData) => LANG as Lang,
     /// Represents an LOGO parameter, see [RFC 6350 6.6.3](https://datatracker.ietf.org/doc/html/rfc6350#section-6.6.3).
-    PropertyLogo(PropertyLogoData),
+    PropertyLogo(property_logo::PropertyLogoData) => LOGO as Logo,
     /// Represents an MEMBER parameter, see [RFC 6350 6.6.5](https://datatracker.ietf.org/doc/html/rfc6350#section-6.6.5).
-    PropertyMember(PropertyMemberData),
+    PropertyMember(property_member::PropertyMemberData) => MEMBER as Member,
     /// Represents an NICKNAME parameter, see [RFC 6350 6.2.3](https://datatracker.ietf.org/doc/html/rfc6350#section-6.2.3).
-    PropertyNickName(PropertyNickNameData),
+    PropertyNickName(property_nickname::PropertyNickNameData) => NICKNAME as NickName,
     /// Represents an NOTE parameter, see [RFC 6350 6.7.2](https://datatracker.ietf.org/doc/html/rfc6350#section-6.7.2).
-    PropertyNote(PropertyNoteData),
+    PropertyNote(property_note::PropertyNoteData) => NOTE as Note,
     /// Represents an N parameter, see [RFC 6350 6.2.2](https://datatracker.ietf.org/doc/html/rfc6350#section-6.2.2).
-    PropertyN(PropertyNData),
+    PropertyN(property_n::PropertyNData) => N as N,
     /// Represents an ORG-DIRECTORY parameter, see [RFC 6715 2.4](https://datatracker.ietf.org/doc/html/rfc6715#section-2.4).
-    PropertyOrgDirectory(PropertyOrgDirectoryData),
+    PropertyOrgDirectory(property_orgdirectory::PropertyOrgDirectoryData) => ORGDIRECTORY as OrgDirectory,
     /// Represents an ORG parameter, see [RFC 6350 6.6.4](https://datatracker.ietf.org/doc/html/rfc6350#section-6.6.4).
-    PropertyOrg(PropertyOrgData),
+    PropertyOrg(property_org::PropertyOrgData) => ORG as Org,
     /// Represents an PHOTO parameter, see [RFC 6350 6.2.4](https://datatracker.ietf.org/doc/html/rfc6350#section-6.2.4).
-    PropertyPhoto(PropertyPhotoData),
+    PropertyPhoto(property_photo::PropertyPhotoData) => PHOTO as Photo,
     /// Represents an PRODID parameter, see [RFC 6350 6.7.3](https://datatracker.ietf.org/doc/html/rfc6350#section-6.7.3).
-    PropertyProdId(PropertyProdIdData),
+    PropertyProdId(property_prodid::PropertyProdIdData) => PRODID as ProdId,
     /// Represents an RELATED parameter, see [RFC 6350 6.6.6](https://datatracker.ietf.org/doc/html/rfc6350#section-6.6.6).
-    PropertyRelated(PropertyRelatedData),
+    PropertyRelated(property_related::PropertyRelatedData) => RELATED as Related,
     /// Represents an REV parameter, see [RFC 6350 6.7.4](https://datatracker.ietf.org/doc/html/rfc6350#section-6.7.4).
-    PropertyRev(PropertyRevData),
+    PropertyRev(property_rev::PropertyRevData) => REV as Rev,
     /// Represents an ROLE parameter, see [RFC 6350 6.6.2](https://datatracker.ietf.org/doc/html/rfc6350#section-6.6.2).
-    PropertyRole(PropertyRoleData),
+    PropertyRole(property_role::PropertyRoleData) => ROLE as Role,
     /// Represents an SOUND parameter, see [RFC 6350 6.7.5](https://datatracker.ietf.org/doc/html/rfc6350#section-6.7.5).
-    PropertySound(PropertySoundData),
+    PropertySound(property_sound::PropertySoundData) => SOUND as Sound,
     /// Represents an SOURCE parameter, see [RFC 6350 6.1.3](https://datatracker.ietf.org/doc/html/rfc6350#section-6.1.3).
-    PropertySource(PropertySourceData),
+    PropertySource(property_source::PropertySourceData) => SOURCE as Source,
     /// Represents an TEL parameter, see [RFC 6350 6.4.1](https://datatracker.ietf.org/doc/html/rfc6350#section-6.4.1).
-    PropertyTel(PropertyTelData),
+    PropertyTel(property_tel::PropertyTelData) => TEL as Tel,
     /// Represents an TITLE parameter, see [RFC 6350 6.6.1](https://datatracker.ietf.org/doc/html/rfc6350#section-6.6.1).
-    PropertyTitle(PropertyTitleData),
+    PropertyTitle(property_title::PropertyTitleData) => TITLE as Title,
     /// Represents an TZ parameter, see [RFC 6350 6.5.1](https://datatracker.ietf.org/doc/html/rfc6350#section-6.5.1).
-    PropertyTz(PropertyTzData),
+    PropertyTz(property_tz::PropertyTzData) => TZ as Tz,
     /// Represents an UID parameter, see [RFC 6350 6.7.6](https://datatracker.ietf.org/doc/html/rfc6350#section-6.5.1).
-    PropertyUid(PropertyUidData),
+    PropertyUid(property_uid::PropertyUidData) => UID as Uid,
     /// Represents an URL parameter, see [RFC 6350 6.7.8](https://datatracker.ietf.org/doc/html/rfc6350#section-6.7.8).
-    PropertyUrl(PropertyUrlData),
+    PropertyUrl(property_url::PropertyUrlData) => URL as Url,
     /// Represents an XML parameter, see [RFC 6350 6.1.5](https://datatracker.ietf.org/doc/html/rfc6350#section-6.1.5).
-    PropertyXml(PropertyXmlData),
+    PropertyXml(property_xml::PropertyXmlData) => XML as Xml,
+    ;
     /// Represents an XNAME parameter, see [RFC 6350 3.3](https://datatracker.ietf.org/doc/html/rfc6350#section-3.3).
-    PropertyXName(PropertyXNameData),
+    PropertyXName(property_xname::PropertyXNameData) as XName,
+    /// Represents a property kept as written, see [PropertyRawData](property_raw::PropertyRawData).
+    PropertyRaw(property_raw::PropertyRawData) as Raw,
 }
 
 impl Property {
     /// Create a new property from required information (group, name, parameters, and value).
     ///
+    /// Empty values are handled by the [empty value policy](EmptyValue) of the value type, properties treated as unset
+    /// fail with [VcardError::ValueEmpty].
+    ///
     /// # Examples
     /// ```
     /// use vcard_parser::traits::HasValue;
@@ -245,56 +591,25 @@ impl Property {
     /// assert_eq!(property.export(), "FN:John Doe\n");
     /// ```
     pub fn create((property_group, property_name, property_parameters, property_value): (Option<String>, &str, Vec<Parameter>, &str)) -> Result<Self, VcardError> {
-        match property_name.to_uppercase().as_str() {
-            PropertyName::ADR => Ok(Property::PropertyAdr(PropertyAdrData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::ANNIVERSARY => Ok(Property::PropertyAnniversary(PropertyAnniversaryData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::BDAY => Ok(Property::PropertyBDay(PropertyBDayData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::BIRTHPLACE => Ok(Property::PropertyBirthPlace(PropertyBirthPlaceData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::CALADRURI => Ok(Property::PropertyCalAdrUri(PropertyCalAdrUriData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::CALURI => Ok(Property::PropertyCalUri(PropertyCalUriData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::CATEGORIES => Ok(Property::PropertyCategories(PropertyCategoriesData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::CLIENTPIDMAP => Ok(Property::PropertyClientPidMap(PropertyClientPidMapData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::CONTACTURI => Ok(Property::PropertyContactUri(PropertyContactUriData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::DEATHDATE => Ok(Property::PropertyDeathDate(PropertyDeathDateData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::DEATHPLACE => Ok(Property::PropertyDeathPlace(PropertyDeathPlaceData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::EMAIL => Ok(Property::PropertyEmail(PropertyEmailData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::EXPERTISE => Ok(Property::PropertyExpertise(PropertyExpertiseData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::FBURL => Ok(Property::PropertyFbUrl(PropertyFbUrlData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::FN => Ok(Property::PropertyFn(PropertyFnData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::GENDER => Ok(Property::PropertyGender(PropertyGenderData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::GEO => Ok(Property::PropertyGeo(PropertyGeoData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::HOBBY => Ok(Property::PropertyHobby(PropertyHobbyData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::IMPP => Ok(Property::PropertyImpp(PropertyImppData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::INTEREST => Ok(Property::PropertyInterest(PropertyInterestData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::KEY => Ok(Property::PropertyKey(PropertyKeyData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::KIND => Ok(Property::PropertyKind(PropertyKindData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::LANG => Ok(Property::PropertyLang(PropertyLangData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::LOGO => Ok(Property::PropertyLogo(PropertyLogoData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::MEMBER => Ok(Property::PropertyMember(PropertyMemberData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::NICKNAME => Ok(Property::PropertyNickName(PropertyNickNameData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::NOTE => Ok(Property::PropertyNote(PropertyNoteData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::N => Ok(Property::PropertyN(PropertyNData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::ORGDIRECTORY => Ok(Property::PropertyOrgDirectory(PropertyOrgDirectoryData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::ORG => Ok(Property::PropertyOrg(PropertyOrgData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::PHOTO => Ok(Property::PropertyPhoto(PropertyPhotoData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::PRODID => Ok(Property::PropertyProdId(PropertyProdIdData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::RELATED => Ok(Property::PropertyRelated(PropertyRelatedData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::REV => Ok(Property::PropertyRev(PropertyRevData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::ROLE => Ok(Property::PropertyRole(PropertyRoleData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::SOUND => Ok(Property::PropertySound(PropertySoundData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::SOURCE => Ok(Property::PropertySource(PropertySourceData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::TEL => Ok(Property::PropertyTel(PropertyTelData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::TITLE => Ok(Property::PropertyTitle(PropertyTitleData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::TZ => Ok(Property::PropertyTz(PropertyTzData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::UID => Ok(Property::PropertyUid(PropertyUidData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::URL => Ok(Property::PropertyUrl(PropertyUrlData::try_from((property_group, property_value, property_parameters))?)),
-            PropertyName::XML => Ok(Property::PropertyXml(PropertyXmlData::try_from((property_group, property_value, property_parameters))?)),
-            _ => Ok(Property::PropertyXName(PropertyXNameData::try_from((property_group, property_name, property_value, property_parameters))?)),
+        if property_value.is_empty() {
+            let value_type = property_parameters.iter().find(|parameter| matches!(parameter, Parameter::ParameterValue(_))).map(|parameter| parameter.get_value().to_string());
+            let value_name = PropertySpec::for_name(property_name).and_then(|spec| spec.value_name(value_type.as_deref()));
+            if value_name.map(EmptyValue::for_value) == Some(EmptyValue::Unset) {
+                return Err(VcardError::ValueEmpty(property_name.to_uppercase()));
+            }
         }
+
+        Self::create_registered(property_group, property_name, property_parameters, property_value)
     }
 
-    pub fn create_from_data(((group, name), parameters, (value, folds)): PropertyData) -> Result<Self, VcardError> {
-        let property_name = utf8_to_string(name)?;
+    pub fn create_from_data(data: PropertyData) -> Result<Self, VcardError> {
+        Self::create_from_data_with(data, false).map(|(property, _)| property)
+    }
+
+    /// Helper function creating a property from parsed data, returning the parameters dropped because the property
+    /// doesn't allow them if drop_disallowed is set, see [lenient](crate::options::ParserOptions::lenient) parsing.
+    pub(crate) fn create_from_data_with(((group, name), parameters, (value, folds)): PropertyData, drop_disallowed: bool) -> Result<(Self, Vec<Parameter>), VcardError> {
+        let property_name = utf8_to_str(name)?;
 
         let property_group = {
             if let Some(data) = group {
@@ -304,21 +619,31 @@ impl Property {
             }
         };
 
-        let mut property_parameters: Vec<Parameter> = Vec::new();
+        let mut property_parameters: Vec<Parameter> = Vec::with_capacity(parameters.len());
+        let mut dropped = Vec::new();
         for datum in parameters {
-            property_parameters.push(Parameter::try_from(datum)?)
+            let parameter = Parameter::try_from(datum)?;
+            match PropertySpec::for_name(property_name) {
+                Some(spec) if drop_disallowed && !spec.allows_parameter(parameter.name()) => dropped.push(parameter),
+                _ => property_parameters.push(parameter),
+            }
         }
 
-        let mut property_value = Vec::from([utf8_to_string(value)?]);
-        if let Some(v) = folds {
-            for u in v {
-                if let Ok(string) = utf8_to_string(u) {
-                    property_value.push(string);
+        // Unfold the value into a single string, borrowing the value when it isn't folded.
+        let first = utf8_to_str(value)?;
+        let property_value = match folds {
+            Some(folds) if !folds.is_empty() => {
+                let mut string = String::with_capacity(first.len() + folds.iter().map(|u| u.len()).sum::<usize>());
+                string.push_str(first);
+                for str in folds.iter().filter_map(|u| utf8_to_str(u).ok()) {
+                    string.push_str(str);
                 }
+                Cow::Owned(string)
             }
-        }
+            _ => Cow::Borrowed(first),
+        };
 
-        Self::create((property_group, property_name.as_str(), property_parameters, property_value.join("").as_str()))
+        Ok((Self::create((property_group, property_name, property_parameters, property_value.as_ref()))?, dropped))
     }
 
     pub fn create_from_str(str: &str) -> Result<Self, VcardError> {
@@ -342,51 +667,404 @@ impl Property {
     /// assert_eq!(property.export(), "FN:John Doe\n");
     /// ```
     pub fn default(name: &str) -> Self {
-        match name.to_uppercase().as_str() {
-            PropertyName::ADR => Property::PropertyAdr(PropertyAdrData::default()),
-            PropertyName::ANNIVERSARY => Property::PropertyAnniversary(PropertyAnniversaryData::default()),
-            PropertyName::BDAY => Property::PropertyBDay(PropertyBDayData::default()),
-            PropertyName::BIRTHPLACE => Property::PropertyBirthPlace(PropertyBirthPlaceData::default()),
-            PropertyName::CALADRURI => Property::PropertyCalAdrUri(PropertyCalAdrUriData::default()),
-            PropertyName::CALURI => Property::PropertyCalUri(PropertyCalUriData::default()),
-            PropertyName::CATEGORIES => Property::PropertyCategories(PropertyCategoriesData::default()),
-            PropertyName::CLIENTPIDMAP => Property::PropertyClientPidMap(PropertyClientPidMapData::default()),
-            PropertyName::CONTACTURI => Property::PropertyContactUri(PropertyContactUriData::default()),
-            PropertyName::DEATHDATE => Property::PropertyDeathDate(PropertyDeathDateData::default()),
-            PropertyName::DEATHPLACE => Property::PropertyDeathPlace(PropertyDeathPlaceData::default()),
-            PropertyName::EMAIL => Property::PropertyEmail(PropertyEmailData::default()),
-            PropertyName::EXPERTISE => Property::PropertyExpertise(PropertyExpertiseData::default()),
-            PropertyName::FBURL => Property::PropertyFbUrl(PropertyFbUrlData::default()),
-            PropertyName::FN => Property::PropertyFn(PropertyFnData::default()),
-            PropertyName::GENDER => Property::PropertyGender(PropertyGenderData::default()),
-            PropertyName::GEO => Property::PropertyGeo(PropertyGeoData::default()),
-            PropertyName::HOBBY => Property::PropertyHobby(PropertyHobbyData::default()),
-            PropertyName::IMPP => Property::PropertyImpp(PropertyImppData::default()),
-            PropertyName::INTEREST => Property::PropertyInterest(PropertyInterestData::default()),
-            PropertyName::KEY => Property::PropertyKey(PropertyKeyData::default()),
-            PropertyName::KIND => Property::PropertyKind(PropertyKindData::default()),
-            PropertyName::LANG => Property::PropertyLang(PropertyLangData::default()),
-            PropertyName::LOGO => Property::PropertyLogo(PropertyLogoData::default()),
-            PropertyName::MEMBER => Property::PropertyMember(PropertyMemberData::default()),
-            PropertyName::NICKNAME => Property::PropertyNickName(PropertyNickNameData::default()),
-            PropertyName::NOTE => Property::PropertyNote(PropertyNoteData::default()),
-            PropertyName::N => Property::PropertyN(PropertyNData::default()),
-            PropertyName::ORGDIRECTORY => Property::PropertyOrgDirectory(PropertyOrgDirectoryData::default()),
-            PropertyName::ORG => Property::PropertyOrg(PropertyOrgData::default()),
-            PropertyName::PHOTO => Property::PropertyPhoto(PropertyPhotoData::default()),
-            PropertyName::PRODID => Property::PropertyProdId(PropertyProdIdData::default()),
-            PropertyName::RELATED => Property::PropertyRelated(PropertyRelatedData::default()),
-            PropertyName::REV => Property::PropertyRev(PropertyRevData::default()),
-            PropertyName::ROLE => Property::PropertyRole(PropertyRoleData::default()),
-            PropertyName::SOUND => Property::PropertySound(PropertySoundData::default()),
-            PropertyName::SOURCE => Property::PropertySource(PropertySourceData::default()),
-            PropertyName::TEL => Property::PropertyTel(PropertyTelData::default()),
-            PropertyName::TITLE => Property::PropertyTitle(PropertyTitleData::default()),
-            PropertyName::TZ => Property::PropertyTz(PropertyTzData::default()),
-            PropertyName::UID => Property::PropertyUid(PropertyUidData::default()),
-            PropertyName::URL => Property::PropertyUrl(PropertyUrlData::default()),
-            PropertyName::XML => Property::PropertyXml(PropertyXmlData::default()),
-            _ => Property::PropertyXName(PropertyXNameData::default(name)),
+        Self::default_registered(name)
+    }
+
+    /// Create an EMAIL property from an address, checked against the addr-spec syntax of [RFC 5322 3.4.1](https://datatracker.ietf.org/doc/html/rfc5322#section-3.4.1).
+    ///
+    /// The typed constructors take unescaped values and don't need a content line, see also [tel](Property::tel),
+    /// [url](Property::url) and [adr](Property::adr).
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::Property;
+    ///
+    /// let property = Property::email("john.doe@example.com").expect("Unable to create property.");
+    /// assert_eq!(property.export(), "EMAIL:john.doe@example.com\n");
+    /// assert!(Property::email("not an address").is_err());
+    /// ```
+    pub fn email(address: &str) -> Result<Self, VcardError> {
+        let mut data = PropertyEmailData::default();
+        data.set_value(ValueText(ValueTextData { value: address.to_string() }))?;
+        if !data.is_valid_address() {
+            return Err(VcardError::ValueInvalid(address.to_string(), PropertyName::EMAIL.to_string()));
+        }
+        Ok(Property::PropertyEmail(data))
+    }
+
+    /// Create a TEL property from a number, either as text or as a tel uri.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::Property;
+    ///
+    /// assert_eq!(Property::tel("+1 555-555-5555").expect("Unable to create property.").export(), "TEL:+1 555-555-5555\n");
+    /// assert_eq!(Property::tel("tel:+1-555-555-5555").expect("Unable to create property.").export(), "TEL;VALUE=URI:tel:+1-555-555-5555\n");
+    /// assert!(Property::tel("").is_err());
+    /// ```
+    pub fn tel(number: &str) -> Result<Self, VcardError> {
+        if number.trim().is_empty() {
+            return Err(VcardError::ValueEmpty(PropertyName::TEL.to_string()));
+        }
+
+        let mut data = PropertyTelData::default();
+        if number.get(..4).is_some_and(|scheme| scheme.eq_ignore_ascii_case("tel:")) {
+            data.add_parameter(Parameter::try_from((ParameterName::VALUE, ValueType::URI))?)?;
+            data.set_value(ValueUri(ValueUriData::try_from(number)?))?;
+        } else {
+            data.set_value(ValueText(ValueTextData { value: number.to_string() }))?;
+        }
+        Ok(Property::PropertyTel(data))
+    }
+
+    /// Create a URL property from a uri.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::Property;
+    ///
+    /// assert_eq!(Property::url("https://example.com/~john").expect("Unable to create property.").export(), "URL:https://example.com/~john\n");
+    /// assert!(Property::url("not a uri").is_err());
+    /// ```
+    pub fn url(uri: &str) -> Result<Self, VcardError> {
+        let mut data = PropertyUrlData::default();
+        data.set_value(ValueUri(ValueUriData::try_from(uri)?))?;
+        Ok(Property::PropertyUrl(data))
+    }
+
+    /// Create an ADR property from [address components](AddressParts), at least one component must be set.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::property_adr::AddressParts;
+    /// use vcard_parser::vcard::property::Property;
+    ///
+    /// let parts = AddressParts { street: "123 Main St.; Apt 4", locality: "Any Town", country: "USA", ..AddressParts::default() };
+    /// assert_eq!(Property::adr(parts).expect("Unable to create property.").export(), "ADR:;;123 Main St.\\; Apt 4;Any Town;;;USA\n");
+    /// assert!(Property::adr(AddressParts::default()).is_err());
+    /// ```
+    pub fn adr(parts: AddressParts) -> Result<Self, VcardError> {
+        let data = PropertyAdrData::from(parts);
+        if data.get_value().is_empty() {
+            return Err(VcardError::ValueEmpty(PropertyName::ADR.to_string()));
+        }
+        Ok(Property::PropertyAdr(data))
+    }
+
+    /// Set the value of the property from text written as in a content line, parsed with the rules of the property and
+    /// its VALUE parameter, e.g. splitting NICKNAME on commas and N on semicolons.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::traits::HasValue;
+    /// use vcard_parser::vcard::property::Property;
+    /// use vcard_parser::vcard::value::Value;
+    ///
+    /// let mut property = Property::try_from("NICKNAME:Johnny\n").expect("Unable to parse property.");
+    /// property.set_value_str("Johnny,Jay").expect("Unable to set value.");
+    /// assert!(matches!(property.get_value(), Value::ValueTextList(list) if list.value == ["Johnny", "Jay"]));
+    ///
+    /// let mut property = Property::try_from("BDAY;VALUE=TEXT:circa 1800\n").expect("Unable to parse property.");
+    /// property.set_value_str("circa 1900").expect("Unable to set value.");
+    /// assert_eq!(property.export(), "BDAY;VALUE=TEXT:circa 1900\n");
+    /// assert!(Property::default("GEO").set_value_str("not a uri").is_err());
+    /// ```
+    pub fn set_value_str(&mut self, str: &str) -> Result<(), VcardError> {
+        if let Property::PropertyRaw(data) = self {
+            data.set_raw_value(str);
+            return Ok(());
+        }
+
+        let property = Self::create((self.group().clone(), self.name(), self.get_parameters(), str))?;
+        self.set_value(property.get_value().clone())
+    }
+
+    /// Get the X-name parameters of the property, e.g. sync metadata such as X-SYNCID.
+    ///
+    /// X-name parameters are allowed on all properties and are kept as is when exporting.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::traits::{HasName, HasValue};
+    /// use vcard_parser::vcard::property::Property;
+    ///
+    /// let property = Property::try_from("EMAIL;TYPE=work;X-SYNCID=abc123:john@example.com\n").expect("Unable to parse property.");
+    /// let parameters = property.x_parameters();
+    /// assert_eq!(parameters.len(), 1);
+    /// assert_eq!(parameters[0].name(), "X-SYNCID");
+    /// assert_eq!(parameters[0].get_value().to_string(), "abc123");
+    /// ```
+    pub fn x_parameters(&self) -> Vec<Parameter> {
+        self.get_parameters().into_iter().filter(|parameter| matches!(parameter, Parameter::ParameterXName(_)) && parameter.name().to_uppercase().starts_with("X-")).collect()
+    }
+
+    /// Get the client URI of the first PID source, resolved through the CLIENTPIDMAP properties of the vCard,
+    /// see [RFC 6350 7.1.2](https://datatracker.ietf.org/doc/html/rfc6350#section-7.1.2).
+    ///
+    /// Returns None if the property has no PID with a source or the source isn't mapped.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::Vcard;
+    ///
+    /// let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nCLIENTPIDMAP:1;urn:uuid:google\nCLIENTPIDMAP:2;urn:uuid:icloud\nTEL;PID=1.1:555-5555\nTEL;PID=1.2:555-1234\nEND:VCARD\n").expect("Unable to parse vCard.");
+    /// let clients: Vec<Option<&str>> = vcard.get_properties_by_name("TEL").iter().map(|property| property.source_client(&vcard)).collect();
+    /// assert_eq!(clients, Vec::from([Some("urn:uuid:google"), Some("urn:uuid:icloud")]));
+    /// ```
+    pub fn source_client<'a>(&self, vcard: &'a Vcard) -> Option<&'a str> {
+        let source = self.get_parameters().into_iter().find_map(|parameter| match parameter.get_value() {
+            ValuePid(data) if parameter.name() == ParameterName::PID => data.value.iter().find_map(|(_, source)| *source),
+            _ => None,
+        })?;
+
+        vcard.properties.iter().find_map(|property| match property.get_value() {
+            ValueClientPidMap(data) if data.id == source => Some(data.client.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Check if two properties have the same name and value, ignoring the group and parameters such as PID, TYPE and
+    /// PREF, e.g. for finding duplicates with different labels.
+    ///
+    /// Names are compared case-insensitively and values like [Ord](Ord) compares them, e.g. uri schemes and timestamp
+    /// offsets are normalized.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::Property;
+    ///
+    /// let work = Property::try_from("item1.EMAIL;TYPE=work;PID=1.1:john@example.com\n").expect("Unable to parse property.");
+    /// let home = Property::try_from("email;TYPE=home;PREF=1:john@example.com\n").expect("Unable to parse property.");
+    /// assert!(work.value_eq(&home));
+    /// assert!(!work.value_eq(&Property::try_from("EMAIL:jane@example.com\n").expect("Unable to parse property.")));
+    /// assert!(!work.value_eq(&Property::try_from("NOTE:john@example.com\n").expect("Unable to parse property.")));
+    /// ```
+    pub fn value_eq(&self, other: &Property) -> bool {
+        self.name().eq_ignore_ascii_case(other.name()) && value_key(self.get_value()) == value_key(other.get_value())
+    }
+
+    /// Get the values of all PID parameters of the property, see [RFC 6350 5.5](https://datatracker.ietf.org/doc/html/rfc6350#section-5.5).
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::Property;
+    /// use vcard_parser::vcard::value::value_pid::Pid;
+    ///
+    /// let property = Property::try_from("TEL;PID=1.1,2.2;PID=3:555-5555\n").expect("Unable to parse property.");
+    /// assert_eq!(property.pids(), [Pid { instance: 1, source: Some(1) }, Pid { instance: 2, source: Some(2) }, Pid::from(3)]);
+    /// ```
+    pub fn pids(&self) -> Vec<Pid> {
+        self.get_parameters()
+            .iter()
+            .filter_map(|parameter| match (parameter, parameter.get_value()) {
+                (Parameter::ParameterPid(_), ValuePid(data)) => Some(data.pids()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Add a PID value to the first PID parameter of the property, adding a PID parameter if there is none.
+    ///
+    /// Values the property already has aren't added again.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::Property;
+    /// use vcard_parser::vcard::value::value_pid::Pid;
+    ///
+    /// let mut property = Property::try_from("EMAIL;TYPE=work:john@example.com\n").expect("Unable to parse property.");
+    /// property.add_pid(Pid { instance: 1, source: Some(1) }).expect("Unable to add pid.");
+    /// property.add_pid(Pid { instance: 2, source: Some(1) }).expect("Unable to add pid.");
+    /// assert_eq!(property.to_string(), "EMAIL;TYPE=work;PID=1.1,2.1:john@example.com\n");
+    /// ```
+    pub fn add_pid(&mut self, pid: Pid) -> Result<(), VcardError> {
+        if self.pids().contains(&pid) {
+            return Ok(());
+        }
+
+        let mut parameters = self.get_parameters();
+        if let Some(parameter) = parameters.iter_mut().find(|parameter| matches!(parameter, Parameter::ParameterPid(_))) {
+            let mut pids = match parameter.get_value() {
+                ValuePid(data) => data.pids(),
+                _ => Vec::new(),
+            };
+            pids.push(pid);
+            parameter.set_value(Value::from(ValuePidData::from(pids)))?;
+            self.set_parameters(parameters);
+            return Ok(());
+        }
+
+        self.add_parameter(Parameter::ParameterPid(ParameterPidData {
+            value: Value::from(ValuePidData::from(Vec::from([pid]))),
+        }))
+    }
+
+    /// Remove a PID value from all PID parameters of the property, removing PID parameters left without values.
+    ///
+    /// Returns false if the property didn't have the value.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::Property;
+    /// use vcard_parser::vcard::value::value_pid::Pid;
+    ///
+    /// let mut property = Property::try_from("TEL;PID=1.1,2.1;PID=3.2:555-5555\n").expect("Unable to parse property.");
+    /// assert!(property.remove_pid(&Pid { instance: 1, source: Some(1) }));
+    /// assert!(property.remove_pid(&Pid { instance: 3, source: Some(2) }));
+    /// assert!(!property.remove_pid(&Pid::from(3)));
+    /// assert_eq!(property.to_string(), "TEL;PID=2.1:555-5555\n");
+    /// ```
+    pub fn remove_pid(&mut self, pid: &Pid) -> bool {
+        if !self.pids().contains(pid) {
+            return false;
+        }
+
+        let mut parameters = Vec::new();
+        for mut parameter in self.get_parameters() {
+            if let ValuePid(data) = parameter.get_value() {
+                let pids: Vec<Pid> = data.pids().into_iter().filter(|other| other != pid).collect();
+                if pids.is_empty() {
+                    continue;
+                }
+                // The value has the type of the existing value.
+                let _ = parameter.set_value(Value::from(ValuePidData::from(pids)));
+            }
+            parameters.push(parameter);
+        }
+
+        self.set_parameters(parameters);
+        true
+    }
+
+    /// Get the value of the LEVEL parameter, if any, see [RFC 6715 3.2](https://datatracker.ietf.org/doc/html/rfc6715#section-3.2).
+    pub fn level(&self) -> Option<Level> {
+        self.get_parameters().iter().find_map(|parameter| match parameter {
+            Parameter::ParameterLevel(data) => data.level(),
+            _ => None,
+        })
+    }
+
+    /// Get the value of the INDEX parameter, if any, see [RFC 6715 3.1](https://datatracker.ietf.org/doc/html/rfc6715#section-3.1).
+    pub fn index(&self) -> Option<i64> {
+        self.get_parameters().iter().find_map(|parameter| match parameter {
+            Parameter::ParameterIndex(data) => Some(data.index()),
+            _ => None,
+        })
+    }
+
+    /// Set the INDEX parameter of the property, replacing an existing INDEX parameter.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::Property;
+    ///
+    /// let mut property = Property::try_from("HOBBY;INDEX=1:reading\n").expect("Unable to parse property.");
+    /// property.set_index(2).expect("Unable to set index.");
+    /// assert_eq!(property.index(), Some(2));
+    /// assert_eq!(property.to_string(), "HOBBY;INDEX=2:reading\n");
+    /// assert!(property.set_index(0).is_err());
+    /// ```
+    pub fn set_index(&mut self, index: i64) -> Result<(), VcardError> {
+        let mut data = ParameterIndexData::default();
+        data.set_value(Value::from(ValueIntegerData::from(index)))?;
+
+        let mut parameters = self.get_parameters();
+        if let Some(parameter) = parameters.iter_mut().find(|parameter| matches!(parameter, Parameter::ParameterIndex(_))) {
+            *parameter = Parameter::ParameterIndex(data);
+            self.set_parameters(parameters);
+            return Ok(());
+        }
+
+        self.add_parameter(Parameter::ParameterIndex(data))
+    }
+
+    /// Merge repeated TYPE and PID parameters into their first occurrence, dropping repeated values.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::Property;
+    ///
+    /// let mut property = Property::try_from("TEL;TYPE=home;PREF=1;TYPE=voice,HOME;PID=1.1;PID=2.1:555-5555\n").expect("Unable to parse property.");
+    /// property.merge_parameters();
+    /// assert_eq!(property.to_string(), "TEL;TYPE=home,voice;PREF=1;PID=1.1,2.1:555-5555\n");
+    /// ```
+    pub fn merge_parameters(&mut self) {
+        let mut parameters: Vec<Parameter> = Vec::new();
+
+        for parameter in self.get_parameters() {
+            let Some(existing) = parameters.iter_mut().find(|existing| existing.name() == parameter.name() && (existing.name() == ParameterName::TYPE || existing.name() == ParameterName::PID)) else {
+                parameters.push(parameter);
+                continue;
+            };
+
+            let merged = match (existing.get_value(), parameter.get_value()) {
+                (ValueTextList(merged), ValueTextList(list)) => {
+                    let mut merged = merged.clone();
+                    for value in list.value.iter() {
+                        if !merged.value.iter().any(|merged| merged.eq_ignore_ascii_case(value)) {
+                            merged.value.push(value.clone());
+                        }
+                    }
+                    Value::from(merged)
+                }
+                (ValuePid(merged), ValuePid(pids)) => {
+                    let mut merged = merged.clone();
+                    for pid in pids.value.iter() {
+                        if !merged.value.contains(pid) {
+                            merged.value.push(*pid);
+                        }
+                    }
+                    Value::from(merged)
+                }
+                _ => {
+                    parameters.push(parameter);
+                    continue;
+                }
+            };
+
+            // The merged value has the type of the existing value.
+            let _ = existing.set_value(merged);
+        }
+
+        self.set_parameters(parameters);
+    }
+
+    /// Helper function getting the parameters as written in a [ParameterStyle](ParameterStyle).
+    fn styled_parameters(&self, style: ParameterStyle) -> Vec<Parameter> {
+        match style {
+            ParameterStyle::Preserve => self.get_parameters(),
+            ParameterStyle::Merged => {
+                let mut property = self.clone();
+                property.merge_parameters();
+                property.get_parameters()
+            }
+            ParameterStyle::Repeated => {
+                let mut parameters = Vec::new();
+                for parameter in self.get_parameters() {
+                    match parameter.get_value() {
+                        ValueTextList(list) if parameter.name() == ParameterName::TYPE && list.value.len() > 1 => {
+                            for value in list.value.iter() {
+                                let mut repeated = parameter.clone();
+                                let _ = repeated.set_value(Value::from(ValueTextListData {
+                                    delimiter: list.delimiter,
+                                    value: Vec::from([value.clone()]),
+                                }));
+                                parameters.push(repeated);
+                            }
+                        }
+                        ValuePid(pids) if pids.value.len() > 1 => {
+                            for pid in pids.value.iter() {
+                                let mut repeated = parameter.clone();
+                                let _ = repeated.set_value(Value::from(ValuePidData::from(Vec::from([*pid]))));
+                                parameters.push(repeated);
+                            }
+                        }
+                        _ => parameters.push(parameter),
+                    }
+                }
+                parameters
+            }
         }
     }
 
@@ -402,25 +1080,35 @@ impl Property {
     /// assert_eq!(property.export(), "FN:John Doe\n");
     /// ```
     pub fn export(&self) -> String {
-        let mut property = self.clone();
+        let mut string = String::new();
 
-        // Remove all pids from property.
-        property.set_parameters(property.get_parameters().into_iter().filter(|p| p.name() != ParameterName::PID).collect());
+        // Remove all pids from property, writing to a string can't fail.
+        let _ = self.write_to(&mut string, false, ParameterStyle::Preserve);
 
-        property.to_string()
+        string
     }
-}
 
-impl Display for Property {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    /// Helper function writing the property, optionally including pid parameters.
+    pub(crate) fn write_to(&self, f: &mut dyn Write, pids: bool, style: ParameterStyle) -> std::fmt::Result {
         if let Some(group) = self.group() {
             write!(f, "{}.", group)?;
         }
 
         write!(f, "{}", self.name())?;
 
-        for parameter in self.get_parameters() {
-            write!(f, "{}", parameter)?;
+        if let Property::PropertyRaw(data) = self {
+            for (name, value) in data.raw_parameters() {
+                if pids || !name.eq_ignore_ascii_case(ParameterName::PID) {
+                    write!(f, ";{}={}", name, value)?;
+                }
+            }
+            return writeln!(f, ":{}", data.value());
+        }
+
+        for parameter in self.styled_parameters(style) {
+            if pids || parameter.name() != ParameterName::PID {
+                write!(f, "{}", parameter)?;
+            }
         }
 
         write!(f, ":{}", self.get_value())?;
@@ -431,9 +1119,18 @@ impl Display for Property {
     }
 }
 
-/// Matches properties based on [RFC 6350 7.1.2](https://datatracker.ietf.org/doc/html/rfc6350#section-7.1.2) and [RFC 6350 7.1.3](https://datatracker.ietf.org/doc/html/rfc6350#section-7.1.3).
-impl PartialEq<Property> for Property {
-    fn eq(&self, other: &Property) -> bool {
+impl Display for Property {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.write_to(f, true, ParameterStyle::Preserve)
+    }
+}
+
+impl Property {
+    /// Matches properties based on [RFC 6350 7.1.2](https://datatracker.ietf.org/doc/html/rfc6350#section-7.1.2) and [RFC 6350 7.1.3](https://datatracker.ietf.org/doc/html/rfc6350#section-7.1.3).
+    ///
+    /// Unlike `==`, which compares the group, parameters and value, matching only looks at the name,
+    /// cardinality and PID parameters, to find the instance of a property a change applies to.
+    pub fn matches(&self, other: &Property) -> bool {
         // Property instances whose name is CLIENTPIDMAP are handled separately
         // and MUST NOT be matched.  The synchronization MUST ensure that there
         // is consistency of CLIENTPIDMAPs among matched vCard instances.
@@ -457,18 +1154,7 @@ impl PartialEq<Property> for Property {
         // same, and whose PID parameters match, MUST be matched. See
         // Section 7.1.3 for details on PID matching.
         if self.is_multiple() && self.name() == other.name() {
-            fn _pids_get(property: &Property) -> Option<Vec<(i32, Option<i32>)>> {
-                for parameter in property.get_parameters() {
-                    if parameter.name() == ParameterName::PID {
-                        if let ValuePid(data) = parameter.get_value() {
-                            return Some(data.value.clone());
-                        }
-                    }
-                }
-                None
-            }
-
-            if let (Some(a), Some(b)) = (_pids_get(self), _pids_get(other)) {
+            if let (Some(a), Some(b)) = (self.pid_values(), other.pid_values()) {
                 for (a1, a2) in &a {
                     for (b1, b2) in &b {
                         if a1 == b1 && a2 == b2 {
@@ -483,6 +1169,92 @@ impl PartialEq<Property> for Property {
         // discretion of the synchronization engine.
         false
     }
+
+    /// Helper function returning the value of the PREF parameter, if any.
+    fn pref(&self) -> Option<u8> {
+        self.get_parameters().iter().find_map(|parameter| match parameter {
+            Parameter::ParameterPref(data) => Some(data.pref()),
+            _ => None,
+        })
+    }
+
+    /// Helper function returning the values of the PID parameter, if any.
+    fn pid_values(&self) -> Option<Vec<(i64, Option<i64>)>> {
+        self.get_parameters().iter().find_map(|parameter| match (parameter, parameter.get_value()) {
+            (Parameter::ParameterPid(_), ValuePid(data)) => Some(data.value.clone()),
+            _ => None,
+        })
+    }
+}
+
+/// Orders properties by name, group, PREF and PID, the canonical order used by [`ExportOptions::sort_properties`](crate::options::ExportOptions::sort_properties).
+///
+/// Names are compared case-insensitively, properties without a group come before grouped ones, and properties with a
+/// PREF or PID parameter come before those without, lowest first. Remaining ties are broken by the parameters and
+/// value in their written form, normalized like their equality so that the ordering agrees with `==`, floats are
+/// ordered numerically.
+///
+/// # Examples
+/// ```
+/// use std::collections::BTreeSet;
+/// use vcard_parser::vcard::property::Property;
+///
+/// let properties: BTreeSet<Property> = ["TEL:555-5557\n", "EMAIL:john@example.com\n", "TEL;PREF=2:555-5556\n", "TEL;PREF=1:555-5555\n"]
+///     .iter()
+///     .map(|str| Property::try_from(*str).expect("Unable to parse property."))
+///     .collect();
+///
+/// let export: Vec<String> = properties.iter().map(|property| property.export()).collect();
+/// assert_eq!(export, ["EMAIL:john@example.com\n", "TEL;PREF=1:555-5555\n", "TEL;PREF=2:555-5556\n", "TEL:555-5557\n"]);
+/// ```
+impl Ord for Property {
+    fn cmp(&self, other: &Self) -> Ordering {
+        fn _some_first<T: Ord>(a: Option<T>, b: Option<T>) -> Ordering {
+            match (a, b) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                (a, b) => b.is_some().cmp(&a.is_some()),
+            }
+        }
+
+        fn _parameter_keys(property: &Property) -> Vec<(String, ValueKey)> {
+            property.get_parameters().iter().map(|parameter| (parameter.name().to_string(), value_key(parameter.get_value()))).collect()
+        }
+
+        self.name()
+            .to_uppercase()
+            .cmp(&other.name().to_uppercase())
+            .then_with(|| self.group().cmp(other.group()))
+            .then_with(|| _some_first(self.pref(), other.pref()))
+            .then_with(|| _some_first(self.pid_values(), other.pid_values()))
+            .then_with(|| self.name().cmp(other.name()))
+            .then_with(|| _parameter_keys(self).cmp(&_parameter_keys(other)))
+            .then_with(|| value_key(self.get_value()).cmp(&value_key(other.get_value())))
+    }
+}
+
+/// Key for comparing values, floats are ordered numerically like their equality and other values by their written form.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum ValueKey {
+    Float(ValueFloatData),
+    Written(String),
+}
+
+/// Helper function getting a key for comparing values, normalizing language tags, timestamps, uris and utc offsets.
+fn value_key(value: &Value) -> ValueKey {
+    match value {
+        ValueFloat(data) => ValueKey::Float(data.clone()),
+        ValueLanguageTag(data) => ValueKey::Written(data.value.to_ascii_lowercase()),
+        ValueTimestamp(data) => ValueKey::Written(data.value.to_offset(time::UtcOffset::UTC).to_string()),
+        ValueUri(data) => ValueKey::Written(data.normalized()),
+        ValueUtcOffset(data) => ValueKey::Written(data.normalized()),
+        value => ValueKey::Written(value.to_string()),
+    }
+}
+
+impl PartialOrd for Property {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl TryFrom<&str> for Property {
@@ -508,410 +1280,66 @@ impl TryFrom<(Option<String>, &str, Vec<Parameter>, &str)> for Property {
 
 impl HasGroup for Property {
     fn group(&self) -> &Option<String> {
-        match self {
-            Property::PropertyAdr(property) => property.group(),
-            Property::PropertyAnniversary(property) => property.group(),
-            Property::PropertyBDay(property) => property.group(),
-            Property::PropertyBirthPlace(property) => property.group(),
-            Property::PropertyCalAdrUri(property) => property.group(),
-            Property::PropertyCalUri(property) => property.group(),
-            Property::PropertyCategories(property) => property.group(),
-            Property::PropertyClientPidMap(property) => property.group(),
-            Property::PropertyContactUri(property) => property.group(),
-            Property::PropertyDeathDate(property) => property.group(),
-            Property::PropertyDeathPlace(property) => property.group(),
-            Property::PropertyEmail(property) => property.group(),
-            Property::PropertyExpertise(property) => property.group(),
-            Property::PropertyFbUrl(property) => property.group(),
-            Property::PropertyFn(property) => property.group(),
-            Property::PropertyGender(property) => property.group(),
-            Property::PropertyGeo(property) => property.group(),
-            Property::PropertyHobby(property) => property.group(),
-            Property::PropertyImpp(property) => property.group(),
-            Property::PropertyInterest(property) => property.group(),
-            Property::PropertyKey(property) => property.group(),
-            Property::PropertyKind(property) => property.group(),
-            Property::PropertyLang(property) => property.group(),
-            Property::PropertyLogo(property) => property.group(),
-            Property::PropertyMember(property) => property.group(),
-            Property::PropertyNickName(property) => property.group(),
-            Property::PropertyNote(property) => property.group(),
-            Property::PropertyN(property) => property.group(),
-            Property::PropertyOrgDirectory(property) => property.group(),
-            Property::PropertyOrg(property) => property.group(),
-            Property::PropertyPhoto(property) => property.group(),
-            Property::PropertyProdId(property) => property.group(),
-            Property::PropertyRelated(property) => property.group(),
-            Property::PropertyRev(property) => property.group(),
-            Property::PropertyRole(property) => property.group(),
-            Property::PropertySound(property) => property.group(),
-            Property::PropertySource(property) => property.group(),
-            Property::PropertyTel(property) => property.group(),
-            Property::PropertyTitle(property) => property.group(),
-            Property::PropertyTz(property) => property.group(),
-            Property::PropertyUid(property) => property.group(),
-            Property::PropertyUrl(property) => property.group(),
-            Property::PropertyXml(property) => property.group(),
-            Property::PropertyXName(property) => property.group(),
-        }
+        self.data().group()
     }
 }
 
 impl HasName for Property {
     fn name(&self) -> &str {
-        match self {
-            Property::PropertyAdr(property) => property.name(),
-            Property::PropertyAnniversary(property) => property.name(),
-            Property::PropertyBDay(property) => property.name(),
-            Property::PropertyBirthPlace(property) => property.name(),
-            Property::PropertyCalAdrUri(property) => property.name(),
-            Property::PropertyCalUri(property) => property.name(),
-            Property::PropertyCategories(property) => property.name(),
-            Property::PropertyClientPidMap(property) => property.name(),
-            Property::PropertyContactUri(property) => property.name(),
-            Property::PropertyDeathDate(property) => property.name(),
-            Property::PropertyDeathPlace(property) => property.name(),
-            Property::PropertyEmail(property) => property.name(),
-            Property::PropertyExpertise(property) => property.name(),
-            Property::PropertyFbUrl(property) => property.name(),
-            Property::PropertyFn(property) => property.name(),
-            Property::PropertyGender(property) => property.name(),
-            Property::PropertyGeo(property) => property.name(),
-            Property::PropertyHobby(property) => property.name(),
-            Property::PropertyImpp(property) => property.name(),
-            Property::PropertyInterest(property) => property.name(),
-            Property::PropertyKey(property) => property.name(),
-            Property::PropertyKind(property) => property.name(),
-            Property::PropertyLang(property) => property.name(),
-            Property::PropertyLogo(property) => property.name(),
-            Property::PropertyMember(property) => property.name(),
-            Property::PropertyNickName(property) => property.name(),
-            Property::PropertyNote(property) => property.name(),
-            Property::PropertyN(property) => property.name(),
-            Property::PropertyOrgDirectory(property) => property.name(),
-            Property::PropertyOrg(property) => property.name(),
-            Property::PropertyPhoto(property) => property.name(),
-            Property::PropertyProdId(property) => property.name(),
-            Property::PropertyRelated(property) => property.name(),
-            Property::PropertyRev(property) => property.name(),
-            Property::PropertyRole(property) => property.name(),
-            Property::PropertySound(property) => property.name(),
-            Property::PropertySource(property) => property.name(),
-            Property::PropertyTel(property) => property.name(),
-            Property::PropertyTitle(property) => property.name(),
-            Property::PropertyTz(property) => property.name(),
-            Property::PropertyUid(property) => property.name(),
-            Property::PropertyUrl(property) => property.name(),
-            Property::PropertyXml(property) => property.name(),
-            Property::PropertyXName(property) => property.name(),
-        }
+        self.data().name()
     }
 }
 
 impl HasCardinality for Property {
     fn cardinality(&self) -> &str {
-        match self {
-            Property::PropertyAdr(property) => property.cardinality(),
-            Property::PropertyAnniversary(property) => property.cardinality(),
-            Property::PropertyBDay(property) => property.cardinality(),
-            Property::PropertyBirthPlace(property) => property.cardinality(),
-            Property::PropertyCalAdrUri(property) => property.cardinality(),
-            Property::PropertyCalUri(property) => property.cardinality(),
-            Property::PropertyCategories(property) => property.cardinality(),
-            Property::PropertyClientPidMap(property) => property.cardinality(),
-            Property::PropertyContactUri(property) => property.cardinality(),
-            Property::PropertyDeathDate(property) => property.cardinality(),
-            Property::PropertyDeathPlace(property) => property.cardinality(),
-            Property::PropertyEmail(property) => property.cardinality(),
-            Property::PropertyExpertise(property) => property.cardinality(),
-            Property::PropertyFbUrl(property) => property.cardinality(),
-            Property::PropertyFn(property) => property.cardinality(),
-            Property::PropertyGender(property) => property.cardinality(),
-            Property::PropertyGeo(property) => property.cardinality(),
-            Property::PropertyHobby(property) => property.cardinality(),
-            Property::PropertyImpp(property) => property.cardinality(),
-            Property::PropertyInterest(property) => property.cardinality(),
-            Property::PropertyKey(property) => property.cardinality(),
-            Property::PropertyKind(property) => property.cardinality(),
-            Property::PropertyLang(property) => property.cardinality(),
-            Property::PropertyLogo(property) => property.cardinality(),
-            Property::PropertyMember(property) => property.cardinality(),
-            Property::PropertyNickName(property) => property.cardinality(),
-            Property::PropertyNote(property) => property.cardinality(),
-            Property::PropertyN(property) => property.cardinality(),
-            Property::PropertyOrgDirectory(property) => property.cardinality(),
-            Property::PropertyOrg(property) => property.cardinality(),
-            Property::PropertyPhoto(property) => property.cardinality(),
-            Property::PropertyProdId(property) => property.cardinality(),
-            Property::PropertyRelated(property) => property.cardinality(),
-            Property::PropertyRev(property) => property.cardinality(),
-            Property::PropertyRole(property) => property.cardinality(),
-            Property::PropertySound(property) => property.cardinality(),
-            Property::PropertySource(property) => property.cardinality(),
-            Property::PropertyTel(property) => property.cardinality(),
-            Property::PropertyTitle(property) => property.cardinality(),
-            Property::PropertyTz(property) => property.cardinality(),
-            Property::PropertyUid(property) => property.cardinality(),
-            Property::PropertyUrl(property) => property.cardinality(),
-            Property::PropertyXml(property) => property.cardinality(),
-            Property::PropertyXName(property) => property.cardinality(),
-        }
+        self.data().cardinality()
     }
 }
 
 impl HasValue for Property {
     fn get_value(&self) -> &Value {
-        match self {
-            Property::PropertyAdr(property) => property.get_value(),
-            Property::PropertyAnniversary(property) => property.get_value(),
-            Property::PropertyBDay(property) => property.get_value(),
-            Property::PropertyBirthPlace(property) => property.get_value(),
-            Property::PropertyCalAdrUri(property) => property.get_value(),
-            Property::PropertyCalUri(property) => property.get_value(),
-            Property::PropertyCategories(property) => property.get_value(),
-            Property::PropertyClientPidMap(property) => property.get_value(),
-            Property::PropertyContactUri(property) => property.get_value(),
-            Property::PropertyDeathDate(property) => property.get_value(),
-            Property::PropertyDeathPlace(property) => property.get_value(),
-            Property::PropertyEmail(property) => property.get_value(),
-            Property::PropertyExpertise(property) => property.get_value(),
-            Property::PropertyFbUrl(property) => property.get_value(),
-            Property::PropertyFn(property) => property.get_value(),
-            Property::PropertyGender(property) => property.get_value(),
-            Property::PropertyGeo(property) => property.get_value(),
-            Property::PropertyHobby(property) => property.get_value(),
-            Property::PropertyImpp(property) => property.get_value(),
-            Property::PropertyInterest(property) => property.get_value(),
-            Property::PropertyKey(property) => property.get_value(),
-            Property::PropertyKind(property) => property.get_value(),
-            Property::PropertyLang(property) => property.get_value(),
-            Property::PropertyLogo(property) => property.get_value(),
-            Property::PropertyMember(property) => property.get_value(),
-            Property::PropertyNickName(property) => property.get_value(),
-            Property::PropertyNote(property) => property.get_value(),
-            Property::PropertyN(property) => property.get_value(),
-            Property::PropertyOrgDirectory(property) => property.get_value(),
-            Property::PropertyOrg(property) => property.get_value(),
-            Property::PropertyPhoto(property) => property.get_value(),
-            Property::PropertyProdId(property) => property.get_value(),
-            Property::PropertyRelated(property) => property.get_value(),
-            Property::PropertyRev(property) => property.get_value(),
-            Property::PropertyRole(property) => property.get_value(),
-            Property::PropertySound(property) => property.get_value(),
-            Property::PropertySource(property) => property.get_value(),
-            Property::PropertyTel(property) => property.get_value(),
-            Property::PropertyTitle(property) => property.get_value(),
-            Property::PropertyTz(property) => property.get_value(),
-            Property::PropertyUid(property) => property.get_value(),
-            Property::PropertyUrl(property) => property.get_value(),
-            Property::PropertyXml(property) => property.get_value(),
-            Property::PropertyXName(property) => property.get_value(),
-        }
+        self.data().get_value()
     }
 
     fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        match self {
-            Property::PropertyAdr(property) => property.set_value(value),
-            Property::PropertyAnniversary(property) => property.set_value(value),
-            Property::PropertyBDay(property) => property.set_value(value),
-            Property::PropertyBirthPlace(property) => property.set_value(value),
-            Property::PropertyCalAdrUri(property) => property.set_value(value),
-            Property::PropertyCalUri(property) => property.set_value(value),
-            Property::PropertyCategories(property) => property.set_value(value),
-            Property::PropertyClientPidMap(property) => property.set_value(value),
-            Property::PropertyContactUri(property) => property.set_value(value),
-            Property::PropertyDeathDate(property) => property.set_value(value),
-            Property::PropertyDeathPlace(property) => property.set_value(value),
-            Property::PropertyEmail(property) => property.set_value(value),
-            Property::PropertyExpertise(property) => property.set_value(value),
-            Property::PropertyFbUrl(property) => property.set_value(value),
-            Property::PropertyFn(property) => property.set_value(value),
-            Property::PropertyGender(property) => property.set_value(value),
-            Property::PropertyGeo(property) => property.set_value(value),
-            Property::PropertyHobby(property) => property.set_value(value),
-            Property::PropertyImpp(property) => property.set_value(value),
-            Property::PropertyInterest(property) => property.set_value(value),
-            Property::PropertyKey(property) => property.set_value(value),
-            Property::PropertyKind(property) => property.set_value(value),
-            Property::PropertyLang(property) => property.set_value(value),
-            Property::PropertyLogo(property) => property.set_value(value),
-            Property::PropertyMember(property) => property.set_value(value),
-            Property::PropertyNickName(property) => property.set_value(value),
-            Property::PropertyNote(property) => property.set_value(value),
-            Property::PropertyN(property) => property.set_value(value),
-            Property::PropertyOrgDirectory(property) => property.set_value(value),
-            Property::PropertyOrg(property) => property.set_value(value),
-            Property::PropertyPhoto(property) => property.set_value(value),
-            Property::PropertyProdId(property) => property.set_value(value),
-            Property::PropertyRelated(property) => property.set_value(value),
-            Property::PropertyRev(property) => property.set_value(value),
-            Property::PropertyRole(property) => property.set_value(value),
-            Property::PropertySound(property) => property.set_value(value),
-            Property::PropertySource(property) => property.set_value(value),
-            Property::PropertyTel(property) => property.set_value(value),
-            Property::PropertyTitle(property) => property.set_value(value),
-            Property::PropertyTz(property) => property.set_value(value),
-            Property::PropertyUid(property) => property.set_value(value),
-            Property::PropertyUrl(property) => property.set_value(value),
-            Property::PropertyXml(property) => property.set_value(value),
-            Property::PropertyXName(property) => property.set_value(value),
-        }
+        self.data_mut().set_value(value)
     }
 }
 
 impl HasParameters for Property {
     fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
-        match self {
-            Property::PropertyAdr(property) => property.allowed_parameters(),
-            Property::PropertyAnniversary(property) => property.allowed_parameters(),
-            Property::PropertyBDay(property) => property.allowed_parameters(),
-            Property::PropertyBirthPlace(property) => property.allowed_parameters(),
-            Property::PropertyCalAdrUri(property) => property.allowed_parameters(),
-            Property::PropertyCalUri(property) => property.allowed_parameters(),
-            Property::PropertyCategories(property) => property.allowed_parameters(),
-            Property::PropertyClientPidMap(property) => property.allowed_parameters(),
-            Property::PropertyContactUri(property) => property.allowed_parameters(),
-            Property::PropertyDeathDate(property) => property.allowed_parameters(),
-            Property::PropertyDeathPlace(property) => property.allowed_parameters(),
-            Property::PropertyEmail(property) => property.allowed_parameters(),
-            Property::PropertyExpertise(property) => property.allowed_parameters(),
-            Property::PropertyFbUrl(property) => property.allowed_parameters(),
-            Property::PropertyFn(property) => property.allowed_parameters(),
-            Property::PropertyGender(property) => property.allowed_parameters(),
-            Property::PropertyGeo(property) => property.allowed_parameters(),
-            Property::PropertyHobby(property) => property.allowed_parameters(),
-            Property::PropertyImpp(property) => property.allowed_parameters(),
-            Property::PropertyInterest(property) => property.allowed_parameters(),
-            Property::PropertyKey(property) => property.allowed_parameters(),
-            Property::PropertyKind(property) => property.allowed_parameters(),
-            Property::PropertyLang(property) => property.allowed_parameters(),
-            Property::PropertyLogo(property) => property.allowed_parameters(),
-            Property::PropertyMember(property) => property.allowed_parameters(),
-            Property::PropertyNickName(property) => property.allowed_parameters(),
-            Property::PropertyNote(property) => property.allowed_parameters(),
-            Property::PropertyN(property) => property.allowed_parameters(),
-            Property::PropertyOrgDirectory(property) => property.allowed_parameters(),
-            Property::PropertyOrg(property) => property.allowed_parameters(),
-            Property::PropertyPhoto(property) => property.allowed_parameters(),
-            Property::PropertyProdId(property) => property.allowed_parameters(),
-            Property::PropertyRelated(property) => property.allowed_parameters(),
-            Property::PropertyRev(property) => property.allowed_parameters(),
-            Property::PropertyRole(property) => property.allowed_parameters(),
-            Property::PropertySound(property) => property.allowed_parameters(),
-            Property::PropertySource(property) => property.allowed_parameters(),
-            Property::PropertyTel(property) => property.allowed_parameters(),
-            Property::PropertyTitle(property) => property.allowed_parameters(),
-            Property::PropertyTz(property) => property.allowed_parameters(),
-            Property::PropertyUid(property) => property.allowed_parameters(),
-            Property::PropertyUrl(property) => property.allowed_parameters(),
-            Property::PropertyXml(property) => property.allowed_parameters(),
-            Property::PropertyXName(property) => property.allowed_parameters(),
-        }
+        self.data().allowed_parameters()
     }
 
     fn get_parameters(&self) -> Vec<Parameter> {
-        match self {
-            Property::PropertyAdr(property) => property.get_parameters(),
-            Property::PropertyAnniversary(property) => property.get_parameters(),
-            Property::PropertyBDay(property) => property.get_parameters(),
-            Property::PropertyBirthPlace(property) => property.get_parameters(),
-            Property::PropertyCalAdrUri(property) => property.get_parameters(),
-            Property::PropertyCalUri(property) => property.get_parameters(),
-            Property::PropertyCategories(property) => property.get_parameters(),
-            Property::PropertyClientPidMap(property) => property.get_parameters(),
-            Property::PropertyContactUri(property) => property.get_parameters(),
-            Property::PropertyDeathDate(property) => property.get_parameters(),
-            Property::PropertyDeathPlace(property) => property.get_parameters(),
-            Property::PropertyEmail(property) => property.get_parameters(),
-            Property::PropertyExpertise(property) => property.get_parameters(),
-            Property::PropertyFbUrl(property) => property.get_parameters(),
-            Property::PropertyFn(property) => property.get_parameters(),
-            Property::PropertyGender(property) => property.get_parameters(),
-            Property::PropertyGeo(property) => property.get_parameters(),
-            Property::PropertyHobby(property) => property.get_parameters(),
-            Property::PropertyImpp(property) => property.get_parameters(),
-            Property::PropertyInterest(property) => property.get_parameters(),
-            Property::PropertyKey(property) => property.get_parameters(),
-            Property::PropertyKind(property) => property.get_parameters(),
-            Property::PropertyLang(property) => property.get_parameters(),
-            Property::PropertyLogo(property) => property.get_parameters(),
-            Property::PropertyMember(property) => property.get_parameters(),
-            Property::PropertyNickName(property) => property.get_parameters(),
-            Property::PropertyNote(property) => property.get_parameters(),
-            Property::PropertyN(property) => property.get_parameters(),
-            Property::PropertyOrgDirectory(property) => property.get_parameters(),
-            Property::PropertyOrg(property) => property.get_parameters(),
-            Property::PropertyPhoto(property) => property.get_parameters(),
-            Property::PropertyProdId(property) => property.get_parameters(),
-            Property::PropertyRelated(property) => property.get_parameters(),
-            Property::PropertyRev(property) => property.get_parameters(),
-            Property::PropertyRole(property) => property.get_parameters(),
-            Property::PropertySound(property) => property.get_parameters(),
-            Property::PropertySource(property) => property.get_parameters(),
-            Property::PropertyTel(property) => property.get_parameters(),
-            Property::PropertyTitle(property) => property.get_parameters(),
-            Property::PropertyTz(property) => property.get_parameters(),
-            Property::PropertyUid(property) => property.get_parameters(),
-            Property::PropertyUrl(property) => property.get_parameters(),
-            Property::PropertyXml(property) => property.get_parameters(),
-            Property::PropertyXName(property) => property.get_parameters(),
-        }
+        self.data().get_parameters()
     }
 
     fn set_parameters(&mut self, parameters: Vec<Parameter>) {
-        match self {
-            Property::PropertyAdr(property) => property.set_parameters(parameters),
-            Property::PropertyAnniversary(property) => property.set_parameters(parameters),
-            Property::PropertyBDay(property) => property.set_parameters(parameters),
-            Property::PropertyBirthPlace(property) => property.set_parameters(parameters),
-            Property::PropertyCalAdrUri(property) => property.set_parameters(parameters),
-            Property::PropertyCalUri(property) => property.set_parameters(parameters),
-            Property::PropertyCategories(property) => property.set_parameters(parameters),
-            Property::PropertyClientPidMap(property) => property.set_parameters(parameters),
-            Property::PropertyContactUri(property) => property.set_parameters(parameters),
-            Property::PropertyDeathDate(property) => property.set_parameters(parameters),
-            Property::PropertyDeathPlace(property) => property.set_parameters(parameters),
-            Property::PropertyEmail(property) => property.set_parameters(parameters),
-            Property::PropertyExpertise(property) => property.set_parameters(parameters),
-            Property::PropertyFbUrl(property) => property.set_parameters(parameters),
-            Property::PropertyFn(property) => property.set_parameters(parameters),
-            Property::PropertyGender(property) => property.set_parameters(parameters),
-            Property::PropertyGeo(property) => property.set_parameters(parameters),
-            Property::PropertyHobby(property) => property.set_parameters(parameters),
-            Property::PropertyImpp(property) => property.set_parameters(parameters),
-            Property::PropertyInterest(property) => property.set_parameters(parameters),
-            Property::PropertyKey(property) => property.set_parameters(parameters),
-            Property::PropertyKind(property) => property.set_parameters(parameters),
-            Property::PropertyLang(property) => property.set_parameters(parameters),
-            Property::PropertyLogo(property) => property.set_parameters(parameters),
-            Property::PropertyMember(property) => property.set_parameters(parameters),
-            Property::PropertyNickName(property) => property.set_parameters(parameters),
-            Property::PropertyNote(property) => property.set_parameters(parameters),
-            Property::PropertyN(property) => property.set_parameters(parameters),
-            Property::PropertyOrgDirectory(property) => property.set_parameters(parameters),
-            Property::PropertyOrg(property) => property.set_parameters(parameters),
-            Property::PropertyPhoto(property) => property.set_parameters(parameters),
-            Property::PropertyProdId(property) => property.set_parameters(parameters),
-            Property::PropertyRelated(property) => property.set_parameters(parameters),
-            Property::PropertyRev(property) => property.set_parameters(parameters),
-            Property::PropertyRole(property) => property.set_parameters(parameters),
-            Property::PropertySound(property) => property.set_parameters(parameters),
-            Property::PropertySource(property) => property.set_parameters(parameters),
-            Property::PropertyTel(property) => property.set_parameters(parameters),
-            Property::PropertyTitle(property) => property.set_parameters(parameters),
-            Property::PropertyTz(property) => property.set_parameters(parameters),
-            Property::PropertyUid(property) => property.set_parameters(parameters),
-            Property::PropertyUrl(property) => property.set_parameters(parameters),
-            Property::PropertyXml(property) => property.set_parameters(parameters),
-            Property::PropertyXName(property) => property.set_parameters(parameters),
-        }
+        self.data_mut().set_parameters(parameters)
+    }
+
+    fn add_parameter(&mut self, parameter: Parameter) -> Result<(), VcardError> {
+        let group = self.group().clone();
+        self.data_mut().add_parameter(parameter).map_err(|err| match err {
+            VcardError::ParameterTypeNotAllowed(parameter, name, None) => VcardError::ParameterTypeNotAllowed(parameter, name, group),
+            err => err,
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::constants::{PropertyName, TestDataPropertyValues};
-    use crate::vcard::property::property_adr::PropertyAdrData;
+    use std::cmp::Ordering;
+    use std::collections::{BTreeSet, HashSet};
+
+    use time::{Date, Month};
+
+    use crate::constants::{ParameterName, PropertyHobbyValues, PropertyName, TestDataPropertyValues, ValueName, ValueType};
+    use crate::options::{ExportOptions, ExportProfile, ParameterStyle, ParserOptions};
+    use crate::vcard::parameter::parameter_level::{Level, ParameterLevelData};
+    use crate::vcard::parameter::Parameter;
+    use crate::vcard::property::property_adr::{AddressParts, CountryFormat, PropertyAdrData};
     use crate::vcard::property::property_anniversary::PropertyAnniversaryData;
     use crate::vcard::property::property_bday::PropertyBDayData;
     use crate::vcard::property::property_birthplace::PropertyBirthPlaceData;
@@ -943,6 +1371,7 @@ mod tests {
     use crate::vcard::property::property_orgdirectory::PropertyOrgDirectoryData;
     use crate::vcard::property::property_photo::PropertyPhotoData;
     use crate::vcard::property::property_prodid::PropertyProdIdData;
+    use crate::vcard::property::property_raw::PropertyRawData;
     use crate::vcard::property::property_related::PropertyRelatedData;
     use crate::vcard::property::property_rev::PropertyRevData;
     use crate::vcard::property::property_role::PropertyRoleData;
@@ -954,7 +1383,14 @@ mod tests {
     use crate::vcard::property::property_uid::PropertyUidData;
     use crate::vcard::property::property_url::PropertyUrlData;
     use crate::vcard::property::property_xml::PropertyXmlData;
-    use crate::{HasCardinality, HasName, HasValue, Property, Vcard};
+    use crate::vcard::property::{value_key, PropertyKind, PropertySpec};
+    use crate::vcard::value::value_component::Component;
+    use crate::vcard::value::value_float::ValueFloatData;
+    use crate::vcard::value::value_pid::Pid;
+    use crate::vcard::value::value_text::ValueTextData;
+    use crate::vcard::value::value_uri::ValueUriContent;
+    use crate::vcard::value::Value;
+    use crate::{HasCardinality, HasName, HasParameters, HasValue, Property, Vcard, VcardError};
 
     #[test]
     pub fn property_cardinality() {
@@ -1051,6 +1487,283 @@ mod tests {
         assert_eq!(PropertyXmlData::default().name(), PropertyName::XML);
     }
 
+    #[test]
+    pub fn property_level_parameter() {
+        assert!(Property::try_from("HOBBY;LEVEL=high:reading\n").is_ok());
+        assert!(Property::try_from("HOBBY;X-SOURCE=test:reading\n").is_ok());
+        assert!(matches!(Property::try_from("HOBBY;LEVEL=expert:reading\n"), Err(VcardError::ValueInvalid(_, _))));
+        assert!(Property::try_from("EXPERTISE;LEVEL=expert:chemistry\n").is_ok());
+        assert!(matches!(Property::try_from("EXPERTISE;LEVEL=high:chemistry\n"), Err(VcardError::ValueInvalid(_, _))));
+        assert!(Property::try_from("EXPERTISE;LEVEL=expertly:chemistry\n").is_err());
+        assert_eq!(Property::try_from("INTEREST;LEVEL=MEDIUM:r&b music\n").unwrap().level(), Some(Level::Medium));
+        assert_eq!(Property::try_from("INTEREST:r&b music\n").unwrap().level(), None);
+
+        let mut property = Property::default(PropertyName::INTEREST);
+        assert!(matches!(property.add_parameter(Parameter::try_from(";LEVEL=expert").unwrap()), Err(VcardError::ValueInvalid(_, _))));
+        assert!(property.add_parameter(Parameter::try_from(";LEVEL=low").unwrap()).is_ok());
+        assert_eq!(property.level(), Some(Level::Low));
+        assert!(property.add_parameter(Parameter::ParameterLevel(ParameterLevelData::from(Level::Beginner))).is_err());
+        assert_eq!(property.add_parameter(Parameter::try_from(";TZ=UTC").unwrap()), Err(VcardError::ParameterTypeNotAllowed(String::from("TZ=UTC"), String::from("INTEREST"), None)));
+        assert_eq!(Property::try_from("item2.HOBBY;TZ=\"https://example.com/tz\":reading\n").unwrap_err().to_string(), "Parameter TZ=\"https://example.com/tz\" is not allowed for item2.HOBBY.");
+        let mut property = Property::try_from("item3.HOBBY:reading\n").unwrap();
+        assert_eq!(property.add_parameter(Parameter::try_from(";TZ=UTC").unwrap()), Err(VcardError::ParameterTypeNotAllowed(String::from("TZ=UTC"), String::from("HOBBY"), Some(String::from("item3")))));
+    }
+
+    #[test]
+    pub fn property_spec() {
+        assert_eq!(PropertySpec::ALL.len(), 43);
+
+        for spec in PropertySpec::ALL {
+            let property = Property::default(spec.name);
+            assert_eq!(PropertySpec::for_name(spec.name), Some(*spec));
+            assert_eq!(property.spec(), Some(*spec));
+            assert_eq!(property.allowed_parameters(), spec.parameters.to_vec());
+            assert_eq!(property.cardinality(), spec.cardinality);
+            if spec.name != PropertyName::REV {
+                assert_eq!(&spec.default_value(), property.get_value());
+            }
+        }
+        assert_eq!(Property::default(PropertyName::TZ).export(), "TZ:\n");
+
+        let spec = PropertySpec::for_name("hobby").unwrap();
+        assert!(spec.allows_parameter("level"));
+        assert!(spec.allows_parameter("X-SOURCE"));
+        assert!(!spec.allows_parameter("TZ"));
+        assert_eq!(spec.levels, Some(&PropertyHobbyValues::TYPES[..]));
+
+        let spec = PropertySpec::for_name(PropertyName::BDAY).unwrap();
+        assert_eq!(spec.values.iter().find(|value| value.name == ValueName::TEXT).and_then(|value| value.value_types), Some(&[ValueType::TEXT][..]));
+
+        assert!(PropertySpec::for_name("X-CUSTOM").is_none());
+        assert!(Property::try_from("X-CUSTOM:value\n").unwrap().spec().is_none());
+    }
+
+    #[test]
+    pub fn property_set_value_str() {
+        let mut property = Property::try_from("item1.N:Doe;John;;;\n").unwrap();
+        property.set_value_str("Roe;Jane;Q.;Dr.;").unwrap();
+        assert_eq!(property.export(), "item1.N:Roe;Jane;Q.;Dr.;\n");
+
+        let mut property = Property::try_from("X-CUSTOM;X-SYNCID=1:old\n").unwrap();
+        property.set_value_str("new\\, value").unwrap();
+        assert_eq!(property.export(), "X-CUSTOM;X-SYNCID=1:new\\, value\n");
+
+        let mut property = Property::try_from("TEL;VALUE=URI:tel:555-5555\n").unwrap();
+        property.set_value_str("tel:555-1234").unwrap();
+        assert_eq!(property.get_value().to_string(), "tel:555-1234");
+        assert!(property.set_value_str("").is_err());
+        assert_eq!(property.get_value().to_string(), "tel:555-1234");
+    }
+
+    #[test]
+    pub fn property_raw() {
+        let line = "item1.EMAIL;TYPE=\"work,pref\";PID=1.1;PREF=0;LEVEL=none:not\\, an address";
+        assert!(Property::try_from(format!("{}\n", line).as_str()).is_err());
+
+        let mut property = Property::PropertyRaw(PropertyRawData::try_from(line).unwrap());
+        assert_eq!(property.to_string(), format!("{}\n", line));
+        assert_eq!(property.export(), "item1.EMAIL;TYPE=\"work,pref\";PREF=0;LEVEL=none:not\\, an address\n");
+        assert_eq!(property.kind(), PropertyKind::Raw);
+        assert_eq!(property.get_value().as_str(), Some("not, an address"));
+        assert_eq!(property.get_parameters().len(), 2);
+        assert!(property.spec().is_none());
+
+        property.set_value_str("a\\,b").unwrap();
+        property.add_parameter(Parameter::try_from(";X-SYNCID=\"a:b\"").unwrap()).unwrap();
+        assert_eq!(property.export(), "item1.EMAIL;TYPE=\"work,pref\";PREF=0;LEVEL=none;X-SYNCID=\"a:b\":a\\,b\n");
+        property.set_value(Value::from(ValueTextData::from("c,d"))).unwrap();
+        assert_eq!(property.export(), "item1.EMAIL;TYPE=\"work,pref\";PREF=0;LEVEL=none;X-SYNCID=\"a:b\":c\\,d\n");
+
+        let parameters = property.get_parameters();
+        property.set_parameters(parameters.clone());
+        assert_eq!(property.export(), "item1.EMAIL;TYPE=work,pref;PREF=0;LEVEL=none;X-SYNCID=\"a:b\":c\\,d\n");
+        property.set_parameters(parameters.into_iter().filter(|parameter| parameter.name() != ParameterName::TYPE).collect());
+        assert_eq!(property.export(), "item1.EMAIL;PREF=0;LEVEL=none;X-SYNCID=\"a:b\":c\\,d\n");
+
+        let property = Property::PropertyRaw(PropertyRawData::try_from("EMAIL;TYPE=work;PREF=0;LEVEL=none;X-Q=\"a:b\":a\\,b").unwrap());
+        let adjusted = ExportProfile::GoogleContacts.spec().adjust(&property).unwrap();
+        assert_eq!(adjusted.export(), "EMAIL;TYPE=WORK;PREF=0;LEVEL=none;X-Q=\"a:b\":a\\,b\n");
+
+        let raw = PropertyRawData::new(None, "X-CUSTOM", Vec::from([(String::from("X-A"), String::from("1"))]), "kept;as,is");
+        assert_eq!(Property::PropertyRaw(raw).to_string(), "X-CUSTOM;X-A=1:kept;as,is\n");
+    }
+
+    #[test]
+    pub fn property_pids() {
+        let mut property = Property::try_from("TEL;PID=1.1,2.2;TYPE=cell:555-5555\n").unwrap();
+        assert_eq!(
+            property.pids(),
+            [
+                Pid { instance: 1, source: Some(1) },
+                Pid { instance: 2, source: Some(2) }
+            ]
+        );
+        assert_eq!(property.to_string(), "TEL;PID=1.1,2.2;TYPE=cell:555-5555\n");
+        assert_eq!(Property::try_from(property.to_string().as_str()).unwrap(), property);
+
+        property.add_pid(Pid::from(3)).unwrap();
+        property.add_pid(Pid { instance: 1, source: Some(1) }).unwrap();
+        assert_eq!(property.to_string(), "TEL;PID=1.1,2.2,3;TYPE=cell:555-5555\n");
+
+        assert!(property.remove_pid(&Pid { instance: 2, source: Some(2) }));
+        assert!(property.remove_pid(&Pid { instance: 1, source: Some(1) }));
+        assert!(property.remove_pid(&Pid::from(3)));
+        assert!(property.pids().is_empty());
+        assert_eq!(property.to_string(), "TEL;TYPE=cell:555-5555\n");
+        assert!(!property.remove_pid(&Pid::from(3)));
+
+        let vcard = Vcard::try_from(("urn:uuid:client", "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nEMAIL:john@example.com\nEND:VCARD\n")).unwrap();
+        let email = vcard.get_properties_by_name("EMAIL").remove(0);
+        assert_eq!(email.pids(), [Pid { instance: 1, source: Some(1) }]);
+        assert_eq!(Property::try_from("NOTE;PID=x:Hello\n").unwrap_err(), VcardError::ValueMalformed(String::from("x")));
+    }
+
+    #[test]
+    pub fn property_kind() {
+        for spec in PropertySpec::ALL {
+            let kind = Property::default(spec.name).kind();
+            assert_eq!(kind.name(), Some(spec.name));
+            assert_eq!(PropertyKind::from_name(spec.name.to_lowercase().as_str()), kind);
+        }
+
+        let property = Property::try_from("X-CUSTOM:value\n").unwrap();
+        assert_eq!(property.kind(), PropertyKind::XName);
+        assert_eq!(PropertyKind::from_name("IANA-TOKEN"), PropertyKind::XName);
+        assert_eq!(Property::try_from("tel:555-5555\n").unwrap().kind(), PropertyKind::Tel);
+
+        let vcard = Vcard::try_from("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL:555-5555\nitem1.TEL:555-5556\nEMAIL:john@example.com\nEND:VCARD\n").unwrap();
+        let tels = vcard.properties_of(PropertyKind::Tel);
+        assert_eq!(tels.iter().map(|p| p.get_value().to_string()).collect::<Vec<String>>(), ["555-5555", "555-5556"]);
+        assert!(vcard.properties_of(PropertyKind::Adr).is_empty());
+    }
+
+    #[test]
+    pub fn property_x_parameters() {
+        for spec in PropertySpec::ALL {
+            let mut property = Property::default(spec.name);
+            assert!(spec.allows_parameter("X-SYNCID"));
+            property.add_parameter(Parameter::try_from(";X-SYNCID=abc123").unwrap()).unwrap();
+            assert_eq!(property.x_parameters().len(), 1);
+        }
+
+        for str in [
+            "EMAIL;X-SYNCID=abc123:john@example.com\n",
+            "TEL;X-SYNCID=abc123:+1-555-555-5555\n",
+            "HOBBY;LEVEL=high;X-SYNCID=abc123:reading\n",
+        ] {
+            assert_eq!(Property::try_from(str).unwrap().to_string(), str);
+        }
+
+        let property = Property::try_from("TEL;VALUE=uri;X-SYNCID=1;x-source=test;PREF=1:tel:+1-555-555-5555\n").unwrap();
+        assert_eq!(property.x_parameters().iter().map(|parameter| parameter.to_string()).collect::<String>(), ";X-SYNCID=1;x-source=test");
+        assert!(Property::try_from("NOTE:text\n").unwrap().x_parameters().is_empty());
+    }
+
+    #[test]
+    pub fn property_impp_schemes() {
+        let cases = [
+            ("IMPP:xmpp:alice@example.com\n", "xmpp", "alice@example.com"),
+            ("IMPP:XMPP:alice@example.com?message\n", "xmpp", "alice@example.com"),
+            ("IMPP:sip:alice@example.com;transport=tcp\n", "sip", "alice@example.com"),
+            ("IMPP:skype:alice.smith?call\n", "skype", "alice.smith"),
+            ("IMPP:aim:goim?screenname=alice\n", "aim", "alice"),
+            ("IMPP:ymsgr:sendIM?alice\n", "ymsgr", "alice"),
+            ("IMPP:msnim:chat?contact=alice@example.com\n", "msnim", "alice@example.com"),
+            ("IMPP:irc://irc.example.com/alice\n", "irc", "irc.example.com/alice"),
+        ];
+
+        for (input, scheme, address) in cases {
+            let Property::PropertyImpp(impp) = Property::try_from(input).unwrap() else {
+                panic!("Expected IMPP property.");
+            };
+            assert_eq!(impp.scheme(), scheme);
+            assert_eq!(impp.address(), address);
+            assert!(impp.is_known_scheme());
+            assert!(impp.validate_scheme().is_ok());
+        }
+
+        let Property::PropertyImpp(impp) = Property::try_from("IMPP;PREF=1:xmpp:alice@example.com\n").unwrap() else { unreachable!() };
+        assert!(impp.is_xmpp() && !impp.is_sip() && !impp.is_skype());
+
+        let Property::PropertyImpp(impp) = Property::try_from("IMPP:sips:bob@example.com\n").unwrap() else { unreachable!() };
+        assert!(impp.is_sip());
+
+        let Property::PropertyImpp(impp) = Property::try_from("IMPP:https://chat.example.com/bob\n").unwrap() else { unreachable!() };
+        assert!(!impp.is_known_scheme());
+        assert!(impp.validate_scheme().is_err());
+    }
+
+    #[test]
+    pub fn property_org_units() {
+        let Property::PropertyOrg(mut org) = Property::try_from("ORG:ABC\\, Inc.;North American Division;Marketing\n").unwrap() else { unreachable!() };
+        assert_eq!(
+            org.units(),
+            Vec::from([
+                String::from("ABC, Inc."),
+                String::from("North American Division"),
+                String::from("Marketing")
+            ])
+        );
+
+        org.set_units(&["Example; Co.", "R&D"]);
+        let property = Property::PropertyOrg(org);
+        assert_eq!(property.to_string(), "ORG:Example\\; Co.;R&D\n");
+
+        let Property::PropertyOrg(org) = Property::try_from(property.to_string().as_str()).unwrap() else { unreachable!() };
+        assert_eq!(
+            org.units(),
+            Vec::from([
+                String::from("Example; Co."),
+                String::from("R&D")
+            ])
+        );
+    }
+
+    #[test]
+    pub fn property_component_escaping() {
+        let lines = [
+            "N:Doe\\, Jr.;John,Johnny\\,J;;;\n",
+            "ADR:;;1 Main St\\; Apt 2;Town\\\\;;;\n",
+            "ORG:Smith\\, Jones & Co.;Sales\\;East\n",
+            "GENDER:O;it\\;s complicated\n",
+        ];
+        for line in lines {
+            let property = Property::try_from(line).unwrap();
+            assert_eq!(property.to_string(), line);
+            assert_eq!(Property::try_from(property.to_string().as_str()).unwrap(), property);
+        }
+
+        let Value::ValueListComponent(n) = Property::try_from(lines[0]).unwrap().get_value().clone() else { unreachable!() };
+        assert_eq!(n.value[0], [Component::from("Doe, Jr.")]);
+        assert_eq!(
+            n.value[1],
+            [
+                Component::from("John"),
+                Component::from("Johnny,J")
+            ]
+        );
+
+        let Value::ValueListComponent(adr) = Property::try_from(lines[1]).unwrap().get_value().clone() else { unreachable!() };
+        assert_eq!(
+            adr.value[2],
+            [Component::from(
+                "1 Main St; Apt 2"
+            )]
+        );
+        assert_eq!(adr.value[3], [Component::from("Town\\")]);
+        assert_eq!(adr.value.len(), 7);
+    }
+
+    #[test]
+    pub fn property_xml() {
+        let Property::PropertyXml(xml) = Property::try_from("XML:<b a=\"1\">Not an xCard XML element\\, really</b>\n").unwrap() else { unreachable!() };
+        assert_eq!(xml.xml(), "<b a=\"1\">Not an xCard XML element, really</b>");
+        assert!(xml.validate_xml().is_ok());
+
+        let Property::PropertyXml(xml) = Property::try_from("XML:<a><b></a>\n").unwrap() else { unreachable!() };
+        assert!(matches!(xml.validate_xml(), Err(VcardError::ValueInvalid(_, _))));
+    }
+
     #[test]
     pub fn property_equality() {
         let a = Property::try_from("TEL;PID=1.1:555-5555\n").expect("Unable to parse property string.");
@@ -1058,16 +1771,224 @@ mod tests {
         let c = Property::try_from("TEL;PID=1.2:555-5555\n").expect("Unable to parse property string.");
         let d = Property::try_from("TEL;PID=2.1:555-5557\n").expect("Unable to parse property string.");
 
-        assert_eq!(a, b);
-        assert_eq!(b, a);
-        assert_ne!(a, c);
-        assert_ne!(a, d);
-        assert_ne!(b, c);
-        assert_ne!(b, d);
-        assert_ne!(c, d);
+        assert!(a.matches(&b));
+        assert!(b.matches(&a));
+        assert!(!a.matches(&c));
+        assert!(!a.matches(&d));
+        assert!(!b.matches(&c));
+        assert!(!b.matches(&d));
+        assert!(!c.matches(&d));
 
+        assert_ne!(a, b);
+        assert_eq!(a, a.clone());
         assert_ne!(a.get_value(), b.get_value());
         assert_eq!(a.get_value(), c.get_value());
+
+        let clientpidmap = Property::try_from("CLIENTPIDMAP:1;urn:uuid:3df403f4-5924-4bb7-b077-3c711d9eb34b\n").unwrap();
+        assert!(!clientpidmap.matches(&clientpidmap));
+        assert_eq!(clientpidmap, clientpidmap.clone());
+    }
+
+    #[test]
+    pub fn property_recurring_dates() {
+        fn _date(year: i32, month: Month, day: u8) -> Date {
+            Date::from_calendar_date(year, month, day).unwrap()
+        }
+
+        let leap = PropertyBDayData::try_from((None, "1996-02-29", Vec::new())).unwrap();
+        assert_eq!(leap.age_on(_date(1996, Month::February, 28)), None);
+        assert_eq!(leap.age_on(_date(1996, Month::February, 29)), Some(0));
+        assert_eq!(leap.age_on(_date(2024, Month::February, 28)), Some(27));
+        assert_eq!(leap.age_on(_date(2024, Month::February, 29)), Some(28));
+        assert_eq!(leap.next_occurrence(_date(2022, Month::March, 1)), Some(_date(2023, Month::February, 28)));
+        assert_eq!(leap.next_occurrence(_date(2023, Month::March, 1)), Some(_date(2024, Month::February, 29)));
+        assert_eq!(leap.next_occurrence(_date(1990, Month::January, 1)), Some(_date(1996, Month::February, 29)));
+
+        let partial = PropertyBDayData::try_from((None, "--12-31", Vec::new())).unwrap();
+        assert_eq!(partial.next_occurrence(_date(2024, Month::December, 31)), Some(_date(2025, Month::December, 31)));
+        assert_eq!(partial.next_occurrence(_date(2024, Month::December, 30)), Some(_date(2024, Month::December, 31)));
+        assert_eq!(PropertyBDayData::try_from((None, "--0229", Vec::new())).unwrap().next_occurrence(_date(2025, Month::January, 1)), Some(_date(2025, Month::February, 28)));
+
+        assert_eq!(PropertyBDayData::try_from((None, "--0230", Vec::new())).unwrap().next_occurrence(_date(2025, Month::January, 1)), None);
+        assert_eq!(PropertyBDayData::try_from((None, "circa 1800", Vec::new())).unwrap().age_on(_date(2025, Month::January, 1)), None);
+
+        let anniversary = PropertyAnniversaryData::try_from((None, "19960415", Vec::new())).unwrap();
+        assert_eq!(anniversary.age_on(_date(2021, Month::April, 15)), Some(25));
+        assert_eq!(anniversary.next_occurrence(_date(2021, Month::April, 15)), Some(_date(2022, Month::April, 15)));
+    }
+
+    #[test]
+    pub fn property_ordering() {
+        fn _property(str: &str) -> Property {
+            Property::try_from(str).expect("Unable to parse property string.")
+        }
+
+        assert!(_property("EMAIL:b@example.com\n") < _property("FN:A\n"));
+        assert!(_property("note:B\n") < _property("NOTE;LANGUAGE=en:A\n"));
+        assert!(_property("NOTE:B\n") < _property("item1.NOTE:A\n"));
+        assert!(_property("TEL;PREF=1:555-5556\n") < _property("TEL;PREF=2:555-5555\n"));
+        assert!(_property("TEL;PREF=100:555-5556\n") < _property("TEL:555-5555\n"));
+        assert!(_property("TEL;PID=1.1:555-5556\n") < _property("TEL;PID=2.1:555-5555\n"));
+        assert!(_property("TEL:555-5555\n") < _property("TEL:555-5556\n"));
+        assert_eq!(_property("TZ;VALUE=utc-offset:+05:00\n").cmp(&_property("TZ;VALUE=utc-offset:+0500\n")), Ordering::Equal);
+
+        // No property has float values yet, their keys are ordered numerically like their equality.
+        let _float = |str: &str| value_key(&Value::from(ValueFloatData::try_from(str).unwrap()));
+        assert!(_float("9.5") < _float("10"));
+        assert!(_float("-2") < _float("-1.5"));
+        assert!(_float("1.50") == _float("1.5"));
+
+        let properties: BTreeSet<Property> = [
+            "URL:https://EXAMPLE.com\n",
+            "URL:https://example.com\n",
+            "NOTE:A\n",
+        ]
+        .iter()
+        .map(|str| _property(str))
+        .collect();
+        assert_eq!(properties.len(), 2);
+    }
+
+    #[test]
+    pub fn property_value_eq() {
+        let _property = |str: &str| Property::try_from(str).unwrap();
+
+        assert!(_property("URL;PREF=1:HTTPS://example.com\n").value_eq(&_property("item2.URL:https://example.com\n")));
+        assert!(_property("REV:20240101T120000Z\n").value_eq(&_property("REV:20240101T120000Z\n")));
+        assert!(_property("TZ;VALUE=utc-offset:+05:00\n").value_eq(&_property("TZ;VALUE=utc-offset:+0500\n")));
+        assert!(_property("X-CUSTOM;X-A=1:Value\n").value_eq(&Property::PropertyRaw(PropertyRawData::try_from("x-custom;X-A=2:Value").unwrap())));
+        assert!(!_property("TEL:555-5555\n").value_eq(&_property("TEL;VALUE=uri:tel:555-5555\n")));
+        assert!(!_property("NOTE:a\n").value_eq(&_property("NOTE:A\n")));
+    }
+
+    #[test]
+    pub fn property_presets() {
+        let parts = AddressParts {
+            pobox: "Box 1",
+            street: "1, Main St.",
+            locality: "Town\\Village",
+            ..AddressParts::default()
+        };
+        let presets = [
+            Property::email("\"john doe\"@example.com").unwrap(),
+            Property::tel("555-5555 ext. 1, 2").unwrap(),
+            Property::tel("TEL:+1-555-555-5555").unwrap(),
+            Property::url("https://example.com/a;b").unwrap(),
+            Property::adr(parts).unwrap(),
+        ];
+        for preset in presets {
+            assert_eq!(Property::try_from(preset.export().as_str()).unwrap(), preset);
+        }
+
+        assert_eq!(Property::tel("555-5555 ext. 1, 2").unwrap().get_value().as_str(), Some("555-5555 ext. 1, 2"));
+        assert_eq!(Property::adr(parts).unwrap().export(), "ADR:Box 1;;1\\, Main St.;Town\\\\Village;;;\n");
+        assert!(matches!(Property::email("john@"), Err(VcardError::ValueInvalid(_, name)) if name == PropertyName::EMAIL));
+        assert!(matches!(Property::tel(" "), Err(VcardError::ValueEmpty(name)) if name == PropertyName::TEL));
+        assert!(Property::url("").is_err());
+    }
+
+    #[test]
+    pub fn property_hash() {
+        let properties = [
+            "URL:https://EXAMPLE.com/a\n",
+            "URL:https://example.com/a\n",
+            "LANG;TYPE=home:en-US\n",
+            "LANG;TYPE=home:en-us\n",
+            "TZ;VALUE=utc-offset:+05:00\n",
+            "TZ;VALUE=utc-offset:+0500\n",
+            "NOTE:Note\n",
+            "item1.NOTE:Note\n",
+        ];
+
+        let set: HashSet<Property> = properties.iter().map(|str| Property::try_from(*str).expect("Unable to parse property string.")).collect();
+        assert_eq!(set.len(), 5);
+
+        let parameters: HashSet<Parameter> = [
+            ";LANGUAGE=en-US",
+            ";LANGUAGE=en-us",
+            ";PREF=1",
+        ]
+        .iter()
+        .map(|str| Parameter::try_from(*str).unwrap())
+        .collect();
+        assert_eq!(parameters.len(), 2);
+    }
+
+    #[test]
+    pub fn property_uri_schemes() {
+        assert!(matches!(Property::try_from("TEL:tel:+1-555-555-5555\n").unwrap().get_value(), Value::ValueUri(_)));
+        assert!(matches!(Property::try_from("TEL:https://example.com\n").unwrap().get_value(), Value::ValueText(_)));
+        assert!(matches!(Property::try_from("EMAIL:mailto:user@example.com\n").unwrap().get_value(), Value::ValueUri(_)));
+        assert!(Property::try_from("EMAIL;VALUE=URI:https://example.com\n").is_err());
+        assert!(Property::try_from("GEO:https://example.com\n").is_err());
+        assert!(Property::try_from("ADR;GEO=\"https://example.com\":;;;;;;\n").is_err());
+    }
+
+    #[test]
+    pub fn property_email_address() {
+        fn _email(str: &str) -> PropertyEmailData {
+            PropertyEmailData::try_from((None, str, Vec::new())).unwrap()
+        }
+
+        assert!(_email("user@example.com").is_valid_address());
+        assert!(_email("first.last+tag@sub.example.com").is_valid_address());
+        assert!(_email("\"john doe\"@example.com").is_valid_address());
+        assert!(_email("user@[192.168.0.1]").is_valid_address());
+        assert!(_email("mailto:user@example.com?subject=Hi").is_valid_address());
+        assert!(!_email("user").is_valid_address());
+        assert!(!_email("user@@example.com").is_valid_address());
+        assert!(!_email(".user@example.com").is_valid_address());
+        assert!(!_email("user..name@example.com").is_valid_address());
+        assert!(!_email("user name@example.com").is_valid_address());
+        assert!(!_email("user@example..com").is_valid_address());
+
+        let mut email = _email("John.Doe@Example.COM");
+        email.normalize();
+        assert_eq!(email.address(), "John.Doe@example.com");
+    }
+
+    #[test]
+    pub fn property_key_bytes() {
+        fn _key(str: &str, parameters: &str) -> PropertyKeyData {
+            let parameters = if parameters.is_empty() { Vec::new() } else { Vec::from([Parameter::try_from(parameters).unwrap()]) };
+            PropertyKeyData::try_from((None, str, parameters)).unwrap()
+        }
+
+        assert_eq!(_key("data:application/pgp-keys;base64,a2V5", "").key_bytes(), Some((Some(String::from("application/pgp-keys")), b"key".to_vec())));
+        assert_eq!(_key("data:;base64,a2V5", ";MEDIATYPE=application/pgp-keys").key_bytes(), Some((Some(String::from("application/pgp-keys")), b"key".to_vec())));
+        assert_eq!(_key("data:,key%20data", "").key_bytes(), Some((None, b"key data".to_vec())));
+        assert_eq!(_key("http://www.example.com/keys/jdoe.cer", "").key_bytes(), None);
+        assert_eq!(_key("data:application/pgp-keys;base64,!!!", "").key_bytes(), None);
+    }
+
+    #[test]
+    pub fn property_sound_content() {
+        let sound = PropertySoundData::try_from((None, "data:audio/ogg;base64,T2dnUw==", Vec::new())).unwrap();
+        assert_eq!(
+            sound.content(),
+            Some(ValueUriContent::Inline {
+                mediatype: Some(String::from("audio/ogg")),
+                bytes: b"OggS".to_vec()
+            })
+        );
+
+        let sound = PropertySoundData::try_from((None, "data:;base64,T2dnUw==", Vec::from([Parameter::try_from(";MEDIATYPE=audio/ogg").unwrap()]))).unwrap();
+        assert_eq!(
+            sound.content(),
+            Some(ValueUriContent::Inline {
+                mediatype: Some(String::from("audio/ogg")),
+                bytes: b"OggS".to_vec()
+            })
+        );
+
+        let sound = PropertySoundData::try_from((None, "CID:JOHNQPUBLIC.part8.19960229T080000.xyzMail@example.com", Vec::from([Parameter::try_from(";MEDIATYPE=audio/basic").unwrap()]))).unwrap();
+        assert_eq!(
+            sound.content(),
+            Some(ValueUriContent::Remote {
+                mediatype: Some(String::from("audio/basic")),
+                uri: String::from("cid:JOHNQPUBLIC.part8.19960229T080000.xyzMail@example.com")
+            })
+        );
     }
 
     #[test]
@@ -1144,4 +2065,61 @@ mod tests {
         _property_matching(PropertyName::URL, TestDataPropertyValues::URL);
         _property_matching(PropertyName::XML, TestDataPropertyValues::XML);
     }
+
+    #[test]
+    pub fn property_merge_parameters() {
+        let mut property = Property::try_from("EMAIL;TYPE=work;X-A=1;type=\"internet,WORK\";PID=1;PID=1,2.1:john@example.com\n").unwrap();
+        property.merge_parameters();
+        assert_eq!(property.to_string(), "EMAIL;TYPE=work,internet;X-A=1;PID=1,2.1:john@example.com\n");
+        assert_eq!(Property::try_from(property.to_string().as_str()).unwrap(), property);
+
+        let text = "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nTEL;TYPE=home;TYPE=voice:555-5555\nEND:VCARD\n";
+        let vcard = Vcard::try_from(text).unwrap();
+        assert_eq!(vcard.get_properties_by_name("TEL")[0].get_parameters().iter().filter(|p| p.name() == "TYPE").count(), 2);
+        assert_eq!(
+            vcard.export_with(&ExportOptions {
+                parameter_style: ParameterStyle::Repeated,
+                ..ExportOptions::default()
+            }),
+            text
+        );
+
+        let mut merged = Vcard::new("John Doe");
+        merged.set_options(ParserOptions {
+            merge_parameters: true,
+            ..ParserOptions::default()
+        });
+        let tel = merged.set_property(&vcard.get_properties_by_name("TEL")[0]).unwrap();
+        assert_eq!(tel.get_parameters().iter().filter(|p| p.name() == "TYPE").count(), 1);
+        assert_eq!(
+            merged.export_with(&ExportOptions {
+                parameter_style: ParameterStyle::Repeated,
+                ..ExportOptions::default()
+            }),
+            text
+        );
+        assert_eq!(merged.export(), text.replace("TYPE=home;TYPE=voice", "TYPE=home,voice"));
+    }
+
+    #[test]
+    pub fn property_adr_format() {
+        let parts = AddressParts {
+            pobox: "PO Box 12",
+            street: "1 High Street",
+            locality: "London",
+            code: "SW1A 1AA",
+            country: "United Kingdom",
+            ..AddressParts::default()
+        };
+        assert_eq!(parts.format(CountryFormat::Gb), "PO Box 12\n1 High Street\nLondon\nSW1A 1AA\nUnited Kingdom");
+        assert_eq!(parts.format(CountryFormat::Us), "PO Box 12\n1 High Street\nLondon SW1A 1AA\nUnited Kingdom");
+        assert_eq!(AddressParts::default().format(CountryFormat::Jp), "");
+
+        let adr = PropertyAdrData::from(parts);
+        assert_eq!(Property::PropertyAdr(adr.clone()).export(), "ADR:PO Box 12;;1 High Street;London;;SW1A 1AA;United Kingdom\n");
+        assert_eq!(adr.formatted(), parts.format(CountryFormat::Us));
+
+        let Property::PropertyAdr(adr) = Property::try_from("ADR;CC=de;TYPE=work:;Gebäude 2;Hauptstraße 1,Hinterhaus;Berlin;;10115;\n").unwrap() else { panic!("Expected ADR property.") };
+        assert_eq!(adr.formatted(), "Gebäude 2\nHauptstraße 1, Hinterhaus\n10115 Berlin");
+    }
 }
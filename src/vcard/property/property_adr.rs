@@ -1,108 +1,192 @@
-use crate::constants::{Cardinality, ParameterName, PropertyName, ValueType};
-use crate::traits::{HasCardinality, HasGroup, HasName, HasParameters, HasValue};
+use crate::traits::{HasParameters, HasValue};
 use crate::vcard::parameter::Parameter;
+use crate::vcard::value::value_component::Component;
 use crate::vcard::value::value_listcomponent::ValueListComponentData;
 use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueListComponent;
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct PropertyAdrData {
-    group: Option<String>,
-    parameters: Vec<Parameter>,
-    value: Value,
-}
-
-impl HasCardinality for PropertyAdrData {
-    fn cardinality(&self) -> &str {
-        Cardinality::MULTIPLE
+define_property! {
+    PropertyAdrData {
+        name: ADR,
+        cardinality: MULTIPLE,
+        parameters: [ALTID, ANY, CC, GEO, INDEX, LABEL, LANGUAGE, PID, PREF, TYPE, TZ, VALUE],
+        values: [ValueListComponent => [TEXT]],
+        default: ValueListComponent(ValueListComponentData {
+        delimiter_child: ',',
+        delimiter_parent: ';',
+        value: Vec::from([
+            Vec::from([Component::default()]),
+            Vec::from([Component::default()]),
+            Vec::from([Component::default()]),
+            Vec::from([Component::default()]),
+            Vec::from([Component::default()]),
+            Vec::from([Component::default()]),
+            Vec::from([Component::default()]),
+        ]),
+    }),
+        validate: validate_value,
     }
 }
 
-impl HasGroup for PropertyAdrData {
-    fn group(&self) -> &Option<String> {
-        &self.group
-    }
+/// The components of an ADR property, see [RFC 6350 6.3.1](https://datatracker.ietf.org/doc/html/rfc6350#section-6.3.1).
+///
+/// Components are unescaped text, empty components are left out of the [formatted address](AddressParts::format).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AddressParts<'a> {
+    pub pobox: &'a str,
+    pub extended: &'a str,
+    pub street: &'a str,
+    pub locality: &'a str,
+    pub region: &'a str,
+    pub code: &'a str,
+    pub country: &'a str,
 }
 
-impl HasName for PropertyAdrData {
-    fn name(&self) -> &str {
-        PropertyName::ADR
+impl AddressParts<'_> {
+    /// Compose a postal block, with lines ordered as in the country format and separated by a line feed.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::property_adr::{AddressParts, CountryFormat};
+    ///
+    /// let parts = AddressParts { street: "123 Main St.", locality: "Any Town", region: "CA", code: "91921", country: "USA", ..AddressParts::default() };
+    /// assert_eq!(parts.format(CountryFormat::Us), "123 Main St.\nAny Town, CA 91921\nUSA");
+    ///
+    /// let parts = AddressParts { street: "Hauptstraße 1", locality: "Berlin", code: "10115", ..AddressParts::default() };
+    /// assert_eq!(parts.format(CountryFormat::De), "Hauptstraße 1\n10115 Berlin");
+    /// ```
+    pub fn format(&self, format: CountryFormat) -> String {
+        let join = |parts: &[&str], separator: &str| parts.iter().map(|s| s.trim()).filter(|s| !s.is_empty()).collect::<Vec<&str>>().join(separator);
+
+        let lines = match format {
+            CountryFormat::Us => Vec::from([
+                self.pobox.to_string(),
+                self.extended.to_string(),
+                self.street.to_string(),
+                join(&[join(&[self.locality, self.region], ", ").as_str(), self.code], " "),
+                self.country.to_string(),
+            ]),
+            CountryFormat::Gb => Vec::from([
+                self.pobox.to_string(),
+                self.extended.to_string(),
+                self.street.to_string(),
+                self.locality.to_string(),
+                self.region.to_string(),
+                self.code.to_string(),
+                self.country.to_string(),
+            ]),
+            CountryFormat::De => Vec::from([
+                self.pobox.to_string(),
+                self.extended.to_string(),
+                self.street.to_string(),
+                join(&[self.code, self.locality], " "),
+                self.region.to_string(),
+                self.country.to_string(),
+            ]),
+            CountryFormat::Jp => Vec::from([
+                self.code.to_string(),
+                join(&[self.region, self.locality], " "),
+                self.street.to_string(),
+                self.extended.to_string(),
+                self.pobox.to_string(),
+                self.country.to_string(),
+            ]),
+        };
+
+        join(&lines.iter().map(|line| line.as_str()).collect::<Vec<&str>>(), "\n")
     }
 }
 
-impl HasParameters for PropertyAdrData {
-    fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
-        Vec::from([
-            ParameterName::ALTID,
-            ParameterName::ANY,
-            ParameterName::CC,
-            ParameterName::GEO,
-            ParameterName::INDEX,
-            ParameterName::LABEL,
-            ParameterName::LANGUAGE,
-            ParameterName::PID,
-            ParameterName::PREF,
-            ParameterName::TYPE,
-            ParameterName::TZ,
-            ParameterName::VALUE,
-        ])
-    }
-
-    fn get_parameters(&self) -> Vec<Parameter> {
-        self.parameters.clone()
-    }
-
-    fn set_parameters(&mut self, parameters: Vec<Parameter>) {
-        self.parameters = parameters;
+impl<'a> From<&'a [String; 7]> for AddressParts<'a> {
+    fn from(components: &'a [String; 7]) -> Self {
+        let [pobox, extended, street, locality, region, code, country] = components;
+        Self {
+            pobox,
+            extended,
+            street,
+            locality,
+            region,
+            code,
+            country,
+        }
     }
 }
 
-impl HasValue for PropertyAdrData {
-    fn get_value(&self) -> &Value {
-        &self.value
-    }
-
-    fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        if !matches!(value, ValueListComponent(_)) {
-            return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
-        }
-
-        if let Some(value_type) = self.has_value_type() {
-            if matches!(value, ValueListComponent(_)) && value_type != ValueType::TEXT {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-        }
+/// Orderings of the lines of a postal block, see [AddressParts::format](AddressParts::format).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CountryFormat {
+    /// Street, then locality, region and postal code on one line, e.g. in the United States, Canada and Australia.
+    #[default]
+    Us,
+    /// Street, locality, region and postal code on separate lines, e.g. in the United Kingdom and Ireland.
+    Gb,
+    /// Street, then postal code and locality on one line, e.g. in most of continental Europe and Latin America.
+    De,
+    /// Postal code, region and locality, then street, e.g. in Japan, China, Korea and Taiwan.
+    Jp,
+}
 
-        if let ValueListComponent(list) = &value {
-            if list.value.len() != 7 {
-                return Err(VcardError::ValueInvalid(value.to_string(), self.name().to_string()));
-            }
+impl CountryFormat {
+    /// Get the format of an ISO 3166-1 alpha-2 country code as used by the CC parameter, unknown codes are formatted like [Us](CountryFormat::Us).
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::property_adr::CountryFormat;
+    ///
+    /// assert_eq!(CountryFormat::from_code("fr"), CountryFormat::De);
+    /// assert_eq!(CountryFormat::from_code("GB"), CountryFormat::Gb);
+    /// assert_eq!(CountryFormat::from_code("zz"), CountryFormat::Us);
+    /// ```
+    pub fn from_code(code: &str) -> Self {
+        match code.trim().to_uppercase().as_str() {
+            "GB" | "IE" | "UK" => CountryFormat::Gb,
+            "AR" | "AT" | "BE" | "BR" | "CH" | "CL" | "CZ" | "DE" | "DK" | "ES" | "FI" | "FR" | "GR" | "HU" | "IS" | "IT" | "LI" | "LU" | "MX" | "NL" | "NO" | "PL" | "PT" | "SE" | "SI" | "SK" => CountryFormat::De,
+            "CN" | "JP" | "KR" | "TW" => CountryFormat::Jp,
+            _ => CountryFormat::Us,
         }
+    }
+}
 
-        self.value = value;
-
-        Ok(())
+impl PropertyAdrData {
+    /// Compose a postal block from the address, formatted for the country of the CC parameter, see [RFC 8605 3.1](https://datatracker.ietf.org/doc/html/rfc8605#section-3.1).
+    ///
+    /// The values of a component with several values are joined by a comma, addresses without a CC parameter are
+    /// formatted like [Us](CountryFormat::Us).
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::Property;
+    ///
+    /// let property = Property::try_from("ADR;CC=JP:;;1-1 Chiyoda;Chiyoda-ku;Tokyo;100-0001;Japan\n").expect("Unable to parse property.");
+    /// if let Property::PropertyAdr(adr) = property {
+    ///     assert_eq!(adr.formatted(), "100-0001\nTokyo Chiyoda-ku\n1-1 Chiyoda\nJapan");
+    /// }
+    /// ```
+    pub fn formatted(&self) -> String {
+        let format = self.parameters.iter().find_map(|parameter| match parameter {
+            Parameter::ParameterCc(cc) => Some(CountryFormat::from_code(cc.get_value().to_string().as_str())),
+            _ => None,
+        });
+
+        let components: [String; 7] = match &self.value {
+            ValueListComponent(list) => std::array::from_fn(|i| list.value.get(i).map(|values| values.iter().map(|s| s.trim()).filter(|s| !s.is_empty()).collect::<Vec<&str>>().join(", ")).unwrap_or_default()),
+            _ => Default::default(),
+        };
+
+        AddressParts::from(&components).format(format.unwrap_or_default())
     }
 }
 
-impl Default for PropertyAdrData {
-    fn default() -> Self {
+impl From<AddressParts<'_>> for PropertyAdrData {
+    fn from(parts: AddressParts) -> Self {
         Self {
             group: None,
             parameters: Vec::new(),
             value: ValueListComponent(ValueListComponentData {
                 delimiter_child: ',',
                 delimiter_parent: ';',
-                value: Vec::from([
-                    Vec::from([String::new()]),
-                    Vec::from([String::new()]),
-                    Vec::from([String::new()]),
-                    Vec::from([String::new()]),
-                    Vec::from([String::new()]),
-                    Vec::from([String::new()]),
-                    Vec::from([String::new()]),
-                ]),
+                value: [parts.pobox, parts.extended, parts.street, parts.locality, parts.region, parts.code, parts.country].iter().map(|s| Vec::from([Component::from(*s)])).collect(),
             }),
         }
     }
@@ -114,8 +198,23 @@ impl TryFrom<(Option<String>, &str, Vec<Parameter>)> for PropertyAdrData {
         let mut property = Self { group, ..Self::default() };
 
         property.add_parameters(parameters)?;
-        property.set_value(ValueListComponent(ValueListComponentData::try_from((value, ';', ','))?))?;
+
+        // An empty value has all components empty.
+        if !value.is_empty() {
+            property.set_value(ValueListComponent(ValueListComponentData::try_from((value, ';', ','))?))?;
+        }
 
         Ok(property)
     }
 }
+
+/// Helper function validating values after the value type checks.
+fn validate_value(name: &str, value: &Value) -> Result<(), VcardError> {
+    if let ValueListComponent(list) = value {
+        if list.value.len() != 7 {
+            return Err(VcardError::ValueInvalid(value.to_string(), name.to_string()));
+        }
+    }
+
+    Ok(())
+}
@@ -1,79 +1,32 @@
-use crate::constants::{Cardinality, ParameterName, PropertyName, ValueType};
-use crate::traits::{HasCardinality, HasGroup, HasName, HasParameters, HasValue};
+use time::Date;
+
+use crate::constants::ValueType;
+use crate::traits::{HasParameters, HasValue};
 use crate::vcard::parameter::Parameter;
-use crate::vcard::value::value_date::ValueDateData;
+use crate::vcard::value::value_date::{RecurringDate, ValueDateData};
 use crate::vcard::value::value_text::ValueTextData;
-use crate::vcard::value::Value;
 use crate::vcard::value::Value::{ValueDate, ValueText};
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct PropertyAnniversaryData {
-    group: Option<String>,
-    parameters: Vec<Parameter>,
-    value: Value,
-}
-
-impl HasCardinality for PropertyAnniversaryData {
-    fn cardinality(&self) -> &str {
-        Cardinality::SINGLE
-    }
-}
-
-impl HasGroup for PropertyAnniversaryData {
-    fn group(&self) -> &Option<String> {
-        &self.group
+define_property! {
+    PropertyAnniversaryData {
+        name: ANNIVERSARY,
+        cardinality: SINGLE,
+        parameters: [ALTID, ANY, CALSCALE, VALUE],
+        values: [ValueText, ValueDate],
+        default: ValueText(ValueTextData::default()),
     }
 }
 
-impl HasName for PropertyAnniversaryData {
-    fn name(&self) -> &str {
-        PropertyName::ANNIVERSARY
-    }
-}
-
-impl HasParameters for PropertyAnniversaryData {
-    fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
-        Vec::from([
-            ParameterName::ALTID,
-            ParameterName::ANY,
-            ParameterName::CALSCALE,
-            ParameterName::VALUE,
-        ])
+impl PropertyAnniversaryData {
+    /// Get the number of full years since the anniversary on a date, None if the year is unknown or the date is earlier.
+    pub fn age_on(&self, date: Date) -> Option<u32> {
+        RecurringDate::from_value(&self.value)?.years_on(date)
     }
 
-    fn get_parameters(&self) -> Vec<Parameter> {
-        self.parameters.clone()
-    }
-
-    fn set_parameters(&mut self, parameters: Vec<Parameter>) {
-        self.parameters = parameters;
-    }
-}
-
-impl HasValue for PropertyAnniversaryData {
-    fn get_value(&self) -> &Value {
-        &self.value
-    }
-
-    fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        if !matches!(value, ValueText(_)) && !matches!(value, ValueDate(_)) {
-            return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
-        }
-
-        self.value = value;
-
-        Ok(())
-    }
-}
-
-impl Default for PropertyAnniversaryData {
-    fn default() -> Self {
-        Self {
-            group: None,
-            parameters: Vec::new(),
-            value: ValueText(ValueTextData::default()),
-        }
+    /// Get the next anniversary after a date, also for anniversaries without a year, e.g. "--0415".
+    pub fn next_occurrence(&self, after: Date) -> Option<Date> {
+        RecurringDate::from_value(&self.value)?.next_occurrence(after)
     }
 }
 
@@ -1,89 +1,54 @@
-use crate::constants::{Cardinality, ParameterName, PropertyName, ValueType};
-use crate::traits::{HasCardinality, HasGroup, HasName, HasParameters, HasValue};
+use time::Date;
+
+use crate::constants::ValueType;
+use crate::traits::{HasParameters, HasValue};
 use crate::vcard::parameter::Parameter;
-use crate::vcard::value::value_date::ValueDateData;
+use crate::vcard::value::value_date::{RecurringDate, ValueDateData};
 use crate::vcard::value::value_text::ValueTextData;
-use crate::vcard::value::Value;
 use crate::vcard::value::Value::{ValueDate, ValueText};
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct PropertyBDayData {
-    group: Option<String>,
-    parameters: Vec<Parameter>,
-    value: Value,
-}
-
-impl HasCardinality for PropertyBDayData {
-    fn cardinality(&self) -> &str {
-        Cardinality::SINGLE
-    }
-}
-
-impl HasGroup for PropertyBDayData {
-    fn group(&self) -> &Option<String> {
-        &self.group
-    }
-}
-
-impl HasName for PropertyBDayData {
-    fn name(&self) -> &str {
-        PropertyName::BDAY
+define_property! {
+    PropertyBDayData {
+        name: BDAY,
+        cardinality: SINGLE,
+        parameters: [ALTID, ANY, CALSCALE, LANGUAGE, VALUE],
+        values: [ValueText => [TEXT], ValueDate => [DATE, DATE_TIME, DATE_AND_OR_TIME]],
+        default: ValueText(ValueTextData::default()),
     }
 }
 
-impl HasParameters for PropertyBDayData {
-    fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
-        Vec::from([
-            ParameterName::ALTID,
-            ParameterName::ANY,
-            ParameterName::CALSCALE,
-            ParameterName::LANGUAGE,
-            ParameterName::VALUE,
-        ])
-    }
-
-    fn get_parameters(&self) -> Vec<Parameter> {
-        self.parameters.clone()
+impl PropertyBDayData {
+    /// Get the age in full years on a date, None if the year of birth is unknown or the date is before the birth.
+    ///
+    /// People born on February 29 turn a year older on February 28 in years that aren't leap years.
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{Date, Month};
+    /// use vcard_parser::vcard::property::Property;
+    ///
+    /// let Property::PropertyBDay(bday) = Property::try_from("BDAY:19960229\n").expect("Unable to parse property.") else { unreachable!() };
+    /// assert_eq!(bday.age_on(Date::from_calendar_date(2023, Month::February, 27).unwrap()), Some(26));
+    /// assert_eq!(bday.age_on(Date::from_calendar_date(2023, Month::February, 28).unwrap()), Some(27));
+    /// ```
+    pub fn age_on(&self, date: Date) -> Option<u32> {
+        RecurringDate::from_value(&self.value)?.years_on(date)
     }
 
-    fn set_parameters(&mut self, parameters: Vec<Parameter>) {
-        self.parameters = parameters;
-    }
-}
-
-impl HasValue for PropertyBDayData {
-    fn get_value(&self) -> &Value {
-        &self.value
-    }
-
-    fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        if !matches!(value, ValueText(_)) && !matches!(value, ValueDate(_)) {
-            return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
-        }
-
-        if let Some(value_type) = self.has_value_type() {
-            if matches!(value, ValueText(_)) && value_type != ValueType::TEXT {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-            if matches!(value, ValueDate(_)) && !(value_type == ValueType::DATE || value_type == ValueType::DATE_TIME || value_type == ValueType::DATE_AND_OR_TIME) {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-        }
-
-        self.value = value;
-
-        Ok(())
-    }
-}
-
-impl Default for PropertyBDayData {
-    fn default() -> Self {
-        Self {
-            group: None,
-            parameters: Vec::new(),
-            value: ValueText(ValueTextData::default()),
-        }
+    /// Get the next birthday after a date, also for birthdays without a year, e.g. "--0415".
+    ///
+    /// # Examples
+    /// ```
+    /// use time::{Date, Month};
+    /// use vcard_parser::vcard::property::Property;
+    ///
+    /// let Property::PropertyBDay(bday) = Property::try_from("BDAY:--0415\n").expect("Unable to parse property.") else { unreachable!() };
+    /// assert_eq!(bday.next_occurrence(Date::from_calendar_date(2024, Month::April, 15).unwrap()), Some(Date::from_calendar_date(2025, Month::April, 15).unwrap()));
+    /// assert_eq!(bday.age_on(Date::from_calendar_date(2024, Month::April, 15).unwrap()), None);
+    /// ```
+    pub fn next_occurrence(&self, after: Date) -> Option<Date> {
+        RecurringDate::from_value(&self.value)?.next_occurrence(after)
     }
 }
 
@@ -1,87 +1,18 @@
-use crate::constants::{Cardinality, ParameterName, PropertyName, ValueType};
-use crate::traits::{HasCardinality, HasGroup, HasName, HasParameters, HasValue};
+use crate::constants::ValueType;
+use crate::traits::{HasParameters, HasValue};
 use crate::vcard::parameter::Parameter;
 use crate::vcard::value::value_text::ValueTextData;
 use crate::vcard::value::value_uri::ValueUriData;
-use crate::vcard::value::Value;
 use crate::vcard::value::Value::{ValueText, ValueUri};
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct PropertyBirthPlaceData {
-    group: Option<String>,
-    parameters: Vec<Parameter>,
-    value: Value,
-}
-
-impl HasCardinality for PropertyBirthPlaceData {
-    fn cardinality(&self) -> &str {
-        Cardinality::SINGLE
-    }
-}
-
-impl HasGroup for PropertyBirthPlaceData {
-    fn group(&self) -> &Option<String> {
-        &self.group
-    }
-}
-
-impl HasName for PropertyBirthPlaceData {
-    fn name(&self) -> &str {
-        PropertyName::BIRTHPLACE
-    }
-}
-
-impl HasParameters for PropertyBirthPlaceData {
-    fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
-        Vec::from([
-            ParameterName::ALTID,
-            ParameterName::LANGUAGE,
-            ParameterName::VALUE,
-        ])
-    }
-
-    fn get_parameters(&self) -> Vec<Parameter> {
-        self.parameters.clone()
-    }
-
-    fn set_parameters(&mut self, parameters: Vec<Parameter>) {
-        self.parameters = parameters;
-    }
-}
-
-impl HasValue for PropertyBirthPlaceData {
-    fn get_value(&self) -> &Value {
-        &self.value
-    }
-
-    fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        if !matches!(value, ValueText(_)) && !matches!(value, ValueUri(_)) {
-            return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
-        }
-
-        if let Some(value_type) = self.has_value_type() {
-            if matches!(value, ValueText(_)) && value_type != ValueType::TEXT {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-            if matches!(value, ValueUri(_)) && value_type != ValueType::URI {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-        }
-
-        self.value = value;
-
-        Ok(())
-    }
-}
-
-impl Default for PropertyBirthPlaceData {
-    fn default() -> Self {
-        Self {
-            group: None,
-            parameters: Vec::new(),
-            value: ValueText(ValueTextData::default()),
-        }
+define_property! {
+    PropertyBirthPlaceData {
+        name: BIRTHPLACE,
+        cardinality: SINGLE,
+        parameters: [ALTID, LANGUAGE, VALUE],
+        values: [ValueText => [TEXT], ValueUri => [URI]],
+        default: ValueText(ValueTextData::default()),
     }
 }
 
@@ -1,87 +1,16 @@
-use crate::constants::{Cardinality, ParameterName, PropertyName, ValueType};
-use crate::traits::{HasCardinality, HasGroup, HasName, HasParameters, HasValue};
+use crate::traits::{HasParameters, HasValue};
 use crate::vcard::parameter::Parameter;
 use crate::vcard::value::value_textlist::ValueTextListData;
-use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueTextList;
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct PropertyCategoriesData {
-    group: Option<String>,
-    parameters: Vec<Parameter>,
-    value: Value,
-}
-
-impl HasCardinality for PropertyCategoriesData {
-    fn cardinality(&self) -> &str {
-        Cardinality::MULTIPLE
-    }
-}
-
-impl HasGroup for PropertyCategoriesData {
-    fn group(&self) -> &Option<String> {
-        &self.group
-    }
-}
-
-impl HasName for PropertyCategoriesData {
-    fn name(&self) -> &str {
-        PropertyName::CATEGORIES
-    }
-}
-
-impl HasParameters for PropertyCategoriesData {
-    fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
-        Vec::from([
-            ParameterName::ALTID,
-            ParameterName::ANY,
-            ParameterName::INDEX,
-            ParameterName::PID,
-            ParameterName::PREF,
-            ParameterName::TYPE,
-            ParameterName::VALUE,
-        ])
-    }
-
-    fn get_parameters(&self) -> Vec<Parameter> {
-        self.parameters.clone()
-    }
-
-    fn set_parameters(&mut self, parameters: Vec<Parameter>) {
-        self.parameters = parameters;
-    }
-}
-
-impl HasValue for PropertyCategoriesData {
-    fn get_value(&self) -> &Value {
-        &self.value
-    }
-
-    fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        if !matches!(value, ValueTextList(_)) {
-            return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
-        }
-
-        if let Some(value_type) = self.has_value_type() {
-            if matches!(value, ValueTextList(_)) && value_type != ValueType::TEXT {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-        }
-
-        self.value = value;
-
-        Ok(())
-    }
-}
-
-impl Default for PropertyCategoriesData {
-    fn default() -> Self {
-        Self {
-            group: None,
-            parameters: Vec::new(),
-            value: ValueTextList(ValueTextListData::default()),
-        }
+define_property! {
+    PropertyCategoriesData {
+        name: CATEGORIES,
+        cardinality: MULTIPLE,
+        parameters: [ALTID, ANY, INDEX, PID, PREF, TYPE, VALUE],
+        values: [ValueTextList => [TEXT]],
+        default: ValueTextList(ValueTextListData::default()),
     }
 }
 
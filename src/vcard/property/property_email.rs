@@ -1,5 +1,5 @@
-use crate::constants::{Cardinality, ParameterName, PropertyName, ValueType};
-use crate::traits::{HasCardinality, HasGroup, HasName, HasParameters, HasValue};
+use crate::constants::{PropertyUriSchemes, ValueType};
+use crate::traits::{HasParameters, HasValue};
 use crate::vcard::parameter::Parameter;
 use crate::vcard::value::value_text::ValueTextData;
 use crate::vcard::value::value_uri::ValueUriData;
@@ -7,85 +7,41 @@ use crate::vcard::value::Value;
 use crate::vcard::value::Value::{ValueText, ValueUri};
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct PropertyEmailData {
-    group: Option<String>,
-    parameters: Vec<Parameter>,
-    value: Value,
-}
-
-impl HasCardinality for PropertyEmailData {
-    fn cardinality(&self) -> &str {
-        Cardinality::MULTIPLE
-    }
-}
-
-impl HasGroup for PropertyEmailData {
-    fn group(&self) -> &Option<String> {
-        &self.group
+define_property! {
+    PropertyEmailData {
+        name: EMAIL,
+        cardinality: MULTIPLE,
+        parameters: [ALTID, ANY, INDEX, PID, PREF, TYPE, VALUE],
+        values: [ValueText => [TEXT], ValueUri => [URI]],
+        default: ValueText(ValueTextData::default()),
+        validate: validate_value,
     }
 }
 
-impl HasName for PropertyEmailData {
-    fn name(&self) -> &str {
-        PropertyName::EMAIL
-    }
-}
-
-impl HasParameters for PropertyEmailData {
-    fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
-        Vec::from([
-            ParameterName::ALTID,
-            ParameterName::ANY,
-            ParameterName::INDEX,
-            ParameterName::PID,
-            ParameterName::PREF,
-            ParameterName::TYPE,
-            ParameterName::VALUE,
-        ])
-    }
-
-    fn get_parameters(&self) -> Vec<Parameter> {
-        self.parameters.clone()
+impl PropertyEmailData {
+    /// Get the email address, either the text value or the address of a mailto uri.
+    pub fn address(&self) -> String {
+        match &self.value {
+            ValueUri(uri) => uri.value.split_once(':').map(|(_, address)| address.split('?').next().unwrap_or_default().to_string()).unwrap_or_default(),
+            ValueText(text) => text.value.clone(),
+            value => value.to_string(),
+        }
     }
 
-    fn set_parameters(&mut self, parameters: Vec<Parameter>) {
-        self.parameters = parameters;
+    /// Check the address against the addr-spec syntax, see [RFC 5322 3.4.1](https://datatracker.ietf.org/doc/html/rfc5322#section-3.4.1).
+    ///
+    /// Obsolete syntax, comments and folding whitespace are not accepted.
+    pub fn is_valid_address(&self) -> bool {
+        is_addr_spec(self.address().as_str())
     }
-}
 
-impl HasValue for PropertyEmailData {
-    fn get_value(&self) -> &Value {
-        &self.value
-    }
-
-    fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        if !matches!(value, ValueText(_)) && !matches!(value, ValueUri(_)) {
-            return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
-        }
-
-        if let Some(value_type) = self.has_value_type() {
-            if matches!(value, ValueText(_)) && value_type != ValueType::TEXT {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-            if matches!(value, ValueUri(_)) && value_type != ValueType::URI {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
+    /// Lowercase the domain part of the address, the local part is left untouched as it may be case-sensitive.
+    pub fn normalize(&mut self) {
+        if let ValueText(text) = &mut self.value {
+            if let Some((local, domain)) = text.value.rsplit_once('@') {
+                text.value = format!("{}@{}", local, domain.to_lowercase());
             }
         }
-
-        self.value = value;
-
-        Ok(())
-    }
-}
-
-impl Default for PropertyEmailData {
-    fn default() -> Self {
-        Self {
-            group: None,
-            parameters: Vec::new(),
-            value: ValueText(ValueTextData::default()),
-        }
     }
 }
 
@@ -104,11 +60,66 @@ impl TryFrom<(Option<String>, &str, Vec<Parameter>)> for PropertyEmailData {
             }
         } else {
             property.set_value(match ValueUriData::try_from(value) {
-                Ok(data) => ValueUri(data),
-                Err(_) => ValueText(ValueTextData::from(value)),
+                Ok(data) if data.has_scheme(&PropertyUriSchemes::EMAIL) => ValueUri(data),
+                _ => ValueText(ValueTextData::from(value)),
             })?;
         }
 
         Ok(property)
     }
 }
+
+/// Helper function checking a string against the addr-spec rule without obsolete forms.
+fn is_addr_spec(str: &str) -> bool {
+    let Some((local, domain)) = str.rsplit_once('@') else {
+        return false;
+    };
+
+    let local_valid = if local.len() >= 2 && local.starts_with('"') && local.ends_with('"') {
+        is_quoted_content(&local[1..local.len() - 1])
+    } else {
+        is_dot_atom(local)
+    };
+
+    let domain_valid = if domain.starts_with('[') && domain.ends_with(']') {
+        domain[1..domain.len() - 1].chars().all(|c| c.is_ascii_graphic() && !matches!(c, '[' | ']' | '\\'))
+    } else {
+        is_dot_atom(domain)
+    };
+
+    local_valid && domain_valid
+}
+
+fn is_dot_atom(str: &str) -> bool {
+    !str.is_empty() && str.split('.').all(|atom| !atom.is_empty() && atom.chars().all(is_atext))
+}
+
+fn is_atext(char: char) -> bool {
+    char.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(char) || !char.is_ascii()
+}
+
+fn is_quoted_content(str: &str) -> bool {
+    let mut chars = str.chars();
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => {
+                if !matches!(chars.next(), Some(c) if c == ' ' || c == '\t' || c.is_ascii_graphic()) {
+                    return false;
+                }
+            }
+            '"' => return false,
+            c if c == ' ' || c == '\t' || c.is_ascii_graphic() || !c.is_ascii() => {}
+            _ => return false,
+        }
+    }
+    true
+}
+
+/// Helper function validating values after the value type checks.
+fn validate_value(name: &str, value: &Value) -> Result<(), VcardError> {
+    if let ValueUri(uri) = value {
+        uri.validate_scheme(&PropertyUriSchemes::EMAIL, name)?;
+    }
+
+    Ok(())
+}
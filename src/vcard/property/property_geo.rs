@@ -1,87 +1,29 @@
-use crate::constants::{Cardinality, ParameterName, PropertyName, ValueType};
-use crate::traits::{HasCardinality, HasGroup, HasName, HasParameters, HasValue};
+use crate::constants::PropertyUriSchemes;
+use crate::traits::{HasParameters, HasValue};
 use crate::vcard::parameter::Parameter;
+use crate::vcard::value::value_geo::ValueGeoData;
 use crate::vcard::value::value_uri::ValueUriData;
 use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueUri;
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct PropertyGeoData {
-    group: Option<String>,
-    parameters: Vec<Parameter>,
-    value: Value,
-}
-
-impl HasCardinality for PropertyGeoData {
-    fn cardinality(&self) -> &str {
-        Cardinality::MULTIPLE
-    }
-}
-
-impl HasGroup for PropertyGeoData {
-    fn group(&self) -> &Option<String> {
-        &self.group
-    }
-}
-
-impl HasName for PropertyGeoData {
-    fn name(&self) -> &str {
-        PropertyName::GEO
-    }
-}
-
-impl HasParameters for PropertyGeoData {
-    fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
-        Vec::from([
-            ParameterName::ALTID,
-            ParameterName::ANY,
-            ParameterName::INDEX,
-            ParameterName::MEDIATYPE,
-            ParameterName::PID,
-            ParameterName::PREF,
-            ParameterName::TYPE,
-            ParameterName::VALUE,
-        ])
-    }
-
-    fn get_parameters(&self) -> Vec<Parameter> {
-        self.parameters.clone()
-    }
-
-    fn set_parameters(&mut self, parameters: Vec<Parameter>) {
-        self.parameters = parameters;
+define_property! {
+    PropertyGeoData {
+        name: GEO,
+        cardinality: MULTIPLE,
+        parameters: [ALTID, ANY, INDEX, MEDIATYPE, PID, PREF, TYPE, VALUE],
+        values: [ValueUri => [URI]],
+        default: ValueUri(ValueUriData::default()),
+        validate: validate_value,
     }
 }
 
-impl HasValue for PropertyGeoData {
-    fn get_value(&self) -> &Value {
-        &self.value
-    }
-
-    fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        if !matches!(value, ValueUri(_)) {
-            return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
-        }
-
-        if let Some(value_type) = self.has_value_type() {
-            if matches!(value, ValueUri(_)) && value_type != ValueType::URI {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-        }
-
-        self.value = value;
-
-        Ok(())
-    }
-}
-
-impl Default for PropertyGeoData {
-    fn default() -> Self {
-        Self {
-            group: None,
-            parameters: Vec::new(),
-            value: ValueUri(ValueUriData::default()),
+impl PropertyGeoData {
+    /// Get the coordinates of the geo uri, shared with the [GEO parameter](crate::vcard::parameter::parameter_geo::ParameterGeoData::geo).
+    pub fn geo(&self) -> Option<ValueGeoData> {
+        match &self.value {
+            ValueUri(uri) => ValueGeoData::try_from(uri).ok(),
+            _ => None,
         }
     }
 }
@@ -97,3 +39,13 @@ impl TryFrom<(Option<String>, &str, Vec<Parameter>)> for PropertyGeoData {
         Ok(property)
     }
 }
+
+/// Helper function validating values after the value type checks.
+fn validate_value(name: &str, value: &Value) -> Result<(), VcardError> {
+    if let ValueUri(uri) = value {
+        uri.validate_scheme(&PropertyUriSchemes::GEO, name)?;
+        ValueGeoData::try_from(uri)?;
+    }
+
+    Ok(())
+}
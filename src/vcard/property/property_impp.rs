@@ -1,87 +1,79 @@
-use crate::constants::{Cardinality, ParameterName, PropertyName, ValueType};
-use crate::traits::{HasCardinality, HasGroup, HasName, HasParameters, HasValue};
+use crate::constants::{PropertyName, PropertyUriSchemes};
+use crate::traits::{HasParameters, HasValue};
 use crate::vcard::parameter::Parameter;
 use crate::vcard::value::value_uri::ValueUriData;
-use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueUri;
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct PropertyImppData {
-    group: Option<String>,
-    parameters: Vec<Parameter>,
-    value: Value,
-}
-
-impl HasCardinality for PropertyImppData {
-    fn cardinality(&self) -> &str {
-        Cardinality::MULTIPLE
+define_property! {
+    PropertyImppData {
+        name: IMPP,
+        cardinality: MULTIPLE,
+        parameters: [ALTID, ANY, INDEX, MEDIATYPE, PID, PREF, TYPE, VALUE],
+        values: [ValueUri => [URI]],
+        default: ValueUri(ValueUriData::default()),
     }
 }
 
-impl HasGroup for PropertyImppData {
-    fn group(&self) -> &Option<String> {
-        &self.group
+impl PropertyImppData {
+    /// Get the lowercase uri scheme, e.g. "xmpp" for "xmpp:alice@example.com".
+    pub fn scheme(&self) -> String {
+        match &self.value {
+            ValueUri(uri) => uri.scheme(),
+            _ => String::new(),
+        }
     }
-}
 
-impl HasName for PropertyImppData {
-    fn name(&self) -> &str {
-        PropertyName::IMPP
-    }
-}
+    /// Get the instant messaging address without the scheme, uri parameters or query, e.g. "alice@example.com" for
+    /// "sip:alice@example.com;transport=tcp". For schemes passing the address in the query, such as
+    /// "aim:goim?screenname=alice", the address from the query is returned.
+    pub fn address(&self) -> String {
+        let ValueUri(uri) = &self.value else {
+            return String::new();
+        };
+
+        let rest = uri.value.split_once(':').map(|(_, rest)| rest).unwrap_or_default();
+        let (address, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+        // Schemes like aim, msnim and ymsgr use an action followed by the address in the query.
+        if ["chat", "goim", "sendim"].contains(&address.to_lowercase().as_str()) {
+            for field in query.split(['&', ';']) {
+                match field.split_once('=') {
+                    Some((key, value)) if ["contact", "screenname"].contains(&key.to_lowercase().as_str()) => return value.to_string(),
+                    None if !field.is_empty() => return field.to_string(),
+                    _ => {}
+                }
+            }
+        }
 
-impl HasParameters for PropertyImppData {
-    fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
-        Vec::from([
-            ParameterName::ALTID,
-            ParameterName::ANY,
-            ParameterName::INDEX,
-            ParameterName::MEDIATYPE,
-            ParameterName::PID,
-            ParameterName::PREF,
-            ParameterName::TYPE,
-            ParameterName::VALUE,
-        ])
+        address.trim_start_matches('/').split(';').next().unwrap_or_default().trim_end_matches('/').to_string()
     }
 
-    fn get_parameters(&self) -> Vec<Parameter> {
-        self.parameters.clone()
+    /// Check if the uri uses a known instant messaging scheme, see [PropertyUriSchemes::IMPP](PropertyUriSchemes::IMPP).
+    pub fn is_known_scheme(&self) -> bool {
+        PropertyUriSchemes::IMPP.contains(&self.scheme().as_str())
     }
 
-    fn set_parameters(&mut self, parameters: Vec<Parameter>) {
-        self.parameters = parameters;
+    /// Check if the uri is an XMPP (Jabber) address, see [RFC 5122](https://datatracker.ietf.org/doc/html/rfc5122).
+    pub fn is_xmpp(&self) -> bool {
+        self.scheme() == "xmpp"
     }
-}
 
-impl HasValue for PropertyImppData {
-    fn get_value(&self) -> &Value {
-        &self.value
+    /// Check if the uri is a SIP or SIPS address, see [RFC 3261](https://datatracker.ietf.org/doc/html/rfc3261).
+    pub fn is_sip(&self) -> bool {
+        matches!(self.scheme().as_str(), "sip" | "sips")
     }
 
-    fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        if !matches!(value, ValueUri(_)) {
-            return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
-        }
-
-        if let Some(value_type) = self.has_value_type() {
-            if matches!(value, ValueUri(_)) && value_type != ValueType::URI {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-        }
-
-        self.value = value;
-
-        Ok(())
+    /// Check if the uri is a Skype address.
+    pub fn is_skype(&self) -> bool {
+        self.scheme() == "skype"
     }
-}
 
-impl Default for PropertyImppData {
-    fn default() -> Self {
-        Self {
-            group: None,
-            parameters: Vec::new(),
-            value: ValueUri(ValueUriData::default()),
+    /// Return an error if the uri doesn't use a known instant messaging scheme.
+    pub fn validate_scheme(&self) -> Result<(), VcardError> {
+        match &self.value {
+            ValueUri(uri) => uri.validate_scheme(&PropertyUriSchemes::IMPP, PropertyName::IMPP),
+            value => Err(VcardError::ValueInvalid(value.to_string(), PropertyName::IMPP.to_string())),
         }
     }
 }
@@ -97,3 +89,4 @@ impl TryFrom<(Option<String>, &str, Vec<Parameter>)> for PropertyImppData {
         Ok(property)
     }
 }
+
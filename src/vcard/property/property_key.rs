@@ -1,89 +1,31 @@
-use crate::constants::{Cardinality, ParameterName, PropertyName, ValueType};
-use crate::traits::{HasCardinality, HasGroup, HasName, HasParameters, HasValue};
+use crate::constants::ValueType;
+use crate::traits::{HasParameters, HasValue};
 use crate::vcard::parameter::Parameter;
 use crate::vcard::value::value_text::ValueTextData;
 use crate::vcard::value::value_uri::ValueUriData;
-use crate::vcard::value::Value;
 use crate::vcard::value::Value::{ValueText, ValueUri};
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct PropertyKeyData {
-    group: Option<String>,
-    parameters: Vec<Parameter>,
-    value: Value,
-}
-
-impl HasCardinality for PropertyKeyData {
-    fn cardinality(&self) -> &str {
-        Cardinality::MULTIPLE
-    }
-}
-
-impl HasGroup for PropertyKeyData {
-    fn group(&self) -> &Option<String> {
-        &self.group
-    }
-}
-
-impl HasName for PropertyKeyData {
-    fn name(&self) -> &str {
-        PropertyName::KEY
-    }
-}
-
-impl HasParameters for PropertyKeyData {
-    fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
-        Vec::from([
-            ParameterName::ALTID,
-            ParameterName::ANY,
-            ParameterName::LANGUAGE,
-            ParameterName::PID,
-            ParameterName::VALUE,
-        ])
-    }
-
-    fn get_parameters(&self) -> Vec<Parameter> {
-        self.parameters.clone()
-    }
-
-    fn set_parameters(&mut self, parameters: Vec<Parameter>) {
-        self.parameters = parameters;
-    }
-}
-
-impl HasValue for PropertyKeyData {
-    fn get_value(&self) -> &Value {
-        &self.value
-    }
-
-    fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        if !matches!(value, ValueText(_)) && !matches!(value, ValueUri(_)) {
-            return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
-        }
-
-        if let Some(value_type) = self.has_value_type() {
-            if matches!(value, ValueText(_)) && value_type != ValueType::TEXT {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-            if matches!(value, ValueUri(_)) && value_type != ValueType::URI {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-        }
-
-        self.value = value;
-
-        Ok(())
+define_property! {
+    PropertyKeyData {
+        name: KEY,
+        cardinality: MULTIPLE,
+        parameters: [ALTID, ANY, LANGUAGE, MEDIATYPE, PID, PREF, TYPE, VALUE],
+        values: [ValueText => [TEXT], ValueUri => [URI]],
+        default: ValueText(ValueTextData::default()),
     }
 }
 
-impl Default for PropertyKeyData {
-    fn default() -> Self {
-        Self {
-            group: None,
-            parameters: Vec::new(),
-            value: ValueText(ValueTextData::default()),
+impl PropertyKeyData {
+    /// Get the decoded key and its mediatype from an inline data uri value.
+    ///
+    /// The mediatype of the data uri takes precedence over the MEDIATYPE parameter. Returns None for text values and external uris.
+    pub fn key_bytes(&self) -> Option<(Option<String>, Vec<u8>)> {
+        if let ValueUri(uri) = &self.value {
+            let (mediatype, bytes) = uri.decode_data()?;
+            return Some((mediatype.or_else(|| self.has_mediatype()), bytes));
         }
+        None
     }
 }
 
@@ -1,88 +1,19 @@
-use crate::constants::{Cardinality, ParameterName, PropertyKindValues, PropertyName, ValueType};
-use crate::traits::{HasCardinality, HasGroup, HasName, HasParameters, HasValue};
+use crate::constants::PropertyKindValues;
+use crate::traits::{HasParameters, HasValue};
 use crate::vcard::parameter::Parameter;
 use crate::vcard::value::value_text::ValueTextData;
 use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueText;
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct PropertyKindData {
-    group: Option<String>,
-    parameters: Vec<Parameter>,
-    value: Value,
-}
-
-impl HasCardinality for PropertyKindData {
-    fn cardinality(&self) -> &str {
-        Cardinality::SINGLE
-    }
-}
-
-impl HasGroup for PropertyKindData {
-    fn group(&self) -> &Option<String> {
-        &self.group
-    }
-}
-
-impl HasName for PropertyKindData {
-    fn name(&self) -> &str {
-        PropertyName::KIND
-    }
-}
-
-impl HasParameters for PropertyKindData {
-    fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
-        Vec::from([
-            ParameterName::ANY,
-            ParameterName::VALUE,
-        ])
-    }
-
-    fn get_parameters(&self) -> Vec<Parameter> {
-        self.parameters.clone()
-    }
-
-    fn set_parameters(&mut self, parameters: Vec<Parameter>) {
-        self.parameters = parameters;
-    }
-}
-
-impl HasValue for PropertyKindData {
-    fn get_value(&self) -> &Value {
-        &self.value
-    }
-
-    fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        if !matches!(value, ValueText(_)) {
-            return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
-        }
-
-        if let Some(value_type) = self.has_value_type() {
-            if matches!(value, ValueText(_)) && value_type != ValueType::TEXT {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-        }
-
-        if let ValueText(data) = &value {
-            if !PropertyKindValues::TYPES.contains(&data.value.to_uppercase().as_str()) {
-                return Err(VcardError::ValueInvalid(data.value.to_string(), self.name().to_string()));
-            }
-        }
-
-        self.value = value;
-
-        Ok(())
-    }
-}
-
-impl Default for PropertyKindData {
-    fn default() -> Self {
-        Self {
-            group: None,
-            parameters: Vec::new(),
-            value: ValueText(ValueTextData::from("individual")),
-        }
+define_property! {
+    PropertyKindData {
+        name: KIND,
+        cardinality: SINGLE,
+        parameters: [ANY, VALUE],
+        values: [ValueText => [TEXT]],
+        default: ValueText(ValueTextData::from("individual")),
+        validate: validate_value,
     }
 }
 
@@ -97,3 +28,14 @@ impl TryFrom<(Option<String>, &str, Vec<Parameter>)> for PropertyKindData {
         Ok(property)
     }
 }
+
+/// Helper function validating values after the value type checks.
+fn validate_value(name: &str, value: &Value) -> Result<(), VcardError> {
+    if let ValueText(data) = value {
+        if !PropertyKindValues::TYPES.contains(&data.value.to_uppercase().as_str()) {
+            return Err(VcardError::ValueInvalid(data.value.to_string(), name.to_string()));
+        }
+    }
+
+    Ok(())
+}
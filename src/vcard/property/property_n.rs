@@ -1,99 +1,68 @@
-use crate::constants::{Cardinality, ParameterName, PropertyName, ValueType};
-use crate::traits::{HasCardinality, HasGroup, HasName, HasParameters, HasValue};
+use crate::traits::{HasParameters, HasValue};
 use crate::vcard::parameter::Parameter;
+use crate::vcard::value::value_component::Component;
 use crate::vcard::value::value_listcomponent::ValueListComponentData;
 use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueListComponent;
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct PropertyNData {
-    group: Option<String>,
-    parameters: Vec<Parameter>,
-    value: Value,
-}
-
-impl HasCardinality for PropertyNData {
-    fn cardinality(&self) -> &str {
-        Cardinality::SINGLE
+define_property! {
+    PropertyNData {
+        name: N,
+        cardinality: SINGLE,
+        parameters: [ALTID, ANY, LANGUAGE, SORTAS, VALUE],
+        values: [ValueListComponent => [TEXT]],
+        default: ValueListComponent(ValueListComponentData {
+        delimiter_child: ',',
+        delimiter_parent: ';',
+        value: Vec::from([
+            Vec::from([Component::default()]),
+            Vec::from([Component::default()]),
+            Vec::from([Component::default()]),
+            Vec::from([Component::default()]),
+            Vec::from([Component::default()]),
+        ]),
+    }),
+        validate: validate_value,
     }
 }
 
-impl HasGroup for PropertyNData {
-    fn group(&self) -> &Option<String> {
-        &self.group
-    }
+/// The components of an N property, see [RFC 6350 6.2.2](https://datatracker.ietf.org/doc/html/rfc6350#section-6.2.2).
+///
+/// Components are unescaped text, empty components are left out of the [formatted name](NameParts::formatted).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NameParts<'a> {
+    pub family: &'a str,
+    pub given: &'a str,
+    pub additional: &'a str,
+    pub prefixes: &'a str,
+    pub suffixes: &'a str,
 }
 
-impl HasName for PropertyNData {
-    fn name(&self) -> &str {
-        PropertyName::N
+impl NameParts<'_> {
+    /// Compose a formatted name for the FN property, as "prefixes given additional family suffixes".
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::property_n::NameParts;
+    ///
+    /// let parts = NameParts { given: "John", family: "Doe", suffixes: "Jr.", ..NameParts::default() };
+    /// assert_eq!(parts.formatted(), "John Doe Jr.");
+    /// ```
+    pub fn formatted(&self) -> String {
+        [self.prefixes, self.given, self.additional, self.family, self.suffixes].iter().map(|s| s.trim()).filter(|s| !s.is_empty()).collect::<Vec<&str>>().join(" ")
     }
 }
 
-impl HasParameters for PropertyNData {
-    fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
-        Vec::from([
-            ParameterName::ALTID,
-            ParameterName::ANY,
-            ParameterName::LANGUAGE,
-            ParameterName::SORTAS,
-            ParameterName::VALUE,
-        ])
-    }
-
-    fn get_parameters(&self) -> Vec<Parameter> {
-        self.parameters.clone()
-    }
-
-    fn set_parameters(&mut self, parameters: Vec<Parameter>) {
-        self.parameters = parameters;
-    }
-}
-
-impl HasValue for PropertyNData {
-    fn get_value(&self) -> &Value {
-        &self.value
-    }
-
-    fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        if !matches!(value, ValueListComponent(_)) {
-            return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
-        }
-
-        if let Some(value_type) = self.has_value_type() {
-            if matches!(value, ValueListComponent(_)) && value_type != ValueType::TEXT {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-        }
-
-        if let ValueListComponent(list) = &value {
-            if list.value.len() != 5 {
-                return Err(VcardError::ValueInvalid(value.to_string(), self.name().to_string()));
-            }
-        }
-
-        self.value = value;
-
-        Ok(())
-    }
-}
-
-impl Default for PropertyNData {
-    fn default() -> Self {
+impl From<NameParts<'_>> for PropertyNData {
+    fn from(parts: NameParts) -> Self {
         Self {
             group: None,
             parameters: Vec::new(),
             value: ValueListComponent(ValueListComponentData {
                 delimiter_child: ',',
                 delimiter_parent: ';',
-                value: Vec::from([
-                    Vec::from([String::new()]),
-                    Vec::from([String::new()]),
-                    Vec::from([String::new()]),
-                    Vec::from([String::new()]),
-                    Vec::from([String::new()]),
-                ]),
+                value: [parts.family, parts.given, parts.additional, parts.prefixes, parts.suffixes].iter().map(|s| Vec::from([Component::from(*s)])).collect(),
             }),
         }
     }
@@ -105,8 +74,23 @@ impl TryFrom<(Option<String>, &str, Vec<Parameter>)> for PropertyNData {
         let mut property = Self { group, ..Self::default() };
 
         property.add_parameters(parameters)?;
-        property.set_value(ValueListComponent(ValueListComponentData::try_from((value, ';', ','))?))?;
+
+        // An empty value has all components empty.
+        if !value.is_empty() {
+            property.set_value(ValueListComponent(ValueListComponentData::try_from((value, ';', ','))?))?;
+        }
 
         Ok(property)
     }
 }
+
+/// Helper function validating values after the value type checks.
+fn validate_value(name: &str, value: &Value) -> Result<(), VcardError> {
+    if let ValueListComponent(list) = value {
+        if list.value.len() != 5 {
+            return Err(VcardError::ValueInvalid(value.to_string(), name.to_string()));
+        }
+    }
+
+    Ok(())
+}
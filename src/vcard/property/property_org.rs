@@ -1,89 +1,34 @@
-use crate::constants::{Cardinality, ParameterName, PropertyName, ValueType};
-use crate::traits::{HasCardinality, HasGroup, HasName, HasParameters, HasValue};
+use crate::traits::{HasParameters, HasValue};
 use crate::vcard::parameter::Parameter;
 use crate::vcard::value::value_textlist::ValueTextListData;
-use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueTextList;
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct PropertyOrgData {
-    group: Option<String>,
-    parameters: Vec<Parameter>,
-    value: Value,
-}
-
-impl HasCardinality for PropertyOrgData {
-    fn cardinality(&self) -> &str {
-        Cardinality::MULTIPLE
-    }
-}
-
-impl HasGroup for PropertyOrgData {
-    fn group(&self) -> &Option<String> {
-        &self.group
-    }
-}
-
-impl HasName for PropertyOrgData {
-    fn name(&self) -> &str {
-        PropertyName::ORG
+define_property! {
+    PropertyOrgData {
+        name: ORG,
+        cardinality: MULTIPLE,
+        parameters: [ALTID, ANY, INDEX, LANGUAGE, PID, PREF, SORTAS, TYPE, VALUE],
+        values: [ValueTextList => [TEXT]],
+        default: ValueTextList(ValueTextListData::default()),
     }
 }
 
-impl HasParameters for PropertyOrgData {
-    fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
-        Vec::from([
-            ParameterName::ALTID,
-            ParameterName::ANY,
-            ParameterName::INDEX,
-            ParameterName::LANGUAGE,
-            ParameterName::PID,
-            ParameterName::PREF,
-            ParameterName::SORTAS,
-            ParameterName::TYPE,
-            ParameterName::VALUE,
-        ])
-    }
-
-    fn get_parameters(&self) -> Vec<Parameter> {
-        self.parameters.clone()
-    }
-
-    fn set_parameters(&mut self, parameters: Vec<Parameter>) {
-        self.parameters = parameters;
-    }
-}
-
-impl HasValue for PropertyOrgData {
-    fn get_value(&self) -> &Value {
-        &self.value
-    }
-
-    fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        if !matches!(value, ValueTextList(_)) {
-            return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
+impl PropertyOrgData {
+    /// Get the unescaped organizational units, starting with the organization name, e.g. `["ABC, Inc.", "North American Division", "Marketing"]`.
+    pub fn units(&self) -> Vec<String> {
+        match &self.value {
+            ValueTextList(list) => list.value.clone(),
+            value => Vec::from([value.to_string()]),
         }
-
-        if let Some(value_type) = self.has_value_type() {
-            if matches!(value, ValueTextList(_)) && value_type != ValueType::TEXT {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-        }
-
-        self.value = value;
-
-        Ok(())
     }
-}
 
-impl Default for PropertyOrgData {
-    fn default() -> Self {
-        Self {
-            group: None,
-            parameters: Vec::new(),
-            value: ValueTextList(ValueTextListData::default()),
-        }
+    /// Set the organizational units, starting with the organization name. Units are escaped when exported.
+    pub fn set_units(&mut self, units: &[&str]) {
+        self.value = ValueTextList(ValueTextListData {
+            delimiter: ';',
+            value: units.iter().map(|unit| unit.to_string()).collect(),
+        });
     }
 }
 
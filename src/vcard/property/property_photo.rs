@@ -1,88 +1,59 @@
-use crate::constants::{Cardinality, ParameterName, PropertyName, ValueType};
-use crate::traits::{HasCardinality, HasGroup, HasName, HasParameters, HasValue};
+use crate::constants::ParameterName;
+use crate::media::detect_image_mediatype;
+use crate::parse::encoding::base64_encode;
+use crate::traits::{HasName, HasParameters, HasValue};
 use crate::vcard::parameter::Parameter;
 use crate::vcard::value::value_uri::ValueUriData;
-use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueUri;
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct PropertyPhotoData {
-    group: Option<String>,
-    parameters: Vec<Parameter>,
-    value: Value,
-}
-
-impl HasCardinality for PropertyPhotoData {
-    fn cardinality(&self) -> &str {
-        Cardinality::MULTIPLE
-    }
-}
-
-impl HasGroup for PropertyPhotoData {
-    fn group(&self) -> &Option<String> {
-        &self.group
-    }
-}
-
-impl HasName for PropertyPhotoData {
-    fn name(&self) -> &str {
-        PropertyName::PHOTO
-    }
-}
-
-impl HasParameters for PropertyPhotoData {
-    fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
-        Vec::from([
-            ParameterName::ALTID,
-            ParameterName::ANY,
-            ParameterName::INDEX,
-            ParameterName::MEDIATYPE,
-            ParameterName::PID,
-            ParameterName::PREF,
-            ParameterName::TYPE,
-            ParameterName::VALUE,
-        ])
-    }
-
-    fn get_parameters(&self) -> Vec<Parameter> {
-        self.parameters.clone()
-    }
-
-    fn set_parameters(&mut self, parameters: Vec<Parameter>) {
-        self.parameters = parameters;
+define_property! {
+    PropertyPhotoData {
+        name: PHOTO,
+        cardinality: MULTIPLE,
+        parameters: [ALTID, ANY, INDEX, MEDIATYPE, PID, PREF, TYPE, VALUE],
+        values: [ValueUri => [URI]],
+        default: ValueUri(ValueUriData::default()),
     }
 }
 
-impl HasValue for PropertyPhotoData {
-    fn get_value(&self) -> &Value {
-        &self.value
+impl PropertyPhotoData {
+    /// Create a photo embedding image bytes as a data uri, see [set_bytes](PropertyPhotoData::set_bytes).
+    pub fn from_bytes(bytes: &[u8], max_size: usize) -> Result<Self, VcardError> {
+        let mut property = Self::default();
+        property.set_bytes(bytes, max_size)?;
+        Ok(property)
     }
 
-    fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        if !matches!(value, ValueUri(_)) {
-            return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
+    /// Embed image bytes as a data uri with the type detected from the magic bytes.
+    ///
+    /// Returns [`VcardError::MediaTooLarge`] if there are more than max_size bytes. The MEDIATYPE parameter is removed
+    /// as the data uri carries its own mediatype, like when [inlining media](crate::media).
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::error::VcardError;
+    /// use vcard_parser::vcard::property::property_photo::PropertyPhotoData;
+    /// use vcard_parser::vcard::property::Property;
+    ///
+    /// let photo = PropertyPhotoData::from_bytes(b"\xff\xd8\xff\xe0", 1024).expect("Unable to embed photo.");
+    /// assert_eq!(Property::PropertyPhoto(photo).export(), "PHOTO:data:image/jpeg;base64,/9j/4A==\n");
+    ///
+    /// assert_eq!(PropertyPhotoData::from_bytes(&[0; 2048], 1024), Err(VcardError::MediaTooLarge(2048, 1024)));
+    /// ```
+    pub fn set_bytes(&mut self, bytes: &[u8], max_size: usize) -> Result<(), VcardError> {
+        if bytes.len() > max_size {
+            return Err(VcardError::MediaTooLarge(bytes.len(), max_size));
         }
 
-        if let Some(value_type) = self.has_value_type() {
-            if matches!(value, ValueUri(_)) && value_type != ValueType::URI {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-        }
+        let mediatype = detect_image_mediatype(bytes);
 
-        self.value = value;
+        let data = format!("data:{};base64,{}", mediatype.unwrap_or_default(), base64_encode(bytes));
+        self.set_value(ValueUri(ValueUriData::try_from(data.as_str())?))?;
 
-        Ok(())
-    }
-}
+        self.set_parameters(self.get_parameters().into_iter().filter(|parameter| parameter.name() != ParameterName::MEDIATYPE).collect());
 
-impl Default for PropertyPhotoData {
-    fn default() -> Self {
-        Self {
-            group: None,
-            parameters: Vec::new(),
-            value: ValueUri(ValueUriData::default()),
-        }
+        Ok(())
     }
 }
 
@@ -0,0 +1,148 @@
+use crate::constants::{Cardinality, ParameterName};
+use crate::parse::{content_line, ContentLine};
+use crate::traits::HasGroup;
+use crate::vcard::parameter::Parameter;
+use crate::vcard::value::value_text::ValueTextData;
+use crate::vcard::value::Value;
+use crate::vcard::value::Value::ValueText;
+use crate::{HasCardinality, HasName, HasParameters, HasValue, VcardError};
+
+/// A property kept exactly as written, bypassing parameter and value typing, e.g. for servers that must store
+/// arbitrary content unchanged while still using the [Vcard](crate::vcard::Vcard) container.
+///
+/// Raw properties are only created explicitly, parsing never returns them. They are written back with their
+/// parameters and value as given. [get_value](HasValue::get_value) returns the unescaped text of the value and
+/// [get_parameters](HasParameters::get_parameters) the parameters that can be typed, both only as views of the raw
+/// content. Setting the parameters keeps the raw parameters that can't be typed.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::vcard::property::property_raw::PropertyRawData;
+/// use vcard_parser::vcard::property::Property;
+/// use vcard_parser::vcard::Vcard;
+///
+/// let raw = PropertyRawData::try_from("item1.GEO;PREF=0:not a uri").expect("Unable to parse content line.");
+/// assert_eq!(raw.value(), "not a uri");
+///
+/// let mut vcard = Vcard::new("John Doe");
+/// vcard.set_property(&Property::PropertyRaw(raw)).expect("Unable to set property.");
+/// assert_eq!(vcard.export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nitem1.GEO;PREF=0:not a uri\nEND:VCARD\n");
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct PropertyRawData {
+    group: Option<String>,
+    name: String,
+    parameters: Vec<(String, String)>,
+    value: String,
+    text: Value,
+}
+
+impl PropertyRawData {
+    /// Create a raw property, parameter values and the value are written as given.
+    pub fn new(group: Option<String>, name: &str, parameters: Vec<(String, String)>, value: &str) -> Self {
+        Self {
+            group,
+            name: name.to_string(),
+            parameters,
+            value: value.to_string(),
+            text: ValueText(ValueTextData::from(value)),
+        }
+    }
+
+    /// Get the parameter names and written values.
+    pub fn raw_parameters(&self) -> &[(String, String)] {
+        self.parameters.as_slice()
+    }
+
+    /// Get the written value.
+    pub fn value(&self) -> &str {
+        self.value.as_str()
+    }
+
+    /// Set the written value.
+    pub fn set_raw_value(&mut self, value: &str) {
+        self.value = value.to_string();
+        self.text = ValueText(ValueTextData::from(value));
+    }
+}
+
+impl From<ContentLine> for PropertyRawData {
+    fn from(line: ContentLine) -> Self {
+        Self::new(line.group, line.name.as_str(), line.parameters, line.value.as_str())
+    }
+}
+
+impl TryFrom<&str> for PropertyRawData {
+    type Error = VcardError;
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        Ok(Self::from(content_line(str)?))
+    }
+}
+
+impl HasCardinality for PropertyRawData {
+    fn cardinality(&self) -> &str {
+        Cardinality::MULTIPLE
+    }
+}
+
+impl HasGroup for PropertyRawData {
+    fn group(&self) -> &Option<String> {
+        &self.group
+    }
+}
+
+impl HasName for PropertyRawData {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl HasParameters for PropertyRawData {
+    fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
+        Vec::from([ParameterName::ANY])
+    }
+
+    fn get_parameters(&self) -> Vec<Parameter> {
+        self.parameters.iter().filter_map(|(name, value)| Parameter::try_from((name.as_str(), value.as_str())).ok()).collect()
+    }
+
+    /// Set the parameters that can be typed, raw parameters that can't be typed aren't returned by
+    /// [get_parameters](HasParameters::get_parameters) and are kept in place.
+    fn set_parameters(&mut self, parameters: Vec<Parameter>) {
+        // Parameters replacing one of the same name take its place, others are added at the end.
+        let mut parameters: Vec<(String, String)> = parameters.iter().map(written_parameter).collect();
+        let mut written = Vec::with_capacity(self.parameters.len());
+        for (name, value) in std::mem::take(&mut self.parameters) {
+            if Parameter::try_from((name.as_str(), value.as_str())).is_err() {
+                written.push((name, value));
+            } else if let Some(i) = parameters.iter().position(|(other, _)| other.eq_ignore_ascii_case(&name)) {
+                written.push(parameters.remove(i));
+            }
+        }
+        written.extend(parameters);
+        self.parameters = written;
+    }
+
+    fn add_parameter(&mut self, parameter: Parameter) -> Result<(), VcardError> {
+        self.parameters.push(written_parameter(&parameter));
+        Ok(())
+    }
+}
+
+impl HasValue for PropertyRawData {
+    fn get_value(&self) -> &Value {
+        &self.text
+    }
+
+    fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
+        self.set_raw_value(value.to_string().as_str());
+        Ok(())
+    }
+}
+
+/// Helper function splitting a written parameter into its name and written value.
+fn written_parameter(parameter: &Parameter) -> (String, String) {
+    let string = parameter.to_string();
+    let value = string.split_once('=').map(|(_, value)| value).unwrap_or_default();
+    (parameter.name().to_string(), value.to_string())
+}
@@ -1,5 +1,5 @@
-use crate::constants::{Cardinality, ParameterName, PropertyName, ValueType};
-use crate::traits::{HasCardinality, HasGroup, HasName, HasParameters, HasValue};
+use crate::constants::ValueType;
+use crate::traits::{HasParameters, HasValue};
 use crate::vcard::parameter::Parameter;
 use crate::vcard::value::value_text::ValueTextData;
 use crate::vcard::value::value_uri::ValueUriData;
@@ -8,89 +8,13 @@ use crate::vcard::value::Value;
 use crate::vcard::value::Value::{ValueText, ValueUri, ValueUtcOffset};
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct PropertyTzData {
-    group: Option<String>,
-    parameters: Vec<Parameter>,
-    value: Value,
-}
-
-impl HasCardinality for PropertyTzData {
-    fn cardinality(&self) -> &str {
-        Cardinality::MULTIPLE
-    }
-}
-
-impl HasGroup for PropertyTzData {
-    fn group(&self) -> &Option<String> {
-        &self.group
-    }
-}
-
-impl HasName for PropertyTzData {
-    fn name(&self) -> &str {
-        PropertyName::TZ
-    }
-}
-
-impl HasParameters for PropertyTzData {
-    fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
-        Vec::from([
-            ParameterName::ALTID,
-            ParameterName::ANY,
-            ParameterName::INDEX,
-            ParameterName::MEDIATYPE,
-            ParameterName::PID,
-            ParameterName::PREF,
-            ParameterName::TYPE,
-            ParameterName::VALUE,
-        ])
-    }
-
-    fn get_parameters(&self) -> Vec<Parameter> {
-        self.parameters.clone()
-    }
-
-    fn set_parameters(&mut self, parameters: Vec<Parameter>) {
-        self.parameters = parameters;
-    }
-}
-
-impl HasValue for PropertyTzData {
-    fn get_value(&self) -> &Value {
-        &self.value
-    }
-
-    fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        if !matches!(value, ValueText(_)) && !matches!(value, ValueUtcOffset(_)) && !matches!(value, ValueUri(_)) {
-            return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
-        }
-
-        if let Some(value_type) = self.has_value_type() {
-            if matches!(value, ValueText(_)) && value_type != ValueType::TEXT {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-            if matches!(value, ValueUtcOffset(_)) && value_type != ValueType::UTC_OFFSET {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-            if matches!(value, ValueUri(_)) && value_type != ValueType::URI {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-        }
-
-        self.value = value;
-
-        Ok(())
-    }
-}
-
-impl Default for PropertyTzData {
-    fn default() -> Self {
-        Self {
-            group: None,
-            parameters: Vec::new(),
-            value: ValueText(ValueTextData::from("America/Los_Angeles")),
-        }
+define_property! {
+    PropertyTzData {
+        name: TZ,
+        cardinality: MULTIPLE,
+        parameters: [ALTID, ANY, INDEX, MEDIATYPE, PID, PREF, TYPE, VALUE],
+        values: [ValueText => [TEXT], ValueUtcOffset => [UTC_OFFSET], ValueUri => [URI]],
+        default: ValueText(ValueTextData::default()),
     }
 }
 
@@ -101,24 +25,27 @@ impl TryFrom<(Option<String>, &str, Vec<Parameter>)> for PropertyTzData {
 
         property.add_parameters(parameters)?;
 
-        if let Some(value_type) = property.has_value_type() {
-            if value_type == ValueType::TEXT {
-                property.set_value(ValueText(ValueTextData::from(value)))?;
-            } else if value_type == ValueType::UTC_OFFSET {
-                property.set_value(ValueUtcOffset(ValueUtcOffsetData::try_from(value)?))?;
-            } else if value_type == ValueType::URI {
-                property.set_value(ValueUri(ValueUriData::try_from(value)?))?;
-            }
-        } else {
-            property.set_value(match ValueUriData::try_from(value) {
-                Ok(data) => ValueUri(data),
-                Err(_) => match ValueUtcOffsetData::try_from(value) {
-                    Ok(data) => ValueUtcOffset(data),
-                    Err(_) => ValueText(ValueTextData::from(value)),
-                },
-            })?;
-        }
+        let value_type = property.has_value_type();
+        property.set_value(tz_value(value_type.as_deref(), value)?)?;
 
         Ok(property)
     }
 }
+
+/// Helper function converting a value of the TZ property or parameter, see [RFC 6350 6.5.1](https://datatracker.ietf.org/doc/html/rfc6350#section-6.5.1).
+///
+/// Without a VALUE type the value is a uri if it has a scheme, a utc-offset if it is one, and text otherwise.
+pub(crate) fn tz_value(value_type: Option<&str>, value: &str) -> Result<Value, VcardError> {
+    match value_type.map(|value_type| value_type.to_uppercase()).as_deref() {
+        Some(ValueType::TEXT) => Ok(ValueText(ValueTextData::from(value))),
+        Some(ValueType::UTC_OFFSET) => Ok(ValueUtcOffset(ValueUtcOffsetData::try_from(value)?)),
+        Some(ValueType::URI) => Ok(ValueUri(ValueUriData::try_from(value)?)),
+        _ => Ok(match ValueUriData::try_from(value) {
+            Ok(data) => ValueUri(data),
+            Err(_) => match ValueUtcOffsetData::try_from(value) {
+                Ok(data) => ValueUtcOffset(data),
+                Err(_) => ValueText(ValueTextData::from(value)),
+            },
+        }),
+    }
+}
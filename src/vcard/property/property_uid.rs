@@ -1,76 +1,67 @@
-use crate::constants::{Cardinality, ParameterName, PropertyName};
-use crate::traits::{HasCardinality, HasGroup, HasName, HasParameters, HasValue};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::traits::{HasParameters, HasValue};
 use crate::vcard::parameter::Parameter;
 use crate::vcard::value::value_text::ValueTextData;
 use crate::vcard::value::value_uri::ValueUriData;
-use crate::vcard::value::Value;
 use crate::vcard::value::Value::{ValueText, ValueUri};
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct PropertyUidData {
-    group: Option<String>,
-    parameters: Vec<Parameter>,
-    value: Value,
-}
-
-impl HasCardinality for PropertyUidData {
-    fn cardinality(&self) -> &str {
-        Cardinality::SINGLE
-    }
-}
-
-impl HasGroup for PropertyUidData {
-    fn group(&self) -> &Option<String> {
-        &self.group
+define_property! {
+    PropertyUidData {
+        name: UID,
+        cardinality: SINGLE,
+        parameters: [ANY, VALUE],
+        values: [ValueText, ValueUri],
+        default: ValueText(ValueTextData::default()),
     }
 }
 
-impl HasName for PropertyUidData {
-    fn name(&self) -> &str {
-        PropertyName::UID
-    }
-}
-
-impl HasParameters for PropertyUidData {
-    fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
-        Vec::from([
-            ParameterName::ANY,
-            ParameterName::VALUE,
-        ])
-    }
-
-    fn get_parameters(&self) -> Vec<Parameter> {
-        self.parameters.clone()
-    }
-
-    fn set_parameters(&mut self, parameters: Vec<Parameter>) {
-        self.parameters = parameters;
-    }
-}
-
-impl HasValue for PropertyUidData {
-    fn get_value(&self) -> &Value {
-        &self.value
-    }
-
-    fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        if !matches!(value, ValueText(_)) && !matches!(value, ValueUri(_)) {
-            return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
+impl PropertyUidData {
+    /// Create a UID property with a random version 4 UUID URN, e.g. "urn:uuid:f81d4fae-7dec-41d0-a765-00a0c91e6bf6".
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::traits::HasValue;
+    /// use vcard_parser::vcard::property::property_uid::PropertyUidData;
+    ///
+    /// let uid = PropertyUidData::generate();
+    /// assert!(uid.get_value().to_string().starts_with("urn:uuid:"));
+    /// assert_ne!(uid, PropertyUidData::generate());
+    /// ```
+    pub fn generate() -> Self {
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_nanos()).unwrap_or_default();
+
+        // Each RandomState is seeded differently, which is random enough for identifiers without pulling in a dependency.
+        let mut bytes = [0u8; 16];
+        for (i, chunk) in bytes.chunks_mut(8).enumerate() {
+            let mut hasher = RandomState::new().build_hasher();
+            hasher.write_u128(nanos);
+            hasher.write_usize(i);
+            chunk.copy_from_slice(&hasher.finish().to_be_bytes());
         }
+        bytes[6] = (bytes[6] & 0x0f) | 0x40;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
 
-        self.value = value;
+        let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
 
-        Ok(())
+        Self::from(format!("urn:uuid:{}-{}-{}-{}-{}", &hex[0..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..32]).as_str())
     }
 }
 
-impl Default for PropertyUidData {
-    fn default() -> Self {
+impl From<&str> for PropertyUidData {
+    fn from(str: &str) -> Self {
+        let value = match ValueUriData::try_from(str) {
+            Ok(uri) => ValueUri(uri),
+            Err(_) => ValueText(ValueTextData::from(str)),
+        };
+
         Self {
             group: None,
             parameters: Vec::new(),
-            value: ValueText(ValueTextData::default()),
+            value,
         }
     }
 }
@@ -1,82 +1,36 @@
-use crate::constants::{Cardinality, ParameterName, PropertyName, ValueType};
-use crate::traits::{HasCardinality, HasGroup, HasName, HasParameters, HasValue};
+use crate::traits::{HasParameters, HasValue};
 use crate::vcard::parameter::Parameter;
 use crate::vcard::value::value_text::ValueTextData;
-use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueText;
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct PropertyXmlData {
-    group: Option<String>,
-    parameters: Vec<Parameter>,
-    value: Value,
-}
-
-impl HasCardinality for PropertyXmlData {
-    fn cardinality(&self) -> &str {
-        Cardinality::MULTIPLE
-    }
-}
-
-impl HasGroup for PropertyXmlData {
-    fn group(&self) -> &Option<String> {
-        &self.group
-    }
-}
-
-impl HasName for PropertyXmlData {
-    fn name(&self) -> &str {
-        PropertyName::XML
-    }
-}
-
-impl HasParameters for PropertyXmlData {
-    fn allowed_parameters<'a>(&self) -> Vec<&'a str> {
-        Vec::from([
-            ParameterName::ALTID,
-            ParameterName::PID,
-            ParameterName::VALUE,
-        ])
-    }
-
-    fn get_parameters(&self) -> Vec<Parameter> {
-        self.parameters.clone()
-    }
-
-    fn set_parameters(&mut self, parameters: Vec<Parameter>) {
-        self.parameters = parameters;
+define_property! {
+    PropertyXmlData {
+        name: XML,
+        cardinality: MULTIPLE,
+        parameters: [ALTID, PID, VALUE],
+        values: [ValueText => [TEXT]],
+        default: ValueText(ValueTextData::default()),
     }
 }
 
-impl HasValue for PropertyXmlData {
-    fn get_value(&self) -> &Value {
-        &self.value
-    }
-
-    fn set_value(&mut self, value: Value) -> Result<(), VcardError> {
-        if !matches!(value, ValueText(_)) {
-            return Err(VcardError::ValueNotAllowed(value.to_string(), self.name().to_string()));
+impl PropertyXmlData {
+    /// Get the unescaped XML string.
+    pub fn xml(&self) -> &str {
+        match &self.value {
+            ValueText(text) => text.value.as_str(),
+            _ => "",
         }
-
-        if let Some(value_type) = self.has_value_type() {
-            if matches!(value, ValueText(_)) && value_type != ValueType::TEXT {
-                return Err(VcardError::ValueMismatch(value.to_string(), value_type, self.name().to_string()));
-            }
-        }
-
-        self.value = value;
-
-        Ok(())
     }
-}
 
-impl Default for PropertyXmlData {
-    fn default() -> Self {
-        Self {
-            group: None,
-            parameters: Vec::new(),
-            value: ValueText(ValueTextData::default()),
+    /// Return an error if the value isn't a single well-formed XML element, see [RFC 6350 6.1.5](https://datatracker.ietf.org/doc/html/rfc6350#section-6.1.5).
+    ///
+    /// Requires the `xml` feature, with the feature enabled the check also runs in [strict](crate::options::ParserOptions::strict) mode.
+    #[cfg(any(test, feature = "xml"))]
+    pub fn validate_xml(&self) -> Result<(), VcardError> {
+        match crate::parse::xml::xml_document(self.xml().as_bytes()) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(VcardError::ValueInvalid(self.xml().to_string(), crate::constants::PropertyName::XML.to_string())),
         }
     }
 }
@@ -6,7 +6,7 @@ use crate::vcard::value::Value;
 use crate::vcard::value::Value::ValueText;
 use crate::{HasCardinality, HasName, HasParameters, HasValue, VcardError};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct PropertyXNameData {
     group: Option<String>,
     name: String,
@@ -23,6 +23,33 @@ impl PropertyXNameData {
             value: ValueText(ValueTextData::default()),
         }
     }
+
+    /// Split an extended name into its vendor prefix and the rest of the name, e.g. ("GOOGLE", "TALK") for "X-GOOGLE-TALK".
+    ///
+    /// The rest is empty for names without a vendor prefix, e.g. ("SKYPE", "") for "X-SKYPE". Returns None for names
+    /// which don't begin with "X-".
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::property::property_xname::PropertyXNameData;
+    ///
+    /// assert_eq!(PropertyXNameData::default("X-GOOGLE-TALK").vendor_namespace(), Some(("GOOGLE", "TALK")));
+    /// assert_eq!(PropertyXNameData::default("x-ms-ol-design").vendor_namespace(), Some(("ms", "ol-design")));
+    /// assert_eq!(PropertyXNameData::default("X-SKYPE").vendor_namespace(), Some(("SKYPE", "")));
+    /// assert_eq!(PropertyXNameData::default("AB-ADR").vendor_namespace(), None);
+    /// ```
+    pub fn vendor_namespace(&self) -> Option<(&str, &str)> {
+        if !self.name.get(..2)?.eq_ignore_ascii_case("X-") {
+            return None;
+        }
+        let name = &self.name[2..];
+        Some(name.split_once('-').unwrap_or((name, "")))
+    }
+
+    /// Check if the name has a vendor prefix, the vendor is compared case-insensitively.
+    pub fn has_vendor(&self, vendor: &str) -> bool {
+        self.vendor_namespace().is_some_and(|(prefix, _)| prefix.eq_ignore_ascii_case(vendor))
+    }
 }
 
 impl HasCardinality for PropertyXNameData {
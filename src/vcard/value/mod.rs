@@ -38,8 +38,10 @@ use crate::VcardError;
 
 pub mod value_boolean;
 pub mod value_clientpidmap;
+pub mod value_component;
 pub mod value_date;
 pub mod value_float;
+pub mod value_geo;
 pub mod value_integer;
 pub mod value_languagetag;
 pub mod value_listcomponent;
@@ -50,7 +52,7 @@ pub mod value_timestamp;
 pub mod value_uri;
 pub mod value_utcoffset;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub enum Value {
     /// Represents a boolean value, see [RFC 6350 4.4](https://datatracker.ietf.org/doc/html/rfc6350#section-4.4).
     ValueBoolean(ValueBooleanData),
@@ -100,10 +102,132 @@ impl Display for Value {
     }
 }
 
+impl Value {
+    /// Get the text of a TEXT value, or None for other values.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::parameter::Parameter;
+    /// use vcard_parser::traits::HasValue;
+    ///
+    /// let parameter = Parameter::try_from(("LEVEL", "expert")).expect("Unable to parse parameter.");
+    /// assert_eq!(parameter.get_value().as_str(), Some("expert"));
+    /// assert_eq!(parameter.get_value().as_integer(), None);
+    /// ```
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            ValueText(data) => Some(data.value.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Get the number of an INTEGER value, or None for other values.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::parameter::Parameter;
+    /// use vcard_parser::traits::HasValue;
+    ///
+    /// let parameter = Parameter::try_from(("PREF", "1")).expect("Unable to parse parameter.");
+    /// assert_eq!(parameter.get_value().as_integer(), Some(1));
+    /// assert_eq!(parameter.get_value().as_str(), None);
+    /// ```
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            ValueInteger(data) => Some(data.value),
+            _ => None,
+        }
+    }
+
+    /// Get the number of a FLOAT value, or None for other values.
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            ValueFloat(data) => Some(data.value),
+            _ => None,
+        }
+    }
+
+    /// Get the truth value of a BOOLEAN value, or None for other values.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            ValueBoolean(data) => Some(data.value),
+            _ => None,
+        }
+    }
+
+    /// Get the uri of a URI value, or None for other values.
+    pub fn as_uri(&self) -> Option<&str> {
+        match self {
+            ValueUri(data) => Some(data.value.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Check if the value is empty, structured and list values are empty when all their components are.
+    ///
+    /// # Examples
+    /// ```
+    /// use vcard_parser::vcard::value::Value;
+    ///
+    /// assert!(Value::try_from(("LISTCOMPONENT", ";;,;")).expect("Unable to parse value.").is_empty());
+    /// assert!(!Value::try_from(("TEXTLIST", ",a")).expect("Unable to parse value.").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        match self {
+            ValueListComponent(data) => data.value.iter().flatten().all(|component| component.is_empty()),
+            ValueText(data) => data.value.is_empty(),
+            ValueTextList(data) => data.value.iter().all(String::is_empty),
+            ValueUri(data) => data.value.is_empty(),
+            value => value.to_string().is_empty(),
+        }
+    }
+}
+
+/// How an empty value is handled when a property is created, by the value name of the property.
+///
+/// Text and structured values may be empty, e.g. `NOTE:` or `N:` with all components empty. Values which can't be
+/// empty but are often written empty by exporters, e.g. `URL:`, are treated as unset, the property fails to be created
+/// with [VcardError::ValueEmpty] and parsed vCards leave it out with a [warning](crate::vcard::Vcard::warnings).
+/// Empty sync metadata is an error.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::parse_vcards;
+/// use vcard_parser::vcard::value::EmptyValue;
+///
+/// assert_eq!(EmptyValue::for_value("TEXT"), EmptyValue::Allowed);
+/// assert_eq!(EmptyValue::for_value("URI"), EmptyValue::Unset);
+/// assert_eq!(EmptyValue::for_value("CLIENTPIDMAP"), EmptyValue::Error);
+///
+/// let vcards = parse_vcards("BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nN:\nURL:\nEND:VCARD\n").expect("Unable to parse text.");
+/// assert_eq!(vcards[0].export(), "BEGIN:VCARD\nVERSION:4.0\nFN:John Doe\nN:;;;;\nEND:VCARD\n");
+/// assert_eq!(vcards[0].warnings(), ["Value for URL is empty."]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmptyValue {
+    /// The empty value is kept.
+    Allowed,
+    /// The property is treated as if it wasn't set.
+    Unset,
+    /// The empty value is malformed.
+    Error,
+}
+
+impl EmptyValue {
+    /// Get the policy of a [value name](ValueName).
+    pub fn for_value(name: &str) -> Self {
+        match ValueName::intern(name).unwrap_or_default() {
+            ValueName::LISTCOMPONENT | ValueName::TEXT | ValueName::TEXTLIST => Self::Allowed,
+            ValueName::CLIENTPIDMAP | ValueName::PID => Self::Error,
+            _ => Self::Unset,
+        }
+    }
+}
+
 impl TryFrom<(&str, &str)> for Value {
     type Error = VcardError;
     fn try_from((name, str): (&str, &str)) -> Result<Self, Self::Error> {
-        match name.to_uppercase().as_str() {
+        match ValueName::intern(name).unwrap_or_default() {
             ValueName::BOOLEAN => Ok(ValueBoolean(ValueBooleanData::try_from(str)?)),
             ValueName::CLIENTPIDMAP => Ok(ValueClientPidMap(ValueClientPidMapData::try_from(str)?)),
             ValueName::DATE => Ok(ValueDate(ValueDateData::try_from(str)?)),
@@ -215,8 +339,19 @@ impl From<ValueUtcOffsetData> for Value {
 
 #[cfg(test)]
 mod tests {
+    use std::cmp::Ordering;
+    use std::collections::HashSet;
+
+    use crate::constants::ValueName;
+    use crate::vcard::value::value_clientpidmap::ValueClientPidMapData;
+    use crate::vcard::value::value_float::ValueFloatData;
+    use crate::vcard::value::value_integer::ValueIntegerData;
+    use crate::vcard::value::value_languagetag::ValueLanguageTagData;
     use crate::vcard::value::value_listcomponent::ValueListComponentData;
+    use crate::vcard::value::value_pid::ValuePidData;
     use crate::vcard::value::value_textlist::ValueTextListData;
+    use crate::vcard::value::value_uri::ValueUriData;
+    use crate::vcard::value::Value;
 
     #[test]
     fn util_parse_textlist_value() {
@@ -256,4 +391,127 @@ mod tests {
         assert_eq!(ValueListComponentData::try_from(("A,B;C,D", ';', ',')).unwrap().to_string(), "A,B;C,D");
         assert_eq!(ValueListComponentData::try_from(("FOO,BAR;FOO,BAR", ';', ',')).unwrap().to_string(), "FOO,BAR;FOO,BAR");
     }
+
+    #[test]
+    fn util_parse_languagetag_value() {
+        let tag = ValueLanguageTagData::try_from("en-Latn-US").unwrap();
+        assert_eq!(tag.primary_language(), "en");
+        assert_eq!(tag.extended_language(), None);
+        assert_eq!(tag.script(), Some(String::from("Latn")));
+        assert_eq!(tag.region(), Some(String::from("US")));
+        assert!(tag.variants().is_empty());
+        assert!(tag.validate().is_ok());
+
+        let tag = ValueLanguageTagData::try_from("sl-rozaj-biske").unwrap();
+        assert_eq!(
+            tag.variants(),
+            Vec::from([
+                String::from("rozaj"),
+                String::from("biske")
+            ])
+        );
+        assert!(tag.validate().is_ok());
+
+        assert_eq!(ValueLanguageTagData::try_from("zh-yue-HK").unwrap().extended_language(), Some(String::from("yue")));
+        assert!(ValueLanguageTagData::try_from("en-Abcd").unwrap().validate().is_err());
+        assert!(ValueLanguageTagData::try_from("en_US").is_err());
+        assert!(ValueLanguageTagData::try_from("not a tag").is_err());
+    }
+
+    #[test]
+    fn util_value_equality() {
+        fn _value(name: &str, str: &str) -> Value {
+            Value::try_from((name, str)).unwrap()
+        }
+
+        assert_eq!(_value(ValueName::DATE, "20000101"), _value(ValueName::DATE, "2000-01-01"));
+        assert_eq!(_value(ValueName::TIMESTAMP, "20000101T050000+0500"), _value(ValueName::TIMESTAMP, "20000101T000000Z"));
+        assert_eq!(_value(ValueName::URI, "HTTPS://Example.COM/Path"), _value(ValueName::URI, "https://example.com/Path"));
+        assert_eq!(_value(ValueName::URI, "https://User@Example.COM"), _value(ValueName::URI, "https://User@example.com"));
+        assert_ne!(_value(ValueName::URI, "https://example.com/Path"), _value(ValueName::URI, "https://example.com/path"));
+        assert_ne!(_value(ValueName::URI, "https://User@example.com"), _value(ValueName::URI, "https://user@example.com"));
+        assert_eq!(_value(ValueName::FLOAT, "0.3"), Value::from(ValueFloatData::from(0.3)));
+        assert_ne!(_value(ValueName::FLOAT, "0.3"), Value::from(ValueFloatData::from(0.1 + 0.2)));
+        assert_ne!(_value(ValueName::FLOAT, "0.3"), _value(ValueName::FLOAT, "0.31"));
+        assert_eq!(_value(ValueName::LANGUAGE_TAG, "en-US"), _value(ValueName::LANGUAGE_TAG, "EN-us"));
+        assert_eq!(_value(ValueName::UTCOFFSET, "+05"), _value(ValueName::UTCOFFSET, "+05:00"));
+        assert_ne!(_value(ValueName::UTCOFFSET, "+0500"), _value(ValueName::UTCOFFSET, "-0500"));
+        assert_ne!(_value(ValueName::TEXT, "John"), _value(ValueName::TEXT, "john"));
+
+        assert!(_value(ValueName::URI, "HTTPS://Example.COM/Path").to_string().ends_with("/Path"));
+    }
+
+    #[test]
+    fn util_parse_numeric_values() {
+        assert_eq!(ValueIntegerData::try_from("9223372036854775807").unwrap().value, i64::MAX);
+        assert_eq!(ValueIntegerData::try_from("-3000000000").unwrap().value, -3_000_000_000);
+        assert!(ValueIntegerData::try_from("9223372036854775808").is_err());
+        assert!(ValueIntegerData::try_from("1.5").is_err());
+
+        assert_eq!(ValueFloatData::try_from("37.3860131234567").unwrap().value, 37.3860131234567);
+        assert_ne!(ValueFloatData::try_from("37.3860131").unwrap(), ValueFloatData::try_from("37.3860132").unwrap());
+        assert!(ValueFloatData::try_from("inf").is_err());
+        assert!(ValueFloatData::try_from("NaN").is_err());
+        assert_eq!(
+            HashSet::from([
+                ValueFloatData::from(0.3),
+                ValueFloatData::try_from("0.30").unwrap(),
+                ValueFloatData::from(0.1 + 0.2)
+            ])
+            .len(),
+            2
+        );
+        assert_eq!(ValueFloatData::try_from("-0.0").unwrap(), ValueFloatData::from(0.0));
+        assert_eq!(ValueFloatData::from(-0.0).cmp(&ValueFloatData::from(0.0)), Ordering::Equal);
+        assert_eq!(
+            HashSet::from([
+                ValueFloatData::from(-0.0),
+                ValueFloatData::from(0.0)
+            ])
+            .len(),
+            1
+        );
+
+        assert_eq!(ValuePidData::try_from("3000000000.4000000000").unwrap().value, [(3_000_000_000, Some(4_000_000_000))]);
+        assert_eq!(ValuePidData::try_from("1,-2,+3,99999999999999999999,4.5").unwrap().to_string(), "1,4.5");
+        assert!(ValuePidData::try_from("-1").is_err());
+
+        assert_eq!(ValueClientPidMapData::try_from("3000000000;urn:uuid:client").unwrap().id, 3_000_000_000);
+        assert!(ValueClientPidMapData::try_from("-1;urn:uuid:client").is_err());
+        assert!(ValueClientPidMapData::try_from("1.0;urn:uuid:client").is_err());
+    }
+
+    #[test]
+    fn util_value_getters() {
+        let value = Value::try_from((ValueName::TEXT, "expert")).unwrap();
+        assert_eq!((value.as_str(), value.as_integer(), value.as_float(), value.as_bool(), value.as_uri()), (Some("expert"), None, None, None, None));
+
+        let value = Value::try_from((ValueName::INTEGER, "-5000000000")).unwrap();
+        assert_eq!((value.as_str(), value.as_integer(), value.as_float()), (None, Some(-5_000_000_000), None));
+
+        assert_eq!(Value::try_from((ValueName::FLOAT, "1.5")).unwrap().as_float(), Some(1.5));
+        assert_eq!(Value::try_from((ValueName::BOOLEAN, "true")).unwrap().as_bool(), Some(true));
+        assert_eq!(Value::try_from((ValueName::URI, "https://example.com/a")).unwrap().as_uri(), Some("https://example.com/a"));
+        assert_eq!(Value::try_from((ValueName::URI, "https://example.com")).unwrap().as_str(), None);
+        assert_eq!(Value::try_from((ValueName::TEXTLIST, "a,b")).unwrap().as_str(), None);
+    }
+
+    #[test]
+    fn util_parse_uri_value() {
+        assert_eq!(ValueUriData::try_from("HTTP://example.com/%7euser/a%2fb").unwrap().to_string(), "http://example.com/~user/a%2Fb");
+        assert_eq!(ValueUriData::try_from("MAILTO:user@example.com").unwrap().scheme(), "mailto");
+        assert_eq!(ValueUriData { value: String::from("MAILTO:x") }.scheme(), "mailto");
+        assert!(ValueUriData { value: String::from("MAILTO:x") }.has_scheme(&["mailto"]));
+        assert!(ValueUriData::try_from("tel:+1-555-555-5555").unwrap().has_scheme(&["tel", "sip"]));
+        assert!(ValueUriData::try_from("https://example.com").unwrap().validate_scheme(&["geo"], "GEO").is_err());
+        assert!(ValueUriData::try_from("user@example.com").is_err());
+        assert!(ValueUriData::try_from("1http://example.com").is_err());
+
+        #[cfg(feature = "validation-url")]
+        assert_eq!(ValueUriData::try_from("https://EXAMPLE.com/a b").unwrap().to_string(), "https://example.com/a%20b");
+        #[cfg(not(feature = "validation-url"))]
+        assert!(ValueUriData::try_from("https://EXAMPLE.com/a b").is_err());
+        #[cfg(not(feature = "validation-url"))]
+        assert_eq!(ValueUriData::try_from("https://EXAMPLE.com").unwrap().to_string(), "https://EXAMPLE.com");
+    }
 }
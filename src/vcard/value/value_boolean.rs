@@ -2,7 +2,7 @@ use std::fmt::{Display, Formatter};
 
 use crate::VcardError;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
 pub struct ValueBooleanData {
     pub value: bool,
 }
@@ -1,12 +1,12 @@
 use std::fmt::{Display, Formatter};
 
-use url::Url;
-
+use crate::vcard::value::value_pid::parse_digits;
+use crate::vcard::value::value_uri::parse_uri;
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ValueClientPidMapData {
-    pub id: i32,
+    pub id: i64,
     pub client: String,
 }
 
@@ -14,8 +14,8 @@ impl TryFrom<&str> for ValueClientPidMapData {
     type Error = VcardError;
     fn try_from(str: &str) -> Result<Self, Self::Error> {
         if let Some((a, b)) = str.split_once(';') {
-            if let (Ok(id), Ok(url)) = (a.parse::<i32>(), Url::parse(b)) {
-                return Ok(Self { id, client: url.to_string() });
+            if let (Some(id), Some(client)) = (parse_digits(a), parse_uri(b)) {
+                return Ok(Self { id, client });
             }
         }
 
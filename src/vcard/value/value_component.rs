@@ -0,0 +1,118 @@
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+
+use crate::constants::Encoding;
+use crate::parse::encoding::{escape, unescape};
+
+/// A component of a structured or list value, e.g. the street of an ADR or a unit of an ORG, holding unescaped text.
+///
+/// Written values are split on delimiters before components are unescaped, so escaped semicolons and commas stay
+/// inside their component. [Display](Display) escapes the component again, while [Deref](Deref) gives the unescaped
+/// text, so components can be used and joined like strings.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::vcard::value::value_component::Component;
+///
+/// let components = Component::split("Smith\\, Jones & Co.;Sales\\;East", ';');
+/// assert_eq!(components, [Component::from("Smith, Jones & Co."), Component::from("Sales;East")]);
+/// assert_eq!(components[0].to_string(), "Smith\\, Jones & Co.");
+/// ```
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Component(pub String);
+
+impl Component {
+    /// Parse a written component, unescaping it.
+    pub fn parse(str: &str) -> Self {
+        Self(unescape(str))
+    }
+
+    /// Split a written value on unescaped delimiters, parsing each part.
+    pub fn split(str: &str, delimiter: char) -> Vec<Self> {
+        split_written(str, delimiter).into_iter().map(Self::parse).collect()
+    }
+
+    /// Get the unescaped text of the component.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Get the unescaped text of the component, consuming it.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl From<&str> for Component {
+    fn from(str: &str) -> Self {
+        Self(str.to_string())
+    }
+}
+
+impl From<String> for Component {
+    fn from(string: String) -> Self {
+        Self(string)
+    }
+}
+
+impl Deref for Component {
+    type Target = str;
+    fn deref(&self) -> &Self::Target {
+        self.0.as_str()
+    }
+}
+
+impl Borrow<str> for Component {
+    fn borrow(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl Display for Component {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", escape(self.0.as_str()))
+    }
+}
+
+/// Split a written value on delimiters that aren't escaped with a backslash, keeping the parts written.
+pub(crate) fn split_written(str: &str, delimiter: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+
+    let mut chars = str.char_indices();
+    while let Some((index, char)) = chars.next() {
+        if char == Encoding::UNESCAPED_BACKSLASH {
+            chars.next();
+        } else if char == delimiter {
+            parts.push(&str[start..index]);
+            start = index + char.len_utf8();
+        }
+    }
+    parts.push(&str[start..]);
+
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vcard::value::value_component::{split_written, Component};
+
+    #[test]
+    fn split() {
+        assert_eq!(split_written("", ';'), [""]);
+        assert_eq!(split_written(";", ';'), ["", ""]);
+        assert_eq!(split_written("a\\;b;c", ';'), ["a\\;b", "c"]);
+        assert_eq!(split_written("a\\\\;b", ';'), ["a\\\\", "b"]);
+        assert_eq!(split_written("a\\", ';'), ["a\\"]);
+        assert_eq!(
+            Component::split("Doe\\, Jr.,John", ','),
+            [
+                Component::from("Doe, Jr."),
+                Component::from("John")
+            ]
+        );
+        assert_eq!(Component::parse("a\\,b").as_str(), "a,b");
+        assert_eq!(Component::from("a;b,c").to_string(), "a\\;b\\,c");
+    }
+}
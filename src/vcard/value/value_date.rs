@@ -1,11 +1,19 @@
 use std::fmt::{Display, Formatter};
 
 use time::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
-use time::{format_description, Date, OffsetDateTime};
+use time::format_description::BorrowedFormatItem;
+use time::macros::format_description;
+use time::{Date, Month, OffsetDateTime};
 
+use crate::vcard::value::Value;
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// Basic date format, compiled once instead of on every parse.
+const BASIC: &[BorrowedFormatItem<'_>] = format_description!("[year][month][day]");
+/// Extended date format, compiled once instead of on every parse.
+const EXTENDED: &[BorrowedFormatItem<'_>] = format_description!("[year]-[month]-[day]");
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ValueDateData {
     pub day: u8,
     pub month: u8,
@@ -36,14 +44,14 @@ impl TryFrom<&str> for ValueDateData {
                 year: date.year(),
             });
         }
-        if let Ok(date) = Date::parse(str, &format_description::parse("[year][month][day]").unwrap()) {
+        if let Ok(date) = Date::parse(str, BASIC) {
             return Ok(Self {
                 day: date.day(),
                 month: date.month().into(),
                 year: date.year(),
             });
         }
-        if let Ok(date) = Date::parse(str, &format_description::parse("[year]-[month]-[day]").unwrap()) {
+        if let Ok(date) = Date::parse(str, EXTENDED) {
             return Ok(Self {
                 year: date.year(),
                 month: date.month().into(),
@@ -70,3 +78,66 @@ impl Display for ValueDateData {
         write!(f, "{}-{:02}-{:02}", self.year, self.month, self.day)
     }
 }
+
+/// A date recurring every year, taken from a date value or a text value without a year, e.g. "--0415" or "--04-15".
+///
+/// February 29 occurs on February 28 in years that aren't leap years.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct RecurringDate {
+    year: Option<i32>,
+    month: Month,
+    day: u8,
+}
+
+impl RecurringDate {
+    /// Get the recurring date from a date value or a text value without a year, returning None for other values.
+    pub(crate) fn from_value(value: &Value) -> Option<Self> {
+        let (year, month, day) = match value {
+            Value::ValueDate(data) => (Some(data.year), data.month, data.day),
+            Value::ValueText(data) => {
+                let digits: String = data.value.strip_prefix("--")?.chars().filter(|c| *c != '-').collect();
+                if digits.len() != 4 || !digits.chars().all(|c| c.is_ascii_digit()) {
+                    return None;
+                }
+                (None, digits[..2].parse().ok()?, digits[2..].parse().ok()?)
+            }
+            _ => return None,
+        };
+
+        let month = Month::try_from(month).ok()?;
+
+        // Check the day against a leap year if the year is unknown.
+        Date::from_calendar_date(year.unwrap_or(2000), month, day).ok()?;
+
+        Some(Self { year, month, day })
+    }
+
+    /// Get the number of full years from the original date until a date, None if the year is unknown or the date is earlier.
+    pub(crate) fn years_on(&self, date: Date) -> Option<u32> {
+        let years = date.year() - self.year?;
+        let years = if date < self.occurrence_in(date.year())? { years - 1 } else { years };
+        u32::try_from(years).ok()
+    }
+
+    /// Get the first occurrence after a date, not earlier than the original date if the year is known.
+    pub(crate) fn next_occurrence(&self, after: Date) -> Option<Date> {
+        let year = match self.year {
+            Some(year) if year > after.year() => year,
+            _ => after.year(),
+        };
+
+        match self.occurrence_in(year)? {
+            date if date > after => Some(date),
+            _ => self.occurrence_in(year + 1),
+        }
+    }
+
+    /// Helper function getting the occurrence in a year.
+    fn occurrence_in(&self, year: i32) -> Option<Date> {
+        match Date::from_calendar_date(year, self.month, self.day) {
+            Ok(date) => Some(date),
+            Err(_) if self.month == Month::February && self.day == 29 => Date::from_calendar_date(year, Month::February, 28).ok(),
+            Err(_) => None,
+        }
+    }
+}
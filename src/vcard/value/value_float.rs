@@ -1,14 +1,55 @@
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
 
 use crate::VcardError;
 
-#[derive(Clone, Debug, Default, PartialEq)]
+/// Float values are compared and hashed by their bits, using [f64::total_cmp], so equality is transitive and agrees
+/// with the hash. An epsilon comparison is not transitive, so it cannot back [Eq] and [Hash]. Negative zero is
+/// normalized to zero first, as both denote the same number. Only finite values are parsed.
+#[derive(Clone, Debug, Default)]
 pub struct ValueFloatData {
-    pub value: f32,
+    pub value: f64,
 }
 
-impl From<f32> for ValueFloatData {
-    fn from(value: f32) -> Self {
+impl ValueFloatData {
+    fn normalized(&self) -> f64 {
+        if self.value == 0.0 {
+            0.0
+        } else {
+            self.value
+        }
+    }
+}
+
+impl PartialEq for ValueFloatData {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ValueFloatData {}
+
+impl PartialOrd for ValueFloatData {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ValueFloatData {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.normalized().total_cmp(&other.normalized())
+    }
+}
+
+impl Hash for ValueFloatData {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalized().to_bits().hash(state);
+    }
+}
+
+impl From<f64> for ValueFloatData {
+    fn from(value: f64) -> Self {
         Self { value }
     }
 }
@@ -16,9 +57,9 @@ impl From<f32> for ValueFloatData {
 impl TryFrom<&str> for ValueFloatData {
     type Error = VcardError;
     fn try_from(str: &str) -> Result<Self, Self::Error> {
-        match str.parse::<f32>() {
-            Ok(value) => Ok(Self { value }),
-            Err(_) => Err(VcardError::ValueMalformed(str.to_string())),
+        match str.parse::<f64>() {
+            Ok(value) if value.is_finite() => Ok(Self { value }),
+            _ => Err(VcardError::ValueMalformed(str.to_string())),
         }
     }
 }
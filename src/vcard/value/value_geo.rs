@@ -0,0 +1,161 @@
+use std::fmt::{Display, Formatter};
+
+use crate::vcard::value::value_uri::ValueUriData;
+use crate::VcardError;
+
+/// Coordinates of a geo uri, see [RFC 5870](https://datatracker.ietf.org/doc/html/rfc5870).
+///
+/// GEO properties and parameters keep their value as a uri, this type gives typed access to the coordinates of both.
+/// Only the default WGS-84 reference system is supported, other uri parameters are ignored.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::vcard::value::value_geo::ValueGeoData;
+///
+/// let geo = ValueGeoData::try_from("geo:46.772673,-71.282945;u=10").expect("Unable to parse geo uri.");
+/// assert_eq!((geo.latitude, geo.longitude, geo.altitude, geo.uncertainty), (46.772673, -71.282945, None, Some(10.0)));
+/// assert_eq!(geo.to_string(), "geo:46.772673,-71.282945;u=10");
+/// assert!(ValueGeoData::try_from("geo:91,0").is_err());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ValueGeoData {
+    /// Latitude in decimal degrees, from -90 to 90.
+    pub latitude: f64,
+    /// Longitude in decimal degrees, from -180 to 180.
+    pub longitude: f64,
+    /// Altitude in meters.
+    pub altitude: Option<f64>,
+    /// Uncertainty of the location in meters.
+    pub uncertainty: Option<f64>,
+}
+
+/// The mean radius of the earth in meters, as used by [distance_meters].
+pub const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+/// Get the great-circle distance between two coordinates in meters with the haversine formula, altitudes are ignored.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::vcard::value::value_geo::{distance_meters, ValueGeoData};
+///
+/// let paris = ValueGeoData::from((48.8566, 2.3522));
+/// let london = ValueGeoData::from((51.5074, -0.1278));
+/// assert_eq!((distance_meters(&paris, &london) / 1000.0).round(), 344.0);
+/// assert_eq!(distance_meters(&paris, &paris), 0.0);
+/// ```
+pub fn distance_meters(a: &ValueGeoData, b: &ValueGeoData) -> f64 {
+    let (latitude_a, latitude_b) = (a.latitude.to_radians(), b.latitude.to_radians());
+    let latitude_delta = (b.latitude - a.latitude).to_radians();
+    let longitude_delta = (b.longitude - a.longitude).to_radians();
+
+    let h = (latitude_delta / 2.0).sin().powi(2) + latitude_a.cos() * latitude_b.cos() * (longitude_delta / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_METERS * h.sqrt().min(1.0).asin()
+}
+
+impl From<(f64, f64)> for ValueGeoData {
+    fn from((latitude, longitude): (f64, f64)) -> Self {
+        Self {
+            latitude,
+            longitude,
+            ..Self::default()
+        }
+    }
+}
+
+impl TryFrom<&str> for ValueGeoData {
+    type Error = VcardError;
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        let malformed = || VcardError::ValueMalformed(str.to_string());
+        let number = |str: &str| str.parse::<f64>().ok().filter(|number| number.is_finite() && !str.starts_with('+'));
+
+        let (scheme, path) = str.split_once(':').ok_or_else(malformed)?;
+        if !scheme.eq_ignore_ascii_case("geo") {
+            return Err(malformed());
+        }
+
+        let mut parts = path.split(';');
+        let coordinates: Vec<&str> = parts.next().unwrap_or_default().split(',').collect();
+        let (latitude, longitude, altitude) = match coordinates[..] {
+            [latitude, longitude] => (number(latitude), number(longitude), None),
+            [latitude, longitude, altitude] => (number(latitude), number(longitude), Some(number(altitude).ok_or_else(malformed)?)),
+            _ => return Err(malformed()),
+        };
+        let (Some(latitude), Some(longitude)) = (latitude, longitude) else {
+            return Err(malformed());
+        };
+        if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+            return Err(malformed());
+        }
+
+        let mut uncertainty = None;
+        for parameter in parts {
+            let (name, value) = parameter.split_once('=').unwrap_or((parameter, ""));
+            if name.eq_ignore_ascii_case("crs") && !value.eq_ignore_ascii_case("wgs84") {
+                return Err(malformed());
+            }
+            if name.eq_ignore_ascii_case("u") {
+                uncertainty = Some(number(value).filter(|value| *value >= 0.0).ok_or_else(malformed)?);
+            }
+        }
+
+        Ok(Self {
+            latitude,
+            longitude,
+            altitude,
+            uncertainty,
+        })
+    }
+}
+
+impl TryFrom<&ValueUriData> for ValueGeoData {
+    type Error = VcardError;
+    fn try_from(uri: &ValueUriData) -> Result<Self, Self::Error> {
+        Self::try_from(uri.value.as_str())
+    }
+}
+
+impl Display for ValueGeoData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "geo:{},{}", self.latitude, self.longitude)?;
+        if let Some(altitude) = self.altitude {
+            write!(f, ",{}", altitude)?;
+        }
+        if let Some(uncertainty) = self.uncertainty {
+            write!(f, ";u={}", uncertainty)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vcard::value::value_geo::{distance_meters, ValueGeoData};
+
+    #[test]
+    fn try_from() {
+        assert_eq!(ValueGeoData::try_from("geo:37.386013,-122.082932").unwrap(), ValueGeoData::from((37.386013, -122.082932)));
+        assert_eq!(ValueGeoData::try_from("GEO:0.0,-0.0,120;crs=WGS84;u=5.5").unwrap().altitude, Some(120.0));
+        assert!(ValueGeoData::try_from("geo:0,0;foo=bar").is_ok());
+        assert!(ValueGeoData::try_from("geo:0,181").is_err());
+        assert!(ValueGeoData::try_from("geo:0").is_err());
+        assert!(ValueGeoData::try_from("geo:0,0,1,2").is_err());
+        assert!(ValueGeoData::try_from("geo:0,a").is_err());
+        assert!(ValueGeoData::try_from("geo:0,0;u=-1").is_err());
+        assert!(ValueGeoData::try_from("geo:0,0;crs=other").is_err());
+        assert!(ValueGeoData::try_from("https://example.com").is_err());
+    }
+
+    #[test]
+    fn distance() {
+        let north = ValueGeoData::from((90.0, 0.0));
+        let south = ValueGeoData::from((-90.0, 45.0));
+        assert!((distance_meters(&north, &south) - std::f64::consts::PI * 6_371_008.8).abs() < 1e-6);
+        assert!((distance_meters(&ValueGeoData::from((0.0, 179.5)), &ValueGeoData::from((0.0, -179.5))) - 111_195.08).abs() < 0.1);
+        assert_eq!(distance_meters(&ValueGeoData::from((10.0, 20.0)), &ValueGeoData::from((-5.0, 7.0))), distance_meters(&ValueGeoData::from((-5.0, 7.0)), &ValueGeoData::from((10.0, 20.0))));
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(ValueGeoData::try_from("geo:0.0,-0.0,120").unwrap().to_string(), "geo:0,-0,120");
+    }
+}
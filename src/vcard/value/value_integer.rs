@@ -2,13 +2,13 @@ use std::fmt::{Display, Formatter};
 
 use crate::VcardError;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
 pub struct ValueIntegerData {
-    pub value: i32,
+    pub value: i64,
 }
 
-impl From<i32> for ValueIntegerData {
-    fn from(value: i32) -> Self {
+impl From<i64> for ValueIntegerData {
+    fn from(value: i64) -> Self {
         Self { value }
     }
 }
@@ -16,7 +16,7 @@ impl From<i32> for ValueIntegerData {
 impl TryFrom<&str> for ValueIntegerData {
     type Error = VcardError;
     fn try_from(str: &str) -> Result<Self, Self::Error> {
-        match str.parse::<i32>() {
+        match str.parse::<i64>() {
             Ok(value) => Ok(Self { value }),
             Err(_) => Err(VcardError::ValueMalformed(str.to_string())),
         }
@@ -1,14 +1,72 @@
 use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
 
 use language_tags::LanguageTag;
 
+use crate::constants::ValueType;
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// Language tags are compared case-insensitively, see [RFC 5646 2.1.1](https://datatracker.ietf.org/doc/html/rfc5646#section-2.1.1).
+#[derive(Clone, Debug)]
 pub struct ValueLanguageTagData {
     pub value: String,
 }
 
+impl PartialEq for ValueLanguageTagData {
+    fn eq(&self, other: &Self) -> bool {
+        self.value.eq_ignore_ascii_case(other.value.as_str())
+    }
+}
+
+impl Eq for ValueLanguageTagData {}
+
+impl Hash for ValueLanguageTagData {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.to_ascii_lowercase().hash(state);
+    }
+}
+
+impl ValueLanguageTagData {
+    /// Get the primary language subtag, e.g. "en" for "en-Latn-US".
+    pub fn primary_language(&self) -> String {
+        self.tag().map(|tag| tag.primary_language().to_string()).unwrap_or_default()
+    }
+
+    /// Get the extended language subtag, e.g. "yue" for "zh-yue-HK".
+    pub fn extended_language(&self) -> Option<String> {
+        self.tag().and_then(|tag| tag.extended_language().map(String::from))
+    }
+
+    /// Get the script subtag, e.g. "Latn" for "en-Latn-US".
+    pub fn script(&self) -> Option<String> {
+        self.tag().and_then(|tag| tag.script().map(String::from))
+    }
+
+    /// Get the region subtag, e.g. "US" for "en-Latn-US".
+    pub fn region(&self) -> Option<String> {
+        self.tag().and_then(|tag| tag.region().map(String::from))
+    }
+
+    /// Get the variant subtags, e.g. ["rozaj", "biske"] for "sl-rozaj-biske".
+    pub fn variants(&self) -> Vec<String> {
+        self.tag().map(|tag| tag.variant_subtags().map(String::from).collect()).unwrap_or_default()
+    }
+
+    /// Validate the tag against the IANA language subtag registry, as per [RFC 5646 Section 2.2.9](https://datatracker.ietf.org/doc/html/rfc5646#section-2.2.9).
+    ///
+    /// Parsing only checks that a tag is well-formed, this additionally rejects unregistered subtags.
+    pub fn validate(&self) -> Result<(), VcardError> {
+        match self.tag() {
+            Some(tag) if tag.is_valid() => Ok(()),
+            _ => Err(VcardError::ValueInvalid(self.value.clone(), ValueType::LANGUAGE_TAG.to_string())),
+        }
+    }
+
+    fn tag(&self) -> Option<LanguageTag> {
+        LanguageTag::parse(self.value.as_str()).ok()
+    }
+}
+
 impl Default for ValueLanguageTagData {
     fn default() -> Self {
         Self { value: String::from("en") }
@@ -1,14 +1,13 @@
 use std::fmt::{Display, Formatter};
 
-use crate::parse::encoding::escape;
-use crate::vcard::value::value_textlist::ValueTextListData;
+use crate::vcard::value::value_component::{split_written, Component};
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ValueListComponentData {
     pub delimiter_child: char,
     pub delimiter_parent: char,
-    pub value: Vec<Vec<String>>,
+    pub value: Vec<Vec<Component>>,
 }
 
 impl Default for ValueListComponentData {
@@ -24,11 +23,7 @@ impl Default for ValueListComponentData {
 impl TryFrom<(&str, char, char)> for ValueListComponentData {
     type Error = VcardError;
     fn try_from((str, delimiter_parent, delimiter_child): (&str, char, char)) -> Result<Self, Self::Error> {
-        let mut value = Vec::new();
-
-        for string in ValueTextListData::from((str, delimiter_parent)).value {
-            value.push(ValueTextListData::from((string.as_str(), delimiter_child)).value);
-        }
+        let value = split_written(str, delimiter_parent).into_iter().map(|part| Component::split(part, delimiter_child)).collect();
 
         Ok(ValueListComponentData {
             delimiter_child,
@@ -40,6 +35,6 @@ impl TryFrom<(&str, char, char)> for ValueListComponentData {
 
 impl Display for ValueListComponentData {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.value.iter().map(|child| { child.iter().map(|s| { escape(s) }).collect::<Vec<String>>().join(self.delimiter_child.to_string().as_str()) }).collect::<Vec<String>>().join(self.delimiter_parent.to_string().as_str()))
+        write!(f, "{}", self.value.iter().map(|child| { child.iter().map(|component| component.to_string()).collect::<Vec<String>>().join(self.delimiter_child.to_string().as_str()) }).collect::<Vec<String>>().join(self.delimiter_parent.to_string().as_str()))
     }
 }
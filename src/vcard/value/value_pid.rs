@@ -2,30 +2,112 @@ use std::fmt::{Display, Formatter};
 
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// A single PID value, identifying a property instance and optionally the CLIENTPIDMAP source that created it, see
+/// [RFC 6350 5.5](https://datatracker.ietf.org/doc/html/rfc6350#section-5.5).
+///
+/// # Examples
+/// ```
+/// use vcard_parser::vcard::value::value_pid::Pid;
+///
+/// let pid = Pid::try_from("1.2").expect("Unable to parse pid.");
+/// assert_eq!(pid, Pid { instance: 1, source: Some(2) });
+/// assert_eq!(pid.to_string(), "1.2");
+/// assert_eq!(Pid::from(3).to_string(), "3");
+/// assert!(Pid::try_from("1,2").is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Pid {
+    /// The identifier of the property instance.
+    pub instance: i64,
+    /// The identifier of the CLIENTPIDMAP source, if any.
+    pub source: Option<i64>,
+}
+
+impl From<i64> for Pid {
+    fn from(instance: i64) -> Self {
+        Self { instance, source: None }
+    }
+}
+
+impl From<(i64, Option<i64>)> for Pid {
+    fn from((instance, source): (i64, Option<i64>)) -> Self {
+        Self { instance, source }
+    }
+}
+
+impl From<Pid> for (i64, Option<i64>) {
+    fn from(pid: Pid) -> Self {
+        (pid.instance, pid.source)
+    }
+}
+
+impl TryFrom<&str> for Pid {
+    type Error = VcardError;
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        let parsed = match str.split_once('.') {
+            Some((a, b)) => parse_digits(a).zip(parse_digits(b)).map(|(instance, source)| Self { instance, source: Some(source) }),
+            None => parse_digits(str).map(Self::from),
+        };
+
+        parsed.ok_or_else(|| VcardError::ValueMalformed(str.to_string()))
+    }
+}
+
+impl Display for Pid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.source {
+            Some(source) => write!(f, "{}.{}", self.instance, source),
+            None => write!(f, "{}", self.instance),
+        }
+    }
+}
+
+/// A list of PID values, written separated by commas, e.g. "1.1,2.1".
+///
+/// Values separated by commas or semicolons are parsed, malformed values are left out.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::vcard::value::value_pid::{Pid, ValuePidData};
+///
+/// let data = ValuePidData::try_from("1.1;2.2,3").expect("Unable to parse pids.");
+/// assert_eq!(data.pids(), [Pid { instance: 1, source: Some(1) }, Pid { instance: 2, source: Some(2) }, Pid::from(3)]);
+/// assert_eq!(data.to_string(), "1.1,2.2,3");
+/// assert_eq!(ValuePidData::from(data.pids()), data);
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ValuePidData {
-    pub value: Vec<(i32, Option<i32>)>,
+    pub value: Vec<(i64, Option<i64>)>,
 }
 
-impl From<Vec<(i32, Option<i32>)>> for ValuePidData {
-    fn from(value: Vec<(i32, Option<i32>)>) -> Self {
+impl From<Vec<(i64, Option<i64>)>> for ValuePidData {
+    fn from(value: Vec<(i64, Option<i64>)>) -> Self {
         Self { value }
     }
 }
 
+impl From<Vec<Pid>> for ValuePidData {
+    fn from(pids: Vec<Pid>) -> Self {
+        Self {
+            value: pids.into_iter().map(<(i64, Option<i64>)>::from).collect(),
+        }
+    }
+}
+
+impl ValuePidData {
+    /// Get the PID values.
+    pub fn pids(&self) -> Vec<Pid> {
+        self.value.iter().copied().map(Pid::from).collect()
+    }
+}
+
 impl TryFrom<&str> for ValuePidData {
     type Error = VcardError;
     fn try_from(str: &str) -> Result<Self, Self::Error> {
         let mut value = Vec::new();
 
-        for datum in str.split(';').map(|s| s.to_string()).collect::<Vec<String>>() {
-            if let Some((a, b)) = datum.split_once('.') {
-                if let (Ok(id), Ok(cid)) = (a.parse::<i32>(), b.parse::<i32>()) {
-                    value.push((id, Some(cid)))
-                }
-            } else if let Ok(id) = datum.parse::<i32>() {
-                value.push((id, None))
-            }
+        for pid in str.split([';', ',']).filter_map(|datum| Pid::try_from(datum).ok()) {
+            value.push(pid.into())
         }
 
         if !value.is_empty() {
@@ -38,20 +120,14 @@ impl TryFrom<&str> for ValuePidData {
 
 impl Display for ValuePidData {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            self.value
-                .iter()
-                .map(|(id, cid_option)| {
-                    if let Some(cid) = cid_option {
-                        format!("{}.{}", id, cid)
-                    } else {
-                        format!("{}", id)
-                    }
-                })
-                .collect::<Vec<String>>()
-                .join(",")
-        )
+        write!(f, "{}", self.value.iter().map(|pid| Pid::from(*pid).to_string()).collect::<Vec<String>>().join(","))
+    }
+}
+
+/// Helper function parsing a PID or source identifier, which only consists of digits, returning None if it doesn't fit an i64.
+pub(crate) fn parse_digits(str: &str) -> Option<i64> {
+    if str.is_empty() || !str.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
     }
+    str.parse::<i64>().ok()
 }
@@ -2,7 +2,7 @@ use std::fmt::{Display, Formatter};
 
 use crate::parse::encoding::{escape, unescape};
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
 pub struct ValueTextData {
     pub value: String,
 }
@@ -1,11 +1,19 @@
 use std::fmt::{Display, Formatter};
 
 use time::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
-use time::{format_description, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+use time::format_description::BorrowedFormatItem;
+use time::macros::format_description;
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
 
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// Timestamp formats, compiled once instead of on every parse.
+const EXTENDED: &[BorrowedFormatItem<'_>] = format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]");
+const EXTENDED_UTC: &[BorrowedFormatItem<'_>] = format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]Z");
+const BASIC: &[BorrowedFormatItem<'_>] = format_description!("[year][month][day]T[hour][minute][second]");
+const BASIC_UTC: &[BorrowedFormatItem<'_>] = format_description!("[year][month][day]T[hour][minute][second]Z");
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ValueTimestampData {
     pub value: OffsetDateTime,
 }
@@ -22,22 +30,22 @@ impl TryFrom<&str> for ValueTimestampData {
         if let Ok(time) = OffsetDateTime::parse(str, &Iso8601::DEFAULT) {
             return Ok(Self { value: time });
         }
-        if let Ok(datetime) = PrimitiveDateTime::parse(str, &format_description::parse("[year]-[month]-[day]T[hour]:[minute]:[second]").unwrap()) {
+        if let Ok(datetime) = PrimitiveDateTime::parse(str, EXTENDED) {
             return Ok(Self {
                 value: datetime.assume_offset(UtcOffset::UTC),
             });
         }
-        if let Ok(datetime) = PrimitiveDateTime::parse(str, &format_description::parse("[year]-[month]-[day]T[hour]:[minute]:[second]Z").unwrap()) {
+        if let Ok(datetime) = PrimitiveDateTime::parse(str, EXTENDED_UTC) {
             return Ok(Self {
                 value: datetime.assume_offset(UtcOffset::UTC),
             });
         }
-        if let Ok(datetime) = PrimitiveDateTime::parse(str, &format_description::parse("[year][month][day]T[hour][minute][second]").unwrap()) {
+        if let Ok(datetime) = PrimitiveDateTime::parse(str, BASIC) {
             return Ok(Self {
                 value: datetime.assume_offset(UtcOffset::UTC),
             });
         }
-        if let Ok(datetime) = PrimitiveDateTime::parse(str, &format_description::parse("[year][month][day]T[hour][minute][second]Z").unwrap()) {
+        if let Ok(datetime) = PrimitiveDateTime::parse(str, BASIC_UTC) {
             return Ok(Self {
                 value: datetime.assume_offset(UtcOffset::UTC),
             });
@@ -1,26 +1,184 @@
 use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
 
+#[cfg(feature = "validation-url")]
 use url::Url;
 
+use crate::parse::encoding::{base64_decode, percent_decode};
 use crate::VcardError;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// Uri values are compared semantically, ignoring the case of the scheme and host, while the value is kept as written.
+#[derive(Clone, Debug, Default)]
 pub struct ValueUriData {
     pub value: String,
 }
 
+/// Content referenced by a uri value, either decoded inline data or a remote resource that has to be fetched.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum ValueUriContent {
+    Inline { mediatype: Option<String>, bytes: Vec<u8> },
+    Remote { mediatype: Option<String>, uri: String },
+}
+
+impl ValueUriData {
+    /// Helper function returning the uri with the scheme and host lowercased, for comparisons.
+    pub(crate) fn normalized(&self) -> String {
+        let Some((scheme, rest)) = self.value.split_once(':') else {
+            return self.value.clone();
+        };
+
+        let rest = match rest.strip_prefix("//") {
+            Some(hierarchy) => {
+                let end = hierarchy.find(['/', '?', '#']).unwrap_or(hierarchy.len());
+                let (authority, path) = hierarchy.split_at(end);
+                let (userinfo, host) = match authority.rsplit_once('@') {
+                    Some((userinfo, host)) => (format!("{}@", userinfo), host),
+                    None => (String::new(), authority),
+                };
+                format!("//{}{}{}", userinfo, host.to_lowercase(), path)
+            }
+            None => rest.to_string(),
+        };
+
+        format!("{}:{}", scheme.to_lowercase(), rest)
+    }
+
+    /// Get the lowercase scheme of the uri, e.g. "mailto" for "mailto:user@example.com".
+    pub fn scheme(&self) -> String {
+        self.value.split_once(':').map(|(scheme, _)| scheme).unwrap_or_default().to_ascii_lowercase()
+    }
+
+    /// Check whether the uri uses one of the given schemes, the comparison is case-insensitive.
+    pub fn has_scheme(&self, schemes: &[&str]) -> bool {
+        schemes.iter().any(|scheme| scheme.eq_ignore_ascii_case(self.scheme().as_str()))
+    }
+
+    /// Decode a data uri, returning the optional mediatype and the decoded bytes, see [RFC 2397](https://datatracker.ietf.org/doc/html/rfc2397).
+    ///
+    /// Returns None if the uri isn't a data uri or the data can't be decoded.
+    pub fn decode_data(&self) -> Option<(Option<String>, Vec<u8>)> {
+        if !self.has_scheme(&["data"]) {
+            return None;
+        }
+
+        let (header, data) = self.value[5..].split_once(',')?;
+        let (mediatype, bytes) = match header.strip_suffix(";base64") {
+            Some(mediatype) => (mediatype, base64_decode(String::from_utf8(percent_decode(data)).ok()?.as_str())?),
+            None => (header, percent_decode(data)),
+        };
+
+        if mediatype.is_empty() {
+            Some((None, bytes))
+        } else {
+            Some((Some(String::from_utf8(percent_decode(mediatype)).ok()?), bytes))
+        }
+    }
+
+    /// Get the content of the uri, reconciling the data uri mediatype with a mediatype from the MEDIATYPE parameter.
+    ///
+    /// The data uri mediatype describes the embedded bytes, so it takes precedence. Returns None for malformed data uris.
+    pub fn content(&self, mediatype: Option<String>) -> Option<ValueUriContent> {
+        if self.has_scheme(&["data"]) {
+            let (inline, bytes) = self.decode_data()?;
+            return Some(ValueUriContent::Inline {
+                mediatype: inline.or(mediatype),
+                bytes,
+            });
+        }
+        Some(ValueUriContent::Remote {
+            mediatype,
+            uri: self.value.clone(),
+        })
+    }
+
+    /// Return an error if the uri doesn't use one of the schemes allowed for a property.
+    pub fn validate_scheme(&self, schemes: &[&str], property_name: &str) -> Result<(), VcardError> {
+        if !self.has_scheme(schemes) {
+            return Err(VcardError::ValueInvalid(self.value.clone(), property_name.to_string()));
+        }
+        Ok(())
+    }
+}
+
 impl TryFrom<&str> for ValueUriData {
     type Error = VcardError;
     fn try_from(str: &str) -> Result<Self, Self::Error> {
-        match Url::parse(str) {
-            Ok(url) => Ok(Self { value: url.to_string() }),
-            Err(_) => Err(VcardError::ValueMalformed(str.to_string())),
+        match parse_uri(str) {
+            Some(uri) => Ok(Self {
+                value: normalize_percent_encoding(uri.as_str()),
+            }),
+            None => Err(VcardError::ValueMalformed(str.to_string())),
         }
     }
 }
 
+impl PartialEq for ValueUriData {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value || self.normalized() == other.normalized()
+    }
+}
+
+impl Eq for ValueUriData {}
+
+impl Hash for ValueUriData {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalized().hash(state);
+    }
+}
+
 impl Display for ValueUriData {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.value)
     }
 }
+
+/// Helper function parsing and normalizing a uri with the url crate, returning None if the uri is invalid.
+#[cfg(feature = "validation-url")]
+pub(crate) fn parse_uri(str: &str) -> Option<String> {
+    Url::parse(str).ok().map(String::from)
+}
+
+/// Helper function checking the uri syntax without the url crate, returning None if the uri is invalid.
+///
+/// Only the scheme is checked and lowercased, see [RFC 3986 3.1](https://datatracker.ietf.org/doc/html/rfc3986#section-3.1),
+/// the rest of the uri is kept as is if it has no whitespace or control characters.
+#[cfg(not(feature = "validation-url"))]
+pub(crate) fn parse_uri(str: &str) -> Option<String> {
+    let (scheme, rest) = str.split_once(':')?;
+
+    let mut chars = scheme.chars();
+    if !chars.next().is_some_and(|c| c.is_ascii_alphabetic()) || !chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.')) {
+        return None;
+    }
+    if rest.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return None;
+    }
+
+    Some(format!("{}:{}", scheme.to_ascii_lowercase(), rest))
+}
+
+/// Normalize percent-encoded octets as per [RFC 3986 Section 6.2.2](https://datatracker.ietf.org/doc/html/rfc3986#section-6.2.2),
+/// uppercasing hex digits and decoding octets that represent unreserved characters.
+fn normalize_percent_encoding(str: &str) -> String {
+    let bytes = str.as_bytes();
+    let mut normalized = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() && bytes[i + 1].is_ascii_hexdigit() && bytes[i + 2].is_ascii_hexdigit() {
+            let octet = u8::from_str_radix(&str[i + 1..i + 3], 16).unwrap_or_default();
+            if octet.is_ascii_alphanumeric() || matches!(octet, b'-' | b'.' | b'_' | b'~') {
+                normalized.push(octet);
+            } else {
+                normalized.push(b'%');
+                normalized.extend(bytes[i + 1..i + 3].to_ascii_uppercase());
+            }
+            i += 3;
+        } else {
+            normalized.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    String::from_utf8(normalized).unwrap_or_else(|_| str.to_string())
+}
@@ -1,12 +1,39 @@
 use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
 
 use crate::VcardError;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// UTC offsets are compared by their sign, hours and minutes, e.g. "+05", "+0500" and "+05:00" are equal.
+#[derive(Clone, Debug)]
 pub struct ValueUtcOffsetData {
     pub value: String,
 }
 
+impl ValueUtcOffsetData {
+    /// Helper function returning the offset as a sign followed by four digits, for comparisons.
+    pub(crate) fn normalized(&self) -> String {
+        let mut string: String = self.value.trim().chars().filter(|c| *c != ':').collect();
+        if string.len() == 3 {
+            string.push_str("00");
+        }
+        string
+    }
+}
+
+impl PartialEq for ValueUtcOffsetData {
+    fn eq(&self, other: &Self) -> bool {
+        self.normalized() == other.normalized()
+    }
+}
+
+impl Eq for ValueUtcOffsetData {}
+
+impl Hash for ValueUtcOffsetData {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalized().hash(state);
+    }
+}
+
 impl Default for ValueUtcOffsetData {
     fn default() -> Self {
         Self { value: String::from("+0000") }
@@ -16,8 +43,21 @@ impl Default for ValueUtcOffsetData {
 impl TryFrom<&str> for ValueUtcOffsetData {
     type Error = VcardError;
 
-    // TODO: Add utcoffset validator.
+    /// Accepts a sign followed by the hours and optional minutes, e.g. "-05", "-0500" or "-05:00", see [RFC 6350 4.7](https://datatracker.ietf.org/doc/html/rfc6350#section-4.7).
     fn try_from(str: &str) -> Result<Self, Self::Error> {
+        let malformed = || VcardError::ValueMalformed(str.to_string());
+
+        let digits = str.strip_prefix(['+', '-']).ok_or_else(malformed)?;
+        let (hours, minutes) = match digits.len() {
+            2 => (digits, "00"),
+            4 if digits.is_char_boundary(2) => digits.split_at(2),
+            5 if digits.get(2..3) == Some(":") => (&digits[..2], &digits[3..]),
+            _ => return Err(malformed()),
+        };
+        if !hours.chars().chain(minutes.chars()).all(|c| c.is_ascii_digit()) || hours > "23" || minutes > "59" {
+            return Err(malformed());
+        }
+
         Ok(Self { value: str.to_string() })
     }
 }
@@ -37,8 +77,15 @@ mod tests {
         assert!(ValueUtcOffsetData::try_from("+00:00").is_ok());
         assert!(ValueUtcOffsetData::try_from("-23:59").is_ok());
         assert!(ValueUtcOffsetData::try_from("+23:59").is_ok());
-        // assert!(ValueUtcOffsetData::try_from("-24:00").is_err());
-        // assert!(ValueUtcOffsetData::try_from("+24:00").is_err());
+        assert!(ValueUtcOffsetData::try_from("-0500").is_ok());
+        assert!(ValueUtcOffsetData::try_from("+05").is_ok());
+        assert!(ValueUtcOffsetData::try_from("-24:00").is_err());
+        assert!(ValueUtcOffsetData::try_from("+24:00").is_err());
+        assert!(ValueUtcOffsetData::try_from("+05:60").is_err());
+        assert!(ValueUtcOffsetData::try_from("0500").is_err());
+        assert!(ValueUtcOffsetData::try_from("+5").is_err());
+        assert!(ValueUtcOffsetData::try_from("+05-00").is_err());
+        assert!(ValueUtcOffsetData::try_from("America/New_York").is_err());
     }
 
     #[test]
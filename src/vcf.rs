@@ -0,0 +1,129 @@
+//! Fast, non-validating utilities for splitting and merging multi-card VCF files.
+//!
+//! The functions only look at BEGIN:VCARD, END:VCARD and UID lines, so invalid vCards are passed through as is. This
+//! makes them suitable for sharding large files or combining exports before parsing, e.g. in mailbox tooling.
+//!
+//! Group cards (KIND:group) reference their members by UID, so merged files keep one card per UID and group members
+//! stay resolvable.
+
+use crate::constants::PropertyName;
+
+/// Split a VCF file into one string per vCard, keeping the original lines and line endings.
+///
+/// Text outside of BEGIN:VCARD and END:VCARD is dropped, a last vCard without an END:VCARD line is kept as is.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::vcf::split_vcf;
+///
+/// let cards = split_vcf("BEGIN:VCARD\r\nFN:John Doe\r\nEND:VCARD\r\nBEGIN:VCARD\r\nFN:Jane Roe\r\nEND:VCARD\r\n");
+/// assert_eq!(cards, Vec::from(["BEGIN:VCARD\r\nFN:John Doe\r\nEND:VCARD\r\n", "BEGIN:VCARD\r\nFN:Jane Roe\r\nEND:VCARD\r\n"]));
+/// ```
+pub fn split_vcf(input: &str) -> Vec<String> {
+    let mut cards = Vec::new();
+    let mut current: Option<String> = None;
+
+    for line in input.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.eq_ignore_ascii_case("BEGIN:VCARD") {
+            if let Some(card) = current.take() {
+                cards.push(card);
+            }
+            current = Some(String::new());
+        }
+
+        if let Some(card) = current.as_mut() {
+            card.push_str(line);
+            if trimmed.eq_ignore_ascii_case("END:VCARD") {
+                if !card.ends_with('\n') {
+                    card.push('\n');
+                }
+                cards.extend(current.take());
+            }
+        }
+    }
+    cards.extend(current);
+
+    cards
+}
+
+/// Merge VCF files into one, keeping only the first vCard for each UID.
+///
+/// vCards without a UID are kept unless they are identical to a previous vCard.
+///
+/// # Examples
+/// ```
+/// use vcard_parser::vcf::merge_vcf;
+///
+/// let a = "BEGIN:VCARD\nVERSION:4.0\nUID:urn:uuid:1\nFN:John Doe\nEND:VCARD\n";
+/// let b = "BEGIN:VCARD\nVERSION:4.0\nUID:urn:uuid:1\nFN:Johnny Doe\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nEND:VCARD\n";
+///
+/// let merged = merge_vcf(&[a, b]);
+/// assert_eq!(merged, "BEGIN:VCARD\nVERSION:4.0\nUID:urn:uuid:1\nFN:John Doe\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Jane Roe\nEND:VCARD\n");
+/// ```
+pub fn merge_vcf(files: &[&str]) -> String {
+    let mut string = String::with_capacity(files.iter().map(|file| file.len()).sum());
+    let mut uids = Vec::new();
+    let mut cards = Vec::new();
+
+    for card in files.iter().flat_map(|file| split_vcf(file)) {
+        match vcf_uid(&card) {
+            Some(uid) if uids.contains(&uid) => continue,
+            Some(uid) => uids.push(uid),
+            None if cards.contains(&card) => continue,
+            None => cards.push(card.clone()),
+        }
+        string.push_str(&card);
+    }
+
+    string
+}
+
+/// Helper function returning the value of the first UID line of a vCard.
+fn vcf_uid(card: &str) -> Option<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in card.lines() {
+        match (lines.last_mut(), line.strip_prefix([' ', '\t'])) {
+            (Some(last), Some(folded)) => last.push_str(folded),
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    lines.iter().find_map(|line| {
+        let (head, value) = line.split_once(':')?;
+        let name = head.split(';').next()?;
+        let name = name.rsplit('.').next()?;
+        name.trim().eq_ignore_ascii_case(PropertyName::UID).then(|| value.trim().to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vcf::{merge_vcf, split_vcf};
+
+    #[test]
+    fn vcf_split() {
+        let input = "Some preamble\nBEGIN:VCARD\nFN:John\n Doe\nEND:VCARD\n\nbegin:vcard\r\nFN:Jane\r\nend:vcard\nBEGIN:VCARD\nFN:Unterminated\nBEGIN:VCARD\nFN:Last\nEND:VCARD";
+        assert_eq!(
+            split_vcf(input),
+            Vec::from([
+                "BEGIN:VCARD\nFN:John\n Doe\nEND:VCARD\n",
+                "begin:vcard\r\nFN:Jane\r\nend:vcard\n",
+                "BEGIN:VCARD\nFN:Unterminated\n",
+                "BEGIN:VCARD\nFN:Last\nEND:VCARD\n",
+            ])
+        );
+        assert!(split_vcf("").is_empty());
+    }
+
+    #[test]
+    fn vcf_merge() {
+        let a = "BEGIN:VCARD\nVERSION:4.0\nKIND:group\nFN:Friends\nitem1.UID;VALUE=uri:urn:uuid:\n group\nMEMBER:urn:uuid:1\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:No UID\nEND:VCARD\n";
+        let b = "BEGIN:VCARD\nVERSION:4.0\nUID:urn:uuid:group\nFN:Friends (copy)\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:No UID\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nUID:urn:uuid:1\nFN:John Doe\nEND:VCARD\n";
+
+        let merged = merge_vcf(&[a, b]);
+        assert_eq!(split_vcf(&merged).len(), 3);
+        assert_eq!(merged, format!("{}BEGIN:VCARD\nVERSION:4.0\nUID:urn:uuid:1\nFN:John Doe\nEND:VCARD\n", a));
+        assert_eq!(crate::parse_vcards(&merged).unwrap().len(), 3);
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
     use std::fs::read_to_string;
+    use vcard_parser::lint::{fix, lint};
     use vcard_parser::parse_vcards;
 
     #[test]
@@ -8,6 +9,19 @@ mod tests {
         assert!(parse_vcards(read_to_string("tests/assets/concat.vcf").unwrap().as_str()).is_ok());
     }
 
+    #[test]
+    fn lint_fix() {
+        for file in [
+            "concat", "multiple", "photo", "single",
+        ] {
+            let input = read_to_string(format!("tests/assets/{}.vcf", file)).unwrap();
+            let fixed = fix(input.as_str());
+
+            assert!(lint(fixed.as_str()).is_empty());
+            assert_eq!(parse_vcards(fixed.as_str()).unwrap().len(), parse_vcards(input.as_str()).unwrap().len());
+        }
+    }
+
     #[test]
     fn multiple() {
         assert!(parse_vcards(read_to_string("tests/assets/multiple.vcf").unwrap().as_str()).is_ok());